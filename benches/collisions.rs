@@ -0,0 +1,63 @@
+//! Mesure du temps de recherche des collisions entre asteroides et missiles,
+//! en testant toutes les paires ou seulement les paires proches dans la grille.
+//! Lancer avec `cargo bench --bench collisions`.
+use ::rand::rngs::StdRng;
+use ::rand::{Rng, SeedableRng};
+use asteroid::asteroid::{Asteroid, Size};
+use asteroid::missile::Missile;
+use asteroid::spatial_grid::overlapping_pairs;
+use asteroid::stellarobject::{wrapped_distance, StellarObject};
+use macroquad::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Taille de l'écran simulé
+const BOUNDS: Vec2 = vec2(800.0, 600.0);
+/// Nombre de recherches mesurées pour chaque méthode
+const ROUNDS: u32 = 2000;
+
+/// Teste toutes les paires, comme le faisait `check_collision` avant la grille
+fn brute_force(first: &[(Vec2, f32)], second: &[(Vec2, f32)]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, (pos, radius)) in first.iter().enumerate() {
+        for (j, (other, other_radius)) in second.iter().enumerate() {
+            if wrapped_distance(*pos, *other, BOUNDS) < radius + other_radius {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+/// Mesure la durée moyenne d'une recherche
+fn measure(search: impl Fn() -> Vec<(usize, usize)>) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(search());
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(553);
+    let mut random_pos = || vec2(rng.gen_range(0.0..BOUNDS.x), rng.gen_range(0.0..BOUNDS.y));
+    let sizes = [Size::Large, Size::Medium, Size::Small];
+    let asteroids: Vec<(Vec2, f32)> = (0..200)
+        .map(|i| Asteroid::new_with_size(sizes[i % sizes.len()], random_pos(), Vec2::ZERO))
+        .map(|a| (a.get_pos(), a.radius()))
+        .collect();
+    let missiles: Vec<(Vec2, f32)> = (0..100)
+        .map(|_| Missile::new(random_pos(), 0.0))
+        .map(|m| (m.get_pos(), m.radius()))
+        .collect();
+
+    assert_eq!(
+        brute_force(&asteroids, &missiles),
+        overlapping_pairs(&asteroids, &missiles, BOUNDS)
+    );
+    let brute = measure(|| brute_force(&asteroids, &missiles));
+    let grid = measure(|| overlapping_pairs(&asteroids, &missiles, BOUNDS));
+    println!("200 asteroides x 100 missiles");
+    println!("  toutes les paires : {brute:?}");
+    println!("  grille            : {grid:?}");
+}
@@ -0,0 +1,168 @@
+//! Module pour compter les objets créés et supprimés pendant la partie.
+//! Un objet désactivé (`active = false`) doit être retiré de sa liste à l'image suivante :
+//! si une liste garde trop d'objets inactifs pendant plusieurs secondes, un `retain` a été oublié
+//! et la liste grossit sans fin. Ce cas est signalé par une assertion en mode debug,
+//! et par un avertissement dans la console en mode release.
+use crate::pool::Pool;
+
+/// Nombre d'objets inactifs gardés dans une liste au-delà duquel une fuite est soupçonnée
+pub const LEAK_THRESHOLD: usize = 32;
+/// Durée (en secondes) pendant laquelle le seuil doit être dépassé pour signaler une fuite
+pub const LEAK_SECONDS: f32 = 3.0;
+
+/// Structure comptant les objets d'un type
+/// # Champs
+/// - `spawned`: le nombre d'objets ajoutés à la liste depuis le début
+/// - `despawned`: le nombre d'objets retirés de la liste depuis le début
+/// - `len`: la longueur de la liste à la dernière observation
+/// - `active`: le nombre d'objets actifs à la dernière observation
+/// - `leak_timer`: le temps depuis lequel la liste garde trop d'objets inactifs
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct EntityCounter {
+    pub spawned: u64,
+    pub despawned: u64,
+    pub len: usize,
+    pub active: usize,
+    leak_timer: f32,
+}
+
+impl EntityCounter {
+    /// Garde les objets qui vérifient `keep` et compte ceux qui sont retirés
+    /// # Arguments
+    /// - `items`: la liste des objets
+    /// - `keep`: la condition pour garder un objet
+    pub fn retain<T>(&mut self, items: &mut Vec<T>, keep: impl FnMut(&T) -> bool) {
+        let before = items.len();
+        items.retain(keep);
+        self.despawned += (before - items.len()) as u64;
+    }
+
+    /// Vide la liste et compte les objets retirés
+    /// # Arguments
+    /// - `items`: la liste des objets
+    pub fn clear<T>(&mut self, items: &mut Vec<T>) {
+        self.despawned += items.len() as u64;
+        items.clear();
+    }
+
+    /// Rend les places des objets d'une réserve qui ne vérifient pas `keep`, et les compte comme retirés
+    /// # Arguments
+    /// - `pool`: la réserve des objets
+    /// - `keep`: la condition pour garder un objet
+    pub fn release<T>(&mut self, pool: &mut Pool<T>, keep: impl FnMut(&T) -> bool) {
+        self.despawned += pool.release(keep) as u64;
+    }
+
+    /// Vide une réserve et compte les objets retirés
+    /// # Arguments
+    /// - `pool`: la réserve des objets
+    pub fn clear_pool<T>(&mut self, pool: &mut Pool<T>) {
+        self.despawned += pool.clear() as u64;
+    }
+
+    /// Observe la liste à la fin d'une image.
+    /// Tous les retraits passant par `retain`, `release` et `clear`, les objets ajoutés se déduisent de la longueur.
+    /// # Arguments
+    /// - `len`: la longueur de la liste
+    /// - `active`: le nombre d'objets actifs dans la liste
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// # Returns
+    /// - `bool`: `true` si la liste garde plus de `LEAK_THRESHOLD` objets inactifs depuis `LEAK_SECONDS`,
+    ///   signalé une seule fois par fuite
+    pub fn observe(&mut self, len: usize, active: usize, dt: f32) -> bool {
+        self.spawned = self.despawned + len as u64;
+        self.len = len;
+        self.active = active;
+        if len.saturating_sub(active) <= LEAK_THRESHOLD {
+            self.leak_timer = 0.0;
+            return false;
+        }
+        let was_reported = self.leak_timer >= LEAK_SECONDS;
+        self.leak_timer += dt;
+        !was_reported && self.leak_timer >= LEAK_SECONDS
+    }
+}
+
+/// Structure regroupant les compteurs des objets de la partie
+/// # Champs
+/// - `asteroids`: les asteroides
+/// - `missiles`: les missiles, ceux du joueur et ceux des ennemis
+/// - `black_holes`: les trous noirs
+/// - `ufos`: les soucoupes
+/// - `power_ups`: les bonus
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Accounting {
+    pub asteroids: EntityCounter,
+    pub missiles: EntityCounter,
+    pub black_holes: EntityCounter,
+    pub ufos: EntityCounter,
+    pub power_ups: EntityCounter,
+}
+
+impl Accounting {
+    /// Fonction qui renvoie les compteurs avec le nom de leur type, pour l'affichage de développement
+    /// # Returns
+    /// - `[(&str, &EntityCounter); 5]`: les compteurs nommés
+    pub fn entries(&self) -> [(&'static str, &EntityCounter); 5] {
+        [
+            ("asteroids", &self.asteroids),
+            ("missiles", &self.missiles),
+            ("black_holes", &self.black_holes),
+            ("ufos", &self.ufos),
+            ("power_ups", &self.power_ups),
+        ]
+    }
+}
+
+/// Signale une fuite : assertion en mode debug, avertissement dans la console en mode release
+/// # Arguments
+/// - `name`: le type des objets qui fuient
+/// - `counter`: le compteur de ce type
+pub fn report_leak(name: &str, counter: &EntityCounter) {
+    let message = format!(
+        "Fuite possible : {} objets dans `{}` dont seulement {} actifs depuis {} secondes",
+        counter.len, name, counter.active, LEAK_SECONDS
+    );
+    if cfg!(debug_assertions) {
+        panic!("{message}");
+    } else {
+        log::error!("{message}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que les objets ajoutés et retirés sont comptés.
+    #[test]
+    fn test_spawn_and_despawn_counts() {
+        let mut counter = EntityCounter::default();
+        let mut items = vec![1, 2, 3, 4];
+        counter.observe(items.len(), 4, 0.1);
+
+        counter.retain(&mut items, |i| i % 2 == 0);
+        items.push(6);
+        counter.observe(items.len(), 3, 0.1);
+        assert_eq!((counter.spawned, counter.despawned), (5, 2));
+
+        counter.clear(&mut items);
+        counter.observe(items.len(), 0, 0.1);
+        assert_eq!((counter.spawned, counter.despawned), (5, 5));
+    }
+
+    /// Vérifie qu'une liste qui garde ses objets inactifs est signalée une seule fois,
+    /// après `LEAK_SECONDS`, et qu'un retour à la normale relance la surveillance.
+    #[test]
+    fn test_leak_heuristic() {
+        let mut counter = EntityCounter::default();
+        let len = LEAK_THRESHOLD + 10;
+
+        let reports = (0..300).filter(|_| counter.observe(len, 5, 0.1)).count();
+        assert_eq!(reports, 1);
+
+        assert!(!counter.observe(5, 5, 0.1));
+        let first = (0..300).position(|_| counter.observe(len, 5, 0.5));
+        assert_eq!(first, Some((LEAK_SECONDS / 0.5) as usize - 1));
+    }
+}
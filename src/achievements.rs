@@ -0,0 +1,531 @@
+//! Module pour les succès, débloqués une seule fois en jouant.
+//! Les succès sont évalués à partir des événements de la partie, comme les astuces,
+//! et des statistiques de la partie relues à chaque image. Un succès débloqué est
+//! enregistré dans un fichier, annoncé par une notification qui glisse dans le coin
+//! de l'écran, et n'est plus jamais annoncé.
+use asteroid::audio::SoundEvent;
+use asteroid::platform;
+use asteroid::run_stats::RunStats;
+use asteroid::text::{Fonts, TextStyle};
+use asteroid::world::TemporaryText;
+use macroquad::prelude::*;
+use std::collections::{BTreeSet, VecDeque};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier des succès débloqués
+const ACHIEVEMENTS_FILE: &str = "achievements.txt";
+/// Nombre maximal de tirs pour finir une vague en tireur d'élite
+const SHARPSHOOTER_SHOTS: u32 = 10;
+/// Temps de survie (en secondes) du succès de survie
+const SURVIVOR_TIME: f32 = 300.0;
+/// Score du succès de score
+const HIGH_SCORE: i32 = 500;
+/// Nombre d'asteroides détruits pendant une partie pour le succès de démolition
+const DEMOLITION_ASTEROIDS: u32 = 100;
+/// Nombre de bonus ramassés pendant une partie pour le succès de collection
+const COLLECTOR_POWER_UPS: u32 = 5;
+/// Durée (en secondes) d'affichage de la notification d'un succès
+pub const TOAST_LIFETIME: f32 = 4.0;
+/// Durée (en secondes) pendant laquelle la notification glisse depuis le bord de l'écran
+const TOAST_SLIDE_TIME: f32 = 0.3;
+
+/// Énumération représentant les succès du jeu
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Achievement {
+    FirstAsteroid,
+    FirstBlackHole,
+    UfoHunter,
+    Wave5,
+    Wave10,
+    Sharpshooter,
+    Untouchable,
+    Survivor,
+    Score500,
+    Demolition,
+    Collector,
+    BossSlayer,
+}
+
+impl Achievement {
+    /// Tous les succès, dans l'ordre de la liste des succès
+    pub const ALL: [Achievement; 12] = [
+        Achievement::FirstAsteroid,
+        Achievement::FirstBlackHole,
+        Achievement::UfoHunter,
+        Achievement::Wave5,
+        Achievement::Wave10,
+        Achievement::Sharpshooter,
+        Achievement::Untouchable,
+        Achievement::Survivor,
+        Achievement::Score500,
+        Achievement::Demolition,
+        Achievement::Collector,
+        Achievement::BossSlayer,
+    ];
+
+    /// Fonction qui renvoie l'identifiant du succès, enregistré dans le fichier des succès
+    /// # Returns
+    /// - `&str`: l'identifiant
+    pub fn id(self) -> &'static str {
+        match self {
+            Achievement::FirstAsteroid => "first_asteroid",
+            Achievement::FirstBlackHole => "first_black_hole",
+            Achievement::UfoHunter => "ufo_hunter",
+            Achievement::Wave5 => "wave_5",
+            Achievement::Wave10 => "wave_10",
+            Achievement::Sharpshooter => "sharpshooter",
+            Achievement::Untouchable => "untouchable",
+            Achievement::Survivor => "survivor",
+            Achievement::Score500 => "score_500",
+            Achievement::Demolition => "demolition",
+            Achievement::Collector => "collector",
+            Achievement::BossSlayer => "boss_slayer",
+        }
+    }
+
+    /// Fonction qui renvoie la clé de traduction du nom du succès
+    /// # Returns
+    /// - `&str`: la clé du nom
+    pub fn key(self) -> &'static str {
+        match self {
+            Achievement::FirstAsteroid => "achievement.first_asteroid",
+            Achievement::FirstBlackHole => "achievement.first_black_hole",
+            Achievement::UfoHunter => "achievement.ufo_hunter",
+            Achievement::Wave5 => "achievement.wave_5",
+            Achievement::Wave10 => "achievement.wave_10",
+            Achievement::Sharpshooter => "achievement.sharpshooter",
+            Achievement::Untouchable => "achievement.untouchable",
+            Achievement::Survivor => "achievement.survivor",
+            Achievement::Score500 => "achievement.score_500",
+            Achievement::Demolition => "achievement.demolition",
+            Achievement::Collector => "achievement.collector",
+            Achievement::BossSlayer => "achievement.boss_slayer",
+        }
+    }
+
+    /// Fonction qui renvoie la clé de traduction de la condition du succès
+    /// # Returns
+    /// - `&str`: la clé de la description
+    pub fn description_key(self) -> &'static str {
+        match self {
+            Achievement::FirstAsteroid => "achievement.first_asteroid.desc",
+            Achievement::FirstBlackHole => "achievement.first_black_hole.desc",
+            Achievement::UfoHunter => "achievement.ufo_hunter.desc",
+            Achievement::Wave5 => "achievement.wave_5.desc",
+            Achievement::Wave10 => "achievement.wave_10.desc",
+            Achievement::Sharpshooter => "achievement.sharpshooter.desc",
+            Achievement::Untouchable => "achievement.untouchable.desc",
+            Achievement::Survivor => "achievement.survivor.desc",
+            Achievement::Score500 => "achievement.score_500.desc",
+            Achievement::Demolition => "achievement.demolition.desc",
+            Achievement::Collector => "achievement.collector.desc",
+            Achievement::BossSlayer => "achievement.boss_slayer.desc",
+        }
+    }
+
+    /// Fonction qui renvoie la couleur de l'icône du succès
+    /// # Returns
+    /// - `Color`: la couleur de l'icône
+    pub fn icon_color(self) -> Color {
+        match self {
+            Achievement::FirstAsteroid | Achievement::Demolition => ORANGE,
+            Achievement::FirstBlackHole => PURPLE,
+            Achievement::UfoHunter | Achievement::BossSlayer => RED,
+            Achievement::Wave5 | Achievement::Wave10 => SKYBLUE,
+            Achievement::Sharpshooter | Achievement::Untouchable => GREEN,
+            Achievement::Survivor | Achievement::Collector => PINK,
+            Achievement::Score500 => GOLD,
+        }
+    }
+}
+
+/// Énumération représentant les événements utiles pour évaluer les succès
+/// # Variantes
+/// - `RunStarted`: une partie commence
+/// - `WaveCleared`: la vague en cours a été terminée
+/// - `ShotFired`: le vaisseau a tiré un missile
+/// - `ShieldLost`: le vaisseau a perdu son bouclier
+/// - `UfoDestroyed`: une soucoupe a été détruite
+/// - `BossDestroyed`: le vaisseau mère a été détruit
+/// - `PowerUpCollected`: le vaisseau a ramassé un bonus
+/// - `Progress`: les statistiques de la partie à la fin de l'image
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AchievementEvent {
+    RunStarted,
+    WaveCleared,
+    ShotFired,
+    ShieldLost,
+    UfoDestroyed,
+    BossDestroyed,
+    PowerUpCollected,
+    Progress(RunStats),
+}
+
+impl AchievementEvent {
+    /// Fonction qui traduit un événement sonore en événement utile pour les succès
+    /// # Arguments
+    /// - `event`: l'événement sonore
+    /// # Returns
+    /// - `Option<AchievementEvent>`: l'événement correspondant, ou `None` s'il n'est pas utile
+    pub fn from_sound(event: SoundEvent) -> Option<Self> {
+        match event {
+            SoundEvent::StartGame => Some(AchievementEvent::RunStarted),
+            SoundEvent::NewWave => Some(AchievementEvent::WaveCleared),
+            SoundEvent::MissileFired => Some(AchievementEvent::ShotFired),
+            SoundEvent::ShieldLost(_) => Some(AchievementEvent::ShieldLost),
+            SoundEvent::UfoDestroyed(_) => Some(AchievementEvent::UfoDestroyed),
+            SoundEvent::BossDestroyed(_) => Some(AchievementEvent::BossDestroyed),
+            SoundEvent::PowerUpCollected(_) => Some(AchievementEvent::PowerUpCollected),
+            _ => None,
+        }
+    }
+}
+
+/// Structure qui évalue les succès pendant une partie
+/// # Champs
+/// - `wave_shots`: le nombre de tirs pendant la vague en cours
+/// - `wave_shield_lost`: permet de savoir si le bouclier a été perdu pendant la vague en cours
+/// - `power_ups`: le nombre de bonus ramassés pendant la partie
+#[derive(Default)]
+pub struct AchievementTracker {
+    wave_shots: u32,
+    wave_shield_lost: bool,
+    power_ups: u32,
+}
+
+impl AchievementTracker {
+    /// Crée un suivi pour une nouvelle partie
+    /// # Returns
+    /// - `Self`: le suivi des succès
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prend en compte un événement, et renvoie les succès dont la condition est remplie.
+    /// Les succès déjà débloqués peuvent être renvoyés à nouveau : c'est `Achievements::unlock`
+    /// qui les écarte.
+    /// # Arguments
+    /// - `event`: l'événement de la partie
+    /// # Returns
+    /// - `Vec<Achievement>`: les succès mérités par l'événement
+    pub fn record(&mut self, event: AchievementEvent) -> Vec<Achievement> {
+        match event {
+            AchievementEvent::RunStarted => {
+                *self = Self::new();
+                Vec::new()
+            }
+            AchievementEvent::WaveCleared => {
+                let mut earned = Vec::new();
+                if self.wave_shots <= SHARPSHOOTER_SHOTS {
+                    earned.push(Achievement::Sharpshooter);
+                }
+                if !self.wave_shield_lost {
+                    earned.push(Achievement::Untouchable);
+                }
+                self.wave_shots = 0;
+                self.wave_shield_lost = false;
+                earned
+            }
+            AchievementEvent::ShotFired => {
+                self.wave_shots += 1;
+                Vec::new()
+            }
+            AchievementEvent::ShieldLost => {
+                self.wave_shield_lost = true;
+                Vec::new()
+            }
+            AchievementEvent::UfoDestroyed => vec![Achievement::UfoHunter],
+            AchievementEvent::BossDestroyed => vec![Achievement::BossSlayer],
+            AchievementEvent::PowerUpCollected => {
+                self.power_ups += 1;
+                if self.power_ups >= COLLECTOR_POWER_UPS {
+                    vec![Achievement::Collector]
+                } else {
+                    Vec::new()
+                }
+            }
+            AchievementEvent::Progress(stats) => [
+                (stats.total_asteroids() >= 1, Achievement::FirstAsteroid),
+                (
+                    stats.black_holes_destroyed >= 1,
+                    Achievement::FirstBlackHole,
+                ),
+                (stats.highest_wave >= 5, Achievement::Wave5),
+                (stats.highest_wave >= 10, Achievement::Wave10),
+                (stats.time_survived >= SURVIVOR_TIME, Achievement::Survivor),
+                (stats.score >= HIGH_SCORE, Achievement::Score500),
+                (
+                    stats.total_asteroids() >= DEMOLITION_ASTEROIDS,
+                    Achievement::Demolition,
+                ),
+            ]
+            .into_iter()
+            .filter_map(|(reached, achievement)| reached.then_some(achievement))
+            .collect(),
+        }
+    }
+}
+
+/// Structure représentant les succès débloqués par le joueur
+/// # Champs
+/// - `unlocked`: les identifiants des succès débloqués
+#[derive(Default, PartialEq, Debug)]
+pub struct Achievements {
+    unlocked: BTreeSet<String>,
+}
+
+impl Achievements {
+    /// Charge les succès débloqués depuis un fichier texte, avec un identifiant par ligne.
+    /// Si le fichier n'existe pas, aucun succès n'est débloqué.
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `Self`: les succès débloqués
+    pub fn load(path: &Path) -> Self {
+        let Ok(content) = platform::read_to_string(path) else {
+            return Self::default();
+        };
+        Self {
+            unlocked: content
+                .lines()
+                .map(str::trim)
+                .filter(|id| Achievement::ALL.iter().any(|a| a.id() == *id))
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// Sauvegarde les succès débloqués dans un fichier texte
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `io::Result<()>`: une erreur si le fichier n'a pas pu être écrit
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content: String = self.unlocked.iter().map(|id| format!("{id}\n")).collect();
+        platform::write(path, &content)
+    }
+
+    /// Fonction qui permet de savoir si un succès est débloqué
+    /// # Arguments
+    /// - `achievement`: le succès
+    /// # Returns
+    /// - `bool`: `true` si le succès est débloqué
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(achievement.id())
+    }
+
+    /// Débloque un succès
+    /// # Arguments
+    /// - `achievement`: le succès
+    /// # Returns
+    /// - `bool`: `true` si le succès vient d'être débloqué, `false` s'il l'était déjà
+    pub fn unlock(&mut self, achievement: Achievement) -> bool {
+        self.unlocked.insert(achievement.id().to_string())
+    }
+}
+
+/// Fonction qui renvoie le chemin du fichier des succès
+/// # Returns
+/// - `PathBuf`: le chemin du fichier
+pub fn achievements_path() -> PathBuf {
+    crate::profile::data_path(ACHIEVEMENTS_FILE)
+}
+
+/// Structure représentant la file des notifications de succès.
+/// Les notifications sont affichées une par une, chacune pendant `TOAST_LIFETIME` secondes,
+/// dans un texte temporaire qui s'efface comme les points gagnés.
+/// # Champs
+/// - `current`: le succès annoncé et son texte
+/// - `queue`: les succès qui attendent leur notification
+#[derive(Default)]
+pub struct AchievementToasts {
+    current: Option<(Achievement, TemporaryText)>,
+    queue: VecDeque<(Achievement, String)>,
+}
+
+impl AchievementToasts {
+    /// Ajoute la notification d'un succès à la file
+    /// # Arguments
+    /// - `achievement`: le succès débloqué
+    /// - `name`: le nom du succès, dans la langue du joueur
+    pub fn push(&mut self, achievement: Achievement, name: &str) {
+        self.queue.push_back((achievement, name.to_string()));
+    }
+
+    /// Fait vieillir la notification affichée et passe à la suivante quand elle a disparu
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        if let Some((_, text)) = &mut self.current {
+            text.lifetime -= dt;
+            if text.lifetime > 0.0 {
+                return;
+            }
+        }
+        self.current = self.queue.pop_front().map(|(achievement, name)| {
+            let mut text = TemporaryText::default();
+            text.set(format_args!("{name}"), Vec2::ZERO, GOLD, TOAST_LIFETIME);
+            (achievement, text)
+        });
+    }
+
+    /// Dessine la notification dans le coin en bas à gauche de l'écran, au-dessus des vies.
+    /// Elle glisse depuis le bord en apparaissant, puis s'efface.
+    /// # Arguments
+    /// - `title`: le titre des notifications, dans la langue du joueur
+    /// - `fonts`: les polices du jeu
+    pub fn draw(&self, title: &str, fonts: &Fonts) {
+        let Some((achievement, text)) = &self.current else {
+            return;
+        };
+        let padding = 12.0;
+        let icon_radius = 14.0;
+        let width = fonts
+            .measure(&text.text, TextStyle::Body)
+            .width
+            .max(fonts.measure(title, TextStyle::Body).width)
+            + icon_radius * 2.0
+            + padding * 3.0;
+        let height = 64.0;
+        let shown = TOAST_LIFETIME - text.lifetime;
+        let slide = (shown / TOAST_SLIDE_TIME).clamp(0.0, 1.0);
+        let x = 20.0 - (1.0 - slide) * (width + 20.0);
+        let y = screen_height() - height - 70.0;
+        let alpha = text.alpha();
+        let fade = |color: Color| Color {
+            a: color.a * alpha,
+            ..color
+        };
+
+        draw_rectangle(x, y, width, height, fade(Color::new(0.0, 0.0, 0.0, 0.8)));
+        draw_rectangle_lines(x, y, width, height, 2.0, fade(text.color));
+        let icon = vec2(x + padding + icon_radius, y + height / 2.0);
+        draw_circle(icon.x, icon.y, icon_radius, fade(achievement.icon_color()));
+        draw_poly(icon.x, icon.y, 5, icon_radius * 0.5, -90.0, fade(WHITE));
+        let text_x = icon.x + icon_radius + padding;
+        fonts.draw(title, text_x, y + 26.0, TextStyle::Body, fade(LIGHTGRAY));
+        fonts.draw(
+            &text.text,
+            text_x,
+            y + 50.0,
+            TextStyle::Body,
+            fade(text.color),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Joue une suite d'événements et renvoie les succès débloqués, chacun une seule fois
+    fn unlock_all(
+        tracker: &mut AchievementTracker,
+        achievements: &mut Achievements,
+        events: &[AchievementEvent],
+    ) -> Vec<Achievement> {
+        events
+            .iter()
+            .flat_map(|event| tracker.record(*event))
+            .filter(|achievement| achievements.unlock(*achievement))
+            .collect()
+    }
+
+    /// Crée les statistiques d'une partie à la vague donnée, avec le score donné
+    fn progress(wave: u32, score: i32) -> AchievementEvent {
+        AchievementEvent::Progress(RunStats {
+            highest_wave: wave,
+            score,
+            ..RunStats::default()
+        })
+    }
+
+    /// Vérifie que les succès de vague et de score sont débloqués une seule fois,
+    /// même si leur condition reste remplie image après image.
+    #[test]
+    fn test_progress_unlocks_once() {
+        let mut tracker = AchievementTracker::new();
+        let mut achievements = Achievements::default();
+
+        let unlocked = unlock_all(
+            &mut tracker,
+            &mut achievements,
+            &[progress(4, 100), progress(5, 100), progress(5, 600)],
+        );
+        assert_eq!(unlocked, vec![Achievement::Wave5, Achievement::Score500]);
+
+        let again = unlock_all(
+            &mut tracker,
+            &mut achievements,
+            &[AchievementEvent::RunStarted, progress(5, 600)],
+        );
+        assert!(again.is_empty(), "Un succès a été débloqué deux fois !");
+    }
+
+    /// Vérifie que le tireur d'élite compte les tirs de la vague terminée seulement,
+    /// et que le bouclier perdu empêche le succès sans dégât.
+    #[test]
+    fn test_wave_achievements() {
+        let mut tracker = AchievementTracker::new();
+        let mut achievements = Achievements::default();
+        let mut events = vec![AchievementEvent::RunStarted];
+        events.extend([AchievementEvent::ShotFired; 11]);
+        events.push(AchievementEvent::ShieldLost);
+        events.push(AchievementEvent::WaveCleared);
+        assert!(unlock_all(&mut tracker, &mut achievements, &events).is_empty());
+
+        let mut events = vec![AchievementEvent::ShieldLost];
+        events.extend([AchievementEvent::ShotFired; 10]);
+        events.push(AchievementEvent::WaveCleared);
+        assert_eq!(
+            unlock_all(&mut tracker, &mut achievements, &events),
+            vec![Achievement::Sharpshooter]
+        );
+        assert_eq!(
+            unlock_all(
+                &mut tracker,
+                &mut achievements,
+                &[AchievementEvent::WaveCleared]
+            ),
+            vec![Achievement::Untouchable]
+        );
+    }
+
+    /// Vérifie que les bonus ramassés sont comptés par partie.
+    #[test]
+    fn test_collector_counts_one_run() {
+        let mut tracker = AchievementTracker::new();
+        let mut achievements = Achievements::default();
+        let mut events = vec![AchievementEvent::PowerUpCollected; 4];
+        events.push(AchievementEvent::RunStarted);
+        events.push(AchievementEvent::PowerUpCollected);
+        assert!(unlock_all(&mut tracker, &mut achievements, &events).is_empty());
+
+        let events = [AchievementEvent::PowerUpCollected; 4];
+        assert_eq!(
+            unlock_all(&mut tracker, &mut achievements, &events),
+            vec![Achievement::Collector]
+        );
+    }
+
+    /// Vérifie que les succès débloqués sont relus depuis leur fichier,
+    /// en ignorant les identifiants inconnus.
+    #[test]
+    fn test_achievements_round_trip() {
+        let path = std::env::temp_dir().join("asteroid_test_achievements.txt");
+        let mut achievements = Achievements::default();
+        achievements.unlock(Achievement::UfoHunter);
+        achievements.unlock(Achievement::Wave10);
+        achievements.save(&path).unwrap();
+        fs::write(&path, fs::read_to_string(&path).unwrap() + "inconnu\n").unwrap();
+
+        let loaded = Achievements::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, achievements);
+        assert!(loaded.is_unlocked(Achievement::Wave10));
+        assert!(!loaded.is_unlocked(Achievement::BossSlayer));
+    }
+}
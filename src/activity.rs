@@ -0,0 +1,134 @@
+//! Module pour la jauge d'activité du mode sans fin.
+//! La jauge se remplit quand le joueur détruit des asteroides et se déplace, et se vide avec le temps.
+//! Un joueur qui se cache dans un coin gagne moins de points qu'un joueur qui prend des risques.
+
+/// Remplissage de la jauge pour chaque asteroide détruit
+pub const KILL_FILL: f32 = 0.2;
+/// Remplissage de la jauge pour 100 pixels parcourus par le vaisseau
+pub const TRAVEL_FILL: f32 = 0.01;
+/// Vidage de la jauge par seconde
+pub const DRAIN_PER_SECOND: f32 = 0.05;
+/// Niveau au-dessus duquel les points gagnés sont augmentés
+pub const HIGH_LEVEL: f32 = 0.75;
+/// Multiplicateur des points gagnés quand la jauge est vide
+pub const EMPTY_MULTIPLIER: f32 = 0.5;
+/// Multiplicateur des points gagnés quand la jauge dépasse `HIGH_LEVEL`
+pub const HIGH_MULTIPLIER: f32 = 1.25;
+
+/// Structure représentant la jauge d'activité
+/// # Champs
+/// - `level`: le niveau de la jauge, entre 0 (vide) et 1 (pleine)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ActivityMeter {
+    pub level: f32,
+}
+
+impl Default for ActivityMeter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActivityMeter {
+    /// Crée une jauge à moitié pleine, pour ne pas pénaliser le début de la partie
+    /// # Returns
+    /// - `Self`: la jauge d'activité
+    pub fn new() -> Self {
+        Self { level: 0.5 }
+    }
+
+    /// Remplit la jauge après la destruction d'un asteroide
+    pub fn record_kill(&mut self) {
+        self.level = (self.level + KILL_FILL).min(1.0);
+    }
+
+    /// Remplit la jauge selon la distance parcourue par le vaisseau
+    /// # Arguments
+    /// - `distance`: la distance parcourue (en pixels)
+    pub fn record_travel(&mut self, distance: f32) {
+        self.level = (self.level + distance / 100.0 * TRAVEL_FILL).min(1.0);
+    }
+
+    /// Vide la jauge avec le temps
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.level = (self.level - DRAIN_PER_SECOND * dt).max(0.0);
+    }
+
+    /// Fonction qui indique si la jauge est vide
+    /// # Returns
+    /// - `bool`: `true` si les points gagnés sont réduits
+    pub fn is_empty(&self) -> bool {
+        self.level <= 0.0
+    }
+
+    /// Fonction qui renvoie le multiplicateur des points gagnés
+    /// # Returns
+    /// - `f32`: `EMPTY_MULTIPLIER` si la jauge est vide, `HIGH_MULTIPLIER` au-dessus de `HIGH_LEVEL`, sinon `1`
+    pub fn multiplier(&self) -> f32 {
+        if self.is_empty() {
+            EMPTY_MULTIPLIER
+        } else if self.level > HIGH_LEVEL {
+            HIGH_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    /// Applique le multiplicateur à des points gagnés. Les points perdus ne sont pas modifiés.
+    /// # Arguments
+    /// - `points`: les points gagnés, ou perdus s'ils sont négatifs
+    /// # Returns
+    /// - `i32`: les points à ajouter au score
+    pub fn award(&self, points: i32) -> i32 {
+        if points <= 0 {
+            return points;
+        }
+        (points as f32 * self.multiplier()).round() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie le remplissage et le vidage de la jauge avec une suite d'événements.
+    #[test]
+    fn test_fill_and_drain() {
+        let mut meter = ActivityMeter { level: 0.0 };
+
+        meter.record_kill();
+        meter.record_kill();
+        meter.record_travel(500.0);
+        assert!((meter.level - 0.45).abs() < 1e-5);
+
+        // 2 secondes sans rien faire, en images de 1/60 s
+        for _ in 0..120 {
+            meter.update(1.0 / 60.0);
+        }
+        assert!((meter.level - 0.35).abs() < 1e-4);
+
+        for _ in 0..10 {
+            meter.record_kill();
+        }
+        assert_eq!(meter.level, 1.0, "La jauge ne dépasse pas 1 !");
+        meter.update(100.0);
+        assert_eq!(meter.level, 0.0, "La jauge ne descend pas sous 0 !");
+    }
+
+    /// Vérifie les seuils du multiplicateur des points gagnés.
+    #[test]
+    fn test_multiplier_thresholds() {
+        let meter = |level| ActivityMeter { level };
+
+        assert_eq!(meter(0.0).multiplier(), EMPTY_MULTIPLIER);
+        assert_eq!(meter(0.01).multiplier(), 1.0);
+        assert_eq!(meter(HIGH_LEVEL).multiplier(), 1.0);
+        assert_eq!(meter(0.8).multiplier(), HIGH_MULTIPLIER);
+
+        assert_eq!(meter(0.0).award(10), 5);
+        assert_eq!(meter(0.8).award(4), 5);
+        assert_eq!(meter(0.0).award(-5), -5, "Les pénalités ne changent pas !");
+    }
+}
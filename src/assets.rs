@@ -0,0 +1,350 @@
+//! Module pour charger les ressources du jeu : textures, sons et polices.
+//! Une texture indispensable qui manque est remplacée par un dessin simple (`Placeholder`),
+//! avec un message qui nomme le fichier manquant : le jeu reste jouable sans aucune ressource.
+//! Les textures optionnelles sont remplacées par celles par défaut.
+//! Toutes les ressources, sons et polices compris, sont lues par `load_asset`.
+//! Avec la fonctionnalité `embedded-assets` (active par défaut), elles sont intégrées
+//! à l'exécutable, qui se lance alors depuis n'importe quel dossier. Les ressources absentes
+//! de l'exécutable sont cherchées dans `DEFAULT_ASSETS_DIR`, et un dossier donné par l'option
+//! `--assets` passe avant les ressources intégrées, pour remplacer les images et les sons.
+use crate::background::Background;
+use crate::text::Fonts;
+use macroquad::prelude::*;
+use std::sync::OnceLock;
+
+/// Macro qui intègre des ressources à l'exécutable, avec leur nom dans le dossier `assets`
+#[cfg(feature = "embedded-assets")]
+macro_rules! embed_assets {
+    ($($name:literal),* $(,)?) => {
+        &[$(($name, include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/", $name)))),*]
+    };
+}
+
+/// Ressources intégrées à l'exécutable, lues depuis le dossier `assets` du projet à la compilation
+#[cfg(feature = "embedded-assets")]
+const EMBEDDED_ASSETS: &[(&str, &[u8])] = embed_assets!(
+    "asteroid.png",
+    "background.png",
+    "background_dead.png",
+    "background_start.png",
+    "black_hole.png",
+    "missile.png",
+    "shield_dead.png",
+    "shield_off.png",
+    "shield_on.png",
+    "spaceship.png",
+    "ufo.png",
+    "audio/asteroid_destroyed.wav",
+    "audio/black_hole_hum.wav",
+    "audio/clink.wav",
+    "audio/game_over.wav",
+    "audio/missile_sound.wav",
+    "audio/new_wave.wav",
+    "audio/shield_lost.wav",
+    "audio/start_game.wav",
+    "audio/ufo_destroyed.wav",
+    "fonts/DejaVuSans.ttf",
+    "fonts/DejaVuSans-Bold.ttf",
+);
+
+/// Sans la fonctionnalité `embedded-assets`, toutes les ressources sont lues dans le dossier des ressources
+#[cfg(not(feature = "embedded-assets"))]
+const EMBEDDED_ASSETS: &[(&str, &[u8])] = &[];
+
+/// Dossier des ressources par défaut, relatif au dossier de lancement du jeu
+pub const DEFAULT_ASSETS_DIR: &str = "assets";
+
+/// Taille (en pixels) des images de remplacement des textures manquantes
+const PLACEHOLDER_SIZE: u16 = 64;
+
+/// Dossier des ressources, choisi une fois pour toutes au lancement du jeu
+static ASSETS_DIR: OnceLock<String> = OnceLock::new();
+
+/// Choisit le dossier des ressources, dont les fichiers passent avant les ressources intégrées.
+/// Il doit être choisi avant le chargement de la première ressource.
+/// # Arguments
+/// - `dir`: le dossier des ressources
+/// # Returns
+/// - `bool`: `false` si le dossier avait déjà été choisi, et n'a donc pas changé
+pub fn set_assets_dir(dir: &str) -> bool {
+    ASSETS_DIR.set(dir.to_string()).is_ok()
+}
+
+/// Fonction qui renvoie le chemin d'une ressource
+/// # Arguments
+/// - `name`: le nom de la ressource dans le dossier des ressources, par exemple `audio/bomb.wav`
+/// # Returns
+/// - `String`: le chemin de la ressource
+pub fn asset_path(name: &str) -> String {
+    join_asset(
+        ASSETS_DIR.get().map_or(DEFAULT_ASSETS_DIR, String::as_str),
+        name,
+    )
+}
+
+/// Fonction qui renvoie une ressource intégrée à l'exécutable
+/// # Arguments
+/// - `name`: le nom de la ressource
+/// # Returns
+/// - `Option<&'static [u8]>`: le contenu de la ressource, ou `None` si elle n'est pas intégrée
+pub fn embedded_asset(name: &str) -> Option<&'static [u8]> {
+    EMBEDDED_ASSETS
+        .iter()
+        .find(|(embedded, _)| *embedded == name)
+        .map(|(_, bytes)| *bytes)
+}
+
+/// Charge le contenu d'une ressource : depuis le dossier choisi par l'option `--assets` s'il la contient,
+/// sinon depuis l'exécutable, sinon depuis le dossier des ressources
+/// # Arguments
+/// - `name`: le nom de la ressource, par exemple `audio/bomb.wav`
+/// # Returns
+/// - `Result<Vec<u8>, macroquad::Error>`: le contenu de la ressource, ou l'erreur de chargement
+pub async fn load_asset(name: &str) -> Result<Vec<u8>, macroquad::Error> {
+    if ASSETS_DIR.get().is_some() {
+        if let Ok(bytes) = load_file(&asset_path(name)).await {
+            return Ok(bytes);
+        }
+    }
+    match embedded_asset(name) {
+        Some(bytes) => Ok(bytes.to_vec()),
+        None => load_file(&asset_path(name)).await,
+    }
+}
+
+/// Charge une texture, depuis une ressource intégrée ou un fichier
+/// # Arguments
+/// - `name`: le nom de la texture
+/// # Returns
+/// - `Result<Texture2D, macroquad::Error>`: la texture chargée, ou l'erreur de chargement
+///   si le fichier manque ou n'est pas une image
+async fn load_asset_texture(name: &str) -> Result<Texture2D, macroquad::Error> {
+    let bytes = load_asset(name).await?;
+    let image = Image::from_file_with_format(&bytes, None)?;
+    Ok(Texture2D::from_image(&image))
+}
+
+/// Énumération représentant les dessins qui remplacent les textures indispensables manquantes
+/// # Variantes
+/// - `Asteroid`: un disque gris
+/// - `Ship`: un triangle blanc pointé vers le haut, comme la texture du vaisseau
+/// - `Shield`: un anneau blanc, teinté au dessin comme le bouclier
+/// - `BlackHole`: un disque sombre au bord violet
+/// - `Ufo`: une ellipse magenta
+/// - `Background`: un fond bleu nuit uni
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Placeholder {
+    Asteroid,
+    Ship,
+    Shield,
+    BlackHole,
+    Ufo,
+    Background,
+}
+
+impl Placeholder {
+    /// Fonction qui renvoie la couleur d'un point du dessin
+    /// # Arguments
+    /// - `point`: le point, de -1 à 1 sur chaque axe, l'axe y vers le bas
+    /// # Returns
+    /// - `Color`: la couleur du point, transparente hors de la forme
+    fn color_at(self, point: Vec2) -> Color {
+        let distance = point.length();
+        let inside = match self {
+            Placeholder::Asteroid => distance < 0.8,
+            Placeholder::Ship => {
+                (-0.9..0.9).contains(&point.y) && point.x.abs() < 0.8 * (point.y + 0.9) / 1.8
+            }
+            Placeholder::Shield => (0.8..0.95).contains(&distance),
+            Placeholder::BlackHole => distance < 0.9,
+            Placeholder::Ufo => (point / vec2(0.9, 0.45)).length() < 1.0,
+            Placeholder::Background => true,
+        };
+        if !inside {
+            return BLANK;
+        }
+        match self {
+            Placeholder::Asteroid => GRAY,
+            Placeholder::Ship | Placeholder::Shield => WHITE,
+            Placeholder::BlackHole if distance > 0.75 => DARKPURPLE,
+            Placeholder::BlackHole => Color::new(0.03, 0.0, 0.06, 1.0),
+            Placeholder::Ufo => MAGENTA,
+            Placeholder::Background => Color::new(0.02, 0.02, 0.08, 1.0),
+        }
+    }
+
+    /// Dessine l'image de remplacement, sans avoir besoin d'une fenêtre
+    /// # Returns
+    /// - `Image`: l'image carrée du dessin
+    pub fn image(self) -> Image {
+        let size = PLACEHOLDER_SIZE as u32;
+        let mut image = Image::gen_image_color(PLACEHOLDER_SIZE, PLACEHOLDER_SIZE, BLANK);
+        for y in 0..size {
+            for x in 0..size {
+                // Centre du pixel, ramené entre -1 et 1
+                let point = (vec2(x as f32, y as f32) + 0.5) / size as f32 * 2.0 - 1.0;
+                image.set_pixel(x, y, self.color_at(point));
+            }
+        }
+        image
+    }
+}
+
+/// Fonction qui place le nom d'une ressource dans un dossier
+/// # Arguments
+/// - `dir`: le dossier des ressources, avec ou sans barre oblique finale
+/// - `name`: le nom de la ressource
+/// # Returns
+/// - `String`: le chemin de la ressource
+fn join_asset(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches(['/', '\\']), name)
+}
+
+/// Structure regroupant les textures chargées au démarrage
+/// # Champs
+/// - `background`: l'arrière-plan de la partie
+/// - `background_start`: la texture d'arrière-plan de l'écran de démarrage
+/// - `background_dead`: la texture d'arrière-plan de l'écran de fin
+/// - `fonts`: les polices utilisées pour les textes
+/// - `missile`: la texture des missiles, ou `None` pour les dessiner sans texture
+pub struct Assets {
+    pub background: Background,
+    pub background_start: Texture2D,
+    pub background_dead: Texture2D,
+    pub fonts: Fonts,
+    pub missile: Option<Texture2D>,
+}
+
+impl Assets {
+    /// Charge toutes les textures et les polices du jeu.
+    /// # Returns
+    /// - `Self`: les ressources chargées, avec des dessins à la place des textures manquantes
+    pub async fn load() -> Self {
+        let background = load_required_texture("background.png", Placeholder::Background).await;
+        let mut variants = vec![background.clone()];
+        for path in ["background_wide.png", "background_ultrawide.png"] {
+            if let Some(texture) = load_optional_texture(path).await {
+                variants.push(texture);
+            }
+        }
+        let tile = load_optional_texture("background_tile.png")
+            .await
+            .unwrap_or(background);
+
+        Self {
+            background: Background::new(variants, tile),
+            background_start: load_required_texture(
+                "background_start.png",
+                Placeholder::Background,
+            )
+            .await,
+            background_dead: load_required_texture("background_dead.png", Placeholder::Background)
+                .await,
+            fonts: Fonts::load().await,
+            missile: load_optional_texture("missile.png").await,
+        }
+    }
+}
+
+/// Crée une texture qui ne nécessite pas de fenêtre, pour construire les objets
+/// dans les tests et les simulations sans affichage.
+/// # Returns
+/// - `Texture2D`: une texture vide
+pub fn dummy_texture() -> Texture2D {
+    use macroquad::miniquad::{RawId, TextureId};
+    Texture2D::from_miniquad_texture(TextureId::from_raw_id(RawId::OpenGl(0)))
+}
+
+/// Charge une texture indispensable au jeu, remplacée par un dessin si elle ne peut pas être chargée.
+/// # Arguments
+/// - `name`: le nom de la texture dans le dossier des ressources
+/// - `placeholder`: le dessin qui remplace la texture manquante
+/// # Returns
+/// - `Texture2D` : la texture chargée, ou celle du dessin
+pub(crate) async fn load_required_texture(name: &str, placeholder: Placeholder) -> Texture2D {
+    match load_asset_texture(name).await {
+        Ok(texture) => texture,
+        Err(err) => {
+            log::warn!(
+                "Texture manquante : {}, remplacée par un dessin ({:?})",
+                asset_path(name),
+                err
+            );
+            Texture2D::from_image(&placeholder.image())
+        }
+    }
+}
+
+/// Charge une texture optionnelle, sans afficher d'erreur si elle n'existe pas.
+/// # Arguments
+/// - `name`: le nom de la texture dans le dossier des ressources
+/// # Returns
+/// - `Option<Texture2D>` : la texture chargée, ou `None` si elle n'a pas pu être chargée
+pub async fn load_optional_texture(name: &str) -> Option<Texture2D> {
+    load_asset_texture(name).await.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que le chemin d'une ressource est le même avec ou sans barre oblique finale.
+    #[test]
+    fn test_asset_path_in_dir() {
+        assert_eq!(
+            join_asset("assets", "audio/bomb.wav"),
+            "assets/audio/bomb.wav"
+        );
+        assert_eq!(join_asset("/opt/jeu/", "ufo.png"), "/opt/jeu/ufo.png");
+        assert!(asset_path("ufo.png").ends_with("/ufo.png"));
+    }
+
+    /// Vérifie que les ressources intégrées sont celles du dossier des ressources du projet.
+    #[cfg(feature = "embedded-assets")]
+    #[test]
+    fn test_embedded_assets_match_files() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_ASSETS_DIR);
+        for (name, bytes) in EMBEDDED_ASSETS {
+            assert_eq!(*bytes, std::fs::read(dir.join(name)).unwrap(), "{name}");
+        }
+        assert!(embedded_asset("spaceship.png").is_some());
+        assert!(embedded_asset("background_wide.png").is_none());
+    }
+
+    /// Vérifie la forme des dessins de remplacement : pleins au centre (sauf l'anneau du bouclier),
+    /// transparents dans les coins (sauf le fond), et de la couleur demandée.
+    #[test]
+    fn test_placeholder_images() {
+        let center = PLACEHOLDER_SIZE as u32 / 2;
+        let corner = PLACEHOLDER_SIZE as u32 - 1;
+        for placeholder in [
+            Placeholder::Asteroid,
+            Placeholder::Ship,
+            Placeholder::Shield,
+            Placeholder::BlackHole,
+            Placeholder::Ufo,
+            Placeholder::Background,
+        ] {
+            let image = placeholder.image();
+            assert_eq!(image.width(), PLACEHOLDER_SIZE as usize);
+            let filled = placeholder != Placeholder::Shield;
+            assert_eq!(
+                image.get_pixel(center, center).a > 0.0,
+                filled,
+                "{placeholder:?}"
+            );
+            let background = placeholder == Placeholder::Background;
+            assert_eq!(
+                image.get_pixel(0, corner).a > 0.0,
+                background,
+                "{placeholder:?}"
+            );
+        }
+        assert_eq!(Placeholder::Asteroid.color_at(Vec2::ZERO), GRAY);
+        // Le triangle du vaisseau est pointé vers le haut : plus large en bas qu'en haut
+        let ship = Placeholder::Ship.image();
+        assert!(ship.get_pixel(center, 8).a > 0.0);
+        assert_eq!(ship.get_pixel(center - 16, 8).a, 0.0);
+        assert!(ship.get_pixel(center - 16, 56).a > 0.0);
+    }
+}
@@ -1,27 +1,73 @@
 //! Module pour gérer nos asteroides
 //! leur taille, leur séparation, leur déplacements
 
-use ::rand::{thread_rng, Rng}; // Utilisation explicite de ::rand pour éviter les conflits
+use crate::effects::{Effects, Particle};
+use crate::platform::entropy_rng;
+use crate::world::{
+    wave_armored_chance, wave_size_weights, wave_speed_factor, wave_volatile_chance,
+};
+use ::rand::distributions::{Distribution, WeightedIndex};
+use ::rand::Rng; // Utilisation explicite de ::rand pour éviter les conflits
 use macroquad::prelude::*;
 use std::f32::consts::PI;
 
+/// Vitesse normale d'un grand asteroide, multipliée selon la taille par `Size::speed_multiplier`
+pub const BASE_SPEED: f32 = 1.0;
+/// Rapport à la vitesse normale de sa taille et de la vague au-delà duquel un asteroide perd des débris
+pub const DEBRIS_SPEED_FACTOR: f32 = 1.3;
+/// Nombre de débris perdus par seconde par un asteroide rapide
+pub const DEBRIS_PER_SECOND: f32 = 2.5;
+/// Durée de vie (en secondes) d'un débris
+const DEBRIS_LIFETIME: f32 = 0.6;
+/// Nombre maximal d'asteroides en jeu pour les apparitions supplémentaires
+pub const MAX_ASTEROIDS: usize = 60;
+/// Durée (en secondes) pendant laquelle les asteroides d'une nouvelle vague sont annoncés
+/// sans pouvoir être touchés par les missiles
+pub const INCUBATION_TIME: f32 = 1.0;
+/// Distance minimale (en pixels) entre le vaisseau et un asteroide d'une nouvelle vague
+pub const SAFE_SPAWN_DISTANCE: f32 = 200.0;
+/// Nombre de positions tirées au hasard avant de placer l'asteroide à l'opposé du vaisseau
+const SPAWN_RETRIES: usize = 20;
+/// Vitesse à laquelle les morceaux d'un asteroide séparé s'écartent l'un de l'autre
+pub const SPLIT_SPEED: f32 = 0.8;
+/// Nombre maximal de textures d'asteroides (`asteroid_1.png` à `asteroid_4.png`)
+pub const ASTEROID_VARIANTS: usize = 4;
+/// Nombre de missiles qu'encaisse un asteroide blindé avant de se séparer
+pub const ARMORED_HP: u32 = 3;
+/// Durée (en secondes) du clignotement blanc d'un asteroide blindé touché
+const ARMOR_FLASH_TIME: f32 = 0.1;
+/// Teinte métallique des asteroides blindés
+const ARMOR_TINT: Color = Color::new(0.65, 0.75, 0.9, 1.0);
+/// Teinte rougeâtre des asteroides explosifs
+const VOLATILE_TINT: Color = Color::new(1.0, 0.55, 0.45, 1.0);
+
 #[derive(Clone)]
 /// Structure qui représente un asteroide
 /// # Champs
 /// - `position`: la position de l'asteroide
 /// - `speed`: la vitesse de l'asteroide
 /// - `size`: la taille de l'asteroide
-/// - `texture`: la texture de l'asteroide
+/// - `variant`: l'indice de la texture de l'asteroide, ramené au nombre de textures chargées
 /// - `active`: permet de savoir si l'asteroide est actif ou non
+/// - `debris_accumulator`: la fraction de débris accumulée en attendant d'en émettre un entier
+/// - `incubation`: le temps restant pendant lequel l'asteroide est annoncé mais pas encore touchable
+/// - `kind`: la sorte d'asteroide, normal ou blindé
+/// - `hp`: le nombre de missiles que l'asteroide peut encore encaisser
+/// - `flash_timer`: le temps restant du clignotement quand un blindage est touché
 pub struct Asteroid {
     position: Vec2,
     speed: Vec2,
     size: Size,
-    texture: Texture2D,
+    variant: usize,
     pub active: bool,
+    debris_accumulator: f32,
+    incubation: f32,
+    kind: AsteroidKind,
+    hp: u32,
+    flash_timer: f32,
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 /// Énumération représentant les différents taille d'asteroides possible
 pub enum Size {
     Large,
@@ -30,6 +76,13 @@ pub enum Size {
 }
 
 impl Size {
+    /// Multiplicateur de la vitesse des grands asteroides
+    pub const LARGE_SPEED: f32 = 1.0;
+    /// Multiplicateur de la vitesse des asteroides moyens
+    pub const MEDIUM_SPEED: f32 = 1.5;
+    /// Multiplicateur de la vitesse des petits asteroides, les plus dangereux
+    pub const SMALL_SPEED: f32 = 2.2;
+
     /// Fonction qui donne une size en f32 pour chaque taille d'asteroide posssible
     /// # Arguments
     /// - `self`: représente l'objet asteroid lui même
@@ -43,6 +96,32 @@ impl Size {
         }
     }
 
+    /// Fonction qui donne la part du rayon dessiné utilisée pour les collisions.
+    /// La texture des asteroides a une marge transparente d'environ un cinquième de sa largeur :
+    /// le cercle de collision suit le bord de la roche plutôt que celui de la texture.
+    /// Les petits asteroides gardent un peu plus de marge, pour rester faciles à toucher.
+    /// # Returns
+    /// - `f32`: le rapport entre le rayon de collision et le rayon dessiné
+    pub fn collision_factor(self) -> f32 {
+        match self {
+            Size::Large => 0.8,
+            Size::Medium => 0.8,
+            Size::Small => 0.85,
+        }
+    }
+
+    /// Fonction qui donne le multiplicateur de la vitesse pour chaque taille d'asteroide :
+    /// plus un asteroide est petit, plus il est rapide
+    /// # Returns
+    /// - `f32`: le multiplicateur de `BASE_SPEED`
+    pub fn speed_multiplier(self) -> f32 {
+        match self {
+            Size::Large => Self::LARGE_SPEED,
+            Size::Medium => Self::MEDIUM_SPEED,
+            Size::Small => Self::SMALL_SPEED,
+        }
+    }
+
     /// Fonction qui donne le prochain état de l'asteroide en fonction de sa taille
     /// Large --> Medium --> Small --> None
     /// # Arguments
@@ -56,45 +135,248 @@ impl Size {
             Size::Small => None,
         }
     }
+
+    /// Fonction qui donne le rayon de l'explosion d'un asteroide explosif de cette taille,
+    /// le double du rayon dessiné
+    /// # Returns
+    /// - `f32`: le rayon de l'explosion
+    pub fn blast_radius(self) -> f32 {
+        self.scale()
+    }
+
+    /// Fonction qui donne le nombre de particules de l'explosion d'un asteroide de cette taille
+    /// # Returns
+    /// - `usize`: le nombre de particules dans le profil de retour normal
+    pub fn explosion_particles(self) -> usize {
+        match self {
+            Size::Large => 30,
+            Size::Medium => 22,
+            Size::Small => 15,
+        }
+    }
+
+    /// Fonction qui donne la couleur des débris perdus par un asteroide de cette taille
+    /// # Returns
+    /// - `Color`: la couleur des débris
+    pub fn debris_color(self) -> Color {
+        match self {
+            Size::Large => Color::new(0.75, 0.7, 0.65, 0.9),
+            Size::Medium => Color::new(0.95, 0.75, 0.45, 0.9),
+            Size::Small => Color::new(1.0, 0.5, 0.3, 0.9),
+        }
+    }
+}
+
+/// Énumération représentant les sortes d'asteroides
+/// # Variantes
+/// - `Normal`: un asteroide qui se sépare au premier missile
+/// - `Armored`: un asteroide blindé, qui encaisse `ARMORED_HP` missiles avant de se séparer
+/// - `Volatile`: un asteroide explosif, qui abîme tout ce qui l'entoure quand il est détruit
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AsteroidKind {
+    Normal,
+    Armored,
+    Volatile,
+}
+
+impl AsteroidKind {
+    /// Fonction qui renvoie les points de vie d'un asteroide de cette sorte
+    /// # Returns
+    /// - `u32`: le nombre de missiles nécessaires pour le séparer
+    pub fn hit_points(self) -> u32 {
+        match self {
+            AsteroidKind::Normal | AsteroidKind::Volatile => 1,
+            AsteroidKind::Armored => ARMORED_HP,
+        }
+    }
+
+    /// Fonction qui renvoie les points rapportés par un asteroide de cette sorte, avant le combo
+    /// # Returns
+    /// - `i32`: 1 pour un asteroide normal ou explosif, 3 pour un asteroide blindé
+    pub fn score(self) -> i32 {
+        match self {
+            AsteroidKind::Normal | AsteroidKind::Volatile => 1,
+            AsteroidKind::Armored => 3,
+        }
+    }
 }
 
 impl Asteroid {
     const SIZES: [Size; 3] = [Size::Large, Size::Medium, Size::Small];
 
-    /// Fonction qui créer un nouvel asteroid
+    /// Fonction qui créer un asteroid de taille aléatoire près d'un coin de l'écran,
+    /// avec une texture choisie au hasard.
+    /// Plus l'asteroide est petit, plus il est rapide.
+    /// Plus la vague est avancée, plus l'asteroide est rapide et a de chances d'être grand.
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// - `wave`: le numéro de la vague
+    /// - `rng`: le générateur de la partie, pour que la même graine donne les mêmes asteroides
     /// # Returns
     /// - `self`: un objet asteroid, avec sa position, vitesse, taille, et texture.
-    pub async fn new() -> Self {
-        let mut rng = thread_rng();
-        let size = Self::SIZES[rng.gen_range(0..Self::SIZES.len())];
-        let texture = load_texture("assets/asteroid.png").await.unwrap();
+    pub fn random(bounds: Vec2, wave: u32, rng: &mut impl Rng) -> Self {
+        let weights = WeightedIndex::new(wave_size_weights(wave))
+            .expect("Les poids des tailles sont positifs");
+        let size = Self::SIZES[weights.sample(rng)];
+        let roll: f64 = rng.gen();
+        let armored = wave_armored_chance(wave);
+        let kind = if roll < armored {
+            AsteroidKind::Armored
+        } else if roll < armored + wave_volatile_chance(wave) {
+            AsteroidKind::Volatile
+        } else {
+            AsteroidKind::Normal
+        };
         Self {
-            position: Self::new_random_position(size.scale()),
-            speed: Self::new_random_speed(),
+            position: Self::new_random_position(size.scale(), bounds, rng),
+            speed: Self::new_random_speed(rng) * size.speed_multiplier() * wave_speed_factor(wave),
             size,
-            texture,
+            variant: rng.gen_range(0..ASTEROID_VARIANTS),
             active: true,
+            debris_accumulator: 0.0,
+            incubation: 0.0,
+            kind,
+            hp: kind.hit_points(),
+            flash_timer: 0.0,
+        }
+    }
+
+    /// Fonction qui créer un asteroid de taille aléatoire, loin d'une position à éviter.
+    /// Les positions trop proches sont tirées à nouveau ; après `SPAWN_RETRIES` essais,
+    /// l'asteroide est placé à l'opposé de la position à éviter, le point le plus éloigné de l'écran.
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// - `wave`: le numéro de la vague
+    /// - `avoid`: la position à éviter, en général celle du vaisseau
+    /// - `min_distance`: la distance minimale à la position à éviter
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `self`: un objet asteroid, avec sa position, vitesse, taille, et texture.
+    pub fn new_away_from(
+        bounds: Vec2,
+        wave: u32,
+        avoid: Vec2,
+        min_distance: f32,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let mut asteroid = Self::random(bounds, wave, rng);
+        for _ in 0..SPAWN_RETRIES {
+            if wrapped_distance(asteroid.position, avoid, bounds) >= min_distance {
+                return asteroid;
+            }
+            asteroid.position = Self::new_random_position(asteroid.size.scale(), bounds, rng);
+        }
+        if wrapped_distance(asteroid.position, avoid, bounds) < min_distance {
+            asteroid.position = Self::wrap_around_screen(avoid + bounds / 2.0, bounds);
         }
+        asteroid
     }
 
-    /// Fonction qui créer un nouveau asteroid avec sa nouvelle taille actualisé
+    /// Fonction qui créer un asteroid de taille aléatoire sur un bord de l'écran choisi au hasard,
+    /// qui se dirige vers l'intérieur de l'écran, pour le mode sans fin
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// - `wave`: le numéro de la vague, pour la taille, la vitesse et le type
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `self`: un objet asteroid, avec sa position sur le bord et sa vitesse vers l'intérieur
+    pub fn new_from_edge(bounds: Vec2, wave: u32, rng: &mut impl Rng) -> Self {
+        let mut asteroid = Self::random(bounds, wave, rng);
+        let along: f32 = rng.gen_range(0.0..1.0);
+        asteroid.position = match rng.gen_range(0..4) {
+            0 => vec2(along * bounds.x, 0.0),
+            1 => vec2(along * bounds.x, bounds.y),
+            2 => vec2(0.0, along * bounds.y),
+            _ => vec2(bounds.x, along * bounds.y),
+        };
+        let aim = vec2(
+            rng.gen_range(0.25..0.75) * bounds.x,
+            rng.gen_range(0.25..0.75) * bounds.y,
+        );
+        let direction = (aim - asteroid.position).normalize_or_zero();
+        asteroid.speed = direction * asteroid.speed.length();
+        asteroid
+    }
+
+    /// Fonction qui créer un nouveau asteroid avec sa nouvelle taille actualisé,
+    /// avec la première texture
     /// # Arguments
     /// - `size`: la taille de l'asteroide
     /// - `position`: la position de l'asteroide
     /// - `speed`: la vitesse de l'asteroide
-    /// - `texture`: la texture de l'asteroid
     /// # Returns
     /// - `Self': un nouveau objet Asteroid
-    pub fn new_with_size(size: Size, position: Vec2, speed: Vec2, texture: Texture2D) -> Self {
+    pub fn new_with_size(size: Size, position: Vec2, speed: Vec2) -> Self {
         Self {
             position,
             speed,
             size,
-            texture,
+            variant: 0,
             active: true,
+            debris_accumulator: 0.0,
+            incubation: 0.0,
+            kind: AsteroidKind::Normal,
+            hp: 1,
+            flash_timer: 0.0,
         }
     }
 
+    /// Fonction qui renvoie le rayon de la texture dessinée, plus grand que le rayon de collision
+    /// # Returns
+    /// - `f32`: la moitié de la taille de la texture
+    pub fn visual_radius(&self) -> f32 {
+        self.size.scale() / 2.0
+    }
+
+    /// Fonction qui change la texture de l'asteroide
+    /// # Arguments
+    /// - `variant`: l'indice de la texture
+    /// # Returns
+    /// - `Self`: l'asteroide avec sa nouvelle texture
+    pub fn with_variant(mut self, variant: usize) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Fonction qui change la sorte de l'asteroide, avec tous les points de vie de sa sorte
+    /// # Arguments
+    /// - `kind`: la sorte de l'asteroide
+    /// # Returns
+    /// - `Self`: l'asteroide de la nouvelle sorte
+    pub fn with_kind(mut self, kind: AsteroidKind) -> Self {
+        self.kind = kind;
+        self.hp = kind.hit_points();
+        self
+    }
+
+    /// Fonction qui renvoie la sorte de l'asteroide
+    /// # Returns
+    /// - `AsteroidKind`: normal ou blindé
+    pub fn kind(&self) -> AsteroidKind {
+        self.kind
+    }
+
+    /// Fonction qui renvoie le nombre de missiles que l'asteroide peut encore encaisser
+    /// # Returns
+    /// - `u32`: les points de vie restants
+    pub fn hp(&self) -> u32 {
+        self.hp
+    }
+
+    /// Détruit l'asteroide d'un coup, quel que soit son blindage :
+    /// un trou noir, le bouclier du vaisseau ou une bombe ne laissent rien derrière eux
+    pub fn destroy(&mut self) {
+        self.hp = 0;
+        self.active = false;
+    }
+
+    /// Fonction qui renvoie l'indice de la texture de l'asteroide
+    /// # Returns
+    /// - `usize`: l'indice de la texture, avant d'être ramené au nombre de textures chargées
+    pub fn variant(&self) -> usize {
+        self.variant
+    }
+
     /// Fonction qui renvoie la taille de l'objet
     /// # Arguments
     /// - `&self`: l'objet asteroid lui même
@@ -104,93 +386,237 @@ impl Asteroid {
         self.size
     }
 
-    /// Fonction qui dessine la texture sur l'asteroide
+    /// Fonction qui renvoie la vitesse de l'objet
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet
+    pub fn get_speed(&self) -> Vec2 {
+        self.speed
+    }
+
+    /// Multiplie la vitesse de l'asteroide, par exemple selon la difficulté
+    /// # Arguments
+    /// - `factor`: le multiplicateur de la vitesse
+    pub fn scale_speed(&mut self, factor: f32) {
+        self.speed *= factor;
+    }
+
+    /// Annonce l'asteroide : pendant la durée donnée, il est dessiné en transparence
+    /// et les missiles le traversent
+    /// # Arguments
+    /// - `duration`: la durée de l'annonce (en secondes)
+    pub fn incubate(&mut self, duration: f32) {
+        self.incubation = duration;
+    }
+
+    /// Fonction qui indique si l'asteroide est encore annoncé
+    /// # Returns
+    /// - `bool`: `true` si les missiles ne peuvent pas encore le toucher
+    pub fn incubating(&self) -> bool {
+        self.incubation > 0.0
+    }
+
+    /// Met à jour l'asteroide pour une image.
+    /// Un asteroide plus rapide que la normale de sa taille perd des débris derrière lui pour prévenir le joueur.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `speed_factor`: le multiplicateur de la vitesse des asteroides de la vague et de la difficulté,
+    ///   qui fait partie de la vitesse normale
+    /// - `effects`: le système d'effets qui reçoit les débris
+    pub fn update(&mut self, dt: f32, speed_factor: f32, effects: &mut Effects) {
+        self.incubation = (self.incubation - dt).max(0.0);
+        self.flash_timer = (self.flash_timer - dt).max(0.0);
+        let normal_speed = BASE_SPEED * self.size.speed_multiplier() * speed_factor;
+        if self.speed.length() <= normal_speed * DEBRIS_SPEED_FACTOR {
+            self.debris_accumulator = 0.0;
+            return;
+        }
+
+        self.debris_accumulator += DEBRIS_PER_SECOND * dt;
+        let mut rng = entropy_rng();
+        while self.debris_accumulator >= 1.0 {
+            self.debris_accumulator -= 1.0;
+            let behind = self.position - self.speed.normalize() * self.visual_radius();
+            let drift = vec2(rng.gen_range(-0.2..0.2), rng.gen_range(-0.2..0.2));
+            effects.spawn(Particle::new(
+                behind,
+                -self.speed * 0.2 + drift,
+                self.size.debris_color(),
+                2.0,
+                DEBRIS_LIFETIME,
+            ));
+        }
+    }
+
+    /// Fonction qui dessine la texture sur l'asteroide.
+    /// Un asteroide qui chevauche un bord est aussi dessiné de l'autre côté de l'écran,
+    /// et un asteroide annoncé clignote en transparence.
+    /// Un asteroide blindé a une teinte métallique, et devient blanc un instant quand il est touché ;
+    /// un asteroide explosif est rougeâtre.
     /// # Arguments
     /// - `&self`: l'objet asteroid lui même
-    pub fn draw(&self) {
-        draw_texture_ex(
-            &self.texture, // Utilisation d'une référence à la texture
-            self.position.x - self.radius(),
-            self.position.y - self.radius(),
-            WHITE,
-            DrawTextureParams {
-                dest_size: Some(Vec2::new(self.size.scale(), self.size.scale())),
-                ..Default::default()
-            },
-        );
+    /// - `bounds`: la taille de l'aire de jeu
+    /// - `textures`: les textures des asteroides, dont au moins une
+    pub fn draw(&self, bounds: Vec2, textures: &[Texture2D]) {
+        // S'il y a moins de textures que de variantes, les variantes se partagent les textures
+        let texture = &textures[self.variant % textures.len()];
+        let tint = match self.kind {
+            AsteroidKind::Normal => WHITE,
+            AsteroidKind::Armored => ARMOR_TINT,
+            AsteroidKind::Volatile => VOLATILE_TINT,
+        };
+        let color = if self.incubating() {
+            let blink = (self.incubation * 12.0).sin() * 0.5 + 0.5;
+            Color::new(tint.r, tint.g, tint.b, 0.2 + blink * 0.3)
+        } else {
+            tint
+        };
+        for offset in wrap_offsets(self.position, self.visual_radius(), bounds) {
+            let position = self.position + offset;
+            draw_texture_ex(
+                texture,
+                position.x - self.visual_radius(),
+                position.y - self.visual_radius(),
+                color,
+                DrawTextureParams {
+                    dest_size: Some(Vec2::new(self.size.scale(), self.size.scale())),
+                    ..Default::default()
+                },
+            );
+            if self.flash_timer > 0.0 {
+                draw_circle(
+                    position.x,
+                    position.y,
+                    self.radius(),
+                    Color::new(1.0, 1.0, 1.0, 0.7),
+                );
+            }
+        }
     }
 
-    /// Fonction qui sépare l'asteroid en fonction de sa taille
+    /// Fonction qui sépare l'asteroid en fonction de sa taille.
+    /// Les morceaux gardent la direction de l'asteroide, accélérée selon leur taille plus petite,
+    /// et sa texture, pour qu'on voie d'où ils viennent.
+    /// Ils apparaissent de part et d'autre de l'asteroide, perpendiculairement au tir,
+    /// et s'écartent l'un de l'autre à `SPLIT_SPEED`.
     /// # Arguments
     /// - `&self`: l'objet Asteroid lui même
+    /// - `impact`: la vitesse du missile qui a touché l'asteroide
+    /// - `rng`: le générateur de la partie
     /// # Returns
     /// - Òption(<Asteroid, Asteroid): renvoie deux nouveau objet si l'asteroide est séparable sinon `None`
-    pub fn split(&self) -> Option<(Asteroid, Asteroid)> {
+    pub fn split(&self, impact: Vec2, rng: &mut impl Rng) -> Option<(Asteroid, Asteroid)> {
         if let Some(new_size) = self.size.next() {
-            let mut rng = thread_rng();
-            let speed_variation = Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+            let direction = impact.try_normalize().unwrap_or(Vec2::Y);
+            let axis = direction.perp();
+            let offset = axis * new_size.scale() / 2.0;
+            // Un peu de hasard dans le sens du tir, pour que deux séparations ne se ressemblent pas
+            let variation = direction * rng.gen_range(-0.3..0.3);
+            let spread = axis * SPLIT_SPEED + variation;
+            let speed = self.speed * new_size.speed_multiplier() / self.size.speed_multiplier();
             Some((
-                Asteroid::new_with_size(
-                    new_size,
-                    self.position,
-                    self.speed + speed_variation,
-                    self.texture.clone(),
-                ), // Clone de la texture
-                Asteroid::new_with_size(
-                    new_size,
-                    self.position,
-                    self.speed - speed_variation,
-                    self.texture.clone(),
-                ), // Clone de la texture
+                Asteroid::new_with_size(new_size, self.position + offset, speed + spread)
+                    .with_variant(self.variant),
+                Asteroid::new_with_size(new_size, self.position - offset, speed - spread)
+                    .with_variant(self.variant),
             ))
         } else {
             None
         }
     }
 
+    /// Fait rebondir deux asteroides qui se chevauchent, sans les détruire.
+    /// Le choc est élastique, avec une masse proportionnelle à la taille des asteroides :
+    /// seules les vitesses le long de la ligne qui relie les centres sont échangées.
+    /// Les deux asteroides sont ensuite écartés pour ne plus se toucher à l'image suivante.
+    /// Les asteroides inactifs ou encore annoncés ne rebondissent pas.
+    /// # Arguments
+    /// - `other`: l'autre asteroide
+    /// - `bounds`: la taille de l'écran
+    /// # Returns
+    /// - `bool`: `true` si les deux asteroides se touchaient
+    pub fn bounce(&mut self, other: &mut Asteroid, bounds: Vec2) -> bool {
+        if !self.active || !other.active || self.incubating() || other.incubating() {
+            return false;
+        }
+        let offset = wrapped_offset(self.position, other.position, bounds);
+        let overlap = self.radius() + other.radius() - offset.length();
+        if overlap <= 0.0 {
+            return false;
+        }
+        // Deux asteroides exactement superposés sont séparés horizontalement
+        let normal = offset.try_normalize().unwrap_or(Vec2::X);
+        let mass = self.size.scale();
+        let other_mass = other.size.scale();
+        let total_mass = mass + other_mass;
+
+        // Les asteroides qui s'éloignent déjà l'un de l'autre gardent leur vitesse
+        let approach = (self.speed - other.speed).dot(normal);
+        if approach > 0.0 {
+            self.speed -= normal * approach * 2.0 * other_mass / total_mass;
+            other.speed += normal * approach * 2.0 * mass / total_mass;
+        }
+
+        self.position = Self::wrap_around_screen(
+            self.position - normal * overlap * other_mass / total_mass,
+            bounds,
+        );
+        other.position = Self::wrap_around_screen(
+            other.position + normal * overlap * mass / total_mass,
+            bounds,
+        );
+        true
+    }
+
     /// Fonction qui renvoie une position aléatoire pour le nouveau asteroid crée
     /// # Arguments
     /// - `size`: la taille de l'asteroide actuel
+    /// - `bounds`: la taille de l'écran
+    /// - `rng`: le générateur de la partie
     /// # Returns
     /// - `Vec2`: vecteur avec la nouvelle position de l'asteroide proche de l'ancien
-    fn new_random_position(size: f32) -> Vec2 {
-        let mut rng = thread_rng();
-        let nearpos: f32 = rng.gen_range(size / 2.0..=size);
+    fn new_random_position(size: f32, bounds: Vec2, rng: &mut impl Rng) -> Vec2 {
+        // La marge est bornée pour que la position reste dans un tout petit écran
+        let margin = size.min(bounds.min_element() / 2.0);
+        let nearpos: f32 = rng.gen_range(margin / 2.0..=margin);
         let xpos: f32 = if rng.gen_bool(0.5) {
             nearpos
         } else {
-            screen_width() - nearpos
+            bounds.x - nearpos
         };
         let ypos: f32 = if rng.gen_bool(0.5) {
             nearpos
         } else {
-            screen_height() - nearpos
+            bounds.y - nearpos
         };
         vec2(xpos, ypos)
     }
 
     /// Fonction qui renvoie une vitesse aléatoire pour le nouveau asteroid crée
+    /// # Arguments
+    /// - `rng`: le générateur de la partie
     /// # Returns
     /// - `Vec2`: vecteur avec la nouvelle vitesse
-    fn new_random_speed() -> Vec2 {
-        let mut rng = thread_rng();
+    fn new_random_speed(rng: &mut impl Rng) -> Vec2 {
         Vec2::from_angle(rng.gen_range(0.0..=2.0 * PI))
     }
 
     /// Fonction qui permet d'empêcher les asteroids de sortir de l'écran
     /// # Arguments
     /// - `Vec2`: Vecteur avec la position de l'asteroid
+    /// - `bounds`: la taille de l'écran
     /// # Returns:
     /// - `Vec2`: Renvoie le vecteur avec les nouvelles coordonnées pour l'asteroid
-    fn wrap_around_screen(position: Vec2) -> Vec2 {
+    fn wrap_around_screen(position: Vec2, bounds: Vec2) -> Vec2 {
         vec2(
-            (position.x + screen_width()) % screen_width(),
-            (position.y + screen_height()) % screen_height(),
+            (position.x + bounds.x) % bounds.x,
+            (position.y + bounds.y) % bounds.y,
         )
     }
 }
 
-use crate::stellarobject::StellarObject;
+use crate::stellarobject::{
+    debug_field, format_vec2, wrap_offsets, wrapped_distance, wrapped_offset, StellarObject,
+};
 
 impl StellarObject for Asteroid {
     /// Retourne la position de l'objet.
@@ -202,27 +628,383 @@ impl StellarObject for Asteroid {
         self.position
     }
 
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.speed
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.speed = velocity;
+    }
+
     /// Met a jour la position de l'objet.
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
-    fn move_obj(&mut self) {
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
         self.position += self.speed;
-        self.position = Self::wrap_around_screen(self.position);
+        self.position = Self::wrap_around_screen(self.position, bounds);
     }
 
     /// Retourne le rayon de l'objet.
     /// # Arguments
     /// - `&self`: une instance de l'objet stellaire
     /// # Returns
-    /// - `f32`: le rayon de l'objet stellaire
+    /// - `f32`: le rayon de collision, plus petit que la texture qui a des marges transparentes
     fn radius(&self) -> f32 {
-        self.get_size().scale() / 2.0
+        self.visual_radius() * self.size.collision_factor()
     }
 
-    /// Gere la collision avec un autre objet.
+    /// Gere la collision avec un autre objet : l'asteroide perd un point de vie,
+    /// et n'est détruit que lorsqu'il n'en a plus. Un asteroide blindé encore actif
+    /// après la collision a seulement été abîmé.
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
     fn handle_collision(&mut self) {
-        self.active = false
+        self.hp = self.hp.saturating_sub(1);
+        if self.hp == 0 {
+            self.active = false
+        } else {
+            self.flash_timer = ARMOR_FLASH_TIME;
+        }
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état de l'asteroide pour l'inspecteur de développement.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec<(String, String)>`: la liste des champs, avec leur nom et leur valeur
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "Asteroid"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.speed)),
+            debug_field("radius", format!("{:.1}", self.radius())),
+            debug_field("active", self.active),
+            debug_field("size", format!("{:?}", self.size)),
+            debug_field("kind", format!("{:?}", self.kind)),
+            debug_field("hp", self.hp),
+            debug_field("incubation", format!("{:.2}", self.incubation)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::difficulty::Difficulty;
+    use crate::playfield::{playfield_bounds, playfield_factor};
+    use ::rand::thread_rng;
+
+    /// Compte les débris émis par un asteroide pendant une suite d'images.
+    fn count_debris(speed: Vec2, frame_times: &[f32]) -> usize {
+        let mut asteroid = Asteroid::new_with_size(Size::Medium, Vec2::ZERO, speed);
+        let mut effects = Effects::new();
+        let mut count = 0;
+        for dt in frame_times {
+            asteroid.update(*dt, 1.0, &mut effects);
+            count = effects.particles().len();
+        }
+        count
+    }
+
+    /// Vérifie que le nombre de débris suit le débit voulu, même avec des images de durées variables.
+    #[test]
+    fn test_debris_rate_with_varying_dt() {
+        let fast = vec2(2.0, 0.0);
+        // Environ 2 secondes découpées en images de durées irrégulières
+        let frame_times: Vec<f32> = [1.0 / 60.0, 1.0 / 30.0, 1.0 / 144.0, 0.05]
+            .iter()
+            .cycle()
+            .scan(0.0, |total, dt| {
+                *total += dt;
+                (*total <= 2.1).then_some(*dt)
+            })
+            .collect();
+        let elapsed: f32 = frame_times.iter().sum();
+
+        let expected = (DEBRIS_PER_SECOND * elapsed).floor() as usize;
+        assert_eq!(count_debris(fast, &frame_times), expected);
+        assert_eq!(count_debris(fast, &[1.0 / 60.0; 25]), 1);
+    }
+
+    /// Vérifie que l'annonce d'un asteroide se termine après sa durée.
+    #[test]
+    fn test_incubation_ends() {
+        let mut asteroid = Asteroid::new_with_size(Size::Small, Vec2::ZERO, Vec2::ZERO);
+        let mut effects = Effects::new();
+        assert!(!asteroid.incubating());
+
+        asteroid.incubate(INCUBATION_TIME);
+        asteroid.update(INCUBATION_TIME / 2.0, 1.0, &mut effects);
+        assert!(asteroid.incubating());
+        asteroid.update(INCUBATION_TIME / 2.0, 1.0, &mut effects);
+        assert!(!asteroid.incubating());
+    }
+
+    /// Vérifie qu'un asteroide à vitesse normale ne perd aucun débris.
+    #[test]
+    fn test_normal_speed_sheds_nothing() {
+        assert_eq!(count_debris(vec2(1.0, 0.0), &[0.1; 40]), 0);
+    }
+
+    /// Vérifie que les asteroides d'une vague avancée, plus rapides à cause de la vague et
+    /// de la difficulté, ne perdent aucun débris.
+    #[test]
+    fn test_late_wave_speed_sheds_nothing() {
+        let mut rng = thread_rng();
+        let mut effects = Effects::new();
+        for difficulty in [Difficulty::Normal, Difficulty::Hard] {
+            let speed_factor = wave_speed_factor(10) * difficulty.speed_factor();
+            for _ in 0..50 {
+                let mut asteroid = Asteroid::random(vec2(800.0, 600.0), 10, &mut rng);
+                asteroid.scale_speed(difficulty.speed_factor());
+                for _ in 0..60 {
+                    asteroid.update(0.1, speed_factor, &mut effects);
+                }
+            }
+        }
+        assert!(effects.particles().is_empty());
+    }
+
+    /// Vérifie que la taille et la vitesse apparentes d'un asteroide, rapportées à la largeur
+    /// de la fenêtre, ne dépendent pas de la résolution.
+    #[test]
+    fn test_apparent_size_is_resolution_independent() {
+        let asteroid = Asteroid::new_with_size(Size::Large, Vec2::ZERO, vec2(1.5, 0.0));
+        let ratios = |screen: Vec2| {
+            let factor = playfield_factor(screen);
+            (
+                asteroid.radius() * factor / screen.x,
+                asteroid.get_speed().x * factor / screen.x,
+                asteroid.radius() / playfield_bounds(screen).x,
+            )
+        };
+        let (radius, speed, wrap) = ratios(vec2(1280.0, 720.0));
+        let (radius_4k, speed_4k, wrap_4k) = ratios(vec2(3840.0, 2160.0));
+        assert!((radius - radius_4k).abs() < 1e-6);
+        assert!((speed - speed_4k).abs() < 1e-6);
+        assert!((wrap - wrap_4k).abs() < 1e-6);
+    }
+
+    /// Vérifie que les asteroides trouvent toujours une place dans une fenêtre de 320×240,
+    /// avec ou sans la réduction de l'aire de jeu.
+    #[test]
+    fn test_spawn_placement_in_small_window() {
+        let screen = vec2(320.0, 240.0);
+        for bounds in [playfield_bounds(screen), screen, vec2(60.0, 40.0)] {
+            for _ in 0..200 {
+                let asteroid = Asteroid::random(bounds, 1, &mut thread_rng());
+                let position = asteroid.get_pos();
+                assert!(
+                    (0.0..=bounds.x).contains(&position.x)
+                        && (0.0..=bounds.y).contains(&position.y),
+                    "L'asteroide est apparu hors de l'écran !"
+                );
+            }
+        }
+    }
+
+    /// Vérifie qu'aucun asteroide d'une nouvelle vague n'apparaît trop près du vaisseau.
+    #[test]
+    fn test_spawn_away_from_ship() {
+        let bounds = vec2(800.0, 600.0);
+        for ship in [vec2(400.0, 300.0), vec2(60.0, 60.0), vec2(790.0, 300.0)] {
+            for _ in 0..1000 {
+                let asteroid = Asteroid::new_away_from(
+                    bounds,
+                    1,
+                    ship,
+                    SAFE_SPAWN_DISTANCE,
+                    &mut thread_rng(),
+                );
+                assert!(
+                    wrapped_distance(asteroid.get_pos(), ship, bounds) >= SAFE_SPAWN_DISTANCE,
+                    "L'asteroide est apparu trop près du vaisseau !"
+                );
+            }
+        }
+    }
+
+    /// Vérifie que les asteroides du mode sans fin apparaissent sur un bord
+    /// et se dirigent vers l'intérieur de l'écran.
+    #[test]
+    fn test_edge_spawn_heads_inwards() {
+        let bounds = vec2(800.0, 600.0);
+        for _ in 0..200 {
+            let asteroid = Asteroid::new_from_edge(bounds, 1, &mut thread_rng());
+            let position = asteroid.get_pos();
+            let on_edge = position.x == 0.0
+                || position.y == 0.0
+                || position.x == bounds.x
+                || position.y == bounds.y;
+            assert!(on_edge, "L'asteroide doit apparaître sur un bord !");
+            assert!(asteroid.get_speed().dot(bounds / 2.0 - position) > 0.0);
+        }
+    }
+
+    /// Vérifie que les asteroides de la vague 10 sont plus rapides et plus souvent grands
+    /// que ceux de la première vague.
+    #[test]
+    fn test_wave_difficulty_curve() {
+        let bounds = vec2(800.0, 600.0);
+        let sample = |wave| {
+            let asteroids: Vec<Asteroid> = (0..500)
+                .map(|_| Asteroid::random(bounds, wave, &mut thread_rng()))
+                .collect();
+            // La vitesse est ramenée à celle d'un grand asteroide, pour ne comparer que la vague
+            let speed = asteroids
+                .iter()
+                .map(|a| a.get_speed().length() / a.get_size().speed_multiplier())
+                .sum::<f32>()
+                / 500.0;
+            let large = asteroids
+                .iter()
+                .filter(|a| a.get_size() == Size::Large)
+                .count();
+            (speed, large)
+        };
+
+        let (speed_1, large_1) = sample(1);
+        let (speed_10, large_10) = sample(10);
+
+        assert!((speed_1 - BASE_SPEED).abs() < 1e-4);
+        assert!(
+            speed_10 > speed_1 * 1.3,
+            "Les asteroides de la vague 10 doivent être plus rapides !"
+        );
+        assert!(
+            large_10 > large_1,
+            "Les grands asteroides doivent être plus fréquents !"
+        );
+    }
+
+    /// Vérifie que les petits asteroides sont plus rapides que les grands.
+    #[test]
+    fn test_speed_multipliers_by_size() {
+        assert_eq!(Size::Large.speed_multiplier(), Size::LARGE_SPEED);
+        assert_eq!(Size::Medium.speed_multiplier(), Size::MEDIUM_SPEED);
+        assert_eq!(Size::Small.speed_multiplier(), Size::SMALL_SPEED);
+        let speeds = Asteroid::SIZES.map(Size::speed_multiplier);
+        assert!(speeds[0] < speeds[1] && speeds[1] < speeds[2]);
+
+        let bounds = vec2(800.0, 600.0);
+        for _ in 0..100 {
+            let asteroid = Asteroid::random(bounds, 1, &mut thread_rng());
+            let expected = BASE_SPEED * asteroid.get_size().speed_multiplier();
+            assert!((asteroid.get_speed().length() - expected).abs() < 1e-4);
+        }
+    }
+
+    /// Vérifie que les morceaux d'un asteroide séparé sont plus rapides que lui, en moyenne.
+    #[test]
+    fn test_split_children_are_faster() {
+        let parent = Asteroid::new_with_size(Size::Large, Vec2::ZERO, vec2(1.0, 0.0));
+
+        let (child1, child2) = parent
+            .split(vec2(0.0, -4.0), &mut thread_rng())
+            .expect("Un grand asteroide se sépare");
+
+        let mean = (child1.get_speed() + child2.get_speed()) / 2.0;
+        assert!((mean - vec2(Size::MEDIUM_SPEED, 0.0)).length() < 1e-5);
+        assert!(mean.length() > parent.get_speed().length());
+    }
+
+    /// Vérifie que les morceaux apparaissent séparés, de part et d'autre de l'axe du tir,
+    /// et qu'ils s'écartent l'un de l'autre.
+    #[test]
+    fn test_split_children_are_separated() {
+        let parent = Asteroid::new_with_size(Size::Medium, vec2(400.0, 300.0), vec2(0.0, 1.0));
+        let impact = vec2(0.0, -4.0);
+
+        let (child1, child2) = parent
+            .split(impact, &mut thread_rng())
+            .expect("Un asteroide moyen se sépare");
+
+        let child_radius = child1.radius();
+        assert!(child1.get_pos().distance(child2.get_pos()) >= child_radius);
+        let axis = impact.normalize().perp();
+        let parent_speed = parent.get_speed() * Size::SMALL_SPEED / Size::MEDIUM_SPEED;
+        let along1 = (child1.get_speed() - parent_speed).dot(axis);
+        let along2 = (child2.get_speed() - parent_speed).dot(axis);
+        assert!(
+            along1 > 0.0 && along2 < 0.0,
+            "Les morceaux doivent s'écarter !"
+        );
+        assert!((child1.get_pos() - parent.get_pos()).dot(axis) > 0.0);
+    }
+
+    /// Vérifie que deux asteroides qui se foncent dessus repartent en sens inverse,
+    /// sans se détruire ni rester l'un dans l'autre.
+    #[test]
+    fn test_head_on_asteroids_bounce() {
+        let bounds = vec2(800.0, 600.0);
+        let mut left = Asteroid::new_with_size(Size::Large, vec2(300.0, 300.0), vec2(1.0, 0.0));
+        let mut right = Asteroid::new_with_size(Size::Medium, vec2(390.0, 300.0), vec2(-1.5, 0.0));
+
+        let mut bounced = false;
+        for _ in 0..60 {
+            left.move_obj(bounds);
+            right.move_obj(bounds);
+            bounced |= left.bounce(&mut right, bounds);
+            let distance = wrapped_distance(left.get_pos(), right.get_pos(), bounds);
+            assert!(
+                distance >= left.radius() + right.radius() - 1e-3,
+                "Les asteroides ne doivent pas rester l'un dans l'autre !"
+            );
+        }
+
+        assert!(bounced);
+        assert!(left.active && right.active);
+        assert!(left.get_speed().x < 0.0 && right.get_speed().x > 0.0);
+        // Le choc est élastique : la quantité de mouvement est conservée
+        let momentum = |a: &Asteroid| a.get_speed() * a.get_size().scale();
+        let total = momentum(&left) + momentum(&right);
+        assert!(total.distance(vec2(100.0 - 1.5 * 70.0, 0.0)) < 1e-3);
+    }
+
+    /// Vérifie que les morceaux d'un asteroide gardent sa texture,
+    /// et que les textures tirées au hasard restent parmi les variantes prévues.
+    #[test]
+    fn test_split_keeps_texture_variant() {
+        let asteroid = Asteroid::new_with_size(Size::Large, Vec2::ZERO, Vec2::X).with_variant(3);
+        let (a, b) = asteroid.split(Vec2::Y, &mut thread_rng()).unwrap();
+        assert_eq!((a.variant(), b.variant()), (3, 3));
+
+        for _ in 0..50 {
+            assert!(
+                Asteroid::random(vec2(800.0, 600.0), 1, &mut thread_rng()).variant()
+                    < ASTEROID_VARIANTS
+            );
+        }
+    }
+
+    /// Vérifie que la vitesse changée par le trait est celle utilisée pour le déplacement.
+    #[test]
+    fn test_set_velocity_moves_asteroid() {
+        let mut asteroid = Asteroid::new_with_size(Size::Small, vec2(100.0, 100.0), Vec2::ZERO);
+        asteroid.set_velocity(vec2(3.0, -2.0));
+        asteroid.move_obj(vec2(800.0, 600.0));
+
+        assert_eq!(asteroid.velocity(), vec2(3.0, -2.0));
+        assert_eq!(asteroid.get_pos(), vec2(103.0, 98.0));
+        assert!(asteroid.is_active());
     }
 }
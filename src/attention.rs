@@ -0,0 +1,211 @@
+//! Module pour détecter l'inactivité du joueur pendant une partie.
+//! Si le joueur ne touche à rien pendant un long moment, ne bouge pas,
+//! et que des asteroides s'approchent, la partie est mise en pause pour protéger son score.
+use macroquad::prelude::*;
+
+/// Durée (en secondes) sans action du joueur avant la mise en pause
+pub const IDLE_TIMEOUT: f32 = 30.0;
+/// Distance à partir de laquelle un asteroide est considéré comme dangereux
+pub const DANGER_RADIUS: f32 = 150.0;
+/// Distance parcourue par le vaisseau considérée comme un déplacement volontaire
+pub const MEANINGFUL_MOVE: f32 = 40.0;
+
+/// Énumération représentant les raisons d'une pause
+/// # Variantes
+/// - `Idle`: la pause automatique, quand le joueur est inactif
+/// - `Manual`: la pause demandée par le joueur avec la touche de pause
+/// - `ConfirmQuit`: la pause qui attend que le joueur confirme vouloir quitter la partie
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PauseReason {
+    Idle,
+    Manual,
+    ConfirmQuit,
+}
+
+/// Structure qui suit l'attention du joueur
+/// # Champs
+/// - `idle_time`: le temps écoulé depuis la dernière action du joueur
+/// - `anchor`: la position du vaisseau au début de la période d'inactivité
+/// - `danger_seen`: permet de savoir si un asteroide s'est approché pendant la période
+/// - `paused`: la raison de la pause en cours, s'il y en a une
+#[derive(Default)]
+pub struct AttentionTracker {
+    idle_time: f32,
+    anchor: Vec2,
+    danger_seen: bool,
+    paused: Option<PauseReason>,
+}
+
+impl AttentionTracker {
+    /// Crée un suivi d'attention pour un vaisseau à la position donnée
+    /// # Arguments
+    /// - `ship_pos`: la position du vaisseau
+    /// # Returns
+    /// - `Self`: un suivi sans inactivité
+    pub fn new(ship_pos: Vec2) -> Self {
+        Self {
+            anchor: ship_pos,
+            ..Self::default()
+        }
+    }
+
+    /// Met à jour le suivi pour une image de jeu.
+    /// Une action du joueur ou un déplacement volontaire du vaisseau recommence la période,
+    /// et la pause n'est déclenchée que si un asteroide s'est approché pendant cette période.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `had_input`: permet de savoir si le joueur a agi pendant l'image
+    /// - `ship_pos`: la position du vaisseau
+    /// - `nearest_asteroid`: la distance à l'asteroide le plus proche, s'il y en a un
+    /// # Returns
+    /// - `Option<PauseReason>`: la raison de la pause si elle vient d'être déclenchée
+    pub fn update(
+        &mut self,
+        dt: f32,
+        had_input: bool,
+        ship_pos: Vec2,
+        nearest_asteroid: Option<f32>,
+    ) -> Option<PauseReason> {
+        if self.paused.is_some() {
+            return None;
+        }
+        if had_input || ship_pos.distance(self.anchor) > MEANINGFUL_MOVE {
+            self.reset(ship_pos);
+            return None;
+        }
+
+        self.idle_time += dt;
+        if nearest_asteroid.is_some_and(|distance| distance < DANGER_RADIUS) {
+            self.danger_seen = true;
+        }
+
+        if self.idle_time >= IDLE_TIMEOUT && self.danger_seen {
+            self.paused = Some(PauseReason::Idle);
+            return self.paused;
+        }
+        None
+    }
+
+    /// Fonction qui renvoie la raison de la pause en cours
+    /// # Returns
+    /// - `Option<PauseReason>`: la raison de la pause, ou `None` si la partie n'est pas en pause
+    pub fn paused(&self) -> Option<PauseReason> {
+        self.paused
+    }
+
+    /// Met la partie en pause à la demande du joueur
+    pub fn pause(&mut self) {
+        self.paused = Some(PauseReason::Manual);
+    }
+
+    /// Met la partie en pause pour demander au joueur s'il veut vraiment la quitter
+    pub fn confirm_quit(&mut self) {
+        self.paused = Some(PauseReason::ConfirmQuit);
+    }
+
+    /// Reprend la partie après une action explicite du joueur
+    /// # Arguments
+    /// - `ship_pos`: la position du vaisseau
+    pub fn resume(&mut self, ship_pos: Vec2) {
+        self.paused = None;
+        self.reset(ship_pos);
+    }
+
+    /// Recommence la période d'inactivité à la position donnée
+    /// # Arguments
+    /// - `ship_pos`: la position du vaisseau
+    fn reset(&mut self, ship_pos: Vec2) {
+        self.idle_time = 0.0;
+        self.anchor = ship_pos;
+        self.danger_seen = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Simule une suite d'images et renvoie la première pause déclenchée.
+    fn run(
+        tracker: &mut AttentionTracker,
+        frames: usize,
+        had_input: bool,
+        ship_pos: Vec2,
+        nearest_asteroid: Option<f32>,
+    ) -> Option<PauseReason> {
+        let mut pause = None;
+        for _ in 0..frames {
+            pause = pause.or(tracker.update(0.5, had_input, ship_pos, nearest_asteroid));
+        }
+        pause
+    }
+
+    /// Vérifie qu'un joueur inactif et menacé est mis en pause après `IDLE_TIMEOUT`.
+    #[test]
+    fn test_idle_with_danger_pauses() {
+        let mut tracker = AttentionTracker::new(Vec2::ZERO);
+
+        assert_eq!(run(&mut tracker, 59, false, Vec2::ZERO, Some(100.0)), None);
+        assert_eq!(
+            run(&mut tracker, 1, false, Vec2::ZERO, Some(100.0)),
+            Some(PauseReason::Idle)
+        );
+        assert_eq!(tracker.paused(), Some(PauseReason::Idle));
+    }
+
+    /// Vérifie qu'un joueur immobile mais en sécurité n'est pas mis en pause.
+    #[test]
+    fn test_idle_but_safe_does_not_pause() {
+        let mut tracker = AttentionTracker::new(Vec2::ZERO);
+
+        assert_eq!(run(&mut tracker, 200, false, Vec2::ZERO, Some(500.0)), None);
+        assert_eq!(run(&mut tracker, 200, false, Vec2::ZERO, None), None);
+    }
+
+    /// Vérifie qu'une action du joueur recommence la période d'inactivité.
+    #[test]
+    fn test_input_resets_timer() {
+        let mut tracker = AttentionTracker::new(Vec2::ZERO);
+
+        run(&mut tracker, 50, false, Vec2::ZERO, Some(100.0));
+        run(&mut tracker, 1, true, Vec2::ZERO, Some(100.0));
+        assert_eq!(run(&mut tracker, 50, false, Vec2::ZERO, Some(100.0)), None);
+    }
+
+    /// Vérifie qu'un vaisseau qui dérive volontairement n'est pas mis en pause.
+    #[test]
+    fn test_drifting_ship_does_not_pause() {
+        let mut tracker = AttentionTracker::new(Vec2::ZERO);
+
+        for i in 0..200 {
+            let ship_pos = vec2(i as f32 * 5.0, 0.0);
+            assert_eq!(tracker.update(0.5, false, ship_pos, Some(100.0)), None);
+        }
+    }
+
+    /// Vérifie que la reprise demande une nouvelle période complète avant une autre pause.
+    #[test]
+    fn test_resume_restarts_window() {
+        let mut tracker = AttentionTracker::new(Vec2::ZERO);
+        run(&mut tracker, 60, false, Vec2::ZERO, Some(100.0));
+
+        tracker.resume(Vec2::ZERO);
+
+        assert_eq!(tracker.paused(), None);
+        assert_eq!(run(&mut tracker, 59, false, Vec2::ZERO, Some(100.0)), None);
+    }
+
+    /// Vérifie que la demande de confirmation fige la partie comme une pause,
+    /// sans être remplacée par la pause d'inactivité.
+    #[test]
+    fn test_quit_confirmation_pauses() {
+        let mut tracker = AttentionTracker::new(Vec2::ZERO);
+
+        tracker.confirm_quit();
+
+        assert_eq!(run(&mut tracker, 100, false, Vec2::ZERO, Some(100.0)), None);
+        assert_eq!(tracker.paused(), Some(PauseReason::ConfirmQuit));
+        tracker.resume(Vec2::ZERO);
+        assert_eq!(tracker.paused(), None);
+    }
+}
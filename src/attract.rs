@@ -0,0 +1,150 @@
+//! Module pour le mode démonstration de l'écran de démarrage, comme sur les bornes d'arcade.
+//! Après `ATTRACT_DELAY` secondes sans activité, une partie pilotée par le pilote automatique
+//! remplace l'écran de démarrage. Une touche ou un clic ramène l'écran de démarrage.
+//! La partie de démonstration est silencieuse et recommence quand le vaisseau est détruit.
+use asteroid::autopilot;
+use asteroid::spaceship::{Spaceship, SpaceshipTextures};
+use asteroid::world::{World, WorldTextures};
+use macroquad::prelude::*;
+
+/// Durée (en secondes) d'inactivité sur l'écran de démarrage avant le lancement de la démonstration
+pub const ATTRACT_DELAY: f32 = 10.0;
+
+/// Structure représentant le mode démonstration
+/// # Champs
+/// - `idle`: le temps passé sans activité sur l'écran de démarrage
+/// - `demo`: la partie de démonstration, présente seulement pendant la démonstration
+#[derive(Default)]
+pub struct AttractMode {
+    idle: f32,
+    pub demo: Option<World>,
+}
+
+impl AttractMode {
+    /// Met à jour le mode démonstration pendant une image de l'écran de démarrage.
+    /// Une touche ou un clic arrête la démonstration ; bouger la souris compte comme une activité
+    /// seulement avant la démonstration, pour qu'un léger mouvement ne l'interrompe pas.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `pressed`: permet de savoir si une touche ou un bouton de la souris a été enfoncé
+    /// - `moved`: permet de savoir si la souris a bougé
+    /// - `bounds`: la taille de l'aire de jeu
+    /// - `start`: crée une nouvelle partie de démonstration
+    pub fn update(
+        &mut self,
+        dt: f32,
+        pressed: bool,
+        moved: bool,
+        bounds: Vec2,
+        start: impl FnOnce() -> World,
+    ) {
+        if pressed || (moved && self.demo.is_none()) {
+            self.idle = 0.0;
+            self.demo = None;
+            return;
+        }
+        self.idle += dt;
+        match &mut self.demo {
+            Some(demo) if demo.spaceship.explosion_finished() => *demo = start(),
+            Some(demo) => {
+                demo.bounds = bounds;
+                autopilot::drive(demo, dt);
+                demo.handle_collisions();
+                demo.update(dt);
+                // La démonstration ne fait aucun bruit
+                demo.sound_events.clear();
+            }
+            None if self.idle >= ATTRACT_DELAY => self.demo = Some(start()),
+            None => {}
+        }
+    }
+}
+
+/// Fonction qui crée une partie de démonstration, en mode classique, sans soucoupe
+/// # Arguments
+/// - `ship_textures`: les textures du vaisseau
+/// - `textures`: les textures des objets de la partie
+/// - `bounds`: la taille de l'aire de jeu
+/// # Returns
+/// - `World`: la partie, avec les asteroides de la première vague
+pub fn demo_world(
+    ship_textures: &SpaceshipTextures,
+    textures: &WorldTextures,
+    bounds: Vec2,
+) -> World {
+    World::new(
+        Spaceship::new(bounds, ship_textures.clone()),
+        textures.clone(),
+        bounds,
+        0.0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asteroid::assets::dummy_texture;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Crée une partie de démonstration sans fenêtre
+    fn test_demo() -> World {
+        let ship = SpaceshipTextures {
+            ship: dummy_texture(),
+            shield_on: dummy_texture(),
+            shield_off: dummy_texture(),
+            shield_dead: dummy_texture(),
+        };
+        let textures = WorldTextures {
+            asteroids: vec![dummy_texture()],
+            black_hole: dummy_texture(),
+            ufo: dummy_texture(),
+        };
+        demo_world(&ship, &textures, BOUNDS)
+    }
+
+    /// Vérifie que la démonstration démarre après `ATTRACT_DELAY` secondes d'inactivité,
+    /// qu'un mouvement de souris ne l'arrête pas, et qu'une touche ramène l'écran de démarrage.
+    #[test]
+    fn test_demo_starts_after_idle_delay() {
+        let mut attract = AttractMode::default();
+        for _ in 0..9 {
+            attract.update(1.0, false, false, BOUNDS, test_demo);
+        }
+        assert!(attract.demo.is_none());
+        attract.update(1.0, false, true, BOUNDS, test_demo);
+        for _ in 0..9 {
+            attract.update(1.0, false, false, BOUNDS, test_demo);
+        }
+        assert!(
+            attract.demo.is_none(),
+            "La souris a bougé, l'attente recommence !"
+        );
+
+        attract.update(1.0, false, false, BOUNDS, test_demo);
+        assert!(attract.demo.is_some());
+        attract.update(0.1, false, true, BOUNDS, test_demo);
+        assert!(attract.demo.is_some());
+
+        attract.update(0.1, true, false, BOUNDS, test_demo);
+        assert!(attract.demo.is_none());
+        attract.update(1.0, false, false, BOUNDS, test_demo);
+        assert!(attract.demo.is_none());
+    }
+
+    /// Vérifie que la démonstration recommence une fois le vaisseau détruit et son explosion finie.
+    #[test]
+    fn test_demo_restarts_after_ship_explodes() {
+        let mut attract = AttractMode {
+            idle: ATTRACT_DELAY,
+            demo: Some(test_demo()),
+        };
+        let demo = attract.demo.as_mut().unwrap();
+        demo.spaceship.explode();
+        demo.spaceship.death_timer = 0.0;
+
+        attract.update(0.1, false, false, BOUNDS, test_demo);
+
+        assert!(!attract.demo.unwrap().spaceship.dying);
+    }
+}
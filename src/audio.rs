@@ -0,0 +1,95 @@
+//! Module pour les événements sonores du jeu.
+//! Chaque son joué correspond à un événement, ce qui permet aussi
+//! d'afficher un sous-titre pour les joueurs sourds ou malentendants.
+use macroquad::prelude::*;
+
+/// Énumération représentant les événements sonores du jeu
+/// # Variantes
+/// - `AsteroidDestroyed`: un asteroide a été détruit, à la position donnée
+/// - `ArmorHit`: un missile a rebondi sur le blindage d'un asteroide, à la position donnée
+/// - `ShieldLost`: le vaisseau a perdu son bouclier, à la position donnée
+/// - `ShieldRestored`: le bouclier du vaisseau s'est rechargé, à la position donnée
+/// - `BlackHoleWarning`: un trou noir est annoncé, à la position donnée
+/// - `BlackHoleFormed`: un trou noir est apparu, à la position donnée
+/// - `UfoDestroyed`: une soucoupe a été détruite, à la position donnée
+/// - `TurretDestroyed`: une tourelle du vaisseau mère a été détruite, à la position donnée
+/// - `BossDestroyed`: le vaisseau mère a été détruit, à la position donnée
+/// - `BossPhase`: le combat contre le vaisseau mère passe à la phase suivante
+/// - `PowerUpCollected`: le vaisseau a ramassé un bonus, à la position donnée
+/// - `BombDetonated`: le vaisseau a fait exploser une bombe, à la position donnée
+/// - `MissileFired`: le vaisseau a tiré un missile
+/// - `Overheated`: l'arme du vaisseau a surchauffé et se bloque
+/// - `DryFire`: le joueur a tiré avec une arme bloquée, qui fait un clic à vide
+/// - `NewWave`: une nouvelle vague commence
+/// - `StartGame`: la partie commence
+/// - `ShipDestroyed`: le vaisseau a été détruit
+/// - `GameOver`: la partie est terminée
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SoundEvent {
+    AsteroidDestroyed(Vec2),
+    ArmorHit(Vec2),
+    ShieldLost(Vec2),
+    ShieldRestored(Vec2),
+    BlackHoleWarning(Vec2),
+    BlackHoleFormed(Vec2),
+    UfoDestroyed(Vec2),
+    TurretDestroyed(Vec2),
+    BossDestroyed(Vec2),
+    BossPhase,
+    PowerUpCollected(Vec2),
+    BombDetonated(Vec2),
+    MissileFired,
+    Overheated,
+    DryFire,
+    NewWave,
+    StartGame,
+    ShipDestroyed,
+    GameOver,
+}
+
+impl SoundEvent {
+    /// Fonction qui renvoie la clé de traduction du sous-titre de l'événement
+    /// # Returns
+    /// - `Option<&str>`: la clé du sous-titre, ou `None` si l'événement n'en a pas besoin
+    pub fn caption_key(self) -> Option<&'static str> {
+        match self {
+            SoundEvent::AsteroidDestroyed(_) => Some("caption.asteroid_destroyed"),
+            SoundEvent::ArmorHit(_) => Some("caption.armor_hit"),
+            SoundEvent::ShieldLost(_) => Some("caption.shield_lost"),
+            SoundEvent::ShieldRestored(_) => Some("caption.shield_restored"),
+            SoundEvent::BlackHoleWarning(_) => Some("caption.black_hole_warning"),
+            SoundEvent::BlackHoleFormed(_) => Some("caption.black_hole_formed"),
+            SoundEvent::UfoDestroyed(_) => Some("caption.ufo_destroyed"),
+            SoundEvent::TurretDestroyed(_) => Some("caption.turret_destroyed"),
+            SoundEvent::BossDestroyed(_) => Some("caption.boss_destroyed"),
+            SoundEvent::BossPhase => Some("caption.boss_phase"),
+            SoundEvent::PowerUpCollected(_) => Some("caption.power_up"),
+            SoundEvent::BombDetonated(_) => Some("caption.bomb"),
+            SoundEvent::Overheated => Some("caption.overheated"),
+            SoundEvent::DryFire => Some("caption.dry_fire"),
+            SoundEvent::NewWave => Some("caption.new_wave"),
+            SoundEvent::ShipDestroyed => Some("caption.ship_destroyed"),
+            SoundEvent::MissileFired | SoundEvent::StartGame | SoundEvent::GameOver => None,
+        }
+    }
+
+    /// Fonction qui renvoie la position de l'événement dans le monde, si elle existe
+    /// # Returns
+    /// - `Option<Vec2>`: la position à laquelle l'événement s'est produit
+    pub fn position(self) -> Option<Vec2> {
+        match self {
+            SoundEvent::AsteroidDestroyed(pos)
+            | SoundEvent::ArmorHit(pos)
+            | SoundEvent::ShieldLost(pos)
+            | SoundEvent::ShieldRestored(pos)
+            | SoundEvent::BlackHoleWarning(pos)
+            | SoundEvent::BlackHoleFormed(pos)
+            | SoundEvent::UfoDestroyed(pos)
+            | SoundEvent::TurretDestroyed(pos)
+            | SoundEvent::BossDestroyed(pos)
+            | SoundEvent::PowerUpCollected(pos)
+            | SoundEvent::BombDetonated(pos) => Some(pos),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,496 @@
+//! Module pour charger et jouer les sons du jeu.
+//! La logique de la partie ne joue aucun son : elle produit des `SoundEvent`
+//! que le gestionnaire audio transforme en sons, avec le bon volume.
+//! La musique est jouée en boucle, et passe d'un morceau à l'autre par un fondu manuel.
+use crate::assets::{asset_path, load_asset};
+use crate::audio::SoundEvent;
+use crate::feedback::FeedbackProfile;
+use macroquad::audio::{
+    load_sound_from_bytes, play_sound, set_sound_volume, stop_sound, PlaySoundParams, Sound,
+};
+
+/// Volume de base du bruit du réacteur, joué en boucle tant que le vaisseau pousse
+const ENGINE_VOLUME: f32 = 0.4;
+/// Volume de base de la musique
+const MUSIC_VOLUME: f32 = 0.5;
+/// Durée (en secondes) du fondu entre deux musiques
+pub const MUSIC_FADE_TIME: f32 = 0.5;
+
+/// Énumération représentant les musiques du jeu
+/// # Variantes
+/// - `Menu`: la musique calme des menus
+/// - `Game`: la musique de la partie
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Music {
+    Menu,
+    Game,
+}
+
+/// Énumération représentant les pistes sonores chargées
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Track {
+    AsteroidDestroyed,
+    ShieldLost,
+    Missile,
+    StartGame,
+    GameOver,
+    NewWave,
+    UfoDestroyed,
+    Bomb,
+    Clink,
+    Hum,
+}
+
+/// Structure regroupant les sons chargés au démarrage.
+/// Un son indispensable qui manque est remplacé par le silence, avec un message qui nomme le fichier.
+/// # Champs
+/// - `asteroid_destroyed`: son pour l'asteroide détruit
+/// - `shield_lost`: son quand on perd le bouclier
+/// - `missile`: son d'un tir, aussi joué quand le vaisseau est détruit
+/// - `start_game`: son quand on lance la partie
+/// - `game_over`: son quand on perd la partie
+/// - `new_wave`: son quand une nouvelle vague commence
+/// - `ufo_destroyed`: son quand une soucoupe est détruite
+/// - `engine`: bruit du réacteur, facultatif : le jeu s'en passe si le fichier n'existe pas
+/// - `bomb`: son de la bombe, facultatif : l'explosion d'asteroide le remplace si le fichier n'existe pas
+/// - `clink`: tintement métallique d'un blindage touché, facultatif : le son du tir le remplace
+/// - `hum`: grondement montant d'un trou noir annoncé, facultatif : l'annonce reste silencieuse
+/// - `music_menu`: musique des menus, facultative : les menus restent silencieux si le fichier n'existe pas
+/// - `music_game`: musique de la partie, facultative
+#[derive(Default)]
+struct Sounds {
+    asteroid_destroyed: Option<Sound>,
+    shield_lost: Option<Sound>,
+    missile: Option<Sound>,
+    start_game: Option<Sound>,
+    game_over: Option<Sound>,
+    new_wave: Option<Sound>,
+    ufo_destroyed: Option<Sound>,
+    engine: Option<Sound>,
+    bomb: Option<Sound>,
+    clink: Option<Sound>,
+    hum: Option<Sound>,
+    music_menu: Option<Sound>,
+    music_game: Option<Sound>,
+}
+
+impl Sounds {
+    /// Fonction qui renvoie le son d'une piste
+    /// # Arguments
+    /// - `track`: la piste
+    /// # Returns
+    /// - `Option<&Sound>`: le son chargé, ou `None` si la piste est silencieuse
+    fn get(&self, track: Track) -> Option<&Sound> {
+        match track {
+            Track::AsteroidDestroyed => self.asteroid_destroyed.as_ref(),
+            Track::ShieldLost => self.shield_lost.as_ref(),
+            Track::Missile => self.missile.as_ref(),
+            Track::StartGame => self.start_game.as_ref(),
+            Track::GameOver => self.game_over.as_ref(),
+            Track::NewWave => self.new_wave.as_ref(),
+            Track::UfoDestroyed => self.ufo_destroyed.as_ref(),
+            Track::Bomb => self.bomb.as_ref().or(self.asteroid_destroyed.as_ref()),
+            Track::Clink => self.clink.as_ref().or(self.missile.as_ref()),
+            Track::Hum => self.hum.as_ref(),
+        }
+    }
+
+    /// Fonction qui renvoie le son d'une musique
+    /// # Arguments
+    /// - `music`: la musique
+    /// # Returns
+    /// - `Option<&Sound>`: le son chargé, ou `None` si le fichier n'existe pas
+    fn music(&self, music: Music) -> Option<&Sound> {
+        match music {
+            Music::Menu => self.music_menu.as_ref(),
+            Music::Game => self.music_game.as_ref(),
+        }
+    }
+}
+
+/// Structure qui joue les sons du jeu
+/// # Champs
+/// - `sounds`: les sons chargés, ou `None` pour un gestionnaire muet utilisable sans audio
+/// - `master_volume`: le volume général, multiplié au volume de chaque son
+/// - `sfx_volume`: le volume des effets sonores, multiplié au volume général
+/// - `music_volume`: le volume de la musique, multiplié au volume général, indépendant des effets
+/// - `muted`: permet de couper tous les sons
+/// - `feedback`: le profil de retour, qui peut rendre les effets sonores plus forts
+/// - `engine_playing`: permet de savoir si le bruit du réacteur est en train de tourner
+/// - `music`: la musique en train de tourner, éventuellement pendant son fondu de sortie
+/// - `wanted_music`: la musique demandée pour l'état du jeu, `None` pour le silence
+/// - `music_fade`: le volume du fondu de la musique, entre 0 et 1
+pub struct AudioManager {
+    sounds: Option<Sounds>,
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+    pub muted: bool,
+    pub feedback: FeedbackProfile,
+    engine_playing: bool,
+    music: Option<Music>,
+    wanted_music: Option<Music>,
+    music_fade: f32,
+}
+
+/// Charge un son du dossier des ressources
+/// # Arguments
+/// - `name`: le nom du son dans le dossier des ressources
+/// # Returns
+/// - `Result<Sound, macroquad::Error>`: le son chargé, ou l'erreur de chargement
+async fn load_asset_sound(name: &str) -> Result<Sound, macroquad::Error> {
+    load_sound_from_bytes(&load_asset(name).await?).await
+}
+
+/// Charge un son indispensable, remplacé par le silence s'il ne peut pas être chargé
+/// # Arguments
+/// - `name`: le nom du son dans le dossier des ressources
+/// # Returns
+/// - `Option<Sound>`: le son chargé, ou `None` pour une piste silencieuse
+async fn load_required_sound(name: &str) -> Option<Sound> {
+    load_asset_sound(name)
+        .await
+        .map_err(|err| {
+            log::warn!(
+                "Son manquant : {}, remplacé par le silence ({:?})",
+                asset_path(name),
+                err
+            )
+        })
+        .ok()
+}
+
+impl AudioManager {
+    /// Charge tous les sons du jeu
+    /// # Returns
+    /// - `Self`: le gestionnaire audio, muet pour les sons qui n'ont pas pu être chargés
+    pub async fn load() -> Self {
+        let sounds = Sounds {
+            asteroid_destroyed: load_required_sound("audio/asteroid_destroyed.wav").await,
+            shield_lost: load_required_sound("audio/shield_lost.wav").await,
+            missile: load_required_sound("audio/missile_sound.wav").await,
+            start_game: load_required_sound("audio/start_game.wav").await,
+            game_over: load_required_sound("audio/game_over.wav").await,
+            new_wave: load_required_sound("audio/new_wave.wav").await,
+            ufo_destroyed: load_required_sound("audio/ufo_destroyed.wav").await,
+            engine: load_asset_sound("audio/engine.wav").await.ok(),
+            bomb: load_asset_sound("audio/bomb.wav").await.ok(),
+            clink: load_asset_sound("audio/clink.wav").await.ok(),
+            hum: load_asset_sound("audio/black_hole_hum.wav").await.ok(),
+            music_menu: load_asset_sound("audio/music_menu.wav").await.ok(),
+            music_game: load_asset_sound("audio/music_game.wav").await.ok(),
+        };
+        Self {
+            sounds: Some(sounds),
+            ..Self::silent()
+        }
+    }
+
+    /// Crée un gestionnaire sans aucun son, pour les tests et les simulations sans audio
+    /// # Returns
+    /// - `Self`: un gestionnaire qui ne joue rien
+    pub fn silent() -> Self {
+        Self {
+            sounds: None,
+            master_volume: 1.0,
+            sfx_volume: 1.0,
+            music_volume: 1.0,
+            muted: false,
+            feedback: FeedbackProfile::Normal,
+            engine_playing: false,
+            music: None,
+            wanted_music: None,
+            music_fade: 0.0,
+        }
+    }
+
+    /// Fonction qui associe un événement à sa piste et à son volume de base
+    /// # Arguments
+    /// - `event`: l'événement sonore
+    /// # Returns
+    /// - `Option<(Track, f32)>`: la piste et son volume, ou `None` si l'événement est silencieux
+    fn mix(event: SoundEvent) -> Option<(Track, f32)> {
+        match event {
+            SoundEvent::AsteroidDestroyed(_) => Some((Track::AsteroidDestroyed, 0.7)),
+            SoundEvent::ArmorHit(_) => Some((Track::Clink, 0.8)),
+            SoundEvent::ShieldLost(_) => Some((Track::ShieldLost, 1.5)),
+            SoundEvent::ShieldRestored(_) => Some((Track::StartGame, 0.5)),
+            SoundEvent::BlackHoleWarning(_) => Some((Track::Hum, 0.8)),
+            SoundEvent::BlackHoleFormed(_) => None,
+            SoundEvent::UfoDestroyed(_) => Some((Track::UfoDestroyed, 1.0)),
+            SoundEvent::TurretDestroyed(_) => Some((Track::UfoDestroyed, 0.8)),
+            SoundEvent::BossDestroyed(_) => Some((Track::UfoDestroyed, 1.5)),
+            SoundEvent::BossPhase => Some((Track::NewWave, 1.2)),
+            SoundEvent::PowerUpCollected(_) => Some((Track::StartGame, 0.6)),
+            SoundEvent::BombDetonated(_) => Some((Track::Bomb, 1.5)),
+            SoundEvent::MissileFired => Some((Track::Missile, 0.5)),
+            SoundEvent::Overheated => Some((Track::ShieldLost, 0.8)),
+            SoundEvent::DryFire => Some((Track::Clink, 0.4)),
+            SoundEvent::NewWave => Some((Track::NewWave, 1.0)),
+            SoundEvent::StartGame => Some((Track::StartGame, 1.0)),
+            SoundEvent::ShipDestroyed => Some((Track::Missile, 1.0)),
+            SoundEvent::GameOver => Some((Track::GameOver, 1.0)),
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur appliqué à tous les effets sonores
+    /// # Returns
+    /// - `f32`: le volume général, celui des effets sonores et celui du profil de retour multipliés
+    fn sfx_gain(&self) -> f32 {
+        self.master_volume * self.sfx_volume * self.feedback.volume()
+    }
+
+    /// Fonction qui calcule le volume auquel un événement est joué
+    /// # Arguments
+    /// - `event`: l'événement sonore
+    /// # Returns
+    /// - `Option<f32>`: le volume, ou `None` si le son est coupé ou si l'événement est silencieux
+    pub fn volume_for(&self, event: SoundEvent) -> Option<f32> {
+        if self.muted {
+            return None;
+        }
+        Self::mix(event).map(|(_, volume)| volume * self.sfx_gain())
+    }
+
+    /// Joue le son correspondant à un événement
+    /// # Arguments
+    /// - `event`: l'événement sonore
+    pub fn play_event(&self, event: SoundEvent) {
+        let (Some(sounds), Some((track, _)), Some(volume)) =
+            (&self.sounds, Self::mix(event), self.volume_for(event))
+        else {
+            return;
+        };
+        let Some(sound) = sounds.get(track) else {
+            return;
+        };
+        play_sound(
+            sound,
+            PlaySoundParams {
+                looped: false,
+                volume,
+            },
+        );
+    }
+
+    /// Démarre le bruit du réacteur quand le vaisseau commence à pousser,
+    /// et l'arrête quand il ne pousse plus ou quand le son est coupé
+    /// # Arguments
+    /// - `thrusting`: `true` si le vaisseau pousse pendant cette image
+    pub fn set_engine(&mut self, thrusting: bool) {
+        let on = thrusting && !self.muted;
+        if on == self.engine_playing {
+            return;
+        }
+        self.engine_playing = on;
+        let Some(engine) = self.sounds.as_ref().and_then(|s| s.engine.as_ref()) else {
+            return;
+        };
+        if on {
+            play_sound(
+                engine,
+                PlaySoundParams {
+                    looped: true,
+                    volume: ENGINE_VOLUME * self.sfx_gain(),
+                },
+            );
+        } else {
+            stop_sound(engine);
+        }
+    }
+
+    /// Choisit la musique à jouer, qui remplacera la musique actuelle après son fondu
+    /// # Arguments
+    /// - `music`: la musique demandée, ou `None` pour arrêter la musique
+    pub fn set_music(&mut self, music: Option<Music>) {
+        self.wanted_music = music;
+    }
+
+    /// Fonction qui calcule le volume de la musique
+    /// # Returns
+    /// - `f32`: le volume, nul quand le son est coupé
+    pub fn music_gain(&self) -> f32 {
+        if self.muted {
+            return 0.0;
+        }
+        MUSIC_VOLUME * self.master_volume * self.music_volume * self.music_fade
+    }
+
+    /// Fait avancer le fondu de la musique.
+    /// La musique actuelle baisse jusqu'au silence avant d'être arrêtée et remplacée
+    /// par la musique demandée, qui monte ensuite jusqu'à son volume. Une musique déjà
+    /// en train de tourner n'est jamais relancée : elle ne peut pas être jouée deux fois en même temps.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update_music(&mut self, dt: f32) {
+        let step = dt / MUSIC_FADE_TIME;
+        if self.music == self.wanted_music {
+            self.music_fade = (self.music_fade + step).min(1.0);
+        } else {
+            self.music_fade = (self.music_fade - step).max(0.0);
+            if self.music_fade <= 0.0 || self.music.is_none() {
+                self.switch_music();
+            }
+        }
+        let volume = self.music_gain();
+        if let Some(sound) = self.current_music_sound() {
+            set_sound_volume(sound, volume);
+        }
+    }
+
+    /// Arrête la musique actuelle et démarre la musique demandée, en silence
+    fn switch_music(&mut self) {
+        if let Some(sound) = self.current_music_sound() {
+            stop_sound(sound);
+        }
+        self.music = self.wanted_music;
+        self.music_fade = 0.0;
+        if let Some(sound) = self.current_music_sound() {
+            play_sound(
+                sound,
+                PlaySoundParams {
+                    looped: true,
+                    volume: 0.0,
+                },
+            );
+        }
+    }
+
+    /// Fonction qui renvoie le son de la musique actuelle
+    /// # Returns
+    /// - `Option<&Sound>`: le son, ou `None` s'il n'y a pas de musique ou pas de son chargé
+    fn current_music_sound(&self) -> Option<&Sound> {
+        let music = self.music?;
+        self.sounds.as_ref()?.music(music)
+    }
+
+    /// Joue le son d'un tir
+    pub fn play_missile(&self) {
+        self.play_event(SoundEvent::MissileFired);
+    }
+
+    /// Joue le son d'un asteroide détruit
+    pub fn play_asteroid_destroyed(&self) {
+        self.play_event(SoundEvent::AsteroidDestroyed(Default::default()));
+    }
+
+    /// Joue le son d'une nouvelle vague
+    pub fn play_new_wave(&self) {
+        self.play_event(SoundEvent::NewWave);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que le volume suit le volume général, celui des effets et le profil de retour.
+    #[test]
+    fn test_volume_follows_master_and_feedback() {
+        let mut audio = AudioManager::silent();
+        assert_eq!(audio.volume_for(SoundEvent::NewWave), Some(1.0));
+
+        audio.master_volume = 0.5;
+        assert_eq!(audio.volume_for(SoundEvent::NewWave), Some(0.5));
+
+        audio.sfx_volume = 0.5;
+        assert_eq!(audio.volume_for(SoundEvent::NewWave), Some(0.25));
+
+        audio.feedback = FeedbackProfile::Spectacle;
+        assert_eq!(
+            audio.volume_for(SoundEvent::NewWave),
+            Some(0.25 * FeedbackProfile::Spectacle.volume())
+        );
+    }
+
+    /// Vérifie qu'un gestionnaire muet ne joue rien.
+    #[test]
+    fn test_muted_plays_nothing() {
+        let mut audio = AudioManager::silent();
+        audio.muted = true;
+
+        assert_eq!(audio.volume_for(SoundEvent::MissileFired), None);
+        assert_eq!(
+            audio.volume_for(SoundEvent::BlackHoleFormed(Default::default())),
+            None
+        );
+    }
+
+    /// Vérifie que le gestionnaire sans son peut être utilisé sans audio.
+    #[test]
+    fn test_silent_manager_is_a_no_op() {
+        let audio = AudioManager::silent();
+
+        audio.play_missile();
+        audio.play_asteroid_destroyed();
+        audio.play_new_wave();
+    }
+
+    /// Vérifie que des sons tous manquants sont remplacés par le silence, sans paniquer,
+    /// même pour les pistes qui en remplacent une autre.
+    #[test]
+    fn test_missing_sounds_are_silent() {
+        let sounds = Sounds::default();
+        for track in [Track::Missile, Track::Bomb, Track::Clink, Track::Hum] {
+            assert!(sounds.get(track).is_none());
+        }
+
+        let audio = AudioManager {
+            sounds: Some(sounds),
+            ..AudioManager::silent()
+        };
+        audio.play_event(SoundEvent::MissileFired);
+        audio.play_event(SoundEvent::NewWave);
+    }
+
+    /// Vérifie que le bruit du réacteur suit la poussée, et ne démarre pas quand le son est coupé.
+    #[test]
+    fn test_engine_follows_thrust() {
+        let mut audio = AudioManager::silent();
+        audio.set_engine(true);
+        assert!(audio.engine_playing);
+        audio.set_engine(false);
+        assert!(!audio.engine_playing);
+
+        audio.muted = true;
+        audio.set_engine(true);
+        assert!(!audio.engine_playing);
+    }
+
+    /// Vérifie que la musique passe d'un morceau à l'autre par un fondu, qu'une partie relancée
+    /// pendant le fondu de l'écran de fin reprend la même musique sans la relancer,
+    /// et que le volume de la musique suit son propre réglage et le son coupé.
+    #[test]
+    fn test_music_fades_between_tracks() {
+        let mut audio = AudioManager::silent();
+        audio.set_music(Some(Music::Menu));
+        audio.update_music(0.0);
+        assert_eq!(audio.music, Some(Music::Menu));
+
+        audio.update_music(MUSIC_FADE_TIME);
+        assert_eq!(audio.music_gain(), MUSIC_VOLUME);
+
+        // La partie commence : la musique des menus baisse avant d'être remplacée
+        audio.set_music(Some(Music::Game));
+        audio.update_music(MUSIC_FADE_TIME / 2.0);
+        assert_eq!(audio.music, Some(Music::Menu));
+        audio.update_music(MUSIC_FADE_TIME / 2.0);
+        assert_eq!(audio.music, Some(Music::Game));
+        assert_eq!(audio.music_fade, 0.0);
+        audio.update_music(MUSIC_FADE_TIME);
+
+        // Fin de partie, puis nouvelle partie avant la fin du fondu
+        audio.set_music(None);
+        audio.update_music(MUSIC_FADE_TIME / 4.0);
+        audio.set_music(Some(Music::Game));
+        audio.update_music(MUSIC_FADE_TIME / 4.0);
+        assert_eq!(audio.music, Some(Music::Game));
+        assert_eq!(audio.music_fade, 1.0);
+
+        audio.sfx_volume = 0.0;
+        audio.music_volume = 0.5;
+        assert_eq!(audio.music_gain(), MUSIC_VOLUME * 0.5);
+        audio.muted = true;
+        assert_eq!(audio.music_gain(), 0.0);
+    }
+}
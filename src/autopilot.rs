@@ -0,0 +1,187 @@
+//! Module pour le pilote automatique de la démo affichée derrière l'écran de démarrage.
+//! Le pilote ne triche pas : il n'utilise que les commandes publiques du vaisseau
+//! (rotation, poussée, tir), comme un joueur : ses commandes deviennent une `ReplayFrame`,
+//! appliquée à la partie comme celles du clavier ou d'un replay. Il se tourne vers l'asteroide
+//! le plus proche, tire quand il est aligné et s'écarte des asteroides trop proches.
+use crate::asteroid::Asteroid;
+use crate::replay::ReplayFrame;
+use crate::spaceship::Spaceship;
+use crate::stellarobject::{wrapped_offset, StellarObject};
+use crate::world::World;
+use macroquad::prelude::*;
+use std::f32::consts::PI;
+
+/// Angle maximal (en radians) dont le pilote tourne le vaisseau à chaque image, comme les touches
+pub const AUTOPILOT_TURN_RATE: f32 = 0.05;
+/// Écart d'angle (en radians) en dessous duquel le pilote tire sur sa cible
+pub const FIRE_ALIGNMENT: f32 = 0.1;
+/// Distance (en pixels, entre les bords) en dessous de laquelle un asteroide est une menace
+pub const THREAT_DISTANCE: f32 = 120.0;
+/// Poussée appliquée par le pilote pour s'écarter d'une menace, comme les touches
+pub const AUTOPILOT_THRUST: f32 = 0.01;
+
+/// Structure représentant les commandes choisies par le pilote pour une image
+/// # Champs
+/// - `turn`: l'angle dont tourner le vaisseau, en radians
+/// - `thrust`: la poussée à appliquer, positive en avant, négative en arrière, nulle pour freiner
+/// - `fire`: permet de savoir si le pilote veut tirer
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct AutopilotCommand {
+    pub turn: f32,
+    pub thrust: f32,
+    pub fire: bool,
+}
+
+impl AutopilotCommand {
+    /// Convertit les commandes du pilote en commandes de joueur pour une image
+    /// # Arguments
+    /// - `dt`: la durée de l'image
+    /// # Returns
+    /// - `ReplayFrame`: les mêmes commandes, sans bombe ni pause, le freinage venant de l'absence de poussée
+    pub fn frame(self, dt: f32) -> ReplayFrame {
+        ReplayFrame {
+            dt,
+            turn: self.turn,
+            thrust_forward: self.thrust > 0.0,
+            thrust_backward: self.thrust < 0.0,
+            fire: self.fire,
+            ..ReplayFrame::default()
+        }
+    }
+}
+
+/// Fonction qui choisit les commandes du pilote.
+/// Les asteroides encore annoncés sont ignorés : ils ne peuvent ni toucher ni être touchés.
+/// # Arguments
+/// - `ship`: le vaisseau piloté
+/// - `asteroids`: les asteroides de la partie
+/// - `bounds`: la taille de l'aire de jeu
+/// # Returns
+/// - `AutopilotCommand`: les commandes de l'image, aucune s'il n'y a pas d'asteroide
+pub fn steer(ship: &Spaceship, asteroids: &[Asteroid], bounds: Vec2) -> AutopilotCommand {
+    let position = ship.get_pos();
+    let nearest = asteroids
+        .iter()
+        .filter(|a| a.is_active() && !a.incubating())
+        .map(|a| {
+            let offset = wrapped_offset(position, a.get_pos(), bounds);
+            (offset, offset.length() - a.radius())
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+    let Some((offset, gap)) = nearest else {
+        return AutopilotCommand::default();
+    };
+
+    let wanted = offset.y.atan2(offset.x);
+    let difference = (wanted - ship.rotation + PI).rem_euclid(2.0 * PI) - PI;
+    // Le vaisseau ne pousse que dans l'axe : il recule face à la menace et avance sinon
+    let thrust = if gap - ship.radius() < THREAT_DISTANCE {
+        let facing = Vec2::from_angle(ship.rotation);
+        -facing.dot(offset).signum() * AUTOPILOT_THRUST
+    } else {
+        0.0
+    };
+    AutopilotCommand {
+        turn: difference.clamp(-AUTOPILOT_TURN_RATE, AUTOPILOT_TURN_RATE),
+        thrust,
+        fire: difference.abs() < FIRE_ALIGNMENT,
+    }
+}
+
+/// Pilote le vaisseau de la partie pendant une image, avec les commandes choisies par `steer`.
+/// Un vaisseau qui explose ne répond plus aux commandes.
+/// # Arguments
+/// - `world`: la partie de démonstration
+/// - `dt`: le temps écoulé depuis la dernière image
+pub fn drive(world: &mut World, dt: f32) {
+    if world.spaceship.dying {
+        return;
+    }
+    steer(&world.spaceship, &world.asteroids, world.bounds)
+        .frame(dt)
+        .apply(world);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::dummy_texture;
+    use crate::asteroid::Size;
+    use crate::spaceship::SpaceshipTextures;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Crée un vaisseau immobile tourné vers la droite
+    fn test_ship(position: Vec2) -> Spaceship {
+        Spaceship::with_textures(
+            position,
+            SpaceshipTextures {
+                ship: dummy_texture(),
+                shield_on: dummy_texture(),
+                shield_off: dummy_texture(),
+                shield_dead: dummy_texture(),
+            },
+        )
+    }
+
+    /// Place un petit asteroide immobile
+    fn rock(position: Vec2) -> Asteroid {
+        Asteroid::new_with_size(Size::Small, position, Vec2::ZERO)
+    }
+
+    /// Vérifie que le pilote se tourne vers l'asteroide le plus proche, par le plus court chemin.
+    #[test]
+    fn test_turns_towards_nearest_asteroid() {
+        let ship = test_ship(vec2(400.0, 300.0));
+        let below = [rock(vec2(400.0, 500.0)), rock(vec2(100.0, 100.0))];
+        let above = [rock(vec2(400.0, 100.0))];
+
+        let command = steer(&ship, &below, BOUNDS);
+        assert_eq!(command.turn, AUTOPILOT_TURN_RATE);
+        assert!(!command.fire);
+        assert_eq!(steer(&ship, &above, BOUNDS).turn, -AUTOPILOT_TURN_RATE);
+    }
+
+    /// Vérifie que le pilote ne tire que lorsqu'il est aligné sur sa cible,
+    /// en passant par les bords de l'écran.
+    #[test]
+    fn test_fires_when_aligned() {
+        let mut ship = test_ship(vec2(700.0, 300.0));
+        let across_edge = [rock(vec2(50.0, 300.0)), rock(vec2(400.0, 300.0))];
+        assert!(steer(&ship, &across_edge, BOUNDS).fire);
+
+        ship.rotation = PI;
+        assert!(!steer(&ship, &across_edge, BOUNDS).fire);
+    }
+
+    /// Vérifie que le pilote s'écarte d'une menace proche : il recule si elle est devant lui,
+    /// avance si elle est derrière, et ne pousse pas sans menace.
+    #[test]
+    fn test_thrusts_away_from_threats() {
+        let ship = test_ship(vec2(400.0, 300.0));
+
+        let ahead = steer(&ship, &[rock(vec2(480.0, 300.0))], BOUNDS);
+        assert!(ahead.thrust < 0.0);
+        let behind = steer(&ship, &[rock(vec2(320.0, 300.0))], BOUNDS);
+        assert!(behind.thrust > 0.0);
+        let far = steer(&ship, &[rock(vec2(750.0, 300.0))], BOUNDS);
+        assert_eq!(far.thrust, 0.0);
+        let frame = ahead.frame(0.1);
+        assert!(frame.thrust_backward && !frame.thrust_forward && !frame.bomb);
+        assert!(!far.frame(0.1).thrust_backward && !far.frame(0.1).thrust_forward);
+        assert_eq!(steer(&ship, &[], BOUNDS), AutopilotCommand::default());
+    }
+
+    /// Vérifie que les asteroides annoncés sont ignorés.
+    #[test]
+    fn test_ignores_incubating_asteroids() {
+        let ship = test_ship(vec2(400.0, 300.0));
+        let mut announced = rock(vec2(450.0, 300.0));
+        announced.incubate(1.0);
+
+        assert_eq!(
+            steer(&ship, &[announced], BOUNDS),
+            AutopilotCommand::default()
+        );
+    }
+}
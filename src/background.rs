@@ -0,0 +1,204 @@
+//! Module pour gérer l'arrière-plan du jeu.
+//! L'arrière-plan peut être recadré pour couvrir l'écran, en choisissant la variante
+//! dont le format est le plus proche de celui de la fenêtre, ou répété en mosaïque
+//! avec une lente dérive.
+use macroquad::prelude::*;
+
+/// Vitesse de dérive (en pixels par seconde) de la mosaïque
+const TILE_DRIFT: Vec2 = vec2(6.0, 3.0);
+
+/// Énumération représentant les façons de dessiner l'arrière-plan
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum BackgroundMode {
+    #[default]
+    Cover,
+    Tiled,
+}
+
+/// Structure représentant l'arrière-plan du jeu
+/// # Champs
+/// - `variants`: les textures disponibles pour différents formats d'écran
+/// - `tile`: la texture répétée en mode mosaïque
+/// - `current`: l'indice de la variante choisie pour la taille actuelle de la fenêtre
+/// - `screen_size`: la taille de la fenêtre lors du dernier choix de variante
+/// - `drift`: le décalage actuel de la mosaïque
+pub struct Background {
+    variants: Vec<Texture2D>,
+    tile: Texture2D,
+    current: usize,
+    screen_size: Vec2,
+    drift: Vec2,
+}
+
+impl Background {
+    /// Crée un nouvel arrière-plan
+    /// # Arguments
+    /// - `variants`: les textures disponibles, la première étant celle par défaut
+    /// - `tile`: la texture à répéter en mode mosaïque
+    /// # Returns
+    /// - `Self`: un arrière-plan
+    pub fn new(variants: Vec<Texture2D>, tile: Texture2D) -> Self {
+        Self {
+            variants,
+            tile,
+            current: 0,
+            screen_size: Vec2::ZERO,
+            drift: Vec2::ZERO,
+        }
+    }
+
+    /// Met à jour la variante choisie si la fenêtre a changé de taille et fait dériver la mosaïque
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        let screen_size = vec2(screen_width(), screen_height());
+        if screen_size != self.screen_size {
+            self.screen_size = screen_size;
+            let aspects: Vec<f32> = self
+                .variants
+                .iter()
+                .map(|texture| texture.width() / texture.height())
+                .collect();
+            self.current = best_variant(screen_size.x / screen_size.y, &aspects).unwrap_or(0);
+        }
+
+        let tile_size = self.tile.size();
+        self.drift = (self.drift + TILE_DRIFT * dt).rem_euclid(tile_size);
+    }
+
+    /// Dessine l'arrière-plan selon le mode demandé
+    /// # Arguments
+    /// - `mode`: la façon de dessiner l'arrière-plan
+    pub fn draw(&self, mode: BackgroundMode) {
+        match mode {
+            BackgroundMode::Cover => draw_background(&self.variants[self.current]),
+            BackgroundMode::Tiled => {
+                clear_background(BLACK);
+                let screen_size = vec2(screen_width(), screen_height());
+                for offset in tile_offsets(screen_size, self.tile.size(), self.drift) {
+                    draw_texture(&self.tile, offset.x, offset.y, WHITE);
+                }
+            }
+        }
+    }
+}
+
+/// Fonction qui choisit la variante dont le format est le plus proche de celui de la fenêtre.
+/// Les formats sont comparés par leur rapport, pour qu'un écran deux fois plus large
+/// qu'une variante soit aussi loin d'elle qu'un écran deux fois moins large.
+/// # Arguments
+/// - `window_aspect`: le rapport largeur / hauteur de la fenêtre
+/// - `aspects`: le rapport largeur / hauteur de chaque variante
+/// # Returns
+/// - `Option<usize>`: l'indice de la meilleure variante, ou `None` s'il n'y en a aucune
+pub fn best_variant(window_aspect: f32, aspects: &[f32]) -> Option<usize> {
+    aspects
+        .iter()
+        .enumerate()
+        .filter(|(_, aspect)| aspect.is_finite() && **aspect > 0.0)
+        .min_by(|(_, a), (_, b)| {
+            let distance_a = (window_aspect / **a).ln().abs();
+            let distance_b = (window_aspect / **b).ln().abs();
+            distance_a.total_cmp(&distance_b)
+        })
+        .map(|(i, _)| i)
+}
+
+/// Fonction qui calcule la position de chaque tuile de la mosaïque pour couvrir l'écran
+/// # Arguments
+/// - `screen_size`: la taille de l'écran
+/// - `tile_size`: la taille d'une tuile
+/// - `drift`: le décalage de la mosaïque, ramené à la taille d'une tuile
+/// # Returns
+/// - `Vec<Vec2>`: la position du coin haut gauche de chaque tuile
+pub fn tile_offsets(screen_size: Vec2, tile_size: Vec2, drift: Vec2) -> Vec<Vec2> {
+    if tile_size.x <= 0.0 || tile_size.y <= 0.0 {
+        return Vec::new();
+    }
+    let start = -drift.rem_euclid(tile_size);
+    let columns = ((screen_size.x - start.x) / tile_size.x).ceil() as usize;
+    let rows = ((screen_size.y - start.y) / tile_size.y).ceil() as usize;
+
+    let mut offsets = Vec::with_capacity(columns * rows);
+    for row in 0..rows {
+        for column in 0..columns {
+            offsets.push(start + vec2(column as f32, row as f32) * tile_size);
+        }
+    }
+    offsets
+}
+
+/// Dessine une texture en tant qu'arrière-plan.
+/// # Arguments
+/// - `background_texture`: Référence à une texture d'arrière-plan à dessiner.
+pub fn draw_background(background_texture: &Texture2D) {
+    // effacer l'arriere plan avec une couleur unie
+    clear_background(BLACK);
+
+    // met à l'échelle par rapport à la taille de l'écran
+    let scale_x = screen_width() / background_texture.width();
+    let scale_y = screen_height() / background_texture.height();
+
+    let scale = scale_x.max(scale_y);
+
+    // calculer la largeur et la hauteur de l'image redimensionnée
+    let width = background_texture.width() * scale;
+    let height = background_texture.height() * scale;
+
+    // dessiner la texture redimensionnee pour qu'elle couvre l'ecran
+    draw_texture_ex(
+        background_texture, // Texture d'arrière-plan
+        0.0,
+        0.0,
+        WHITE,
+        DrawTextureParams {
+            dest_size: Some(Vec2::new(width, height)), // Taille de destination
+            ..Default::default()
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que la variante la plus proche du format de la fenêtre est choisie.
+    #[test]
+    fn test_best_variant_picks_closest_aspect() {
+        let aspects = [16.0 / 9.0, 21.0 / 9.0, 32.0 / 9.0];
+
+        assert_eq!(best_variant(16.0 / 9.0, &aspects), Some(0));
+        assert_eq!(best_variant(2.4, &aspects), Some(1));
+        assert_eq!(best_variant(32.0 / 9.0, &aspects), Some(2));
+        assert_eq!(best_variant(4.0 / 3.0, &aspects), Some(0));
+    }
+
+    /// Vérifie qu'aucune variante n'est choisie quand la liste est vide.
+    #[test]
+    fn test_best_variant_without_variants() {
+        assert_eq!(best_variant(1.5, &[]), None);
+    }
+
+    /// Vérifie que la mosaïque couvre exactement l'écran quand il n'y a pas de dérive.
+    #[test]
+    fn test_tile_offsets_without_drift() {
+        let offsets = tile_offsets(vec2(300.0, 200.0), vec2(100.0, 100.0), Vec2::ZERO);
+
+        assert_eq!(offsets.len(), 6);
+        assert_eq!(offsets[0], vec2(0.0, 0.0));
+        assert_eq!(offsets[5], vec2(200.0, 100.0));
+    }
+
+    /// Vérifie qu'avec une dérive, une rangée et une colonne de plus sont dessinées
+    /// et que le décalage est ramené à la taille d'une tuile.
+    #[test]
+    fn test_tile_offsets_with_wrapped_drift() {
+        let offsets = tile_offsets(vec2(300.0, 200.0), vec2(100.0, 100.0), vec2(130.0, 40.0));
+
+        assert_eq!(offsets.len(), 12);
+        assert_eq!(offsets[0], vec2(-30.0, -40.0));
+        let last = offsets[offsets.len() - 1];
+        assert!(last.x < 300.0 && last.x + 100.0 >= 300.0);
+        assert!(last.y < 200.0 && last.y + 100.0 >= 200.0);
+    }
+}
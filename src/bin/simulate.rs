@@ -0,0 +1,166 @@
+//! Simulation de parties sans fenêtre ni son, pour tester le jeu sur de longues durées
+//! et équilibrer la difficulté. Chaque partie est jouée par le pilote automatique,
+//! avec un pas de temps fixe et une graine différente, puis les statistiques sont affichées.
+//! Lancer avec `cargo run --release --bin simulate -- --runs 50 --minutes 10`.
+//! Une panique pendant une partie est rattrapée : sa graine est affichée pour la rejouer,
+//! et le programme se termine avec une erreur.
+use asteroid::difficulty::Difficulty;
+use asteroid::game_mode::GameMode;
+use asteroid::simulation::{simulate_run, RunReport, SimulationConfig, SimulationSummary};
+use std::panic;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+/// Aide affichée par `--help`, ou après une option invalide
+const USAGE: &str = "\
+Utilisation : simulate [options]
+  --runs N              nombre de parties simulées (20 par défaut)
+  --minutes N           durée maximale de chaque partie, en minutes de jeu (10 par défaut)
+  --seed N              graine de la première partie, les suivantes prennent les graines suivantes (1 par défaut)
+  --difficulty NIVEAU   easy, normal ou hard
+  --mode MODE           classic ou endless
+  --help                affiche cette aide";
+
+/// Structure représentant les options de la simulation
+/// # Champs
+/// - `runs`: le nombre de parties
+/// - `first_seed`: la graine de la première partie
+/// - `config`: les réglages de chaque partie
+struct Options {
+    runs: u64,
+    first_seed: u64,
+    config: SimulationConfig,
+}
+
+/// Fonction qui lit les options de la ligne de commande
+/// # Arguments
+/// - `args`: les arguments, sans le nom du programme
+/// # Returns
+/// - `Result<Option<Options>, String>`: les options, `None` pour `--help`,
+///   ou le message d'erreur de la première option invalide
+fn parse_options(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        runs: 20,
+        first_seed: 1,
+        config: SimulationConfig::default(),
+    };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Valeur manquante pour {arg}"))
+        };
+        match arg.as_str() {
+            "--runs" => options.runs = parsed(&value()?, &arg)?,
+            "--minutes" => options.config.minutes = parsed(&value()?, &arg)?,
+            "--seed" => options.first_seed = parsed(&value()?, &arg)?,
+            "--difficulty" => {
+                let id = value()?;
+                options.config.difficulty = Difficulty::from_id(&id)
+                    .ok_or_else(|| format!("Difficulté inconnue pour --difficulty : {id}"))?;
+            }
+            "--mode" => {
+                let id = value()?;
+                options.config.mode = GameMode::from_id(&id)
+                    .ok_or_else(|| format!("Mode inconnu pour --mode : {id}"))?;
+            }
+            "--help" | "-h" => return Ok(None),
+            _ => return Err(format!("Option inconnue : {arg}")),
+        }
+    }
+    if options.runs == 0 || options.config.minutes <= 0.0 {
+        return Err("Il faut au moins une partie d'une durée positive".to_string());
+    }
+    Ok(Some(options))
+}
+
+/// Fonction qui lit la valeur numérique d'une option
+/// # Arguments
+/// - `value`: la valeur écrite sur la ligne de commande
+/// - `name`: le nom de l'option
+/// # Returns
+/// - `Result<T, String>`: la valeur, ou une erreur si ce n'est pas un nombre valide
+fn parsed<T: FromStr>(value: &str, name: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Valeur invalide pour {name} : {value}"))
+}
+
+/// Fonction qui décrit la fin d'une partie
+/// # Arguments
+/// - `report`: le résultat de la partie
+/// # Returns
+/// - `String`: la raison de la fin de la partie
+fn ending(report: &RunReport) -> String {
+    match report.reason {
+        Some(reason) => format!("{reason:?}"),
+        None if report.victory => "victoire".to_string(),
+        None => "temps écoulé".to_string(),
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let config = options.config;
+    let mut reports = Vec::new();
+    let mut panicked = Vec::new();
+    for seed in options.first_seed..options.first_seed + options.runs {
+        match panic::catch_unwind(|| simulate_run(seed, &config)) {
+            Ok(report) => {
+                println!(
+                    "graine={seed} vague={} score={} temps={:.0}s fin={}",
+                    report.wave,
+                    report.score,
+                    report.seconds(),
+                    ending(&report)
+                );
+                reports.push(report);
+            }
+            Err(_) => {
+                println!("graine={seed} PANIQUE");
+                panicked.push(seed);
+            }
+        }
+    }
+
+    if let Some(summary) = SimulationSummary::new(&reports) {
+        let (min_wave, mean_wave, max_wave) = summary.waves;
+        let [min, q1, median, q3, max] = summary.scores;
+        let peaks = summary.peaks;
+        println!();
+        println!(
+            "{} parties, mode {}, difficulté {}, {} minutes au plus",
+            summary.runs,
+            config.mode.id(),
+            config.difficulty.id(),
+            config.minutes
+        );
+        println!("Vagues : min {min_wave}, moyenne {mean_wave:.1}, max {max_wave}");
+        println!("Scores : min {min}, Q1 {q1}, médiane {median}, Q3 {q3}, max {max}");
+        println!(
+            "Asteroides par image : {:.1} en moyenne",
+            summary.asteroids_per_frame
+        );
+        println!(
+            "Maximums : {} asteroides, {} missiles, {} soucoupes, {} trous noirs, {} particules",
+            peaks.asteroids, peaks.missiles, peaks.ufos, peaks.black_holes, peaks.particles
+        );
+    }
+    if panicked.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("Parties qui ont paniqué : graines {panicked:?}");
+        ExitCode::FAILURE
+    }
+}
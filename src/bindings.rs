@@ -0,0 +1,299 @@
+//! Module pour les touches associées aux commandes du jeu.
+//! Les touches sont sauvegardées dans un petit fichier texte avec une entrée `action=touche` par ligne,
+//! pour que le joueur puisse les changer depuis l'écran des contrôles, par exemple pour un clavier AZERTY.
+use asteroid::platform;
+use macroquad::prelude::KeyCode;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier des touches
+const BINDINGS_FILE: &str = "controls.txt";
+
+/// Énumération représentant les commandes du jeu
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Action {
+    RotateLeft,
+    RotateRight,
+    ThrustForward,
+    ThrustBackward,
+    Fire,
+    Pause,
+    Bomb,
+}
+
+impl Action {
+    /// Toutes les commandes, dans l'ordre de l'écran des contrôles
+    pub const ALL: [Action; 7] = [
+        Action::RotateLeft,
+        Action::RotateRight,
+        Action::ThrustForward,
+        Action::ThrustBackward,
+        Action::Fire,
+        Action::Pause,
+        Action::Bomb,
+    ];
+
+    /// Fonction qui renvoie l'identifiant de la commande, utilisé dans le fichier des touches
+    /// # Returns
+    /// - `&'static str`: l'identifiant de la commande
+    pub fn id(self) -> &'static str {
+        match self {
+            Action::RotateLeft => "rotate_left",
+            Action::RotateRight => "rotate_right",
+            Action::ThrustForward => "thrust_forward",
+            Action::ThrustBackward => "thrust_backward",
+            Action::Fire => "fire",
+            Action::Pause => "pause",
+            Action::Bomb => "bomb",
+        }
+    }
+
+    /// Fonction qui renvoie la clé de traduction du nom de la commande
+    /// # Returns
+    /// - `&'static str`: la clé du texte affiché dans l'écran des contrôles
+    pub fn key(self) -> &'static str {
+        match self {
+            Action::RotateLeft => "controls.rotate_left",
+            Action::RotateRight => "controls.rotate_right",
+            Action::ThrustForward => "controls.thrust_forward",
+            Action::ThrustBackward => "controls.thrust_backward",
+            Action::Fire => "controls.fire",
+            Action::Pause => "controls.pause",
+            Action::Bomb => "controls.bomb",
+        }
+    }
+}
+
+/// Touches qui peuvent être associées à une commande, avec leur nom dans le fichier
+const KEY_NAMES: [(KeyCode, &str); 49] = [
+    (KeyCode::Left, "Left"),
+    (KeyCode::Right, "Right"),
+    (KeyCode::Up, "Up"),
+    (KeyCode::Down, "Down"),
+    (KeyCode::Space, "Space"),
+    (KeyCode::Enter, "Enter"),
+    (KeyCode::Tab, "Tab"),
+    (KeyCode::LeftShift, "LeftShift"),
+    (KeyCode::RightShift, "RightShift"),
+    (KeyCode::LeftControl, "LeftControl"),
+    (KeyCode::RightControl, "RightControl"),
+    (KeyCode::LeftAlt, "LeftAlt"),
+    (KeyCode::Backspace, "Backspace"),
+    (KeyCode::Key0, "0"),
+    (KeyCode::Key1, "1"),
+    (KeyCode::Key2, "2"),
+    (KeyCode::Key3, "3"),
+    (KeyCode::Key4, "4"),
+    (KeyCode::Key5, "5"),
+    (KeyCode::Key6, "6"),
+    (KeyCode::Key7, "7"),
+    (KeyCode::Key8, "8"),
+    (KeyCode::Key9, "9"),
+    (KeyCode::A, "A"),
+    (KeyCode::B, "B"),
+    (KeyCode::C, "C"),
+    (KeyCode::D, "D"),
+    (KeyCode::E, "E"),
+    (KeyCode::F, "F"),
+    (KeyCode::G, "G"),
+    (KeyCode::H, "H"),
+    (KeyCode::I, "I"),
+    (KeyCode::J, "J"),
+    (KeyCode::K, "K"),
+    (KeyCode::L, "L"),
+    (KeyCode::M, "M"),
+    (KeyCode::N, "N"),
+    (KeyCode::O, "O"),
+    (KeyCode::P, "P"),
+    (KeyCode::Q, "Q"),
+    (KeyCode::R, "R"),
+    (KeyCode::S, "S"),
+    (KeyCode::T, "T"),
+    (KeyCode::U, "U"),
+    (KeyCode::V, "V"),
+    (KeyCode::W, "W"),
+    (KeyCode::X, "X"),
+    (KeyCode::Y, "Y"),
+    (KeyCode::Z, "Z"),
+];
+
+/// Fonction qui renvoie le nom d'une touche
+/// # Arguments
+/// - `key`: la touche
+/// # Returns
+/// - `Option<&'static str>`: le nom de la touche, ou `None` si elle ne peut pas être associée à une commande
+pub fn key_name(key: KeyCode) -> Option<&'static str> {
+    KEY_NAMES
+        .iter()
+        .find(|(code, _)| *code == key)
+        .map(|(_, name)| *name)
+}
+
+/// Fonction qui retrouve une touche à partir de son nom
+/// # Arguments
+/// - `name`: le nom de la touche
+/// # Returns
+/// - `Option<KeyCode>`: la touche, ou `None` si le nom est inconnu
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    KEY_NAMES
+        .iter()
+        .find(|(_, key_name)| *key_name == name)
+        .map(|(code, _)| *code)
+}
+
+/// Structure représentant la touche de chaque commande
+/// # Champs
+/// - `keys`: les touches, dans l'ordre de `Action::ALL`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct KeyBindings {
+    keys: [KeyCode; 7],
+}
+
+impl Default for KeyBindings {
+    /// Les touches historiques du jeu : les flèches et la barre d'espace
+    fn default() -> Self {
+        Self {
+            keys: [
+                KeyCode::Left,
+                KeyCode::Right,
+                KeyCode::Up,
+                KeyCode::Down,
+                KeyCode::Space,
+                KeyCode::P,
+                KeyCode::B,
+            ],
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Crée les touches ZQSD, pour les claviers AZERTY
+    /// # Returns
+    /// - `Self`: les touches ZQSD
+    pub fn zqsd() -> Self {
+        Self {
+            keys: [
+                KeyCode::Q,
+                KeyCode::D,
+                KeyCode::Z,
+                KeyCode::S,
+                KeyCode::Space,
+                KeyCode::P,
+                KeyCode::B,
+            ],
+        }
+    }
+
+    /// Fonction qui renvoie la touche d'une commande
+    /// # Arguments
+    /// - `action`: la commande
+    /// # Returns
+    /// - `KeyCode`: la touche associée
+    pub fn key(&self, action: Action) -> KeyCode {
+        self.keys[action as usize]
+    }
+
+    /// Associe une touche à une commande.
+    /// Si la touche était déjà celle d'une autre commande, les deux commandes échangent leurs touches.
+    /// # Arguments
+    /// - `action`: la commande
+    /// - `key`: la nouvelle touche
+    pub fn bind(&mut self, action: Action, key: KeyCode) {
+        let previous = self.key(action);
+        if let Some(other) = self.keys.iter().position(|bound| *bound == key) {
+            self.keys[other] = previous;
+        }
+        self.keys[action as usize] = key;
+    }
+
+    /// Charge les touches depuis un fichier.
+    /// Un fichier absent, une commande inconnue ou une touche illisible gardent les touches par défaut.
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `Self`: les touches chargées
+    pub fn load(path: &Path) -> Self {
+        let mut bindings = Self::default();
+        let Ok(content) = platform::read_to_string(path) else {
+            return bindings;
+        };
+        for line in content.lines() {
+            let Some((id, name)) = line.split_once('=') else {
+                continue;
+            };
+            let action = Action::ALL.into_iter().find(|action| action.id() == id);
+            match (action, key_from_name(name)) {
+                (Some(action), Some(key)) => bindings.bind(action, key),
+                _ => log::warn!("Touche illisible dans {} : {line}", path.display()),
+            }
+        }
+        bindings
+    }
+
+    /// Sauvegarde les touches dans un fichier
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `io::Result<()>`: une erreur si le fichier n'a pas pu être écrit
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut content = String::new();
+        for action in Action::ALL {
+            if let Some(name) = key_name(self.key(action)) {
+                content.push_str(&format!("{}={name}\n", action.id()));
+            }
+        }
+        platform::write(path, &content)
+    }
+}
+
+/// Fonction qui renvoie le chemin du fichier des touches
+/// # Returns
+/// - `PathBuf`: le chemin du fichier des touches
+pub fn bindings_path() -> PathBuf {
+    crate::profile::data_path(BINDINGS_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Vérifie que des touches sauvegardées puis rechargées sont identiques.
+    #[test]
+    fn test_bindings_round_trip() {
+        let path = std::env::temp_dir().join("asteroid_test_bindings_round_trip.txt");
+        let mut bindings = KeyBindings::zqsd();
+        bindings.bind(Action::Fire, KeyCode::LeftShift);
+
+        bindings.save(&path).unwrap();
+        let loaded = KeyBindings::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            loaded, bindings,
+            "Les touches rechargées sont différentes !"
+        );
+        assert_eq!(loaded.key(Action::ThrustForward), KeyCode::Z);
+    }
+
+    /// Vérifie qu'un fichier absent donne les touches historiques du jeu.
+    #[test]
+    fn test_missing_bindings_are_default() {
+        let path = std::env::temp_dir().join("asteroid_test_bindings_missing.txt");
+        let _ = fs::remove_file(&path);
+
+        let bindings = KeyBindings::load(&path);
+        assert_eq!(bindings.key(Action::RotateLeft), KeyCode::Left);
+        assert_eq!(bindings.key(Action::Fire), KeyCode::Space);
+    }
+
+    /// Vérifie qu'associer une touche déjà utilisée échange les touches des deux commandes.
+    #[test]
+    fn test_bind_swaps_conflicting_keys() {
+        let mut bindings = KeyBindings::default();
+        bindings.bind(Action::RotateLeft, KeyCode::Right);
+
+        assert_eq!(bindings.key(Action::RotateLeft), KeyCode::Right);
+        assert_eq!(bindings.key(Action::RotateRight), KeyCode::Left);
+    }
+}
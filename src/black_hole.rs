@@ -1,5 +1,54 @@
 //! Module pour gérer les actions de nos trous noirs
+//! Un trou noir qui capture un asteroide peut le rejeter un peu plus tard, sous forme d'un petit asteroide rapide.
+//! Un trou noir ne dure pas toujours : il rétrécit pendant ses dernières secondes puis disparait.
+//! Deux trous noirs qui se touchent fusionnent en un seul, plus grand.
+//! Le trou noir tourne sur lui-même et pulse légèrement ; des anneaux de plus en plus visibles
+//! montrent les tirs encaissés. Le tir qui l'achève le fait s'effondrer pendant `COLLAPSE_TIME` :
+//! il rétrécit dans un éclair, sans plus rien toucher, avant de disparaitre.
+//! Un nouveau trou noir est d'abord annoncé par un cercle violet qui grandit : pendant l'annonce,
+//! il ne touche rien et sa durée de vie ne s'écoule pas encore.
+use crate::asteroid::{Asteroid, Size, ASTEROID_VARIANTS, BASE_SPEED};
+use ::rand::Rng;
 use macroquad::prelude::*;
+use std::f32::consts::PI;
+
+/// Probabilité qu'un asteroide capturé soit rejeté
+pub const EJECTION_CHANCE: f64 = 0.2;
+/// Délai (en secondes) entre la capture et le rejet
+pub const EJECTION_DELAY: f32 = 1.5;
+/// Rapport entre la vitesse d'un asteroide rejeté et la vitesse normale d'un petit asteroide
+pub const EJECTION_SPEED_FACTOR: f32 = 1.5;
+/// Distance supplémentaire entre le bord du trou noir et l'asteroide rejeté
+const EJECTION_MARGIN: f32 = 5.0;
+/// Durée (en secondes) de l'éclair qui accompagne un rejet
+const FLASH_TIME: f32 = 0.3;
+/// Nombre de collisions qui font disparaitre un trou noir
+pub const MAX_HITS: u8 = 5;
+/// Points gagnés pour chaque tir qui touche un trou noir sans l'achever
+pub const HIT_SCORE: i32 = 2;
+/// Points gagnés pour le tir qui achève un trou noir : un trou noir détruit rapporte en tout
+/// `(MAX_HITS - 1) * HIT_SCORE + COLLAPSE_SCORE`, soit 18 points
+pub const COLLAPSE_SCORE: i32 = 10;
+/// Durée de vie par défaut (en secondes) d'un trou noir
+pub const DEFAULT_LIFETIME: f32 = 15.0;
+/// Durée (en secondes) pendant laquelle le trou noir rétrécit avant de disparaitre
+pub const SHRINK_TIME: f32 = 3.0;
+/// Durée (en secondes) par défaut de l'annonce d'un nouveau trou noir
+pub const WARNING_TIME: f32 = 1.5;
+/// Couleur du cercle qui annonce un nouveau trou noir
+const WARNING_COLOR: Color = Color::new(0.6, 0.2, 0.9, 1.0);
+/// Durée (en secondes) de l'effondrement d'un trou noir achevé par les tirs
+pub const COLLAPSE_TIME: f32 = 0.4;
+/// Vitesse de rotation (en radians par seconde) de la texture du trou noir
+const ROTATION_SPEED: f32 = 1.2;
+/// Variation relative de la taille affichée pendant la pulsation
+const PULSE_AMPLITUDE: f32 = 0.05;
+/// Pulsation (en radians par seconde) de la taille affichée
+const PULSE_SPEED: f32 = 4.0;
+/// Nombre d'anneaux dessinés autour du trou noir
+const RINGS: u32 = 3;
+/// Couleur des anneaux, dont l'opacité dépend des tirs encaissés
+const RING_COLOR: Color = Color::new(0.7, 0.4, 1.0, 1.0);
 
 /// Structure permettant de représenter nos trous noirs
 /// # Champs
@@ -8,48 +57,311 @@ use macroquad::prelude::*;
 /// - `counter`: compteur qui compte le nombre de collission
 /// - `active`: permet de savoir si le trou noir est actif ou non
 /// - `texture`: la texture du trou noir
+/// - `clock`: le temps écoulé depuis l'apparition du trou noir
+/// - `pending_ejections`: les instants (selon `clock`) auxquels un asteroide doit être rejeté
+/// - `flash_timer`: le temps restant de l'éclair du dernier rejet
+/// - `lifetime`: la durée de vie du trou noir, qui disparait quand `clock` l'atteint
+/// - `rotation`: l'angle de la texture, en radians
+/// - `collapse_timer`: le temps restant de l'effondrement, nul tant que le trou noir n'est pas achevé
+/// - `warning`: le temps restant de l'annonce, nul une fois le trou noir formé
+/// - `warning_time`: la durée totale de l'annonce, pour faire grandir le cercle
 pub struct BlackHole {
     position: Vec2,
     size: f32,
     pub counter: u8,
     pub active: bool,
     texture: Texture2D,
+    clock: f32,
+    pending_ejections: Vec<f32>,
+    flash_timer: f32,
+    pub lifetime: f32,
+    rotation: f32,
+    collapse_timer: f32,
+    warning: f32,
+    warning_time: f32,
 }
 
 impl BlackHole {
-    /// Créer un nouveau trou noir
+    /// Créer un nouveau trou noir à partir d'une texture déjà chargée
     /// # Arguments
     /// - `position`: la position x et y du trou noir
     /// - `size_ast`: contient la taille de l'asteroid détruit
+    /// - `texture`: la texture du trou noir
     /// # Returns
     /// - `Self`: un trou noir
-    pub async fn new(position: Vec2, size_ast: f32) -> Self {
-        let texture = load_texture("assets/black_hole.png").await.unwrap();
+    pub fn with_texture(position: Vec2, size_ast: f32, texture: Texture2D) -> Self {
         Self {
             position,
             size: size_ast,
             counter: 0,
             active: true,
             texture,
+            clock: 0.0,
+            pending_ejections: Vec::new(),
+            flash_timer: 0.0,
+            lifetime: DEFAULT_LIFETIME,
+            rotation: 0.0,
+            collapse_timer: 0.0,
+            warning: 0.0,
+            warning_time: 0.0,
+        }
+    }
+
+    /// Annonce le trou noir : pendant la durée donnée, seul un cercle grandissant est dessiné,
+    /// et le trou noir ne touche rien
+    /// # Arguments
+    /// - `duration`: la durée de l'annonce (en secondes)
+    pub fn announce(&mut self, duration: f32) {
+        self.warning = duration.max(0.0);
+        self.warning_time = self.warning;
+    }
+
+    /// Fonction qui indique si le trou noir est encore annoncé
+    /// # Returns
+    /// - `bool`: `true` tant qu'il n'est pas formé
+    pub fn announced(&self) -> bool {
+        self.warning > 0.0
+    }
+
+    /// Fonction qui permet de savoir si le trou noir s'effondre après avoir été achevé
+    /// # Returns
+    /// - `bool`: `true` pendant l'effondrement
+    pub fn collapsing(&self) -> bool {
+        self.collapse_timer > 0.0
+    }
+
+    /// Fonction qui permet de savoir si le trou noir peut encore toucher ou être touché
+    /// # Returns
+    /// - `bool`: `true` s'il est actif, formé et ne s'effondre pas
+    pub fn solid(&self) -> bool {
+        self.active && !self.announced() && !self.collapsing()
+    }
+
+    /// Signale au trou noir qu'il vient de capturer un asteroide.
+    /// L'asteroide a une chance d'être rejeté après `EJECTION_DELAY`.
+    /// # Arguments
+    /// - `rng`: le générateur de la partie
+    pub fn capture_asteroid(&mut self, rng: &mut impl Rng) {
+        if rng.gen_bool(EJECTION_CHANCE) {
+            self.schedule_ejection();
+        }
+    }
+
+    /// Programme le rejet d'un asteroide après `EJECTION_DELAY`
+    pub fn schedule_ejection(&mut self) {
+        self.pending_ejections.push(self.clock + EJECTION_DELAY);
+    }
+
+    /// Fonction qui renvoie la part de sa taille que le trou noir garde en fin de vie
+    /// # Returns
+    /// - `f32`: `1.0` avant les `SHRINK_TIME` dernières secondes, puis jusqu'à `0.0` à la fin de sa vie
+    fn shrink_factor(&self) -> f32 {
+        ((self.lifetime - self.clock) / SHRINK_TIME).clamp(0.0, 1.0)
+    }
+
+    /// Absorbe un autre trou noir qui touche celui-ci.
+    /// Le trou noir obtenu a l'aire des deux trous noirs réunis, son centre est la moyenne des centres
+    /// pondérée par leur aire, et il peut de nouveau encaisser tous les tirs.
+    /// Il garde la plus longue des deux durées de vie restantes et les rejets prévus par l'autre.
+    /// L'autre trou noir devient inactif.
+    /// # Arguments
+    /// - `other`: l'autre trou noir
+    /// - `bounds`: la taille de l'écran
+    /// # Returns
+    /// - `bool`: `true` si les deux trous noirs ont fusionné
+    pub fn merge(&mut self, other: &mut BlackHole, bounds: Vec2) -> bool {
+        if !self.solid() || !other.solid() {
+            return false;
+        }
+        let offset = wrapped_offset(self.position, other.position, bounds);
+        if offset.length() >= self.radius() + other.radius() {
+            return false;
+        }
+        let area = self.size * self.size;
+        let other_area = other.size * other.size;
+        let center = self.position + offset * other_area / (area + other_area);
+        self.position = vec2(center.x.rem_euclid(bounds.x), center.y.rem_euclid(bounds.y));
+        self.size = (area + other_area).sqrt();
+        self.counter = 0;
+
+        let remaining = (self.lifetime - self.clock).max(other.lifetime - other.clock);
+        self.lifetime = self.clock + remaining;
+        let clock = self.clock;
+        self.pending_ejections.extend(
+            other
+                .pending_ejections
+                .drain(..)
+                .map(|t| clock + (t - other.clock)),
+        );
+        other.active = false;
+        true
+    }
+
+    /// Met à jour le trou noir et rejette les asteroides dont le délai est écoulé.
+    /// Le trou noir devient inactif à la fin de sa vie, ou à la fin de son effondrement,
+    /// qui abandonne les rejets prévus. Pendant l'annonce, seule l'annonce avance.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `room`: le nombre d'asteroides qui peuvent encore apparaitre avant la limite globale
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `Vec<Asteroid>`: les asteroides rejetés pendant cette image
+    pub fn update(&mut self, dt: f32, room: usize, rng: &mut impl Rng) -> Vec<Asteroid> {
+        if self.announced() {
+            self.warning = (self.warning - dt).max(0.0);
+            return Vec::new();
+        }
+        self.clock += dt;
+        self.rotation = (self.rotation + ROTATION_SPEED * dt) % (2.0 * PI);
+        self.flash_timer = (self.flash_timer - dt).max(0.0);
+        if self.collapsing() {
+            self.collapse_timer = (self.collapse_timer - dt).max(0.0);
+            if !self.collapsing() {
+                self.active = false;
+            }
+            self.pending_ejections.clear();
+            return Vec::new();
+        }
+        if self.clock >= self.lifetime {
+            self.active = false;
+        }
+
+        let clock = self.clock;
+        let due = self
+            .pending_ejections
+            .iter()
+            .filter(|t| **t <= clock)
+            .count();
+        self.pending_ejections.retain(|t| *t > clock);
+
+        // Les rejets au-delà de la limite globale sont abandonnés
+        let ejected: Vec<Asteroid> = (0..due.min(room))
+            .map(|_| {
+                let direction = Vec2::from_angle(rng.gen_range(0.0..2.0 * PI));
+                self.eject(direction, rng.gen_range(0..ASTEROID_VARIANTS))
+            })
+            .collect();
+        if !ejected.is_empty() {
+            self.flash_timer = FLASH_TIME;
         }
+        ejected
+    }
+
+    /// Crée un petit asteroide rejeté dans une direction, juste en dehors du trou noir
+    /// pour qu'il ne soit pas capturé à nouveau aussitôt
+    /// # Arguments
+    /// - `direction`: la direction du rejet, normalisée
+    /// - `variant`: la texture de l'asteroide rejeté
+    /// # Returns
+    /// - `Asteroid`: l'asteroide rejeté
+    fn eject(&self, direction: Vec2, variant: usize) -> Asteroid {
+        let offset = self.radius() + Size::Small.scale() / 2.0 + EJECTION_MARGIN;
+        Asteroid::new_with_size(
+            Size::Small,
+            self.position + direction * offset,
+            direction * BASE_SPEED * Size::Small.speed_multiplier() * EJECTION_SPEED_FACTOR,
+        )
+        .with_variant(variant)
+    }
+
+    /// Fonction qui renvoie le rapport entre la taille affichée et le rayon du trou noir :
+    /// une pulsation de `PULSE_AMPLITUDE`, puis un rétrécissement jusqu'à rien pendant l'effondrement
+    /// # Returns
+    /// - `f32`: le rapport de taille
+    fn draw_scale(&self) -> f32 {
+        let pulse = 1.0 + PULSE_AMPLITUDE * (self.clock * PULSE_SPEED).sin();
+        if self.collapsing() {
+            pulse * self.collapse_timer / COLLAPSE_TIME
+        } else {
+            pulse
+        }
+    }
+
+    /// Fonction qui renvoie l'opacité des anneaux, qui augmente avec les tirs encaissés
+    /// # Returns
+    /// - `f32`: l'opacité, discrète sans tir et presque pleine au dernier tir avant l'effondrement
+    fn ring_alpha(&self) -> f32 {
+        0.1 + 0.6 * self.counter.min(MAX_HITS - 1) as f32 / (MAX_HITS - 1) as f32
     }
 
-    /// Fonction qui dessine le trou noir
+    /// Fonction qui dessine le trou noir, ou le cercle qui l'annonce
     pub fn draw(&self) {
+        if self.announced() {
+            let progress = 1.0 - self.warning / self.warning_time;
+            let (x, y) = (self.position.x, self.position.y);
+            let full = self.size / 2.0;
+            draw_circle_lines(
+                x,
+                y,
+                full,
+                1.5,
+                Color {
+                    a: 0.5,
+                    ..WARNING_COLOR
+                },
+            );
+            draw_circle(
+                x,
+                y,
+                full * progress,
+                Color {
+                    a: 0.15 + 0.25 * progress,
+                    ..WARNING_COLOR
+                },
+            );
+            return;
+        }
+        let radius = self.radius() * self.draw_scale();
+        if !self.collapsing() {
+            let alpha = self.ring_alpha();
+            for ring in 1..=RINGS {
+                let fade = 1.0 - (ring - 1) as f32 / RINGS as f32;
+                draw_circle_lines(
+                    self.position.x,
+                    self.position.y,
+                    radius * (1.0 + ring as f32 * 0.2),
+                    1.5,
+                    Color::new(RING_COLOR.r, RING_COLOR.g, RING_COLOR.b, alpha * fade),
+                );
+            }
+        }
         draw_texture_ex(
             &self.texture,
-            self.position.x - self.radius(),
-            self.position.y - self.radius(),
+            self.position.x - radius,
+            self.position.y - radius,
             WHITE,
             DrawTextureParams {
-                dest_size: Some(vec2(self.radius() * 2.0, self.radius() * 2.0)),
+                dest_size: Some(vec2(radius * 2.0, radius * 2.0)),
+                rotation: self.rotation,
                 ..Default::default()
             },
         );
+
+        // Éclair de l'effondrement, le plus vif au tir qui l'a achevé
+        if self.collapsing() {
+            let alpha = self.collapse_timer / COLLAPSE_TIME;
+            draw_circle(
+                self.position.x,
+                self.position.y,
+                self.radius() * (1.5 - alpha * 0.5),
+                Color::new(1.0, 1.0, 1.0, alpha),
+            );
+        }
+
+        // Éclair qui accompagne le rejet d'un asteroide
+        if self.flash_timer > 0.0 {
+            let alpha = self.flash_timer / FLASH_TIME;
+            draw_circle(
+                self.position.x,
+                self.position.y,
+                self.radius() * (1.0 + alpha * 0.5),
+                Color::new(1.0, 1.0, 1.0, alpha * 0.8),
+            );
+        }
     }
 }
 
-use crate::stellarobject::StellarObject; // Utilise le trait StellarObject
+use crate::stellarobject::{debug_field, format_vec2, wrapped_offset, StellarObject}; // Utilise le trait StellarObject
 
 impl StellarObject for BlackHole {
     /// Retourne la position de l'objet.
@@ -64,26 +376,211 @@ impl StellarObject for BlackHole {
     /// Met a jour la position de l'objet.
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
-    fn move_obj(&mut self) {}
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, _bounds: Vec2) {}
 
-    /// Retourne le rayon de l'objet.
+    /// Retourne le rayon de l'objet, qui diminue avec le trou noir en fin de vie.
+    /// Un trou noir encore annoncé n'a pas de rayon.
     /// # Arguments
     /// - `&self`: une instance de l'objet stellaire
     /// # Returns
     /// - `f32`: le rayon de l'objet stellaire
     fn radius(&self) -> f32 {
-        self.size / 2.0
+        if self.announced() {
+            return 0.0;
+        }
+        self.size / 2.0 * self.shrink_factor()
     }
 
     /// Gere la collision avec un autre objet.
-    /// Ici s'il y a cinq collision avec le trou noir dissparait
+    /// Ici le trou noir commence à s'effondrer à la `MAX_HITS`-ième collision
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
     fn handle_collision(&mut self) {
-        if self.counter == 5 {
-            self.active = false
-        } else {
-            self.counter += 1;
+        if !self.solid() {
+            return;
+        }
+        self.counter += 1;
+        if self.counter >= MAX_HITS {
+            self.collapse_timer = COLLAPSE_TIME;
+        }
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état du trou noir pour l'inspecteur de développement.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec<(String, String)>`: la liste des champs, avec leur nom et leur valeur
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "BlackHole"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(Vec2::ZERO)),
+            debug_field("radius", format!("{:.1}", self.radius())),
+            debug_field("active", self.active),
+            debug_field("counter", self.counter),
+            debug_field("collapse", format!("{:.2}", self.collapse_timer)),
+            debug_field("warning", format!("{:.2}", self.warning)),
+            debug_field("lifetime", format!("{:.1}", self.lifetime - self.clock)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::dummy_texture;
+    use ::rand::thread_rng;
+
+    /// Vérifie que l'asteroide n'est rejeté qu'après `EJECTION_DELAY`.
+    #[test]
+    fn test_ejection_is_delayed() {
+        let mut black_hole = BlackHole::with_texture(vec2(100.0, 100.0), 70.0, dummy_texture());
+        black_hole.schedule_ejection();
+
+        assert!(black_hole
+            .update(EJECTION_DELAY - 0.1, 10, &mut thread_rng())
+            .is_empty());
+        let ejected = black_hole.update(0.2, 10, &mut thread_rng());
+        assert_eq!(ejected.len(), 1);
+        assert_eq!(ejected[0].get_size(), Size::Small);
+        assert!(
+            black_hole.update(5.0, 10, &mut thread_rng()).is_empty(),
+            "Un rejet ne doit avoir lieu qu'une fois !"
+        );
+    }
+
+    /// Vérifie que l'asteroide rejeté apparait en dehors du trou noir.
+    #[test]
+    fn test_ejection_spawns_outside_radius() {
+        let black_hole = BlackHole::with_texture(vec2(100.0, 100.0), 100.0, dummy_texture());
+
+        for direction in [Vec2::X, Vec2::NEG_Y, vec2(-1.0, 1.0).normalize()] {
+            let asteroid = black_hole.eject(direction, 0);
+            let distance = asteroid.get_pos().distance(black_hole.get_pos());
+            assert!(
+                distance > black_hole.radius() + asteroid.radius(),
+                "L'asteroide rejeté touche encore le trou noir !"
+            );
+        }
+    }
+
+    /// Vérifie que les rejets respectent la limite globale d'asteroides.
+    #[test]
+    fn test_ejection_respects_cap() {
+        let mut black_hole = BlackHole::with_texture(Vec2::ZERO, 70.0, dummy_texture());
+        for _ in 0..3 {
+            black_hole.schedule_ejection();
+        }
+
+        assert_eq!(
+            black_hole
+                .update(EJECTION_DELAY, 1, &mut thread_rng())
+                .len(),
+            1
+        );
+        assert!(black_hole.pending_ejections.is_empty());
+    }
+
+    /// Vérifie que le tir qui achève le trou noir le fait s'effondrer : il ne touche plus rien,
+    /// rétrécit à l'écran et abandonne ses rejets, puis devient inactif après `COLLAPSE_TIME`.
+    #[test]
+    fn test_collapse_before_disappearing() {
+        let mut black_hole = BlackHole::with_texture(Vec2::ZERO, 100.0, dummy_texture());
+        black_hole.schedule_ejection();
+        let mut alpha = 0.0;
+        for _ in 0..MAX_HITS - 1 {
+            assert!(
+                black_hole.ring_alpha() > alpha,
+                "Les anneaux doivent se renforcer !"
+            );
+            alpha = black_hole.ring_alpha();
+            black_hole.handle_collision();
+        }
+        assert!(black_hole.solid());
+
+        black_hole.handle_collision();
+        assert!(black_hole.collapsing() && black_hole.active && !black_hole.solid());
+        black_hole.handle_collision();
+        assert_eq!(
+            black_hole.counter, MAX_HITS,
+            "Un trou noir qui s'effondre n'est plus touché !"
+        );
+        assert!(black_hole
+            .update(COLLAPSE_TIME / 2.0, 10, &mut thread_rng())
+            .is_empty());
+        assert!(black_hole.draw_scale() < 0.6);
+        assert!(black_hole
+            .update(EJECTION_DELAY, 10, &mut thread_rng())
+            .is_empty());
+        assert!(!black_hole.active);
+    }
+
+    /// Vérifie qu'un trou noir annoncé ne touche rien et ne vieillit pas avant la fin de l'annonce.
+    #[test]
+    fn test_announcement_delays_black_hole() {
+        let mut black_hole = BlackHole::with_texture(Vec2::ZERO, 100.0, dummy_texture());
+        black_hole.announce(WARNING_TIME);
+        black_hole.handle_collision();
+
+        assert!(!black_hole.solid());
+        assert_eq!(black_hole.radius(), 0.0);
+        assert_eq!(
+            black_hole.counter, 0,
+            "Un trou noir annoncé ne peut pas être touché !"
+        );
+        black_hole.update(WARNING_TIME - 0.1, 0, &mut thread_rng());
+        assert!(black_hole.announced());
+        black_hole.update(0.2, 0, &mut thread_rng());
+        assert!(black_hole.solid());
+        assert_eq!(black_hole.radius(), 50.0);
+        assert_eq!(black_hole.clock, 0.0);
+    }
+
+    /// Vérifie que la taille affichée pulse d'au plus `PULSE_AMPLITUDE` et que la texture tourne.
+    #[test]
+    fn test_pulse_and_rotation() {
+        let mut black_hole = BlackHole::with_texture(Vec2::ZERO, 100.0, dummy_texture());
+        for _ in 0..120 {
+            black_hole.update(1.0 / 60.0, 0, &mut thread_rng());
+            assert!((black_hole.draw_scale() - 1.0).abs() <= PULSE_AMPLITUDE + 1e-6);
         }
+        assert!(black_hole.rotation > 0.0);
+        assert_eq!(black_hole.radius(), 50.0);
+    }
+
+    /// Vérifie que le trou noir rétrécit pendant ses dernières secondes,
+    /// puis devient inactif à la fin de sa vie.
+    #[test]
+    fn test_black_hole_shrinks_and_expires() {
+        let mut black_hole = BlackHole::with_texture(Vec2::ZERO, 100.0, dummy_texture());
+        let dt = 0.1;
+
+        let mut radius = black_hole.radius();
+        while black_hole.clock + dt < DEFAULT_LIFETIME - SHRINK_TIME {
+            black_hole.update(dt, 0, &mut thread_rng());
+            assert_eq!(black_hole.radius(), radius);
+        }
+        while black_hole.active {
+            black_hole.update(dt, 0, &mut thread_rng());
+            assert!(
+                black_hole.radius() < radius,
+                "Le trou noir doit rétrécir en fin de vie !"
+            );
+            radius = black_hole.radius();
+        }
+
+        assert!(black_hole.clock >= DEFAULT_LIFETIME);
+        assert!(black_hole.clock < DEFAULT_LIFETIME + dt);
+        assert_eq!(black_hole.radius(), 0.0);
     }
 }
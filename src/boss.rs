@@ -0,0 +1,510 @@
+//! Module pour le vaisseau mère, le boss final de la vague `BOSS_WAVE` du mode classique.
+//! Le combat se déroule en trois phases, qui dépendent des points de vie du noyau :
+//! - `Seeding`: le vaisseau mère lâche des volées de petits asteroides ;
+//! - `Turrets`: deux tourelles le protègent, le noyau ne peut pas être touché tant qu'elles tiennent ;
+//! - `Desperation`: il tire des missiles visés et fait apparaître un trou noir près du joueur.
+use crate::missile::Missile;
+use crate::stellarobject::{debug_field, format_vec2, StellarObject};
+use ::rand::Rng;
+use macroquad::prelude::*;
+use std::f32::consts::PI;
+
+/// Vague du mode classique remplacée par le combat contre le vaisseau mère
+pub const BOSS_WAVE: u32 = 20;
+/// Points de vie du noyau du vaisseau mère
+pub const CORE_HP: u32 = 30;
+/// Points de vie d'une tourelle
+pub const TURRET_HP: u32 = 5;
+/// Bonus de score pour la destruction du vaisseau mère
+pub const SCORE_BONUS: i32 = 500;
+/// Bonus de score pour la destruction d'une tourelle
+pub const TURRET_BONUS: i32 = 50;
+/// Rayon du vaisseau mère
+const RADIUS: f32 = 70.0;
+/// Rayon d'une tourelle
+const TURRET_RADIUS: f32 = 18.0;
+/// Distance entre le centre du vaisseau mère et ses tourelles
+const TURRET_OFFSET: f32 = 100.0;
+/// Vitesse horizontale du vaisseau mère
+const SPEED: f32 = 0.6;
+/// Temps (en secondes) entre deux volées d'asteroides
+const VOLLEY_INTERVAL: f32 = 4.0;
+/// Nombre de petits asteroides d'une volée
+const VOLLEY_SIZE: usize = 4;
+/// Vitesse des asteroides d'une volée
+const VOLLEY_SPEED: f32 = 1.5;
+/// Temps (en secondes) entre deux tirs d'une tourelle
+const TURRET_FIRE_INTERVAL: f32 = 2.5;
+/// Temps (en secondes) entre deux tirs visés pendant la phase désespérée
+const DESPERATION_FIRE_INTERVAL: f32 = 0.8;
+/// Distance entre le joueur et le trou noir de la phase désespérée
+const BLACK_HOLE_DISTANCE: f32 = 180.0;
+/// Durée (en secondes) du clignotement quand le noyau est touché
+const HIT_FLASH_TIME: f32 = 0.1;
+
+/// Énumération représentant les phases du combat
+/// # Variantes
+/// - `Seeding`: le vaisseau mère lâche des volées de petits asteroides
+/// - `Turrets`: deux tourelles protègent le noyau
+/// - `Desperation`: le vaisseau mère tire sur le joueur et crée un trou noir près de lui
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BossPhase {
+    Seeding,
+    Turrets,
+    Desperation,
+}
+
+impl BossPhase {
+    /// Fonction qui renvoie la phase correspondant aux points de vie du noyau
+    /// # Arguments
+    /// - `hp`: les points de vie restants
+    /// - `max_hp`: les points de vie au début du combat
+    /// # Returns
+    /// - `BossPhase`: `Seeding` au-dessus des deux tiers, `Turrets` au-dessus du tiers, sinon `Desperation`
+    pub fn for_hp(hp: u32, max_hp: u32) -> Self {
+        if hp * 3 > max_hp * 2 {
+            BossPhase::Seeding
+        } else if hp * 3 > max_hp {
+            BossPhase::Turrets
+        } else {
+            BossPhase::Desperation
+        }
+    }
+}
+
+/// Énumération représentant ce que le vaisseau mère demande à la partie de créer
+/// # Variantes
+/// - `SpawnAsteroid`: un petit asteroide, à la position et avec la vitesse données
+/// - `Fire`: un missile ennemi
+/// - `SpawnBlackHole`: un trou noir, à la position donnée
+/// - `PhaseChanged`: le combat passe à la phase donnée
+pub enum BossAction {
+    SpawnAsteroid { position: Vec2, velocity: Vec2 },
+    Fire(Missile),
+    SpawnBlackHole(Vec2),
+    PhaseChanged(BossPhase),
+}
+
+/// Structure représentant une tourelle du vaisseau mère
+/// # Champs
+/// - `position`: la position de la tourelle
+/// - `offset`: la position de la tourelle par rapport au centre du vaisseau mère
+/// - `hp`: les points de vie restants
+/// - `fire_timer`: le temps restant avant le prochain tir
+/// - `active`: permet de savoir si la tourelle est encore debout
+pub struct Turret {
+    position: Vec2,
+    offset: Vec2,
+    pub hp: u32,
+    fire_timer: f32,
+    pub active: bool,
+}
+
+impl Turret {
+    /// Crée une tourelle placée à côté du vaisseau mère
+    /// # Arguments
+    /// - `center`: la position du vaisseau mère
+    /// - `offset`: la position de la tourelle par rapport au vaisseau mère
+    /// # Returns
+    /// - `Self`: une tourelle avec tous ses points de vie
+    fn new(center: Vec2, offset: Vec2) -> Self {
+        Self {
+            position: center + offset,
+            offset,
+            hp: TURRET_HP,
+            fire_timer: TURRET_FIRE_INTERVAL,
+            active: true,
+        }
+    }
+}
+
+impl StellarObject for Turret {
+    /// Retourne la position de la tourelle.
+    fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Les tourelles suivent le vaisseau mère, elles ne se déplacent pas seules.
+    fn move_obj(&mut self, _bounds: Vec2) {}
+
+    /// Retourne le rayon de la tourelle.
+    fn radius(&self) -> f32 {
+        TURRET_RADIUS
+    }
+
+    /// Une tourelle perd un point de vie à chaque collision, et tombe quand elle n'en a plus.
+    fn handle_collision(&mut self) {
+        self.hp = self.hp.saturating_sub(1);
+        if self.hp == 0 {
+            self.active = false;
+        }
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état de la tourelle pour l'inspecteur de développement.
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "Turret"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("hp", self.hp),
+        ]
+    }
+}
+
+/// Structure représentant le vaisseau mère
+/// # Champs
+/// - `position`: la position du vaisseau mère
+/// - `velocity`: la vitesse du vaisseau mère
+/// - `hp`: les points de vie restants du noyau
+/// - `phase`: la phase actuelle du combat
+/// - `turrets`: les tourelles déployées pendant la deuxième phase
+/// - `volley_timer`: le temps restant avant la prochaine volée d'asteroides
+/// - `fire_timer`: le temps restant avant le prochain tir visé
+/// - `black_hole_spawned`: permet de savoir si le trou noir de la phase désespérée est apparu
+/// - `flash_timer`: le temps restant du clignotement quand le noyau est touché
+/// - `active`: permet de savoir si le vaisseau mère est encore en vie
+pub struct Mothership {
+    position: Vec2,
+    velocity: Vec2,
+    pub hp: u32,
+    phase: BossPhase,
+    pub turrets: Vec<Turret>,
+    volley_timer: f32,
+    fire_timer: f32,
+    black_hole_spawned: bool,
+    flash_timer: f32,
+    pub active: bool,
+}
+
+impl Mothership {
+    /// Crée le vaisseau mère en haut de l'écran
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// # Returns
+    /// - `Self`: le vaisseau mère, au début de la première phase
+    pub fn new(bounds: Vec2) -> Self {
+        Self {
+            position: vec2(bounds.x / 2.0, bounds.y * 0.25),
+            velocity: vec2(SPEED, 0.0),
+            hp: CORE_HP,
+            phase: BossPhase::Seeding,
+            turrets: Vec::new(),
+            volley_timer: VOLLEY_INTERVAL / 2.0,
+            fire_timer: DESPERATION_FIRE_INTERVAL,
+            black_hole_spawned: false,
+            flash_timer: 0.0,
+            active: true,
+        }
+    }
+
+    /// Fonction qui renvoie la phase actuelle du combat
+    /// # Returns
+    /// - `BossPhase`: la phase actuelle
+    pub fn phase(&self) -> BossPhase {
+        self.phase
+    }
+
+    /// Fonction qui indique si le noyau peut être touché
+    /// # Returns
+    /// - `bool`: `true` si aucune tourelle ne le protège
+    pub fn core_exposed(&self) -> bool {
+        !self.turrets.iter().any(|turret| turret.active)
+    }
+
+    /// Met à jour le vaisseau mère pour une image : phase, tourelles, volées et tirs
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `target`: la position visée, celle du vaisseau
+    /// - `bounds`: la taille de l'écran
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `Vec<BossAction>`: ce que la partie doit créer pendant cette image
+    pub fn update(
+        &mut self,
+        dt: f32,
+        target: Vec2,
+        bounds: Vec2,
+        rng: &mut impl Rng,
+    ) -> Vec<BossAction> {
+        let mut actions = Vec::new();
+        self.flash_timer = (self.flash_timer - dt).max(0.0);
+
+        let phase = BossPhase::for_hp(self.hp, CORE_HP);
+        if phase != self.phase {
+            self.phase = phase;
+            if phase == BossPhase::Turrets {
+                self.turrets = vec![
+                    Turret::new(self.position, vec2(-TURRET_OFFSET, 0.0)),
+                    Turret::new(self.position, vec2(TURRET_OFFSET, 0.0)),
+                ];
+            }
+            actions.push(BossAction::PhaseChanged(phase));
+        }
+
+        for turret in self.turrets.iter_mut().filter(|t| t.active) {
+            turret.position = self.position + turret.offset;
+            turret.fire_timer -= dt;
+            if turret.fire_timer <= 0.0 {
+                turret.fire_timer = TURRET_FIRE_INTERVAL;
+                let aim = (target - turret.position).to_angle();
+                actions.push(BossAction::Fire(Missile::enemy(turret.position, aim)));
+            }
+        }
+
+        match self.phase {
+            BossPhase::Seeding | BossPhase::Turrets => {
+                // Les volées continuent pendant la deuxième phase, deux fois moins souvent
+                let rate = if self.phase == BossPhase::Seeding {
+                    1.0
+                } else {
+                    0.5
+                };
+                self.volley_timer -= dt * rate;
+                if self.volley_timer <= 0.0 {
+                    self.volley_timer = VOLLEY_INTERVAL;
+                    let start = rng.gen_range(0.0..2.0 * PI);
+                    for i in 0..VOLLEY_SIZE {
+                        let angle = start + i as f32 * 2.0 * PI / VOLLEY_SIZE as f32;
+                        let direction = Vec2::from_angle(angle);
+                        actions.push(BossAction::SpawnAsteroid {
+                            position: self.position + direction * RADIUS,
+                            velocity: direction * VOLLEY_SPEED,
+                        });
+                    }
+                }
+            }
+            BossPhase::Desperation => {
+                if !self.black_hole_spawned {
+                    self.black_hole_spawned = true;
+                    let direction = Vec2::from_angle(rng.gen_range(0.0..2.0 * PI));
+                    let position = target + direction * BLACK_HOLE_DISTANCE;
+                    actions.push(BossAction::SpawnBlackHole(vec2(
+                        position.x.rem_euclid(bounds.x),
+                        position.y.rem_euclid(bounds.y),
+                    )));
+                }
+                self.fire_timer -= dt;
+                if self.fire_timer <= 0.0 {
+                    self.fire_timer = DESPERATION_FIRE_INTERVAL;
+                    let aim = (target - self.position).to_angle();
+                    actions.push(BossAction::Fire(Missile::enemy(self.position, aim)));
+                }
+            }
+        }
+        actions
+    }
+
+    /// Dessine le vaisseau mère et ses tourelles.
+    /// Le noyau est rouge quand il peut être touché, et blanc pendant un instant quand il est touché.
+    pub fn draw(&self) {
+        let hull = match self.phase {
+            BossPhase::Seeding => Color::new(0.45, 0.5, 0.6, 1.0),
+            BossPhase::Turrets => Color::new(0.5, 0.45, 0.6, 1.0),
+            BossPhase::Desperation => Color::new(0.65, 0.35, 0.35, 1.0),
+        };
+        draw_circle(self.position.x, self.position.y, RADIUS, hull);
+        draw_circle_lines(self.position.x, self.position.y, RADIUS, 3.0, LIGHTGRAY);
+        draw_circle_lines(
+            self.position.x,
+            self.position.y,
+            RADIUS * 0.7,
+            2.0,
+            DARKGRAY,
+        );
+
+        let core = if self.flash_timer > 0.0 {
+            WHITE
+        } else if self.core_exposed() {
+            RED
+        } else {
+            GRAY
+        };
+        draw_circle(self.position.x, self.position.y, RADIUS * 0.3, core);
+        if !self.core_exposed() {
+            draw_circle_lines(
+                self.position.x,
+                self.position.y,
+                RADIUS * 0.4,
+                3.0,
+                Color::new(0.4, 0.8, 1.0, 0.8),
+            );
+        }
+
+        for turret in self.turrets.iter().filter(|t| t.active) {
+            let (x, y) = (turret.position.x, turret.position.y);
+            draw_line(self.position.x, self.position.y, x, y, 4.0, DARKGRAY);
+            draw_circle(x, y, TURRET_RADIUS, ORANGE);
+            draw_circle_lines(
+                x,
+                y,
+                TURRET_RADIUS + 4.0,
+                2.0,
+                Color::new(0.4, 0.8, 1.0, 0.8),
+            );
+        }
+    }
+}
+
+impl StellarObject for Mothership {
+    /// Retourne la position du vaisseau mère.
+    fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    /// Déplace le vaisseau mère de gauche à droite, en rebondissant sur les bords de l'écran.
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
+        self.position += self.velocity;
+        let margin = (RADIUS + TURRET_OFFSET).min(bounds.x / 2.0);
+        if self.position.x < margin || self.position.x > bounds.x - margin {
+            self.velocity.x = -self.velocity.x;
+            self.position.x = self.position.x.clamp(margin, bounds.x - margin);
+        }
+    }
+
+    /// Retourne le rayon du vaisseau mère.
+    fn radius(&self) -> f32 {
+        RADIUS
+    }
+
+    /// Le noyau perd un point de vie à chaque collision, seulement s'il n'est plus protégé.
+    fn handle_collision(&mut self) {
+        if !self.core_exposed() {
+            return;
+        }
+        self.hp = self.hp.saturating_sub(1);
+        self.flash_timer = HIT_FLASH_TIME;
+        if self.hp == 0 {
+            self.active = false;
+        }
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état du vaisseau mère pour l'inspecteur de développement.
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "Mothership"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("hp", self.hp),
+            debug_field("phase", format!("{:?}", self.phase)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::thread_rng;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+    const TARGET: Vec2 = vec2(400.0, 500.0);
+
+    /// Touche le noyau un certain nombre de fois, puis met à jour le vaisseau mère
+    fn hit_core(boss: &mut Mothership, hits: u32) -> Vec<BossAction> {
+        for _ in 0..hits {
+            boss.handle_collision();
+        }
+        boss.update(1.0 / 60.0, TARGET, BOUNDS, &mut thread_rng())
+    }
+
+    /// Vérifie les seuils de points de vie des phases.
+    #[test]
+    fn test_phase_thresholds() {
+        assert_eq!(BossPhase::for_hp(30, 30), BossPhase::Seeding);
+        assert_eq!(BossPhase::for_hp(21, 30), BossPhase::Seeding);
+        assert_eq!(BossPhase::for_hp(20, 30), BossPhase::Turrets);
+        assert_eq!(BossPhase::for_hp(11, 30), BossPhase::Turrets);
+        assert_eq!(BossPhase::for_hp(10, 30), BossPhase::Desperation);
+        assert_eq!(BossPhase::for_hp(0, 30), BossPhase::Desperation);
+    }
+
+    /// Vérifie le déroulement du combat : les tourelles protègent le noyau,
+    /// puis la phase désespérée crée un seul trou noir, jusqu'à la destruction du noyau.
+    #[test]
+    fn test_phase_transitions() {
+        let mut boss = Mothership::new(BOUNDS);
+        assert_eq!(boss.phase(), BossPhase::Seeding);
+
+        let actions = hit_core(&mut boss, CORE_HP / 3);
+        assert_eq!(boss.phase(), BossPhase::Turrets);
+        assert!(matches!(
+            actions[0],
+            BossAction::PhaseChanged(BossPhase::Turrets)
+        ));
+        assert_eq!(boss.turrets.len(), 2);
+        assert!(!boss.core_exposed());
+
+        hit_core(&mut boss, 5);
+        assert_eq!(boss.hp, 20, "Le noyau est protégé par les tourelles !");
+
+        for turret in boss.turrets.iter_mut() {
+            for _ in 0..TURRET_HP {
+                turret.handle_collision();
+            }
+        }
+        assert!(boss.core_exposed());
+
+        let actions = hit_core(&mut boss, 10);
+        assert_eq!(boss.phase(), BossPhase::Desperation);
+        let black_holes = |actions: &[BossAction]| {
+            actions
+                .iter()
+                .filter(|a| matches!(a, BossAction::SpawnBlackHole(_)))
+                .count()
+        };
+        assert_eq!(black_holes(&actions), 1);
+        assert_eq!(black_holes(&hit_core(&mut boss, 0)), 0);
+
+        hit_core(&mut boss, 10);
+        assert_eq!(boss.hp, 0);
+        assert!(!boss.active, "Le vaisseau mère doit être détruit !");
+    }
+
+    /// Vérifie que la première phase lâche des volées de petits asteroides.
+    #[test]
+    fn test_seeding_volleys() {
+        let mut boss = Mothership::new(BOUNDS);
+
+        let actions = boss.update(VOLLEY_INTERVAL, TARGET, BOUNDS, &mut thread_rng());
+
+        let asteroids = actions
+            .iter()
+            .filter(|a| matches!(a, BossAction::SpawnAsteroid { .. }))
+            .count();
+        assert_eq!(asteroids, VOLLEY_SIZE);
+    }
+}
@@ -0,0 +1,249 @@
+//! Module pour l'asteroide géant, le boss des vagues `BOSS_ASTEROID_INTERVAL`, 2 x `BOSS_ASTEROID_INTERVAL`…
+//! du mode classique. Il remplace les asteroides de sa vague, résiste à `HP` missiles
+//! et dérive lentement vers le joueur. Détruit, il se sépare en quatre grands asteroides.
+use crate::asteroid::{Asteroid, Size, ASTEROID_VARIANTS, SPLIT_SPEED};
+use crate::stellarobject::{debug_field, format_vec2, wrap_offsets, wrapped_offset, StellarObject};
+use ::rand::Rng;
+use macroquad::prelude::*;
+use std::f32::consts::PI;
+
+/// Nombre de vagues entre deux asteroides géants
+pub const BOSS_ASTEROID_INTERVAL: u32 = 5;
+/// Points de vie de l'asteroide géant
+pub const HP: u32 = 12;
+/// Bonus de score pour la destruction de l'asteroide géant
+pub const SCORE_BONUS: i32 = 50;
+/// Taille de la texture de l'asteroide géant
+const SCALE: f32 = 200.0;
+/// Part du rayon dessiné utilisée pour les collisions, comme pour les grands asteroides
+const COLLISION_FACTOR: f32 = 0.8;
+/// Accélération (en pixels par image, à chaque image) vers le joueur
+const DRIFT_ACCELERATION: f32 = 0.004;
+/// Vitesse maximale (en pixels par image) de l'asteroide géant
+const MAX_SPEED: f32 = 0.6;
+/// Durée (en secondes) du clignotement quand l'asteroide géant est touché
+const HIT_FLASH_TIME: f32 = 0.1;
+/// Taille de la barre de points de vie dessinée au-dessus de l'asteroide géant
+const HEALTH_BAR_SIZE: Vec2 = vec2(120.0, 8.0);
+
+/// Structure représentant l'asteroide géant
+/// # Champs
+/// - `position`: la position de l'asteroide géant
+/// - `velocity`: la vitesse de l'asteroide géant
+/// - `variant`: l'indice de la texture, transmis aux grands asteroides de sa séparation
+/// - `hp`: les points de vie restants
+/// - `flash_timer`: le temps restant du clignotement quand il est touché
+/// - `active`: permet de savoir si l'asteroide géant est encore en jeu
+pub struct BossAsteroid {
+    position: Vec2,
+    velocity: Vec2,
+    variant: usize,
+    pub hp: u32,
+    flash_timer: f32,
+    pub active: bool,
+}
+
+/// Fonction qui permet de savoir si une vague du mode classique est celle d'un asteroide géant
+/// # Arguments
+/// - `wave`: le numéro de la vague
+/// # Returns
+/// - `bool`: `true` pour les vagues 5, 10, 15…
+pub fn is_boss_asteroid_wave(wave: u32) -> bool {
+    wave > 0 && wave.is_multiple_of(BOSS_ASTEROID_INTERVAL)
+}
+
+impl BossAsteroid {
+    /// Crée l'asteroide géant à l'opposé d'une position à éviter, immobile
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// - `avoid`: la position à éviter, en général celle du vaisseau
+    /// - `rng`: le générateur de la partie, pour la texture
+    /// # Returns
+    /// - `Self`: l'asteroide géant avec tous ses points de vie
+    pub fn new(bounds: Vec2, avoid: Vec2, rng: &mut impl Rng) -> Self {
+        let position = avoid + bounds / 2.0;
+        Self {
+            position: vec2(
+                position.x.rem_euclid(bounds.x),
+                position.y.rem_euclid(bounds.y),
+            ),
+            velocity: Vec2::ZERO,
+            variant: rng.gen_range(0..ASTEROID_VARIANTS),
+            hp: HP,
+            flash_timer: 0.0,
+            active: true,
+        }
+    }
+
+    /// Met à jour l'asteroide géant pour une image : il accélère doucement vers sa cible
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `target`: la position visée, celle du vaisseau
+    /// - `bounds`: la taille de l'écran, pour passer par le bord le plus proche
+    pub fn update(&mut self, dt: f32, target: Vec2, bounds: Vec2) {
+        self.flash_timer = (self.flash_timer - dt).max(0.0);
+        let direction = wrapped_offset(self.position, target, bounds).normalize_or_zero();
+        self.velocity =
+            (self.velocity + direction * DRIFT_ACCELERATION).clamp_length_max(MAX_SPEED);
+    }
+
+    /// Fonction qui sépare l'asteroide géant détruit en quatre grands asteroides,
+    /// qui s'écartent en croix à `SPLIT_SPEED`
+    /// # Arguments
+    /// - `rng`: le générateur de la partie, pour l'orientation de la croix
+    /// # Returns
+    /// - `[Asteroid; 4]`: les grands asteroides, avec la texture de l'asteroide géant
+    pub fn split(&self, rng: &mut impl Rng) -> [Asteroid; 4] {
+        let start = rng.gen_range(0.0..PI / 2.0);
+        std::array::from_fn(|i| {
+            let direction = Vec2::from_angle(start + i as f32 * PI / 2.0);
+            Asteroid::new_with_size(
+                Size::Large,
+                self.position + direction * Size::Large.scale() / 2.0,
+                self.velocity + direction * SPLIT_SPEED,
+            )
+            .with_variant(self.variant)
+        })
+    }
+
+    /// Dessine l'asteroide géant avec la texture des asteroides, et sa barre de points de vie
+    /// au-dessus de lui. Il devient rouge pendant un instant quand il est touché.
+    /// # Arguments
+    /// - `bounds`: la taille de l'aire de jeu
+    /// - `textures`: les textures des asteroides, dont au moins une
+    pub fn draw(&self, bounds: Vec2, textures: &[Texture2D]) {
+        let texture = &textures[self.variant % textures.len()];
+        let color = if self.flash_timer > 0.0 { RED } else { WHITE };
+        let half = SCALE / 2.0;
+        for offset in wrap_offsets(self.position, half, bounds) {
+            let position = self.position + offset;
+            draw_texture_ex(
+                texture,
+                position.x - half,
+                position.y - half,
+                color,
+                DrawTextureParams {
+                    dest_size: Some(Vec2::splat(SCALE)),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let x = self.position.x - HEALTH_BAR_SIZE.x / 2.0;
+        let y = self.position.y - half - HEALTH_BAR_SIZE.y - 6.0;
+        draw_rectangle(
+            x,
+            y,
+            HEALTH_BAR_SIZE.x,
+            HEALTH_BAR_SIZE.y,
+            Color::new(1.0, 1.0, 1.0, 0.2),
+        );
+        draw_rectangle(
+            x,
+            y,
+            HEALTH_BAR_SIZE.x * self.hp as f32 / HP as f32,
+            HEALTH_BAR_SIZE.y,
+            ORANGE,
+        );
+    }
+}
+
+impl StellarObject for BossAsteroid {
+    /// Retourne la position de l'asteroide géant.
+    fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    /// Déplace l'asteroide géant, qui passe d'un bord de l'écran à l'autre.
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
+        self.position += self.velocity;
+        self.position = vec2(
+            self.position.x.rem_euclid(bounds.x),
+            self.position.y.rem_euclid(bounds.y),
+        );
+    }
+
+    /// Retourne le rayon de collision de l'asteroide géant.
+    fn radius(&self) -> f32 {
+        SCALE / 2.0 * COLLISION_FACTOR
+    }
+
+    /// L'asteroide géant perd un point de vie à chaque collision, et est détruit
+    /// quand il n'en a plus.
+    fn handle_collision(&mut self) {
+        self.hp = self.hp.saturating_sub(1);
+        self.flash_timer = HIT_FLASH_TIME;
+        if self.hp == 0 {
+            self.active = false;
+        }
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état de l'asteroide géant pour l'inspecteur de développement.
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "BossAsteroid"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.velocity)),
+            debug_field("hp", self.hp),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::thread_rng;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Vérifie que l'asteroide géant revient toutes les `BOSS_ASTEROID_INTERVAL` vagues.
+    #[test]
+    fn test_boss_asteroid_waves() {
+        let waves: Vec<u32> = (0..=16).filter(|&w| is_boss_asteroid_wave(w)).collect();
+        assert_eq!(waves, [5, 10, 15]);
+    }
+
+    /// Vérifie que l'asteroide géant dérive vers sa cible sans dépasser `MAX_SPEED`,
+    /// en passant par le bord le plus proche.
+    #[test]
+    fn test_drifts_towards_target() {
+        let mut boss = BossAsteroid::new(BOUNDS, vec2(400.0, 300.0), &mut thread_rng());
+        assert_eq!(boss.get_pos(), vec2(0.0, 0.0));
+
+        for _ in 0..600 {
+            boss.update(1.0 / 60.0, vec2(700.0, 500.0), BOUNDS);
+        }
+
+        let velocity = boss.velocity();
+        assert!(velocity.x < 0.0 && velocity.y < 0.0);
+        assert!(velocity.length() <= MAX_SPEED + 1e-4);
+    }
+}
@@ -0,0 +1,192 @@
+//! Module pour gérer les sous-titres des événements sonores.
+//! Les sous-titres s'empilent dans un coin de l'écran, le plus récent en haut,
+//! et disparaissent progressivement après un court instant.
+use macroquad::prelude::*;
+
+/// Nombre maximum de sous-titres visibles en même temps
+pub const MAX_VISIBLE: usize = 3;
+/// Durée (en secondes) pendant laquelle un sous-titre reste pleinement visible
+pub const DISPLAY_TIME: f32 = 1.5;
+/// Durée (en secondes) du fondu avant la disparition du sous-titre
+pub const FADE_TIME: f32 = 0.5;
+
+/// Structure représentant un sous-titre
+/// # Champs
+/// - `text`: le texte du sous-titre
+/// - `source`: la position dans le monde de l'événement, si elle existe
+/// - `age`: le temps écoulé depuis l'apparition du sous-titre
+#[derive(Clone, Debug, PartialEq)]
+pub struct Caption {
+    pub text: &'static str,
+    pub source: Option<Vec2>,
+    pub age: f32,
+}
+
+impl Caption {
+    /// Fonction qui renvoie l'opacité du sous-titre en fonction de son âge
+    /// # Returns
+    /// - `f32`: l'opacité entre 0 et 1
+    pub fn alpha(&self) -> f32 {
+        if self.age <= DISPLAY_TIME {
+            1.0
+        } else {
+            (1.0 - (self.age - DISPLAY_TIME) / FADE_TIME).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Structure représentant une ligne de sous-titre prête à être dessinée
+/// # Champs
+/// - `text`: le texte du sous-titre
+/// - `source`: la position dans le monde de l'événement, si elle existe
+/// - `position`: la position à l'écran de la ligne
+/// - `alpha`: l'opacité de la ligne
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaptionLine {
+    pub text: &'static str,
+    pub source: Option<Vec2>,
+    pub position: Vec2,
+    pub alpha: f32,
+}
+
+/// Structure représentant la pile des sous-titres affichés
+/// # Champs
+/// - `captions`: les sous-titres, du plus récent au plus ancien
+#[derive(Default)]
+pub struct CaptionStack {
+    captions: Vec<Caption>,
+}
+
+impl CaptionStack {
+    /// Crée une pile de sous-titres vide
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ajoute un sous-titre en haut de la pile.
+    /// Si le même texte est déjà affiché, il est remonté en haut au lieu d'être dupliqué,
+    /// et le plus ancien est retiré quand la pile dépasse `MAX_VISIBLE`.
+    /// # Arguments
+    /// - `text`: le texte du sous-titre
+    /// - `source`: la position dans le monde de l'événement, si elle existe
+    pub fn push(&mut self, text: &'static str, source: Option<Vec2>) {
+        self.captions.retain(|caption| caption.text != text);
+        self.captions.insert(
+            0,
+            Caption {
+                text,
+                source,
+                age: 0.0,
+            },
+        );
+        self.captions.truncate(MAX_VISIBLE);
+    }
+
+    /// Fait vieillir les sous-titres et retire ceux qui ont fini leur fondu
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        for caption in self.captions.iter_mut() {
+            caption.age += dt;
+        }
+        self.captions
+            .retain(|caption| caption.age < DISPLAY_TIME + FADE_TIME);
+    }
+
+    /// Fonction qui calcule la position de chaque ligne de la pile.
+    /// La pile est ancrée en bas de l'écran : la ligne la plus ancienne est à l'ancre
+    /// et les plus récentes sont empilées au dessus.
+    /// # Arguments
+    /// - `anchor`: la position de la ligne la plus basse
+    /// - `line_height`: la hauteur d'une ligne
+    /// # Returns
+    /// - `Vec<CaptionLine>`: les lignes à dessiner, de la plus haute à la plus basse
+    pub fn layout(&self, anchor: Vec2, line_height: f32) -> Vec<CaptionLine> {
+        let count = self.captions.len();
+        self.captions
+            .iter()
+            .enumerate()
+            .map(|(i, caption)| CaptionLine {
+                text: caption.text,
+                source: caption.source,
+                position: vec2(anchor.x, anchor.y - (count - 1 - i) as f32 * line_height),
+                alpha: caption.alpha(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que la pile ne garde que les `MAX_VISIBLE` sous-titres les plus récents.
+    #[test]
+    fn test_overflow_evicts_oldest() {
+        let mut stack = CaptionStack::new();
+        stack.push("a", None);
+        stack.push("b", None);
+        stack.push("c", None);
+        stack.push("d", None);
+
+        let texts: Vec<&str> = stack.captions.iter().map(|c| c.text).collect();
+        assert_eq!(
+            texts,
+            vec!["d", "c", "b"],
+            "Le plus ancien n'a pas été retiré !"
+        );
+    }
+
+    /// Vérifie qu'un sous-titre déjà affiché est remonté en haut au lieu d'être dupliqué.
+    #[test]
+    fn test_duplicate_moves_to_top() {
+        let mut stack = CaptionStack::new();
+        stack.push("a", None);
+        stack.push("b", None);
+        stack.update(1.0);
+        stack.push("a", Some(vec2(10.0, 10.0)));
+
+        let captions = stack.captions;
+        assert_eq!(captions.len(), 2);
+        assert_eq!(captions[0].text, "a");
+        assert_eq!(
+            captions[0].age, 0.0,
+            "Le sous-titre n'a pas été rafraîchi !"
+        );
+        assert_eq!(captions[0].source, Some(vec2(10.0, 10.0)));
+    }
+
+    /// Vérifie le fondu : opaque pendant `DISPLAY_TIME`, puis retiré après le fondu.
+    #[test]
+    fn test_fade_timing() {
+        let mut stack = CaptionStack::new();
+        stack.push("a", None);
+
+        stack.update(DISPLAY_TIME);
+        assert_eq!(stack.captions[0].alpha(), 1.0);
+
+        stack.update(FADE_TIME / 2.0);
+        let alpha = stack.captions[0].alpha();
+        assert!(
+            (alpha - 0.5).abs() < 1e-4,
+            "Le fondu est incorrect : {alpha}"
+        );
+
+        stack.update(FADE_TIME);
+        assert!(stack.captions.is_empty(), "Le sous-titre n'a pas disparu !");
+    }
+
+    /// Vérifie la disposition : le plus récent en haut, le plus ancien sur l'ancre.
+    #[test]
+    fn test_layout_newest_on_top() {
+        let mut stack = CaptionStack::new();
+        stack.push("ancien", None);
+        stack.push("récent", None);
+
+        let lines = stack.layout(vec2(500.0, 400.0), 20.0);
+        assert_eq!(lines[0].text, "récent");
+        assert_eq!(lines[0].position, vec2(500.0, 380.0));
+        assert_eq!(lines[1].text, "ancien");
+        assert_eq!(lines[1].position, vec2(500.0, 400.0));
+    }
+}
@@ -0,0 +1,197 @@
+//! Module pour les options de la ligne de commande, lues au lancement du jeu.
+//! Une option inconnue ou une valeur invalide affiche l'aide et arrête le jeu avec une erreur.
+use crate::narration::Backend;
+use asteroid::difficulty::Difficulty;
+use asteroid::game_mode::GameMode;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Aide affichée par `--help`, ou après une option invalide
+pub const USAGE: &str = "\
+Utilisation : asteroid [options]
+  --seed N              impose la graine de toutes les parties
+  --wave N              commence les parties à la vague N
+  --difficulty NIVEAU   easy, normal ou hard
+  --mode MODE           classic ou endless
+  --mute                coupe le son au lancement
+  --assets DOSSIER      remplace les ressources du jeu par celles de ce dossier
+  --record FICHIER      enregistre le replay de chaque partie
+  --replay FICHIER      rejoue un replay au lancement
+  --arcade-missiles     les missiles ne gardent pas la vitesse du vaisseau
+  --overheat            l'arme surchauffe quelle que soit la difficulté
+  --fullscreen          lance le jeu en plein écran
+  --narrate             annonce les évènements du jeu sur la sortie standard
+  --narrate-file=FICHIER  annonce les évènements du jeu dans ce fichier
+  --help                affiche cette aide";
+
+/// Structure représentant les options de la ligne de commande
+/// # Champs
+/// - `seed`: la graine imposée à toutes les parties
+/// - `wave`: la vague par laquelle commencent les parties
+/// - `difficulty`: la difficulté choisie au lancement, modifiable ensuite sur l'écran de démarrage
+/// - `mode`: le mode de jeu choisi au lancement, modifiable ensuite sur l'écran de démarrage
+/// - `mute`: permet de couper le son au lancement
+/// - `assets`: le dossier des ressources, qui passent avant celles intégrées au jeu
+/// - `record`: le fichier où enregistrer le replay de chaque partie
+/// - `replay`: le replay à rejouer au lancement
+/// - `arcade_missiles`: les missiles partent toujours à la même vitesse
+/// - `overheat`: l'arme surchauffe quelle que soit la difficulté
+/// - `fullscreen`: permet de lancer le jeu en plein écran
+/// - `narration`: la destination du journal de narration, ou `None` sans narration
+/// - `help`: permet d'afficher l'aide au lieu de lancer le jeu
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CliOptions {
+    pub seed: Option<u64>,
+    pub wave: Option<u32>,
+    pub difficulty: Option<Difficulty>,
+    pub mode: Option<GameMode>,
+    pub mute: bool,
+    pub assets: Option<String>,
+    pub record: Option<PathBuf>,
+    pub replay: Option<PathBuf>,
+    pub arcade_missiles: bool,
+    pub overheat: bool,
+    pub fullscreen: bool,
+    pub narration: Option<Backend>,
+    pub help: bool,
+}
+
+impl CliOptions {
+    /// Lit les options de la ligne de commande
+    /// # Arguments
+    /// - `args`: les arguments, sans le nom du programme
+    /// # Returns
+    /// - `Result<Self, String>`: les options, ou le message d'erreur de la première option invalide
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => options.seed = Some(parsed(&mut args, &arg)?),
+                "--wave" => {
+                    let wave = parsed(&mut args, &arg)?;
+                    if wave == 0 {
+                        return Err("La vague de --wave commence à 1".to_string());
+                    }
+                    options.wave = Some(wave);
+                }
+                "--difficulty" => {
+                    let id = value(&mut args, &arg)?;
+                    options.difficulty =
+                        Some(Difficulty::from_id(&id).ok_or_else(|| {
+                            format!("Difficulté inconnue pour --difficulty : {id}")
+                        })?);
+                }
+                "--mode" => {
+                    let id = value(&mut args, &arg)?;
+                    options.mode = Some(
+                        GameMode::from_id(&id)
+                            .ok_or_else(|| format!("Mode inconnu pour --mode : {id}"))?,
+                    );
+                }
+                "--assets" => options.assets = Some(value(&mut args, &arg)?),
+                "--record" => options.record = Some(value(&mut args, &arg)?.into()),
+                "--replay" => options.replay = Some(value(&mut args, &arg)?.into()),
+                "--mute" => options.mute = true,
+                "--arcade-missiles" => options.arcade_missiles = true,
+                "--overheat" => options.overheat = true,
+                "--fullscreen" => options.fullscreen = true,
+                "--help" | "-h" => options.help = true,
+                _ => match Backend::from_args([arg.clone()]) {
+                    Some(backend) => options.narration = Some(backend),
+                    None => return Err(format!("Option inconnue : {arg}")),
+                },
+            }
+        }
+        Ok(options)
+    }
+
+    /// Lit les options du jeu lancé. Affiche l'aide et arrête le jeu pour `--help`,
+    /// ou avec une erreur pour une option invalide.
+    /// # Returns
+    /// - `Self`: les options valides
+    pub fn from_env_or_exit() -> Self {
+        match Self::parse(std::env::args().skip(1)) {
+            Ok(options) if options.help => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            Ok(options) => options,
+            Err(message) => {
+                eprintln!("{message}\n\n{USAGE}");
+                std::process::exit(2);
+            }
+        }
+    }
+}
+
+/// Fonction qui lit la valeur d'une option, donnée après son nom
+/// # Arguments
+/// - `args`: les arguments qui suivent l'option
+/// - `name`: le nom de l'option
+/// # Returns
+/// - `Result<String, String>`: la valeur, ou une erreur si elle manque
+fn value(args: &mut impl Iterator<Item = String>, name: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("Valeur manquante pour {name}"))
+}
+
+/// Fonction qui lit la valeur numérique d'une option, donnée après son nom
+/// # Arguments
+/// - `args`: les arguments qui suivent l'option
+/// - `name`: le nom de l'option
+/// # Returns
+/// - `Result<T, String>`: la valeur, ou une erreur si elle manque ou n'est pas un nombre valide
+fn parsed<T: FromStr>(args: &mut impl Iterator<Item = String>, name: &str) -> Result<T, String> {
+    let value = value(args, name)?;
+    value
+        .parse()
+        .map_err(|_| format!("Valeur invalide pour {name} : {value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lit des options écrites comme sur la ligne de commande.
+    fn parse(line: &str) -> Result<CliOptions, String> {
+        CliOptions::parse(line.split_whitespace().map(String::from))
+    }
+
+    /// Vérifie que toutes les options sont lues, dans n'importe quel ordre.
+    #[test]
+    fn test_parse_options() {
+        let options = parse(
+            "--mute --seed 42 --wave 5 --difficulty hard --mode endless --assets data --replay a.rpl",
+        )
+        .unwrap();
+
+        assert_eq!(options.seed, Some(42));
+        assert_eq!(options.wave, Some(5));
+        assert_eq!(options.difficulty, Some(Difficulty::Hard));
+        assert_eq!(options.mode, Some(GameMode::Endless));
+        assert_eq!(options.assets.as_deref(), Some("data"));
+        assert_eq!(options.replay, Some(PathBuf::from("a.rpl")));
+        assert!(options.mute && !options.fullscreen);
+        assert_eq!(
+            parse("--narrate-file=log.txt").unwrap().narration,
+            Some(Backend::File(PathBuf::from("log.txt")))
+        );
+        assert_eq!(parse("").unwrap(), CliOptions::default());
+    }
+
+    /// Vérifie qu'une option inconnue, une valeur manquante ou invalide sont refusées.
+    #[test]
+    fn test_invalid_options_are_rejected() {
+        for line in [
+            "--speed 3",
+            "--seed",
+            "--seed abc",
+            "--wave 0",
+            "--difficulty extreme",
+            "--mode survival",
+        ] {
+            assert!(parse(line).is_err(), "« {line} » doit être refusé !");
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Module pour les comètes, un danger qui traverse l'écran à toute vitesse.
+//! Une comète est annoncée pendant `WARNING_TIME` par une flèche au bord par lequel elle va entrer,
+//! puis traverse l'écran sans passer d'un bord à l'autre et disparaît une fois sortie.
+//! Elle pulvérise les asteroides qu'elle touche et les missiles s'y brisent sans l'abîmer.
+use crate::effects::{Effects, Particle};
+use crate::stellarobject::{debug_field, format_vec2, StellarObject};
+use ::rand::{thread_rng, Rng};
+use macroquad::prelude::*;
+
+/// Durée (en secondes) de l'annonce d'une comète avant son entrée
+pub const WARNING_TIME: f32 = 1.0;
+/// Vitesse (en pixels par image) d'une comète
+const SPEED: f32 = 7.0;
+/// Rayon d'une comète
+const RADIUS: f32 = 12.0;
+/// Temps minimal (en secondes) entre deux comètes
+const MIN_INTERVAL: f32 = 20.0;
+/// Temps maximal (en secondes) entre deux comètes
+const MAX_INTERVAL: f32 = 40.0;
+/// Nombre de particules de la queue perdues par seconde
+const TAIL_PER_SECOND: f32 = 90.0;
+/// Durée de vie (en secondes) des particules de la queue
+const TAIL_LIFETIME: f32 = 0.5;
+/// Couleur de la comète et de sa queue
+const COMET_COLOR: Color = Color::new(0.6, 0.85, 1.0, 1.0);
+/// Distance (en pixels) entre la flèche d'annonce et le bord de l'écran
+const ARROW_MARGIN: f32 = 24.0;
+
+/// Structure représentant une comète
+/// # Champs
+/// - `position`: la position de la comète, hors de l'écran tant qu'elle est annoncée
+/// - `velocity`: la vitesse de la comète
+/// - `warning`: le temps restant de l'annonce, pendant lequel la comète ne bouge pas
+/// - `tail_accumulator`: les particules de la queue en attente, pour en perdre un nombre régulier
+/// - `active`: permet de savoir si la comète est encore en jeu
+pub struct Comet {
+    position: Vec2,
+    velocity: Vec2,
+    warning: f32,
+    tail_accumulator: f32,
+    pub active: bool,
+}
+
+/// Structure qui décide quand une comète apparaît : jamais pendant la première vague,
+/// puis toutes les `MIN_INTERVAL` à `MAX_INTERVAL` secondes
+/// # Champs
+/// - `timer`: le temps restant avant la prochaine comète
+#[derive(Clone, Copy, Debug)]
+pub struct CometScheduler {
+    timer: f32,
+}
+
+impl Default for CometScheduler {
+    /// Crée un planificateur dont la première comète arrive après `MIN_INTERVAL` secondes
+    fn default() -> Self {
+        Self {
+            timer: MIN_INTERVAL,
+        }
+    }
+}
+
+impl CometScheduler {
+    /// Fait avancer le planificateur d'une image
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `wave`: le numéro de la vague en cours
+    /// - `rng`: le générateur de la partie, pour l'intervalle suivant
+    /// # Returns
+    /// - `bool`: `true` si une comète doit apparaître pendant cette image
+    pub fn update(&mut self, dt: f32, wave: u32, rng: &mut impl Rng) -> bool {
+        if wave <= 1 {
+            return false;
+        }
+        self.timer -= dt;
+        if self.timer > 0.0 {
+            return false;
+        }
+        self.timer = rng.gen_range(MIN_INTERVAL..MAX_INTERVAL);
+        true
+    }
+}
+
+impl Comet {
+    /// Crée une comète annoncée sur un bord choisi au hasard, qui visera le milieu de l'écran
+    /// à peu près, pour le traverser
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `Self`: la comète, encore annoncée
+    pub fn random(bounds: Vec2, rng: &mut impl Rng) -> Self {
+        let along = rng.gen_range(0.2..0.8);
+        let entry = match rng.gen_range(0..4) {
+            0 => vec2(along * bounds.x, 0.0),
+            1 => vec2(along * bounds.x, bounds.y),
+            2 => vec2(0.0, along * bounds.y),
+            _ => vec2(bounds.x, along * bounds.y),
+        };
+        let aim = vec2(
+            rng.gen_range(0.3..0.7) * bounds.x,
+            rng.gen_range(0.3..0.7) * bounds.y,
+        );
+        Self::new(entry, aim - entry)
+    }
+
+    /// Crée une comète annoncée, qui entrera par un point du bord dans une direction donnée
+    /// # Arguments
+    /// - `entry`: le point du bord par lequel la comète entre
+    /// - `direction`: la direction de la comète, vers l'intérieur de l'écran
+    /// # Returns
+    /// - `Self`: la comète, placée juste avant le bord
+    pub fn new(entry: Vec2, direction: Vec2) -> Self {
+        let direction = direction.try_normalize().unwrap_or(Vec2::X);
+        Self {
+            position: entry - direction * RADIUS * 2.0,
+            velocity: direction * SPEED,
+            warning: WARNING_TIME,
+            tail_accumulator: 0.0,
+            active: true,
+        }
+    }
+
+    /// Permet de savoir si la comète est encore annoncée : elle ne touche alors rien
+    /// # Returns
+    /// - `bool`: `true` pendant l'annonce
+    pub fn warning(&self) -> bool {
+        self.warning > 0.0
+    }
+
+    /// Met à jour la comète pour une image : la fin de l'annonce, puis sa queue de particules
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `effects`: le système d'effets qui reçoit la queue
+    pub fn update(&mut self, dt: f32, effects: &mut Effects) {
+        if self.warning() {
+            self.warning = (self.warning - dt).max(0.0);
+            return;
+        }
+        self.tail_accumulator += TAIL_PER_SECOND * dt;
+        let mut rng = thread_rng();
+        while self.tail_accumulator >= 1.0 {
+            self.tail_accumulator -= 1.0;
+            let drift = vec2(rng.gen_range(-0.4..0.4), rng.gen_range(-0.4..0.4));
+            effects.spawn(Particle::new(
+                self.position,
+                -self.velocity * 0.15 + drift,
+                COMET_COLOR,
+                rng.gen_range(1.5..3.5),
+                TAIL_LIFETIME,
+            ));
+        }
+    }
+
+    /// Dessine la comète, ou pendant l'annonce une flèche clignotante au bord d'entrée
+    /// # Arguments
+    /// - `bounds`: la taille de l'aire de jeu
+    pub fn draw(&self, bounds: Vec2) {
+        let direction = self.velocity.normalize_or_zero();
+        if self.warning() {
+            if (self.warning * 8.0).sin() < 0.0 {
+                return;
+            }
+            let tip = self
+                .position
+                .clamp(Vec2::splat(ARROW_MARGIN), bounds - ARROW_MARGIN);
+            let side = direction.perp() * 10.0;
+            let back = tip - direction * 20.0;
+            draw_triangle(tip, back + side, back - side, RED);
+            return;
+        }
+        draw_circle(self.position.x, self.position.y, RADIUS, COMET_COLOR);
+        draw_circle(self.position.x, self.position.y, RADIUS * 0.6, WHITE);
+    }
+}
+
+impl StellarObject for Comet {
+    /// Retourne la position de la comète.
+    fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    /// Déplace la comète une fois l'annonce finie. Elle ne passe pas d'un bord à l'autre :
+    /// elle disparaît quand elle est sortie de l'écran et s'en éloigne.
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
+        if self.warning() {
+            return;
+        }
+        self.position += self.velocity;
+        let outside = self.position.x < -RADIUS
+            || self.position.y < -RADIUS
+            || self.position.x > bounds.x + RADIUS
+            || self.position.y > bounds.y + RADIUS;
+        let leaving = (self.position - bounds / 2.0).dot(self.velocity) > 0.0;
+        if outside && leaving {
+            self.active = false;
+        }
+    }
+
+    /// Retourne le rayon de collision de la comète.
+    fn radius(&self) -> f32 {
+        RADIUS
+    }
+
+    /// Rien n'abîme une comète.
+    fn handle_collision(&mut self) {}
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état de la comète pour l'inspecteur de développement.
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "Comet"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.velocity)),
+            debug_field("warning", format!("{:.2}", self.warning)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Vérifie que la comète attend la fin de l'annonce, traverse l'écran sans en faire le tour
+    /// et disparaît une fois sortie.
+    #[test]
+    fn test_crosses_screen_once() {
+        let mut effects = Effects::new();
+        let mut comet = Comet::new(vec2(0.0, 300.0), Vec2::X);
+        let start = comet.get_pos();
+        comet.move_obj(BOUNDS);
+        assert_eq!(comet.get_pos(), start, "La comète annoncée ne bouge pas !");
+
+        comet.update(WARNING_TIME, &mut effects);
+        assert!(!comet.warning());
+        let mut frames = 0;
+        while comet.active {
+            comet.update(1.0 / 60.0, &mut effects);
+            comet.move_obj(BOUNDS);
+            assert!(
+                comet.get_pos().x >= start.x,
+                "La comète ne passe pas de l'autre côté !"
+            );
+            frames += 1;
+            assert!(frames < 1000);
+        }
+        assert!(comet.get_pos().x > BOUNDS.x);
+    }
+
+    /// Vérifie que le planificateur n'envoie aucune comète pendant la première vague.
+    #[test]
+    fn test_scheduler_skips_first_wave() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut scheduler = CometScheduler::default();
+        assert!(!scheduler.update(MAX_INTERVAL, 1, &mut rng));
+        assert!(scheduler.update(MIN_INTERVAL, 2, &mut rng));
+        assert!(!scheduler.update(1.0 / 60.0, 2, &mut rng));
+    }
+}
@@ -0,0 +1,203 @@
+//! Module pour les traces d'impact laissées sur l'arrière-plan.
+//! Chaque asteroide détruit laisse une trace sombre à l'endroit de sa destruction,
+//! jusqu'à la fin de la vague : la partie garde en mémoire la liste des traces,
+//! et l'affichage les dessine une seule fois dans une texture posée sur l'arrière-plan.
+use macroquad::prelude::*;
+
+/// Nombre maximal de traces pendant une vague, les suivantes ne sont pas gardées
+pub const MAX_DECALS_PER_WAVE: usize = 64;
+/// Couleur d'une trace
+const DECAL_COLOR: Color = Color::new(0.05, 0.03, 0.02, 0.18);
+
+/// Structure représentant une trace d'impact
+/// # Champs
+/// - `position`: le centre de la trace, dans l'aire de jeu
+/// - `radius`: le rayon de la trace
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Decal {
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+/// Structure regroupant les traces de la vague en cours
+/// # Champs
+/// - `items`: les traces, dans l'ordre de leur apparition
+/// - `epoch`: le nombre de fois où les traces ont été effacées, pour que l'affichage le remarque
+#[derive(Clone, Default, Debug)]
+pub struct Decals {
+    pub items: Vec<Decal>,
+    pub epoch: u32,
+}
+
+impl Decals {
+    /// Ajoute une trace, si la vague n'en a pas déjà `MAX_DECALS_PER_WAVE`
+    /// # Arguments
+    /// - `position`: le centre de la trace
+    /// - `radius`: le rayon de la trace
+    pub fn push(&mut self, position: Vec2, radius: f32) {
+        if self.items.len() < MAX_DECALS_PER_WAVE {
+            self.items.push(Decal { position, radius });
+        }
+    }
+
+    /// Efface toutes les traces, à la fin de la vague
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.epoch += 1;
+    }
+}
+
+/// Fonction qui convertit une position de l'aire de jeu en pixels de la texture des traces
+/// # Arguments
+/// - `position`: la position dans l'aire de jeu
+/// - `bounds`: la taille de l'aire de jeu
+/// - `target_size`: la taille de la texture, en pixels
+/// # Returns
+/// - `Vec2`: la position dans la texture, l'origine étant en haut à gauche
+pub fn to_target(position: Vec2, bounds: Vec2, target_size: Vec2) -> Vec2 {
+    position * target_size / bounds
+}
+
+/// Fonction qui calcule la taille de la texture des traces pour une fenêtre
+/// # Arguments
+/// - `screen`: la taille de la fenêtre, en pixels
+/// # Returns
+/// - `UVec2`: la taille de la texture, d'au moins un pixel sur chaque axe
+pub fn target_size(screen: Vec2) -> UVec2 {
+    screen.max(Vec2::ONE).as_uvec2()
+}
+
+/// Structure représentant la texture dans laquelle les traces sont dessinées
+/// # Champs
+/// - `target`: la texture, créée au premier affichage
+/// - `size`: la taille de la texture, en pixels
+/// - `bounds`: la taille de l'aire de jeu pour laquelle la texture a été dessinée
+/// - `epoch`: l'époque des traces dessinées
+/// - `drawn`: le nombre de traces déjà dessinées dans la texture
+#[derive(Default)]
+pub struct DecalLayer {
+    target: Option<RenderTarget>,
+    size: UVec2,
+    bounds: Vec2,
+    epoch: u32,
+    drawn: usize,
+}
+
+impl DecalLayer {
+    /// Crée une couche de traces vide
+    /// # Returns
+    /// - `Self`: la couche de traces
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dessine dans la texture les nouvelles traces.
+    /// La texture est recréée quand la fenêtre change de taille, et vidée à la fin de la vague.
+    /// La caméra par défaut est remise à la fin.
+    /// # Arguments
+    /// - `decals`: les traces de la vague en cours
+    /// - `bounds`: la taille de l'aire de jeu
+    pub fn sync(&mut self, decals: &Decals, bounds: Vec2) {
+        let size = target_size(vec2(screen_width(), screen_height()));
+        let recreate = self.target.is_none() || size != self.size || bounds != self.bounds;
+        if recreate {
+            let target = render_target(size.x, size.y);
+            target.texture.set_filter(FilterMode::Linear);
+            self.target = Some(target);
+            self.size = size;
+            self.bounds = bounds;
+        }
+        if recreate || decals.epoch != self.epoch {
+            self.epoch = decals.epoch;
+            self.drawn = 0;
+            self.with_target(|| clear_background(Color::new(0.0, 0.0, 0.0, 0.0)));
+        }
+        // Après une recréation, toutes les traces de la vague sont redessinées
+        if self.drawn < decals.items.len() {
+            let pixels = self.size.as_vec2();
+            let new_decals = &decals.items[self.drawn..];
+            self.with_target(|| {
+                for decal in new_decals {
+                    let center = to_target(decal.position, bounds, pixels);
+                    let radius = decal.radius * pixels.x / bounds.x;
+                    draw_circle(center.x, center.y, radius, DECAL_COLOR);
+                    draw_circle(center.x, center.y, radius * 0.6, DECAL_COLOR);
+                }
+            });
+            self.drawn = decals.items.len();
+        }
+    }
+
+    /// Dessine la texture des traces sur toute l'aire de jeu, sous la caméra de l'aire de jeu
+    /// # Arguments
+    /// - `bounds`: la taille de l'aire de jeu
+    pub fn draw(&self, bounds: Vec2) {
+        if let Some(target) = &self.target {
+            draw_texture_ex(
+                &target.texture,
+                0.0,
+                0.0,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(bounds),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    /// Dessine dans la texture, avec une caméra en pixels de la texture
+    /// # Arguments
+    /// - `draw`: les dessins à faire dans la texture
+    fn with_target(&self, draw: impl FnOnce()) {
+        let Some(target) = &self.target else {
+            return;
+        };
+        let pixels = self.size.as_vec2();
+        set_camera(&Camera2D {
+            target: pixels / 2.0,
+            zoom: vec2(2.0 / pixels.x, 2.0 / pixels.y),
+            render_target: Some(target.clone()),
+            ..Default::default()
+        });
+        draw();
+        set_default_camera();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que les coins et le centre de l'aire de jeu tombent aux coins et au centre
+    /// de la texture, y compris quand l'aire de jeu est plus grande que la fenêtre.
+    #[test]
+    fn test_playfield_to_target_mapping() {
+        let screen = vec2(320.0, 240.0);
+        let bounds = crate::playfield::playfield_bounds(screen);
+        let pixels = target_size(screen).as_vec2();
+
+        assert_eq!(pixels, screen);
+        assert_eq!(to_target(Vec2::ZERO, bounds, pixels), Vec2::ZERO);
+        assert!(to_target(bounds, bounds, pixels).distance(pixels) < 1e-3);
+        assert!(
+            to_target(bounds / 2.0, bounds, pixels).distance(pixels / 2.0) < 1e-3,
+            "Le centre de l'aire de jeu doit tomber au centre de la texture !"
+        );
+        assert_eq!(target_size(Vec2::ZERO), UVec2::ONE);
+    }
+
+    /// Vérifie que le nombre de traces est limité et que les effacer change d'époque.
+    #[test]
+    fn test_decals_are_capped_and_cleared() {
+        let mut decals = Decals::default();
+        for i in 0..MAX_DECALS_PER_WAVE + 10 {
+            decals.push(vec2(i as f32, 0.0), 10.0);
+        }
+        assert_eq!(decals.items.len(), MAX_DECALS_PER_WAVE);
+
+        decals.clear();
+        assert!(decals.items.is_empty());
+        assert_eq!(decals.epoch, 1);
+    }
+}
@@ -0,0 +1,111 @@
+//! Module pour les niveaux de difficulté.
+//! La difficulté change le nombre et la vitesse des asteroides de chaque vague,
+//! la durée d'invincibilité du vaisseau, le bouclier rendu à la fin des vagues
+//! et la surchauffe de l'arme.
+
+/// Énumération représentant les niveaux de difficulté
+/// # Variantes
+/// - `Easy`: moins d'asteroides, plus lents, et une invincibilité plus longue
+/// - `Normal`: le jeu habituel
+/// - `Hard`: plus d'asteroides, plus rapides, une invincibilité plus courte,
+///   pas de bouclier rendu à la fin des vagues et une arme qui surchauffe
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Fonction qui renvoie la difficulté suivante, pour faire défiler les difficultés sur l'écran de démarrage
+    /// # Returns
+    /// - `Difficulty`: la difficulté suivante
+    pub fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    /// Fonction qui renvoie la clé de traduction du nom de la difficulté
+    /// # Returns
+    /// - `&'static str`: la clé du texte affiché dans le menu et le tableau de bord
+    pub fn key(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "difficulty.easy",
+            Difficulty::Normal => "difficulty.normal",
+            Difficulty::Hard => "difficulty.hard",
+        }
+    }
+
+    /// Fonction qui renvoie l'identifiant de la difficulté, utilisé dans les fichiers de sauvegarde
+    /// # Returns
+    /// - `&'static str`: l'identifiant de la difficulté
+    pub fn id(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /// Fonction qui retrouve une difficulté à partir de son identifiant
+    /// # Arguments
+    /// - `id`: l'identifiant de la difficulté
+    /// # Returns
+    /// - `Option<Difficulty>`: la difficulté, ou `None` si l'identifiant est inconnu
+    pub fn from_id(id: &str) -> Option<Self> {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+            .into_iter()
+            .find(|difficulty| difficulty.id() == id)
+    }
+
+    /// Fonction qui renvoie le nombre d'asteroides ajoutés à chaque vague
+    /// # Returns
+    /// - `i32`: négatif pour retirer des asteroides
+    pub fn extra_asteroids(self) -> i32 {
+        match self {
+            Difficulty::Easy => -2,
+            Difficulty::Normal => 0,
+            Difficulty::Hard => 2,
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur de la vitesse des asteroides
+    /// # Returns
+    /// - `f32`: le multiplicateur de la vitesse
+    pub fn speed_factor(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.3,
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur des durées d'invincibilité du vaisseau
+    /// # Returns
+    /// - `f32`: le multiplicateur des durées
+    pub fn invincibility_factor(self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.5,
+        }
+    }
+
+    /// Fonction qui indique si le bouclier est rendu à la fin de chaque vague
+    /// # Returns
+    /// - `bool`: `false` en difficulté difficile
+    pub fn refreshes_shield(self) -> bool {
+        self != Difficulty::Hard
+    }
+
+    /// Fonction qui indique si l'arme du vaisseau surchauffe
+    /// # Returns
+    /// - `bool`: `true` en difficulté difficile
+    pub fn overheats(self) -> bool {
+        self == Difficulty::Hard
+    }
+}
@@ -0,0 +1,137 @@
+//! Module pour les réglages de la fenêtre, sauvegardés entre les parties.
+//! Les réglages sont un petit fichier texte avec une entrée `clé=valeur` par ligne,
+//! comme les réglages du volume. La touche F11 (ou Alt+Entrée) passe du plein écran
+//! à la fenêtre et inversement, et l'option `--fullscreen` lance le jeu en plein écran.
+use asteroid::platform;
+use macroquad::miniquad::conf::Icon;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier des réglages de la fenêtre
+const DISPLAY_FILE: &str = "display.txt";
+/// Couleur du vaisseau de l'icône de la fenêtre
+const ICON_COLOR: [u8; 4] = [120, 230, 140, 255];
+
+/// Structure représentant les réglages de la fenêtre
+/// # Champs
+/// - `fullscreen`: permet de savoir si le jeu est en plein écran
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct DisplaySettings {
+    pub fullscreen: bool,
+}
+
+impl DisplaySettings {
+    /// Charge les réglages depuis un fichier.
+    /// Un fichier absent ou des lignes illisibles gardent les réglages par défaut, en fenêtre.
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `Self`: les réglages chargés
+    pub fn load(path: &Path) -> Self {
+        let mut display = Self::default();
+        let Ok(content) = platform::read_to_string(path) else {
+            return display;
+        };
+        for line in content.lines() {
+            if let Some(("fullscreen", value)) = line.split_once('=') {
+                display.fullscreen = value == "true";
+            }
+        }
+        display
+    }
+
+    /// Sauvegarde les réglages dans un fichier
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `io::Result<()>`: une erreur si le fichier n'a pas pu être écrit
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        platform::write(path, &format!("fullscreen={}\n", self.fullscreen))
+    }
+}
+
+/// Fonction qui renvoie le chemin du fichier des réglages de la fenêtre
+/// # Returns
+/// - `PathBuf`: le chemin du fichier des réglages de la fenêtre
+pub fn display_path() -> PathBuf {
+    crate::profile::data_path(DISPLAY_FILE)
+}
+
+/// Fonction qui renvoie les réglages de la fenêtre au lancement du jeu
+/// # Arguments
+/// - `fullscreen`: permet d'imposer le plein écran, avec l'option `--fullscreen`
+/// # Returns
+/// - `DisplaySettings`: les réglages sauvegardés, en plein écran s'il est imposé
+pub fn startup_display(fullscreen: bool) -> DisplaySettings {
+    let mut display = DisplaySettings::load(&display_path());
+    display.fullscreen |= fullscreen;
+    display
+}
+
+/// Fonction qui dessine une image carrée de l'icône : un vaisseau pointé vers le haut, sur fond transparent
+/// # Returns
+/// - `[u8; N]`: les pixels RGBA de l'image, ligne par ligne
+fn icon_pixels<const N: usize>() -> [u8; N] {
+    let size = ((N / 4) as f32).sqrt() as usize;
+    let mut pixels = [0; N];
+    for y in 0..size {
+        for x in 0..size {
+            // Coordonnées du centre du pixel, de -0.5 à 0.5 en largeur et de 0 à 1 en hauteur
+            let u = (x as f32 + 0.5) / size as f32 - 0.5;
+            let v = (y as f32 + 0.5) / size as f32;
+            // Triangle dont la pointe est en haut et la base en bas, avec une encoche au milieu
+            let in_hull = (0.06..0.94).contains(&v) && u.abs() < 0.44 * (v - 0.06) / 0.88;
+            let in_notch = v > 0.75 && u.abs() < (v - 0.75) * 0.9;
+            if in_hull && !in_notch {
+                let start = (y * size + x) * 4;
+                pixels[start..start + 4].copy_from_slice(&ICON_COLOR);
+            }
+        }
+    }
+    pixels
+}
+
+/// Fonction qui crée l'icône de la fenêtre, dessinée à chacune des trois tailles demandées
+/// # Returns
+/// - `Icon`: l'icône de la fenêtre
+pub fn window_icon() -> Icon {
+    Icon {
+        small: icon_pixels(),
+        medium: icon_pixels(),
+        big: icon_pixels(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Vérifie que des réglages sauvegardés puis rechargés sont identiques,
+    /// et qu'un fichier absent garde le jeu en fenêtre.
+    #[test]
+    fn test_display_round_trip() {
+        let path = std::env::temp_dir().join("asteroid_test_display_round_trip.txt");
+        let display = DisplaySettings { fullscreen: true };
+
+        display.save(&path).unwrap();
+        let loaded = DisplaySettings::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, display, "Les réglages rechargés sont différents !");
+        assert!(!DisplaySettings::load(&path).fullscreen);
+    }
+
+    /// Vérifie que le vaisseau de l'icône est au milieu de l'image, avec des coins transparents.
+    #[test]
+    fn test_icon_shape() {
+        let icon = window_icon();
+        let pixel = |image: &[u8], size: usize, x: usize, y: usize| image[(y * size + x) * 4 + 3];
+
+        assert_eq!(pixel(&icon.big, 64, 32, 40), 255);
+        assert_eq!(pixel(&icon.small, 16, 8, 10), 255);
+        for (x, y) in [(0, 0), (63, 0), (0, 63), (63, 63)] {
+            assert_eq!(pixel(&icon.big, 64, x, y), 0);
+        }
+    }
+}
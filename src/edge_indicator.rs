@@ -0,0 +1,189 @@
+//! Module pour les indicateurs au bord de l'écran, qui annoncent les dangers sur le point d'apparaître.
+//! L'aire de jeu est un tore : un objet qui sort par un bord rentre aussitôt par le bord opposé.
+//! Un danger à moins de `WARNING_DISTANCE` du bord vers lequel il se dirige est donc annoncé
+//! par un chevron clignotant sur le bord opposé, pointé vers l'intérieur de l'écran.
+use crate::asteroid::Size;
+use crate::missile::MissileSource;
+use crate::stellarobject::StellarObject;
+use crate::world::World;
+use macroquad::prelude::*;
+
+/// Distance (en pixels) au bord à partir de laquelle un danger est annoncé
+pub const WARNING_DISTANCE: f32 = 80.0;
+/// Vitesse (en pixels par image) sous laquelle un gros asteroide n'est pas annoncé,
+/// pour ne pas encombrer les bords de l'écran
+pub const SLOW_LARGE_SPEED: f32 = 1.5;
+/// Distance (en pixels) entre la pointe du chevron et le bord de l'écran
+const CHEVRON_MARGIN: f32 = 16.0;
+/// Demi-largeur (en pixels) du chevron
+const CHEVRON_SIZE: f32 = 9.0;
+/// Nombre de clignotements du chevron par seconde
+const PULSE_RATE: f32 = 3.0;
+
+/// Énumération représentant les types de danger annoncés, chacun avec sa couleur
+/// # Variantes
+/// - `Asteroid`: un asteroide, sauf les gros asteroides lents
+/// - `Ufo`: une soucoupe, qui ne passe d'un bord à l'autre que verticalement
+/// - `EnemyMissile`: un missile tiré par une soucoupe
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Threat {
+    Asteroid,
+    Ufo,
+    EnemyMissile,
+}
+
+impl Threat {
+    /// Fonction qui renvoie la couleur des chevrons de ce type de danger
+    /// # Returns
+    /// - `Color`: la couleur du chevron
+    pub fn color(self) -> Color {
+        match self {
+            Threat::Asteroid => ORANGE,
+            Threat::Ufo => MAGENTA,
+            Threat::EnemyMissile => RED,
+        }
+    }
+}
+
+/// Structure représentant un indicateur au bord de l'écran
+/// # Champs
+/// - `position`: le point du bord par lequel le danger va entrer
+/// - `direction`: la direction dans laquelle le danger va entrer, vers l'intérieur de l'écran
+/// - `threat`: le type de danger
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EdgeIndicator {
+    pub position: Vec2,
+    pub direction: Vec2,
+    pub threat: Threat,
+}
+
+/// Fonction qui cherche par quel bord un objet va rentrer dans l'écran en passant d'un bord à l'autre
+/// # Arguments
+/// - `position`: la position de l'objet
+/// - `velocity`: la vitesse de l'objet, en pixels par image
+/// - `radius`: le rayon de l'objet, qui commence à apparaître dès qu'il touche le bord
+/// - `bounds`: la taille de l'aire de jeu
+/// # Returns
+/// - `Option<(Vec2, Vec2)>`: le point d'entrée sur le bord opposé et la direction d'entrée,
+///   ou `None` si l'objet n'est pas sur le point de passer d'un bord à l'autre
+pub fn wrap_entry(
+    position: Vec2,
+    velocity: Vec2,
+    radius: f32,
+    bounds: Vec2,
+) -> Option<(Vec2, Vec2)> {
+    let mut closest: Option<(f32, Vec2, Vec2)> = None;
+    for axis in [Vec2::X, Vec2::Y] {
+        let speed = velocity.dot(axis);
+        let along = position.dot(axis);
+        let size = bounds.dot(axis);
+        // Distance entre l'objet et le bord vers lequel il va, et bord opposé où il réapparaîtra
+        let (gap, entry) = if speed > 0.0 {
+            (size - along - radius, 0.0)
+        } else if speed < 0.0 {
+            (along - radius, size)
+        } else {
+            continue;
+        };
+        if !(0.0..WARNING_DISTANCE).contains(&gap) || closest.is_some_and(|(g, _, _)| g <= gap) {
+            continue;
+        }
+        // Sur l'autre axe, l'objet continue d'avancer jusqu'à son passage au bord
+        let arrival = position + velocity * (gap / speed.abs());
+        let across = Vec2::ONE - axis;
+        let point = axis * entry + across * arrival.rem_euclid(bounds);
+        closest = Some((gap, point, axis * speed.signum()));
+    }
+    closest.map(|(_, point, direction)| (point, direction))
+}
+
+/// Fonction qui rassemble les indicateurs de tous les dangers sur le point d'apparaître
+/// # Arguments
+/// - `world`: la partie en cours
+/// # Returns
+/// - `Vec<EdgeIndicator>`: les indicateurs à dessiner
+pub fn edge_indicators(world: &World) -> Vec<EdgeIndicator> {
+    let bounds = world.bounds;
+    let mut indicators = Vec::new();
+    let mut add = |object: &dyn StellarObject, velocity: Vec2, threat: Threat| {
+        if let Some((position, direction)) =
+            wrap_entry(object.get_pos(), velocity, object.radius(), bounds)
+        {
+            indicators.push(EdgeIndicator {
+                position,
+                direction,
+                threat,
+            });
+        }
+    };
+    for asteroid in world.asteroids.iter().filter(|a| a.active) {
+        let velocity = asteroid.velocity();
+        if asteroid.get_size() == Size::Large && velocity.length() < SLOW_LARGE_SPEED {
+            continue;
+        }
+        add(asteroid, velocity, Threat::Asteroid);
+    }
+    for ufo in world.ufos.iter().filter(|u| u.active) {
+        // La soucoupe quitte la partie par les côtés : seul son mouvement vertical la fait réapparaître
+        add(ufo, vec2(0.0, ufo.velocity().y), Threat::Ufo);
+    }
+    for missile in world
+        .missiles
+        .iter()
+        .filter(|m| m.active && m.source == MissileSource::Enemy)
+    {
+        add(missile, missile.velocity, Threat::EnemyMissile);
+    }
+    indicators
+}
+
+/// Dessine les indicateurs, avec la caméra de l'aire de jeu
+/// # Arguments
+/// - `indicators`: les indicateurs à dessiner
+/// - `bounds`: la taille de l'aire de jeu
+/// - `time`: le temps écoulé depuis le lancement du jeu, qui fait clignoter les chevrons
+pub fn draw_edge_indicators(indicators: &[EdgeIndicator], bounds: Vec2, time: f32) {
+    let pulse = 0.55 + 0.45 * (time * PULSE_RATE * std::f32::consts::TAU).sin();
+    for indicator in indicators {
+        let tip = (indicator.position + indicator.direction * CHEVRON_MARGIN)
+            .clamp(Vec2::splat(CHEVRON_MARGIN), bounds - CHEVRON_MARGIN);
+        let back = tip - indicator.direction * CHEVRON_SIZE;
+        let side = indicator.direction.perp() * CHEVRON_SIZE;
+        let mut color = indicator.threat.color();
+        color.a = pulse;
+        for wing in [back + side, back - side] {
+            draw_line(wing.x, wing.y, tip.x, tip.y, 3.0, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Vérifie qu'un objet près du bord droit, qui va vers la droite, est annoncé sur le bord gauche.
+    #[test]
+    fn test_entry_on_opposite_edge() {
+        let (point, direction) =
+            wrap_entry(vec2(760.0, 300.0), vec2(2.0, 1.0), 10.0, BOUNDS).unwrap();
+        assert_eq!(point, vec2(0.0, 315.0));
+        assert_eq!(direction, Vec2::X);
+
+        // Vers le haut, il réapparaît en bas, à l'abscisse de son passage
+        let (point, direction) =
+            wrap_entry(vec2(100.0, 30.0), vec2(-1.0, -2.0), 10.0, BOUNDS).unwrap();
+        assert_eq!(point, vec2(90.0, 600.0));
+        assert_eq!(direction, -Vec2::Y);
+    }
+
+    /// Vérifie que les objets loin des bords, qui s'en éloignent ou déjà en train de passer
+    /// ne sont pas annoncés.
+    #[test]
+    fn test_no_entry_away_from_edges() {
+        assert!(wrap_entry(vec2(400.0, 300.0), vec2(3.0, 3.0), 10.0, BOUNDS).is_none());
+        assert!(wrap_entry(vec2(760.0, 300.0), vec2(-2.0, 0.0), 10.0, BOUNDS).is_none());
+        assert!(wrap_entry(vec2(795.0, 300.0), vec2(2.0, 0.0), 10.0, BOUNDS).is_none());
+    }
+}
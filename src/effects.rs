@@ -0,0 +1,463 @@
+//! Module pour les effets visuels du jeu.
+//! Les particules sont purement décoratives : elles ne participent à aucune collision.
+//! Leur nombre, le flash et les secousses de l'écran dépendent du profil de retour choisi dans les réglages.
+//! Les secousses ne déplacent que l'affichage : les positions utilisées pour les collisions ne changent pas.
+use crate::feedback::FeedbackProfile;
+use macroquad::prelude::*;
+
+/// Nombre maximal de particules affichées en même temps
+pub const MAX_PARTICLES: usize = 300;
+/// Durée de vie (en secondes) de l'éclair de tir, qui ne dure que quelques images
+const MUZZLE_FLASH_LIFETIME: f32 = 0.05;
+/// Durée (en secondes) du flash blanc de l'écran, à peu près une image
+const SCREEN_FLASH_TIME: f32 = 1.0 / 60.0;
+/// Durée (en secondes) d'une secousse de l'écran
+const SHAKE_TIME: f32 = 0.3;
+/// Amplitude (en pixels) de la secousse quand le vaisseau perd son bouclier
+pub const SHIELD_LOST_SHAKE: f32 = 8.0;
+/// Amplitude (en pixels) de la secousse quand un grand asteroide est détruit
+pub const LARGE_KILL_SHAKE: f32 = 5.0;
+/// Durée de vie (en secondes) des particules d'une explosion
+const EXPLOSION_LIFETIME: f32 = 0.6;
+/// Nombre de particules de l'explosion d'un trou noir qui s'effondre
+pub const BLACK_HOLE_BURST: usize = 30;
+/// Durée (en secondes) de l'onde de choc d'une bombe
+const SHOCKWAVE_TIME: f32 = 0.5;
+
+/// Structure représentant une particule
+/// # Champs
+/// - `position`: la position de la particule
+/// - `velocity`: la vitesse de la particule
+/// - `color`: la couleur de la particule
+/// - `size`: le rayon de la particule
+/// - `age`: le temps écoulé depuis l'apparition de la particule
+/// - `lifetime`: la durée de vie de la particule
+#[derive(Clone, Debug)]
+pub struct Particle {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub color: Color,
+    pub size: f32,
+    age: f32,
+    lifetime: f32,
+}
+
+impl Particle {
+    /// Crée une nouvelle particule
+    /// # Arguments
+    /// - `position`: la position de la particule
+    /// - `velocity`: la vitesse de la particule
+    /// - `color`: la couleur de la particule
+    /// - `size`: le rayon de la particule
+    /// - `lifetime`: la durée de vie de la particule
+    /// # Returns
+    /// - `Self`: la particule
+    pub fn new(position: Vec2, velocity: Vec2, color: Color, size: f32, lifetime: f32) -> Self {
+        Self {
+            position,
+            velocity,
+            color,
+            size,
+            age: 0.0,
+            lifetime,
+        }
+    }
+
+    /// Fonction qui renvoie l'opacité de la particule, qui s'efface avec l'âge
+    /// # Returns
+    /// - `f32`: l'opacité entre 0 et 1
+    fn alpha(&self) -> f32 {
+        (1.0 - self.age / self.lifetime).clamp(0.0, 1.0)
+    }
+}
+
+/// Structure représentant une secousse de l'écran, qui s'atténue en `SHAKE_TIME` secondes
+/// # Champs
+/// - `amplitude`: le décalage maximal de l'affichage au début de la secousse
+/// - `timer`: le temps restant de la secousse
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ScreenShake {
+    amplitude: f32,
+    timer: f32,
+}
+
+impl ScreenShake {
+    /// Lance une secousse. Une secousse plus faible ne diminue pas celle en cours.
+    /// # Arguments
+    /// - `amplitude`: le décalage maximal de l'affichage
+    pub fn kick(&mut self, amplitude: f32) {
+        if amplitude >= self.strength() {
+            self.amplitude = amplitude;
+            self.timer = SHAKE_TIME;
+        }
+    }
+
+    /// Fait s'atténuer la secousse
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.timer = (self.timer - dt).max(0.0);
+    }
+
+    /// Fonction qui renvoie le décalage maximal actuel de l'affichage
+    /// # Returns
+    /// - `f32`: l'amplitude, qui diminue jusqu'à 0 à la fin de la secousse
+    pub fn strength(&self) -> f32 {
+        self.amplitude * self.timer / SHAKE_TIME
+    }
+
+    /// Fonction qui tire au hasard le décalage de l'affichage pour cette image
+    /// # Returns
+    /// - `Vec2`: le décalage, nul quand l'écran ne tremble pas
+    pub fn offset(&self) -> Vec2 {
+        let strength = self.strength();
+        if strength <= 0.0 {
+            return Vec2::ZERO;
+        }
+        vec2(
+            rand::gen_range(-strength, strength),
+            rand::gen_range(-strength, strength),
+        )
+    }
+}
+
+/// Structure représentant l'onde de choc d'une bombe, un anneau qui grandit puis s'efface
+/// # Champs
+/// - `center`: le centre de l'onde
+/// - `max_radius`: le rayon de l'anneau à la fin de l'onde
+/// - `age`: le temps écoulé depuis l'explosion
+#[derive(Clone, Copy, Debug)]
+pub struct Shockwave {
+    center: Vec2,
+    max_radius: f32,
+    age: f32,
+}
+
+impl Shockwave {
+    /// Fonction qui renvoie l'avancement de l'onde
+    /// # Returns
+    /// - `f32`: 0 au moment de l'explosion, 1 à la fin de l'onde
+    fn progress(&self) -> f32 {
+        (self.age / SHOCKWAVE_TIME).clamp(0.0, 1.0)
+    }
+}
+
+/// Structure qui gère toutes les particules du jeu
+/// # Champs
+/// - `particles`: les particules en vie
+/// - `feedback`: le profil de retour, qui règle le nombre de particules, le flash et les secousses de l'écran
+/// - `particle_budget`: la fraction de particule accumulée quand le profil n'en ajoute pas un nombre entier
+/// - `flash_timer`: le temps restant du flash blanc de l'écran
+/// - `screen_shake`: la secousse de l'écran en cours
+/// - `shockwaves`: les ondes de choc des bombes et des asteroides explosifs en cours
+pub struct Effects {
+    particles: Vec<Particle>,
+    pub feedback: FeedbackProfile,
+    particle_budget: f32,
+    flash_timer: f32,
+    screen_shake: ScreenShake,
+    shockwaves: Vec<Shockwave>,
+}
+
+impl Effects {
+    /// Crée un système d'effets vide
+    /// # Returns
+    /// - `Self`: le système d'effets
+    pub fn new() -> Self {
+        Self {
+            particles: Vec::with_capacity(MAX_PARTICLES),
+            feedback: FeedbackProfile::Normal,
+            particle_budget: 0.0,
+            flash_timer: 0.0,
+            screen_shake: ScreenShake::default(),
+            shockwaves: Vec::new(),
+        }
+    }
+
+    /// Ajoute une particule, autant de fois que le demande le profil de retour,
+    /// sans dépasser la limite globale
+    /// # Arguments
+    /// - `particle`: la particule à ajouter
+    pub fn spawn(&mut self, particle: Particle) {
+        self.particle_budget += self.feedback.particles();
+        while self.particle_budget >= 1.0 {
+            self.particle_budget -= 1.0;
+            if self.particles.len() < MAX_PARTICLES {
+                self.particles.push(particle.clone());
+            }
+        }
+    }
+
+    /// Fait flasher l'écran en blanc pendant une image, si le profil de retour le permet
+    pub fn flash(&mut self) {
+        if self.feedback.large_kill_flash() {
+            self.flash_timer = SCREEN_FLASH_TIME;
+        }
+    }
+
+    /// Secoue l'écran, avec une amplitude réglée par le profil de retour
+    /// # Arguments
+    /// - `amplitude`: l'amplitude de la secousse dans le profil normal
+    pub fn shake(&mut self, amplitude: f32) {
+        let amplitude = amplitude * self.feedback.shake();
+        if amplitude > 0.0 {
+            self.screen_shake.kick(amplitude);
+        }
+    }
+
+    /// Fonction qui renvoie le décalage de l'affichage dû à la secousse de l'écran
+    /// # Returns
+    /// - `Vec2`: le décalage à appliquer à la caméra pour cette image
+    pub fn shake_offset(&self) -> Vec2 {
+        self.screen_shake.offset()
+    }
+
+    /// Ajoute un éclair de tir, orienté dans la direction du tir
+    /// # Arguments
+    /// - `position`: le point de départ du missile, au nez du vaisseau
+    /// - `rotation`: la direction du tir (en radians)
+    pub fn muzzle_flash(&mut self, position: Vec2, rotation: f32) {
+        let direction = Vec2::from_angle(rotation);
+        for (i, color) in [WHITE, YELLOW, ORANGE].into_iter().enumerate() {
+            let step = i as f32;
+            self.spawn(Particle::new(
+                position + direction * step * 3.0,
+                direction * (0.5 + step * 0.5),
+                color,
+                4.0 - step,
+                MUZZLE_FLASH_LIFETIME,
+            ));
+        }
+    }
+
+    /// Ajoute une explosion : des particules projetées dans toutes les directions
+    /// # Arguments
+    /// - `position`: le centre de l'explosion
+    /// - `count`: le nombre de particules dans le profil normal
+    /// - `speed`: la vitesse maximale des particules
+    /// - `size`: le rayon maximal des particules
+    /// - `color`: la couleur des particules
+    pub fn explosion(&mut self, position: Vec2, count: usize, speed: f32, size: f32, color: Color) {
+        for _ in 0..count {
+            let direction = Vec2::from_angle(rand::gen_range(0.0, std::f32::consts::TAU));
+            self.spawn(Particle::new(
+                position,
+                direction * rand::gen_range(0.3, 1.0) * speed,
+                color,
+                rand::gen_range(0.5, 1.0) * size,
+                rand::gen_range(0.5, 1.0) * EXPLOSION_LIFETIME,
+            ));
+        }
+    }
+
+    /// Ajoute l'onde de choc d'une bombe ou d'un asteroide explosif
+    /// # Arguments
+    /// - `center`: le centre de l'onde
+    /// - `max_radius`: le rayon de l'anneau à la fin de l'onde
+    pub fn shockwave(&mut self, center: Vec2, max_radius: f32) {
+        self.shockwaves.push(Shockwave {
+            center,
+            max_radius,
+            age: 0.0,
+        });
+    }
+
+    /// Fait vieillir et avancer les particules, et supprime celles qui ont disparu
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.flash_timer = (self.flash_timer - dt).max(0.0);
+        self.screen_shake.update(dt);
+        for particle in self.particles.iter_mut() {
+            particle.age += dt;
+            particle.position += particle.velocity;
+        }
+        self.particles.retain(|p| p.age < p.lifetime);
+        for shockwave in self.shockwaves.iter_mut() {
+            shockwave.age += dt;
+        }
+        self.shockwaves.retain(|s| s.age < SHOCKWAVE_TIME);
+    }
+
+    /// Fonction qui renvoie les particules en vie, pour les tests des autres modules
+    /// # Returns
+    /// - `&[Particle]`: les particules
+    #[cfg(test)]
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Fonction qui renvoie le nombre de particules en vie, pour les simulations
+    /// # Returns
+    /// - `usize`: le nombre de particules
+    pub fn particle_count(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Dessine toutes les particules et les ondes de choc
+    pub fn draw(&self) {
+        for shockwave in &self.shockwaves {
+            let progress = shockwave.progress();
+            draw_circle_lines(
+                shockwave.center.x,
+                shockwave.center.y,
+                shockwave.max_radius * progress,
+                8.0 * (1.0 - progress) + 1.0,
+                Color::new(0.8, 0.9, 1.0, 1.0 - progress),
+            );
+        }
+        for particle in &self.particles {
+            let mut color = particle.color;
+            color.a *= particle.alpha();
+            draw_circle(
+                particle.position.x,
+                particle.position.y,
+                particle.size,
+                color,
+            );
+        }
+    }
+
+    /// Dessine le flash blanc par-dessus tout l'écran, s'il est en cours
+    pub fn draw_flash(&self) {
+        if self.flash_timer > 0.0 {
+            draw_rectangle(
+                0.0,
+                0.0,
+                screen_width(),
+                screen_height(),
+                Color::new(1.0, 1.0, 1.0, 0.6),
+            );
+        }
+    }
+}
+
+impl Default for Effects {
+    /// Crée un système d'effets vide, avec les effets activés
+    /// # Returns
+    /// - `Self`: le système d'effets
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn particle() -> Particle {
+        Particle::new(Vec2::ZERO, Vec2::ZERO, WHITE, 1.0, 0.5)
+    }
+
+    /// Vérifie qu'une explosion ajoute le nombre de particules demandé,
+    /// et que plusieurs explosions en même temps ne dépassent pas la limite globale.
+    #[test]
+    fn test_explosion_particles() {
+        let mut effects = Effects::new();
+        effects.explosion(Vec2::ZERO, 30, 4.0, 3.0, WHITE);
+        assert_eq!(effects.particles.len(), 30);
+        assert!(effects.particles.iter().all(|p| p.velocity.length() <= 4.0));
+
+        for _ in 0..20 {
+            effects.explosion(Vec2::ZERO, 30, 4.0, 3.0, WHITE);
+        }
+        assert_eq!(effects.particles.len(), MAX_PARTICLES);
+    }
+
+    /// Vérifie que la limite globale de particules est respectée.
+    #[test]
+    fn test_particle_cap() {
+        let mut effects = Effects::new();
+        for _ in 0..MAX_PARTICLES + 10 {
+            effects.spawn(particle());
+        }
+
+        assert_eq!(effects.particles.len(), MAX_PARTICLES);
+    }
+
+    /// Vérifie que le profil réduit n'ajoute aucune particule.
+    #[test]
+    fn test_reduced_profile_spawns_nothing() {
+        let mut effects = Effects::new();
+        effects.feedback = FeedbackProfile::Reduced;
+
+        effects.spawn(particle());
+
+        assert_eq!(effects.particles.len(), 0);
+    }
+
+    /// Vérifie que le nombre de particules suit le multiplicateur du profil.
+    #[test]
+    fn test_particle_count_follows_profile() {
+        for profile in [FeedbackProfile::Normal, FeedbackProfile::Spectacle] {
+            let mut effects = Effects::new();
+            effects.feedback = profile;
+            for _ in 0..10 {
+                effects.spawn(particle());
+            }
+
+            let expected = (10.0 * profile.particles()) as usize;
+            assert_eq!(effects.particles.len(), expected, "Profil {:?}", profile);
+        }
+    }
+
+    /// Vérifie que seul le profil spectacle fait flasher l'écran, pendant une seule image.
+    #[test]
+    fn test_flash_follows_profile() {
+        let mut effects = Effects::new();
+        effects.flash();
+        assert_eq!(effects.flash_timer, 0.0);
+
+        effects.feedback = FeedbackProfile::Spectacle;
+        effects.flash();
+        assert!(effects.flash_timer > 0.0);
+        effects.update(1.0 / 60.0);
+        assert_eq!(effects.flash_timer, 0.0);
+    }
+
+    /// Vérifie que la secousse s'atténue jusqu'à disparaître, et que le profil réduit la supprime.
+    #[test]
+    fn test_screen_shake_decays_and_follows_profile() {
+        let mut effects = Effects::new();
+        effects.shake(SHIELD_LOST_SHAKE);
+        let start = effects.screen_shake.strength();
+        assert_eq!(start, SHIELD_LOST_SHAKE);
+        assert!(effects.shake_offset().abs().max_element() <= start);
+
+        effects.update(SHAKE_TIME / 2.0);
+        assert!(effects.screen_shake.strength() < start);
+        effects.update(SHAKE_TIME);
+        assert_eq!(effects.shake_offset(), Vec2::ZERO);
+
+        effects.feedback = FeedbackProfile::Reduced;
+        effects.shake(SHIELD_LOST_SHAKE);
+        assert_eq!(effects.shake_offset(), Vec2::ZERO);
+    }
+
+    /// Vérifie que les particules disparaissent à la fin de leur durée de vie.
+    #[test]
+    fn test_particles_expire() {
+        let mut effects = Effects::new();
+        effects.spawn(particle());
+
+        effects.update(0.3);
+        assert_eq!(effects.particles.len(), 1);
+        effects.update(0.3);
+        assert_eq!(effects.particles.len(), 0);
+    }
+
+    /// Vérifie que l'éclair de tir part devant le vaisseau et disparait presque aussitôt.
+    #[test]
+    fn test_muzzle_flash_is_oriented_and_short_lived() {
+        let mut effects = Effects::new();
+        effects.muzzle_flash(vec2(100.0, 100.0), 0.0);
+
+        assert!(!effects.particles.is_empty());
+        for particle in &effects.particles {
+            assert!(particle.position.x >= 100.0 && particle.velocity.x > 0.0);
+            assert_eq!(particle.position.y, 100.0);
+        }
+        effects.update(0.1);
+        assert!(effects.particles.is_empty());
+    }
+}
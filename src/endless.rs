@@ -0,0 +1,90 @@
+//! Module pour l'apparition continue des asteroides du mode sans fin.
+//! Un asteroide arrive par un bord de l'écran toutes les `START_INTERVAL` secondes au début,
+//! puis de plus en plus souvent, jusqu'à `MIN_INTERVAL` secondes après `RAMP_TIME` secondes.
+//! Il n'en apparaît plus tant qu'il y a `MAX_ASTEROIDS` asteroides en jeu.
+
+/// Temps (en secondes) entre deux apparitions au début de la partie
+pub const START_INTERVAL: f32 = 2.0;
+/// Temps minimal (en secondes) entre deux apparitions, une fois la cadence au maximum
+pub const MIN_INTERVAL: f32 = 0.6;
+/// Durée (en secondes) de la montée de la cadence, de `START_INTERVAL` à `MIN_INTERVAL`
+pub const RAMP_TIME: f32 = 240.0;
+/// Nombre d'asteroides au-delà duquel il n'en apparaît plus
+pub const MAX_ASTEROIDS: usize = 20;
+/// Nombre d'asteroides qui arrivent ensemble au début de la partie
+pub const START_ASTEROIDS: u32 = 2;
+
+/// Structure qui décide quand un asteroide apparaît en mode sans fin
+/// # Champs
+/// - `timer`: le temps restant avant la prochaine apparition
+/// - `elapsed`: le temps écoulé depuis le début de la partie, qui accélère la cadence
+#[derive(Clone, Copy, Debug)]
+pub struct EndlessSpawner {
+    timer: f32,
+    elapsed: f32,
+}
+
+impl Default for EndlessSpawner {
+    /// Crée le planificateur d'une nouvelle partie, à la cadence de départ
+    fn default() -> Self {
+        Self {
+            timer: START_INTERVAL,
+            elapsed: 0.0,
+        }
+    }
+}
+
+impl EndlessSpawner {
+    /// Fonction qui renvoie le temps entre deux apparitions à ce moment de la partie
+    /// # Returns
+    /// - `f32`: le temps en secondes, de `START_INTERVAL` à `MIN_INTERVAL`
+    pub fn interval(&self) -> f32 {
+        let progress = (self.elapsed / RAMP_TIME).min(1.0);
+        START_INTERVAL + (MIN_INTERVAL - START_INTERVAL) * progress
+    }
+
+    /// Fait avancer le planificateur d'une image
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `asteroids`: le nombre d'asteroides en jeu
+    /// # Returns
+    /// - `bool`: `true` si un asteroide doit apparaître pendant cette image
+    pub fn update(&mut self, dt: f32, asteroids: usize) -> bool {
+        self.elapsed += dt;
+        self.timer -= dt;
+        if self.timer > 0.0 {
+            return false;
+        }
+        self.timer = self.interval();
+        asteroids < MAX_ASTEROIDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que la cadence accélère avec le temps, sans passer sous `MIN_INTERVAL`.
+    #[test]
+    fn test_rate_ramps_up() {
+        let mut spawner = EndlessSpawner::default();
+        assert_eq!(spawner.interval(), START_INTERVAL);
+        let mut spawns = 0;
+        for _ in 0..(60.0 * RAMP_TIME / 2.0) as u32 {
+            spawns += u32::from(spawner.update(1.0 / 60.0, 0));
+        }
+        assert!(spawner.interval() < START_INTERVAL && spawner.interval() > MIN_INTERVAL);
+        assert!(spawns as f32 > RAMP_TIME / 2.0 / START_INTERVAL);
+
+        spawner.update(RAMP_TIME, 0);
+        assert_eq!(spawner.interval(), MIN_INTERVAL);
+    }
+
+    /// Vérifie qu'aucun asteroide n'apparaît tant qu'il y en a `MAX_ASTEROIDS` en jeu.
+    #[test]
+    fn test_cap_on_asteroids() {
+        let mut spawner = EndlessSpawner::default();
+        assert!(!spawner.update(START_INTERVAL, MAX_ASTEROIDS));
+        assert!(spawner.update(START_INTERVAL, MAX_ASTEROIDS - 1));
+    }
+}
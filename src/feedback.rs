@@ -0,0 +1,134 @@
+//! Module pour les profils de retour visuel et sonore.
+//! Tous les systèmes qui produisent du retour au joueur (particules, secousses, textes de score,
+//! sons) lisent leurs multiplicateurs ici, plutôt que de tester le profil chacun de leur côté.
+
+/// Énumération représentant les profils de retour
+/// # Variantes
+/// - `Normal`: le retour habituel du jeu
+/// - `Reduced`: les mouvements décoratifs sont supprimés, pour les joueurs sensibles au mouvement
+/// - `Spectacle`: le retour est amplifié, pour les captures et les diffusions en direct
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum FeedbackProfile {
+    #[default]
+    Normal,
+    Reduced,
+    Spectacle,
+}
+
+impl FeedbackProfile {
+    /// Fonction qui renvoie le profil suivant, pour faire défiler les profils dans les réglages
+    /// # Returns
+    /// - `FeedbackProfile`: le profil suivant
+    pub fn next(self) -> Self {
+        match self {
+            FeedbackProfile::Normal => FeedbackProfile::Reduced,
+            FeedbackProfile::Reduced => FeedbackProfile::Spectacle,
+            FeedbackProfile::Spectacle => FeedbackProfile::Normal,
+        }
+    }
+
+    /// Fonction qui renvoie la clé de traduction du nom du profil
+    /// # Returns
+    /// - `&'static str`: la clé du texte affiché dans les réglages
+    pub fn key(self) -> &'static str {
+        match self {
+            FeedbackProfile::Normal => "settings.feedback_normal",
+            FeedbackProfile::Reduced => "settings.feedback_reduced",
+            FeedbackProfile::Spectacle => "settings.feedback_spectacle",
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur du nombre de particules
+    /// # Returns
+    /// - `f32`: `0` pour n'afficher aucune particule
+    pub fn particles(self) -> f32 {
+        match self {
+            FeedbackProfile::Normal => 1.0,
+            FeedbackProfile::Reduced => 0.0,
+            FeedbackProfile::Spectacle => 2.0,
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur de l'intensité des secousses de l'écran
+    /// # Returns
+    /// - `f32`: `0` pour ne jamais secouer l'écran
+    pub fn shake(self) -> f32 {
+        match self {
+            FeedbackProfile::Normal => 1.0,
+            FeedbackProfile::Reduced => 0.0,
+            FeedbackProfile::Spectacle => 1.75,
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur de la taille des textes de score
+    /// # Returns
+    /// - `f32`: le multiplicateur de la taille de police
+    pub fn popup_scale(self) -> f32 {
+        match self {
+            FeedbackProfile::Normal | FeedbackProfile::Reduced => 1.0,
+            FeedbackProfile::Spectacle => 1.6,
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur du volume des effets sonores
+    /// # Returns
+    /// - `f32`: le multiplicateur du volume
+    pub fn volume(self) -> f32 {
+        match self {
+            FeedbackProfile::Normal | FeedbackProfile::Reduced => 1.0,
+            FeedbackProfile::Spectacle => 1.3,
+        }
+    }
+
+    /// Fonction qui indique si la destruction d'un grand asteroide fait flasher l'écran
+    /// # Returns
+    /// - `bool`: `true` pour ajouter un flash blanc
+    pub fn large_kill_flash(self) -> bool {
+        self == FeedbackProfile::Spectacle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES: [FeedbackProfile; 3] = [
+        FeedbackProfile::Normal,
+        FeedbackProfile::Reduced,
+        FeedbackProfile::Spectacle,
+    ];
+
+    /// Vérifie que le profil réduit supprime tous les mouvements décoratifs.
+    #[test]
+    fn test_reduced_zeroes_motion() {
+        let reduced = FeedbackProfile::Reduced;
+
+        assert_eq!(reduced.particles(), 0.0);
+        assert_eq!(reduced.shake(), 0.0);
+        assert!(!reduced.large_kill_flash());
+    }
+
+    /// Vérifie que le profil spectacle amplifie chaque retour sans rien diminuer.
+    #[test]
+    fn test_spectacle_amplifies_feedback() {
+        let normal = FeedbackProfile::Normal;
+        let spectacle = FeedbackProfile::Spectacle;
+
+        assert!(spectacle.particles() > normal.particles());
+        assert!(spectacle.shake() > normal.shake());
+        assert!(spectacle.popup_scale() > normal.popup_scale());
+        assert!(spectacle.volume() > normal.volume());
+        assert!(spectacle.large_kill_flash() && !normal.large_kill_flash());
+    }
+
+    /// Vérifie que le défilement passe par tous les profils.
+    #[test]
+    fn test_next_cycles_through_profiles() {
+        for profile in PROFILES {
+            let mut next = profile.next();
+            assert_ne!(next, profile);
+            next = next.next().next();
+            assert_eq!(next, profile);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Module pour mesurer le nombre d'images par seconde, affiché par l'affichage de développement.
+//! La mesure est lissée sur les dernières images, pour que le nombre reste lisible.
+
+/// Nombre d'images sur lesquelles la mesure est lissée
+pub const FPS_SAMPLES: usize = 30;
+
+/// Structure gardant la durée des dernières images
+/// # Champs
+/// - `samples`: la durée des dernières images, utilisée comme un tampon circulaire
+/// - `next`: l'indice de la prochaine durée à remplacer
+/// - `count`: le nombre de durées déjà mesurées, jusqu'à `FPS_SAMPLES`
+#[derive(Clone, Copy, Debug)]
+pub struct FpsCounter {
+    samples: [f32; FPS_SAMPLES],
+    next: usize,
+    count: usize,
+}
+
+impl Default for FpsCounter {
+    fn default() -> Self {
+        Self {
+            samples: [0.0; FPS_SAMPLES],
+            next: 0,
+            count: 0,
+        }
+    }
+}
+
+impl FpsCounter {
+    /// Ajoute la durée d'une image, en remplaçant la plus ancienne
+    /// # Arguments
+    /// - `dt`: la durée de l'image
+    pub fn record(&mut self, dt: f32) {
+        self.samples[self.next] = dt;
+        self.next = (self.next + 1) % FPS_SAMPLES;
+        self.count = (self.count + 1).min(FPS_SAMPLES);
+    }
+
+    /// Fonction qui renvoie le nombre d'images par seconde, lissé sur les dernières images
+    /// # Returns
+    /// - `f32`: le nombre d'images par seconde, ou 0 avant la première mesure
+    pub fn fps(&self) -> f32 {
+        let total: f32 = self.samples[..self.count].iter().sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        self.count as f32 / total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que la mesure est lissée et n'est faite que sur les dernières images.
+    #[test]
+    fn test_fps_is_smoothed_over_recent_frames() {
+        let mut fps = FpsCounter::default();
+        assert_eq!(fps.fps(), 0.0);
+
+        for _ in 0..FPS_SAMPLES {
+            fps.record(1.0 / 60.0);
+        }
+        assert!((fps.fps() - 60.0).abs() < 1e-2);
+
+        // Une seule image lente ne fait pas s'effondrer la mesure
+        fps.record(1.0 / 10.0);
+        assert!(fps.fps() > 40.0);
+
+        for _ in 0..FPS_SAMPLES {
+            fps.record(1.0 / 30.0);
+        }
+        assert!((fps.fps() - 30.0).abs() < 1e-2);
+    }
+}
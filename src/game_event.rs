@@ -0,0 +1,103 @@
+//! Module pour les événements de la partie.
+//! La détection des collisions ne touche ni au score, ni aux sons, ni aux textes :
+//! elle produit des `GameEvent`, que la partie traduit ensuite en points, en sons et en textes.
+//! Les événements qui rapportent des points ou qui touchent un vaisseau donnent le numéro
+//! du joueur concerné, 0 pour le premier joueur et 1 pour le second en coopération.
+use crate::asteroid::{AsteroidKind, Size};
+use crate::game_over::GameOverReason;
+use crate::powerup::PowerUpKind;
+use macroquad::prelude::*;
+
+/// Énumération représentant les événements produits par les collisions
+/// # Variantes
+/// - `AsteroidDestroyed`: un missile du joueur a détruit un asteroide de la taille et de la sorte données
+/// - `AsteroidDamaged`: un missile du joueur a abîmé le blindage d'un asteroide, qui tient encore
+/// - `AsteroidExploded`: un asteroide explosif de la taille donnée a explosé, son souffle
+///   touche tout ce qui se trouve à moins de `Size::blast_radius`
+/// - `AsteroidAbsorbed`: un asteroide a été aspiré par le trou noir à la position `hole`
+/// - `AsteroidCrushed`: une comète a pulvérisé un asteroide de la taille donnée, sans rapporter de points
+/// - `ShieldLost`: le vaisseau du joueur a perdu son bouclier, à la position donnée
+/// - `ShipKnockedBack`: le choc sur le bouclier repousse le vaisseau du joueur de la vitesse `impulse`
+/// - `BlackHoleFormed`: un asteroide percuté par le vaisseau devient un trou noir
+/// - `BlackHoleDamaged`: un missile du joueur a touché un trou noir, qui résiste encore
+/// - `BlackHoleHit`: un missile du joueur a achevé un trou noir
+/// - `UfoDestroyed`: un missile du joueur a détruit une soucoupe
+/// - `TurretDestroyed`: un missile du joueur a détruit une tourelle du vaisseau mère
+/// - `BossDestroyed`: un missile du joueur a détruit le noyau du vaisseau mère
+/// - `BossAsteroidDestroyed`: un missile du joueur a achevé l'asteroide géant
+/// - `GoldAsteroidDestroyed`: un missile du joueur a détruit l'asteroide doré
+/// - `PowerUpCollected`: le vaisseau du joueur a ramassé un bonus du type donné
+/// - `ShipDestroyed`: le vaisseau du joueur a été détruit, pour la raison donnée
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GameEvent {
+    AsteroidDestroyed {
+        pos: Vec2,
+        size: Size,
+        kind: AsteroidKind,
+        player: usize,
+    },
+    AsteroidDamaged {
+        pos: Vec2,
+    },
+    AsteroidExploded {
+        pos: Vec2,
+        size: Size,
+    },
+    AsteroidAbsorbed {
+        pos: Vec2,
+        hole: Vec2,
+    },
+    AsteroidCrushed {
+        pos: Vec2,
+        size: Size,
+    },
+    ShieldLost {
+        pos: Vec2,
+        player: usize,
+    },
+    ShipKnockedBack {
+        impulse: Vec2,
+        player: usize,
+    },
+    BlackHoleFormed {
+        pos: Vec2,
+        size: Size,
+    },
+    BlackHoleDamaged {
+        pos: Vec2,
+        player: usize,
+    },
+    BlackHoleHit {
+        pos: Vec2,
+        player: usize,
+    },
+    UfoDestroyed {
+        pos: Vec2,
+        player: usize,
+    },
+    TurretDestroyed {
+        pos: Vec2,
+        player: usize,
+    },
+    BossDestroyed {
+        pos: Vec2,
+        player: usize,
+    },
+    BossAsteroidDestroyed {
+        pos: Vec2,
+        player: usize,
+    },
+    GoldAsteroidDestroyed {
+        pos: Vec2,
+        player: usize,
+    },
+    PowerUpCollected {
+        pos: Vec2,
+        kind: PowerUpKind,
+        player: usize,
+    },
+    ShipDestroyed {
+        reason: GameOverReason,
+        player: usize,
+    },
+}
@@ -0,0 +1,70 @@
+//! Module pour les modes de jeu, choisis sur l'écran de démarrage.
+
+/// Énumération représentant les modes de jeu
+/// # Variantes
+/// - `Classic`: les asteroides arrivent par vagues, avec un bonus à la fin de chaque vague
+/// - `Endless`: le mode sans fin, sans vagues : les asteroides arrivent sans arrêt par les bords
+///   de l'écran, de plus en plus souvent, et le score est soumis à la jauge d'activité
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum GameMode {
+    #[default]
+    Classic,
+    Endless,
+}
+
+impl GameMode {
+    /// Fonction qui renvoie le mode suivant, pour faire défiler les modes sur l'écran de démarrage
+    /// # Returns
+    /// - `GameMode`: le mode suivant
+    pub fn next(self) -> Self {
+        match self {
+            GameMode::Classic => GameMode::Endless,
+            GameMode::Endless => GameMode::Classic,
+        }
+    }
+
+    /// Fonction qui renvoie la clé de traduction du nom du mode
+    /// # Returns
+    /// - `&'static str`: la clé du texte affiché dans le menu et le tableau des scores
+    pub fn key(self) -> &'static str {
+        match self {
+            GameMode::Classic => "mode.classic",
+            GameMode::Endless => "mode.endless",
+        }
+    }
+
+    /// Fonction qui renvoie l'identifiant du mode, utilisé dans les fichiers de sauvegarde
+    /// # Returns
+    /// - `&'static str`: l'identifiant du mode
+    pub fn id(self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::Endless => "endless",
+        }
+    }
+
+    /// Fonction qui retrouve un mode à partir de son identifiant
+    /// # Arguments
+    /// - `id`: l'identifiant du mode
+    /// # Returns
+    /// - `Option<GameMode>`: le mode, ou `None` si l'identifiant est inconnu
+    pub fn from_id(id: &str) -> Option<Self> {
+        [GameMode::Classic, GameMode::Endless]
+            .into_iter()
+            .find(|mode| mode.id() == id)
+    }
+
+    /// Fonction qui indique si le mode a des vagues
+    /// # Returns
+    /// - `bool`: `false` pour le mode sans fin, qui compte le temps de survie à la place
+    pub fn has_waves(self) -> bool {
+        self == GameMode::Classic
+    }
+
+    /// Fonction qui indique si le mode utilise la jauge d'activité
+    /// # Returns
+    /// - `bool`: `true` si les points gagnés dépendent de l'activité du joueur
+    pub fn has_activity_meter(self) -> bool {
+        self == GameMode::Endless
+    }
+}
@@ -0,0 +1,49 @@
+//! Module pour décrire la raison de la fin d'une partie
+use crate::asteroid::Size;
+use macroquad::prelude::*;
+
+/// Énumération représentant ce qui a détruit le vaisseau
+/// # Variantes
+/// - `AsteroidImpact`: le vaisseau sans bouclier a percuté un asteroide de la taille donnée
+/// - `BlackHole`: le vaisseau a été aspiré par un trou noir
+/// - `EnemyFire`: le vaisseau a été touché par un tir ennemi
+/// - `Mothership`: le vaisseau sans bouclier a percuté le vaisseau mère
+/// - `Comet`: le vaisseau a été percuté par une comète
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GameOverReason {
+    AsteroidImpact { size: Size },
+    BlackHole,
+    EnemyFire,
+    Mothership,
+    Comet,
+}
+
+impl GameOverReason {
+    /// Fonction qui renvoie la clé identifiant la raison, utilisée pour la traduction et la sauvegarde
+    /// # Returns
+    /// - `&str`: la clé de la raison
+    pub fn key(self) -> &'static str {
+        match self {
+            GameOverReason::AsteroidImpact { size: Size::Large } => "reason.asteroid_large",
+            GameOverReason::AsteroidImpact { size: Size::Medium } => "reason.asteroid_medium",
+            GameOverReason::AsteroidImpact { size: Size::Small } => "reason.asteroid_small",
+            GameOverReason::BlackHole => "reason.black_hole",
+            GameOverReason::EnemyFire => "reason.enemy_fire",
+            GameOverReason::Mothership => "reason.mothership",
+            GameOverReason::Comet => "reason.comet",
+        }
+    }
+
+    /// Fonction qui renvoie la couleur de l'icône représentant la raison
+    /// # Returns
+    /// - `Color`: la couleur de l'icône
+    pub fn icon_color(self) -> Color {
+        match self {
+            GameOverReason::AsteroidImpact { .. } => GRAY,
+            GameOverReason::BlackHole => PURPLE,
+            GameOverReason::EnemyFire => RED,
+            GameOverReason::Mothership => MAGENTA,
+            GameOverReason::Comet => SKYBLUE,
+        }
+    }
+}
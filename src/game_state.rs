@@ -0,0 +1,142 @@
+//! Module pour les états du jeu et les sons qui accompagnent leurs changements.
+//! Le son de début de partie n'est joué qu'une fois par partie : il est retenu jusqu'à ce
+//! qu'une nouvelle partie commence. Le son de fin de partie accompagne l'explosion du vaisseau,
+//! il est donc produit par la partie et non par le changement d'état.
+use crate::bindings::Action;
+use asteroid::audio::SoundEvent;
+use asteroid::game_mode::GameMode;
+use asteroid::game_over::GameOverReason;
+use asteroid::run_stats::RunStats;
+
+/// Énumération représentant les différents états du jeu.
+/// L'état `GameOver` garde le résumé de la partie terminée,
+/// l'état `Victory` celui de la partie gagnée contre le vaisseau mère,
+/// l'état `Controls` la commande qui attend sa nouvelle touche,
+/// l'état `Settings` l'écran des réglages du volume,
+/// et l'état `Achievements` la liste des succès.
+#[derive(Clone, Copy)]
+pub enum GameState {
+    StartScreen,
+    Controls { waiting: Option<Action> },
+    Settings,
+    Achievements,
+    Playing,
+    GameOver(RunSummary),
+    Victory(VictorySummary),
+}
+
+impl GameState {
+    /// Fonction qui renvoie le nom de l'état, pour le journal
+    /// # Returns
+    /// - `&'static str`: le nom de l'état
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameState::StartScreen => "StartScreen",
+            GameState::Controls { .. } => "Controls",
+            GameState::Settings => "Settings",
+            GameState::Achievements => "Achievements",
+            GameState::Playing => "Playing",
+            GameState::GameOver(_) => "GameOver",
+            GameState::Victory(_) => "Victory",
+        }
+    }
+}
+
+/// Structure résumant une partie terminée
+/// # Champs
+/// - `reason`: la raison de la destruction du vaisseau
+/// - `wave`: la vague atteinte
+/// - `mode`: le mode de jeu de la partie, dont le tableau des scores est affiché
+/// - `new_record`: permet de savoir si la partie a battu le meilleur score du mode
+/// - `seed`: la graine de la partie, pour la rejouer avec `--seed`
+/// - `stats`: les statistiques de la partie
+#[derive(Clone, Copy)]
+pub struct RunSummary {
+    pub reason: GameOverReason,
+    pub wave: u32,
+    pub mode: GameMode,
+    pub new_record: bool,
+    pub seed: u64,
+    pub stats: RunStats,
+}
+
+/// Structure résumant une partie gagnée
+/// # Champs
+/// - `score`: le score au moment de la victoire
+/// - `wave`: la vague de la victoire
+/// - `asteroids_destroyed`: le nombre d'asteroides détruits pendant la partie
+/// - `new_record`: permet de savoir si la partie bat le meilleur score
+#[derive(Clone, Copy)]
+pub struct VictorySummary {
+    pub score: i32,
+    pub wave: u32,
+    pub asteroids_destroyed: u32,
+    pub new_record: bool,
+}
+
+/// Structure qui retient les sons de changement d'état déjà joués
+/// # Champs
+/// - `start_game`: permet de savoir si le son de début de partie a été joué
+#[derive(Default)]
+pub struct SoundLatches {
+    start_game: bool,
+}
+
+impl SoundLatches {
+    /// Fonction qui renvoie le son à jouer pendant une image de l'état donné.
+    /// Chaque son n'est renvoyé qu'une fois, jusqu'au prochain `reset`.
+    /// # Arguments
+    /// - `state`: l'état du jeu pendant l'image
+    /// # Returns
+    /// - `Option<SoundEvent>`: le son de début de partie, s'il n'a pas encore été joué
+    pub fn frame_sound(&mut self, state: &GameState) -> Option<SoundEvent> {
+        let (latch, event) = match state {
+            GameState::Playing => (&mut self.start_game, SoundEvent::StartGame),
+            _ => return None,
+        };
+        (!std::mem::replace(latch, true)).then_some(event)
+    }
+
+    /// Oublie les sons déjà joués, au début d'une nouvelle partie
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que chaque partie joue une fois le son de début, y compris la deuxième partie
+    /// lancée depuis l'écran de fin, et que l'écran de fin ne rejoue pas le son de fin.
+    #[test]
+    fn test_start_sound_plays_for_every_run() {
+        let game_over = GameState::GameOver(RunSummary {
+            reason: GameOverReason::BlackHole,
+            wave: 1,
+            mode: GameMode::Classic,
+            new_record: false,
+            seed: 0,
+            stats: RunStats::default(),
+        });
+        let mut latches = SoundLatches::default();
+        let mut sounds = Vec::new();
+        let mut run = |latches: &mut SoundLatches, states: &[GameState]| {
+            for state in states {
+                // Chaque état dure plusieurs images
+                for _ in 0..3 {
+                    sounds.extend(latches.frame_sound(state));
+                }
+            }
+        };
+
+        run(
+            &mut latches,
+            &[GameState::StartScreen, GameState::Playing, game_over],
+        );
+        latches.reset();
+        run(&mut latches, &[GameState::Playing, game_over]);
+
+        assert_eq!(sounds, vec![SoundEvent::StartGame, SoundEvent::StartGame]);
+    }
+}
@@ -0,0 +1,222 @@
+//! Module pour l'asteroide doré, une cible bonus rare qui fuit le vaisseau.
+//! Il ne se sépare jamais et ne forme pas de trou noir : le bouclier le repousse simplement.
+//! S'il n'est pas détruit au bout de `LIFETIME` secondes, il disparaît.
+use crate::asteroid::{Size, ASTEROID_VARIANTS};
+use crate::stellarobject::{debug_field, format_vec2, wrap_offsets, wrapped_offset, StellarObject};
+use ::rand::Rng;
+use macroquad::prelude::*;
+
+/// Probabilité qu'un asteroide doré apparaisse au début d'une vague
+pub const SPAWN_CHANCE: f64 = 0.02;
+/// Durée (en secondes) avant que l'asteroide doré ne disparaisse
+pub const LIFETIME: f32 = 10.0;
+/// Bonus de score pour la destruction de l'asteroide doré
+pub const SCORE_BONUS: i32 = 25;
+/// Accélération (en pixels par image, à chaque image) pour s'éloigner du vaisseau
+const FLEE_ACCELERATION: f32 = 0.08;
+/// Vitesse maximale (en pixels par image), plus rapide que les petits asteroides
+const MAX_SPEED: f32 = 3.0;
+/// Taille de l'asteroide doré, celle d'un petit asteroide
+const SIZE: Size = Size::Small;
+/// Vitesse (en radians par seconde) du scintillement de la teinte dorée
+const SHIMMER_SPEED: f32 = 8.0;
+
+/// Structure représentant l'asteroide doré
+/// # Champs
+/// - `position`: la position de l'asteroide doré
+/// - `velocity`: la vitesse de l'asteroide doré
+/// - `variant`: l'indice de la texture
+/// - `age`: le temps écoulé depuis l'apparition de l'asteroide doré
+/// - `active`: permet de savoir si l'asteroide doré est encore en jeu
+pub struct GoldAsteroid {
+    position: Vec2,
+    velocity: Vec2,
+    variant: usize,
+    age: f32,
+    pub active: bool,
+}
+
+impl GoldAsteroid {
+    /// Crée l'asteroide doré à l'opposé d'une position à éviter, immobile
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// - `avoid`: la position à éviter, en général celle du vaisseau
+    /// - `rng`: le générateur de la partie, pour la texture
+    /// # Returns
+    /// - `Self`: l'asteroide doré
+    pub fn new(bounds: Vec2, avoid: Vec2, rng: &mut impl Rng) -> Self {
+        let position = avoid + bounds / 2.0;
+        Self::with_position(
+            vec2(
+                position.x.rem_euclid(bounds.x),
+                position.y.rem_euclid(bounds.y),
+            ),
+            rng.gen_range(0..ASTEROID_VARIANTS),
+        )
+    }
+
+    /// Crée l'asteroide doré à une position donnée, immobile
+    /// # Arguments
+    /// - `position`: la position de l'asteroide doré
+    /// - `variant`: l'indice de la texture
+    /// # Returns
+    /// - `Self`: l'asteroide doré
+    pub fn with_position(position: Vec2, variant: usize) -> Self {
+        Self {
+            position,
+            velocity: Vec2::ZERO,
+            variant,
+            age: 0.0,
+            active: true,
+        }
+    }
+
+    /// Met à jour l'asteroide doré pour une image : il accélère pour s'éloigner du vaisseau,
+    /// et disparaît au bout de `LIFETIME` secondes
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `threat`: la position à fuir, celle du vaisseau
+    /// - `bounds`: la taille de l'écran, pour fuir dans la bonne direction près des bords
+    pub fn update(&mut self, dt: f32, threat: Vec2, bounds: Vec2) {
+        self.age += dt;
+        if self.age >= LIFETIME {
+            self.active = false;
+        }
+        let direction = -wrapped_offset(self.position, threat, bounds).normalize_or_zero();
+        self.velocity = (self.velocity + direction * FLEE_ACCELERATION).clamp_length_max(MAX_SPEED);
+    }
+
+    /// Fait rebondir l'asteroide doré sur un bouclier : il repart dans la direction opposée
+    /// à la normale du contact, sans perdre de vitesse
+    /// # Arguments
+    /// - `normal`: la normale du contact, de l'asteroide doré vers le vaisseau
+    pub fn bounce(&mut self, normal: Vec2) {
+        let closing = self.velocity.dot(normal);
+        if closing > 0.0 {
+            self.velocity -= 2.0 * closing * normal;
+        }
+    }
+
+    /// Dessine l'asteroide doré avec la texture des asteroides et une teinte dorée qui scintille
+    /// # Arguments
+    /// - `bounds`: la taille de l'aire de jeu
+    /// - `textures`: les textures des asteroides, dont au moins une
+    pub fn draw(&self, bounds: Vec2, textures: &[Texture2D]) {
+        let texture = &textures[self.variant % textures.len()];
+        let shimmer = (self.age * SHIMMER_SPEED).sin() * 0.5 + 0.5;
+        let color = Color::new(1.0, 0.75 + shimmer * 0.2, 0.1 + shimmer * 0.4, 1.0);
+        let half = SIZE.scale() / 2.0;
+        for offset in wrap_offsets(self.position, half, bounds) {
+            let position = self.position + offset;
+            draw_texture_ex(
+                texture,
+                position.x - half,
+                position.y - half,
+                color,
+                DrawTextureParams {
+                    dest_size: Some(Vec2::splat(SIZE.scale())),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+}
+
+impl StellarObject for GoldAsteroid {
+    /// Retourne la position de l'asteroide doré.
+    fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    /// Déplace l'asteroide doré, qui passe d'un bord de l'écran à l'autre.
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
+        self.position += self.velocity;
+        self.position = vec2(
+            self.position.x.rem_euclid(bounds.x),
+            self.position.y.rem_euclid(bounds.y),
+        );
+    }
+
+    /// Retourne le rayon de collision de l'asteroide doré, celui d'un petit asteroide.
+    fn radius(&self) -> f32 {
+        SIZE.scale() / 2.0 * SIZE.collision_factor()
+    }
+
+    /// L'asteroide doré est détruit d'un seul missile, sans se séparer.
+    fn handle_collision(&mut self) {
+        self.active = false;
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état de l'asteroide doré pour l'inspecteur de développement.
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "GoldAsteroid"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.velocity)),
+            debug_field("age", format!("{:.1}", self.age)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stellarobject::wrapped_distance;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Vérifie que l'asteroide doré s'éloigne d'un vaisseau immobile, sans dépasser `MAX_SPEED`.
+    #[test]
+    fn test_flees_from_stationary_ship() {
+        let ship = vec2(400.0, 300.0);
+        let mut gold = GoldAsteroid::with_position(vec2(450.0, 320.0), 0);
+        let mut distance = wrapped_distance(gold.get_pos(), ship, BOUNDS);
+
+        for _ in 0..30 {
+            gold.update(1.0 / 60.0, ship, BOUNDS);
+            gold.move_obj(BOUNDS);
+            let new_distance = wrapped_distance(gold.get_pos(), ship, BOUNDS);
+            assert!(new_distance > distance, "L'asteroide doré doit fuir !");
+            distance = new_distance;
+        }
+        assert!(gold.velocity().length() <= MAX_SPEED + 1e-4);
+    }
+
+    /// Vérifie que l'asteroide doré disparaît au bout de `LIFETIME` secondes.
+    #[test]
+    fn test_despawns_after_lifetime() {
+        let mut gold = GoldAsteroid::with_position(vec2(100.0, 100.0), 0);
+        gold.update(LIFETIME - 0.5, vec2(400.0, 300.0), BOUNDS);
+        assert!(gold.active);
+        gold.update(0.5, vec2(400.0, 300.0), BOUNDS);
+        assert!(!gold.active);
+    }
+}
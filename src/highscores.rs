@@ -0,0 +1,344 @@
+//! Module pour gérer le tableau des meilleurs scores, séparé pour chaque mode de jeu.
+//! Le tableau est sauvegardé dans un fichier texte, avec une ligne `score;vague;date;difficulté;mode`
+//! par entrée. Les lignes des anciennes versions, sans difficulté ou sans mode, sont lues comme des
+//! parties en difficulté normale et en mode classique.
+use asteroid::difficulty::Difficulty;
+use asteroid::game_mode::GameMode;
+use asteroid::platform;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier des meilleurs scores
+const HIGHSCORES_FILE: &str = "highscores.txt";
+/// Nom du journal des statistiques des parties
+const STATS_FILE: &str = "stats.csv";
+/// Nombre d'entrées gardées dans le tableau de chaque mode
+pub const MAX_ENTRIES: usize = 5;
+
+/// Structure représentant une entrée du tableau des meilleurs scores
+/// # Champs
+/// - `score`: le score obtenu
+/// - `wave`: la vague atteinte
+/// - `difficulty`: la difficulté de la partie
+/// - `mode`: le mode de jeu de la partie
+/// - `date`: la date de la partie, au format `AAAA-MM-JJ`
+#[derive(Clone, PartialEq, Debug)]
+pub struct HighScoreEntry {
+    pub score: i32,
+    pub wave: u32,
+    pub difficulty: Difficulty,
+    pub mode: GameMode,
+    pub date: String,
+}
+
+/// Structure représentant le tableau des meilleurs scores
+/// # Champs
+/// - `entries`: les entrées de tous les modes, de la meilleure à la moins bonne dans chaque mode
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct HighScores {
+    entries: Vec<HighScoreEntry>,
+}
+
+impl HighScores {
+    /// Charge le tableau depuis un fichier.
+    /// Un fichier absent ou des lignes illisibles donnent un tableau vide plutôt qu'une erreur.
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `Self`: le tableau chargé
+    pub fn load(path: &Path) -> Self {
+        let mut highscores = Self::default();
+        let Ok(content) = platform::read_to_string(path) else {
+            return highscores;
+        };
+        for line in content.lines() {
+            let fields: Vec<&str> = line.split(';').collect();
+            let (score, wave, date, difficulty, mode) = match fields[..] {
+                [score, wave, date] => (
+                    score,
+                    wave,
+                    date,
+                    Some(Difficulty::Normal),
+                    Some(GameMode::Classic),
+                ),
+                [score, wave, date, difficulty] => (
+                    score,
+                    wave,
+                    date,
+                    Difficulty::from_id(difficulty),
+                    Some(GameMode::Classic),
+                ),
+                [score, wave, date, difficulty, mode] => (
+                    score,
+                    wave,
+                    date,
+                    Difficulty::from_id(difficulty),
+                    GameMode::from_id(mode),
+                ),
+                _ => continue,
+            };
+            if let (Ok(score), Ok(wave), Some(difficulty), Some(mode)) =
+                (score.parse(), wave.parse(), difficulty, mode)
+            {
+                highscores.insert(HighScoreEntry {
+                    score,
+                    wave,
+                    difficulty,
+                    mode,
+                    date: date.to_string(),
+                });
+            }
+        }
+        highscores
+    }
+
+    /// Sauvegarde le tableau dans un fichier
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `io::Result<()>`: une erreur si le fichier n'a pas pu être écrit
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut content = String::new();
+        for entry in &self.entries {
+            content.push_str(&format!(
+                "{};{};{};{};{}\n",
+                entry.score,
+                entry.wave,
+                entry.date,
+                entry.difficulty.id(),
+                entry.mode.id()
+            ));
+        }
+        platform::write(path, &content)
+    }
+
+    /// Fonction qui renvoie les entrées du tableau d'un mode de jeu
+    /// # Arguments
+    /// - `mode`: le mode de jeu
+    /// # Returns
+    /// - `impl Iterator<Item = &HighScoreEntry>`: les entrées du mode, de la meilleure à la moins bonne
+    pub fn entries(&self, mode: GameMode) -> impl Iterator<Item = &HighScoreEntry> + Clone {
+        self.entries.iter().filter(move |entry| entry.mode == mode)
+    }
+
+    /// Fonction qui indique si un score bat le meilleur score du tableau d'un mode de jeu
+    /// # Arguments
+    /// - `score`: le score à comparer
+    /// - `mode`: le mode de jeu de la partie
+    /// # Returns
+    /// - `bool`: `true` si le score est strictement meilleur que tous les autres du mode,
+    ///   ou s'il est positif quand le tableau du mode est vide
+    pub fn is_record(&self, score: i32, mode: GameMode) -> bool {
+        match self.entries(mode).next() {
+            Some(best) => score > best.score,
+            None => score > 0,
+        }
+    }
+
+    /// Ajoute une entrée au tableau de son mode, à sa place,
+    /// en ne gardant que les `MAX_ENTRIES` meilleures de ce mode
+    /// # Arguments
+    /// - `entry`: l'entrée à ajouter
+    /// # Returns
+    /// - `Option<usize>`: le rang de l'entrée dans le tableau de son mode,
+    ///   ou `None` si elle n'y entre pas
+    pub fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        let mode = entry.mode;
+        let rank = self
+            .entries(mode)
+            .position(|other| entry.score > other.score)
+            .unwrap_or_else(|| self.entries(mode).count());
+        if rank >= MAX_ENTRIES {
+            return None;
+        }
+        let index = self
+            .entries
+            .iter()
+            .position(|other| other.mode == mode && entry.score > other.score)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, entry);
+        let extra = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.mode == mode)
+            .nth(MAX_ENTRIES)
+            .map(|(index, _)| index);
+        if let Some(extra) = extra {
+            self.entries.remove(extra);
+        }
+        Some(rank)
+    }
+}
+
+/// Fonction qui renvoie le chemin du fichier des meilleurs scores
+/// # Returns
+/// - `PathBuf`: le chemin du fichier
+pub fn highscores_path() -> PathBuf {
+    crate::profile::data_path(HIGHSCORES_FILE)
+}
+
+/// Fonction qui renvoie le chemin du journal des statistiques des parties
+/// # Returns
+/// - `PathBuf`: le chemin du journal
+pub fn stats_path() -> PathBuf {
+    crate::profile::data_path(STATS_FILE)
+}
+
+/// Fonction qui renvoie la date du jour au format `AAAA-MM-JJ`
+/// # Returns
+/// - `String`: la date du jour
+pub fn today() -> String {
+    date_from_days((platform::unix_seconds() / 86_400) as i64)
+}
+
+/// Fonction qui convertit un nombre de jours depuis le 1er janvier 1970 en date
+/// # Arguments
+/// - `days`: le nombre de jours depuis le 1er janvier 1970
+/// # Returns
+/// - `String`: la date au format `AAAA-MM-JJ`
+fn date_from_days(days: i64) -> String {
+    // Algorithme « civil from days » de Howard Hinnant
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn entry(score: i32) -> HighScoreEntry {
+        HighScoreEntry {
+            score,
+            wave: 3,
+            difficulty: Difficulty::Hard,
+            mode: GameMode::Classic,
+            date: "2025-01-31".to_string(),
+        }
+    }
+
+    /// Vérifie qu'un tableau sauvegardé puis rechargé depuis un fichier temporaire est identique.
+    #[test]
+    fn test_round_trip_through_temp_file() {
+        let path = std::env::temp_dir().join("asteroid_test_highscores_round_trip.txt");
+        let mut highscores = HighScores::default();
+        highscores.insert(entry(42));
+        highscores.insert(entry(7));
+        highscores.insert(HighScoreEntry {
+            mode: GameMode::Endless,
+            ..entry(12)
+        });
+
+        highscores.save(&path).unwrap();
+        let loaded = HighScores::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, highscores, "Le tableau rechargé est différent !");
+    }
+
+    /// Vérifie qu'une ligne sans difficulté, écrite par une ancienne version, est lue en difficulté normale.
+    #[test]
+    fn test_old_lines_are_normal_difficulty() {
+        let path = std::env::temp_dir().join("asteroid_test_highscores_old_format.txt");
+        fs::write(&path, "42;3;2025-01-31\n7;1;2025-02-01;easy\n").unwrap();
+
+        let loaded = HighScores::load(&path);
+        let _ = fs::remove_file(&path);
+
+        let difficulties: Vec<Difficulty> = loaded
+            .entries(GameMode::Classic)
+            .map(|e| e.difficulty)
+            .collect();
+        assert_eq!(difficulties, vec![Difficulty::Normal, Difficulty::Easy]);
+    }
+
+    /// Vérifie qu'un fichier absent donne un tableau vide.
+    #[test]
+    fn test_missing_file_gives_empty_table() {
+        let path = std::env::temp_dir().join("asteroid_test_highscores_missing.txt");
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            HighScores::load(&path).entries(GameMode::Classic).count(),
+            0
+        );
+    }
+
+    /// Vérifie que le tableau est trié et ne garde que les `MAX_ENTRIES` meilleurs scores.
+    #[test]
+    fn test_insert_keeps_best_scores_sorted() {
+        let mut highscores = HighScores::default();
+        for score in [10, 50, 30, 20, 40, 60] {
+            highscores.insert(entry(score));
+        }
+
+        let scores: Vec<i32> = highscores
+            .entries(GameMode::Classic)
+            .map(|e| e.score)
+            .collect();
+        assert_eq!(scores, vec![60, 50, 40, 30, 20]);
+        assert_eq!(highscores.insert(entry(5)), None);
+        assert_eq!(highscores.insert(entry(45)), Some(2));
+    }
+
+    /// Vérifie la détection d'un nouveau record.
+    #[test]
+    fn test_is_record() {
+        let mut highscores = HighScores::default();
+        assert!(
+            highscores.is_record(1, GameMode::Classic),
+            "Le premier score positif doit être un record !"
+        );
+        assert!(!highscores.is_record(0, GameMode::Classic));
+        assert!(!highscores.is_record(-5, GameMode::Classic));
+
+        highscores.insert(entry(30));
+        assert!(highscores.is_record(31, GameMode::Classic));
+        assert!(!highscores.is_record(30, GameMode::Classic));
+    }
+
+    /// Vérifie que chaque mode de jeu a son propre tableau, avec ses propres records.
+    #[test]
+    fn test_modes_are_separate() {
+        let mut highscores = HighScores::default();
+        for score in [10, 20, 30, 40, 50] {
+            highscores.insert(entry(score));
+        }
+        let endless = HighScoreEntry {
+            mode: GameMode::Endless,
+            ..entry(5)
+        };
+        assert!(highscores.is_record(5, GameMode::Endless));
+        assert_eq!(highscores.insert(endless), Some(0));
+        assert!(!highscores.is_record(5, GameMode::Endless));
+
+        assert_eq!(highscores.entries(GameMode::Classic).count(), MAX_ENTRIES);
+        let endless: Vec<i32> = highscores
+            .entries(GameMode::Endless)
+            .map(|e| e.score)
+            .collect();
+        assert_eq!(endless, vec![5]);
+    }
+
+    /// Vérifie la conversion d'un nombre de jours en date.
+    #[test]
+    fn test_date_from_days() {
+        assert_eq!(date_from_days(0), "1970-01-01");
+        assert_eq!(date_from_days(20_089), "2025-01-01");
+        assert_eq!(date_from_days(19_782), "2024-02-29");
+    }
+}
@@ -0,0 +1,399 @@
+//! Module pour le tableau de bord dessiné par dessus la partie.
+//! Le tableau de bord ne lit qu'une vue de la partie, `HudData`, construite à chaque image :
+//! il ne dépend pas des objets du jeu. Chaque bloc est accroché à un coin de la fenêtre,
+//! pour rester en place quand la fenêtre change de taille.
+use crate::locale::{tr, Language};
+use asteroid::activity::HIGH_LEVEL;
+use asteroid::boss::CORE_HP;
+use asteroid::difficulty::Difficulty;
+use asteroid::powerup::PowerUpKind;
+use asteroid::score::ScoreSystem;
+use asteroid::spaceship::{shield_tint, SpaceshipTextures, SECOND_PLAYER_TINT};
+use asteroid::text::{hud_scale, Fonts, TextStyle};
+use asteroid::world::World;
+use macroquad::prelude::*;
+
+/// Marge (en pixels) entre les blocs du tableau de bord et les bords de la fenêtre
+const MARGIN: f32 = 10.0;
+/// Taille (en pixels) des icônes du bouclier et des vies, à l'échelle 1
+const ICON_SIZE: f32 = 28.0;
+/// Taille (en pixels) des barres de temps des bonus, à l'échelle 1
+const BAR_SIZE: Vec2 = vec2(120.0, 6.0);
+
+/// Énumération représentant les coins de la fenêtre
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Fonction qui place un bloc dans un coin de la fenêtre
+/// # Arguments
+/// - `corner`: le coin auquel accrocher le bloc
+/// - `screen`: la taille de la fenêtre
+/// - `size`: la taille du bloc
+/// # Returns
+/// - `Vec2`: le coin en haut à gauche du bloc, à `MARGIN` des bords
+pub fn anchor(corner: Corner, screen: Vec2, size: Vec2) -> Vec2 {
+    let right = screen.x - MARGIN - size.x;
+    let bottom = screen.y - MARGIN - size.y;
+    match corner {
+        Corner::TopLeft => vec2(MARGIN, MARGIN),
+        Corner::TopRight => vec2(right, MARGIN),
+        Corner::BottomLeft => vec2(MARGIN, bottom),
+        Corner::BottomRight => vec2(right, bottom),
+    }
+}
+
+/// Structure représentant ce que le tableau de bord montre de la partie
+/// # Champs
+/// - `wave`: le numéro de la vague
+/// - `elapsed`: le temps de jeu (en secondes), affiché à la place de la vague en mode sans fin
+/// - `difficulty`: la difficulté de la partie
+/// - `score`: le score
+/// - `partner_score`: le score du second joueur, en coopération
+/// - `multiplier`: le multiplicateur du combo en cours
+/// - `bombs`: le nombre de bombes restantes
+/// - `shield_charges`: le nombre de charges qui restent au bouclier du vaisseau
+/// - `invincible`: permet de savoir si le vaisseau est invincible après la perte du bouclier
+/// - `lives`: le nombre de chocs que le vaisseau peut encore encaisser : le bouclier, puis la coque
+/// - `heat`: la chaleur de l'arme et si elle est bloquée, quand la surchauffe est active
+/// - `power_ups`: les bonus en cours, avec leur temps restant
+/// - `activity`: le niveau de la jauge d'activité et si elle est vide, s'il y en a une
+/// - `boss`: la part de points de vie du vaisseau mère et si son cœur est exposé, s'il y en a un
+/// - `countdown`: le chiffre du compte à rebours de la nouvelle vague, s'il est en cours
+/// - `hit`: permet de savoir si le vaisseau vient d'être touché
+pub struct HudData<'a> {
+    pub wave: u32,
+    pub elapsed: Option<f32>,
+    pub difficulty: Difficulty,
+    pub score: i32,
+    pub partner_score: Option<i32>,
+    pub multiplier: u32,
+    pub bombs: u32,
+    pub shield_charges: u32,
+    pub invincible: bool,
+    pub lives: u32,
+    pub heat: Option<(f32, bool)>,
+    pub power_ups: &'a [(PowerUpKind, f32)],
+    pub activity: Option<(f32, bool)>,
+    pub boss: Option<(f32, bool)>,
+    pub countdown: Option<u32>,
+    pub hit: bool,
+}
+
+impl<'a> HudData<'a> {
+    /// Crée la vue de la partie affichée par le tableau de bord
+    /// # Arguments
+    /// - `world`: la partie en cours
+    /// # Returns
+    /// - `Self`: la vue de la partie
+    pub fn from_world(world: &'a World) -> Self {
+        let spaceship = &world.spaceship;
+        Self {
+            wave: world.wave,
+            elapsed: (!world.mode.has_waves()).then_some(world.stats.time_survived),
+            difficulty: world.difficulty,
+            score: world.score,
+            partner_score: world.partner.as_ref().map(|_| world.partner_score),
+            multiplier: world.combo.multiplier,
+            bombs: world.bombs,
+            shield_charges: spaceship.shield_charges,
+            invincible: spaceship.invincible,
+            lives: u32::from(spaceship.active) + spaceship.shield_charges,
+            heat: spaceship
+                .weapon
+                .overheat
+                .then(|| (spaceship.weapon.heat(), spaceship.weapon.overheated())),
+            power_ups: spaceship.active_effects.timers(),
+            activity: world
+                .activity
+                .as_ref()
+                .map(|activity| (activity.level, activity.is_empty())),
+            boss: world
+                .boss
+                .as_ref()
+                .map(|boss| (boss.hp as f32 / CORE_HP as f32, boss.core_exposed())),
+            countdown: world.countdown_step(),
+            hit: spaceship.hit,
+        }
+    }
+}
+
+/// Fonction qui calcule la part restante du temps d'un bonus
+/// # Arguments
+/// - `kind`: le type du bonus
+/// - `remaining`: le temps restant
+/// # Returns
+/// - `f32`: la part restante, entre 0 et 1, ou 1 pour un bonus sans durée
+pub fn power_up_fraction(kind: PowerUpKind, remaining: f32) -> f32 {
+    kind.duration()
+        .map_or(1.0, |duration| (remaining / duration).clamp(0.0, 1.0))
+}
+
+/// Dessine le tableau de bord par dessus la partie, sans la caméra de l'aire de jeu.
+/// Le tableau de bord est à l'échelle de l'aire de jeu, textes compris.
+/// # Arguments
+/// - `data`: la vue de la partie
+/// - `textures`: les textures du vaisseau, réduites pour les icônes du bouclier et des vies
+/// - `language`: la langue des textes
+/// - `fonts`: les polices utilisées pour le tableau de bord
+pub fn draw_hud(data: &HudData, textures: &SpaceshipTextures, language: Language, fonts: &Fonts) {
+    let screen = vec2(screen_width(), screen_height());
+    let scale = hud_scale(screen);
+    let fonts = &fonts.with_scale(scale);
+    let heading = TextStyle::Heading.size(scale);
+    let body = TextStyle::Body.size(scale);
+
+    // En haut à gauche : la vague (ou le temps de jeu) et la difficulté, puis la jauge d'activité
+    let top_left = anchor(Corner::TopLeft, screen, Vec2::ZERO);
+    let wave_text = match data.elapsed {
+        Some(elapsed) => {
+            let seconds = elapsed as u32;
+            format!(
+                "{}: {}:{:02}",
+                tr(language, "hud.time"),
+                seconds / 60,
+                seconds % 60
+            )
+        }
+        None => format!("{}: {}", tr(language, "hud.wave"), data.wave),
+    };
+    fonts.draw(
+        &wave_text,
+        top_left.x,
+        top_left.y + heading,
+        TextStyle::Heading,
+        WHITE,
+    );
+    fonts.draw(
+        tr(language, data.difficulty.key()),
+        top_left.x + fonts.measure(&wave_text, TextStyle::Heading).width + 16.0,
+        top_left.y + heading,
+        TextStyle::Body,
+        GRAY,
+    );
+    if let Some((level, empty)) = data.activity {
+        let y = top_left.y + heading + 8.0;
+        draw_rectangle(
+            top_left.x,
+            y,
+            BAR_SIZE.x,
+            4.0,
+            Color::new(1.0, 1.0, 1.0, 0.2),
+        );
+        let color = if level > HIGH_LEVEL { GOLD } else { SKYBLUE };
+        draw_rectangle(top_left.x, y, BAR_SIZE.x * level, 4.0, color);
+        if empty {
+            fonts.draw(
+                tr(language, "hud.activity_empty"),
+                top_left.x + BAR_SIZE.x + 8.0,
+                y + 6.0,
+                TextStyle::Popup,
+                Color::new(0.6, 0.6, 0.6, 0.6),
+            );
+        }
+    }
+
+    // En haut à droite : le score, celui du second joueur en coopération,
+    // puis le multiplicateur du combo en dessous
+    let score_text = format!("{}: {}", tr(language, "hud.score"), data.score);
+    let score_size = vec2(
+        fonts.measure(&score_text, TextStyle::Heading).width,
+        heading,
+    );
+    let top_right = anchor(Corner::TopRight, screen, score_size);
+    fonts.draw(
+        &score_text,
+        top_right.x,
+        top_right.y + heading,
+        TextStyle::Heading,
+        WHITE,
+    );
+    // À gauche du score : la jauge verticale de chaleur de l'arme, qui clignote pendant le blocage
+    if let Some((heat, overheated)) = data.heat {
+        let height = heading * 1.5;
+        let x = top_right.x - 16.0;
+        draw_rectangle(x, top_right.y, 6.0, height, Color::new(1.0, 1.0, 1.0, 0.2));
+        let flash = overheated && (get_time() * 8.0).sin() < 0.0;
+        if !flash {
+            let color = if overheated { RED } else { ORANGE };
+            let filled = height * heat.clamp(0.0, 1.0);
+            draw_rectangle(x, top_right.y + height - filled, 6.0, filled, color);
+        }
+    }
+    let mut line = 2.0;
+    if let Some(partner_score) = data.partner_score {
+        let partner_text = format!("{}: {}", tr(language, "hud.player2"), partner_score);
+        let partner_width = fonts.measure(&partner_text, TextStyle::Heading).width;
+        fonts.draw(
+            &partner_text,
+            screen.x - MARGIN - partner_width,
+            top_right.y + line * heading,
+            TextStyle::Heading,
+            SECOND_PLAYER_TINT,
+        );
+        line += 1.0;
+    }
+    if data.multiplier > 1 {
+        let combo_text = format!("x{}", data.multiplier);
+        let combo_width = fonts.measure(&combo_text, TextStyle::Heading).width;
+        fonts.draw(
+            &combo_text,
+            screen.x - MARGIN - combo_width,
+            top_right.y + line * heading,
+            TextStyle::Heading,
+            ScoreSystem::color(data.multiplier),
+        );
+    }
+
+    // En bas à gauche : l'icône du bouclier avec ses charges, suivie des vies en petits vaisseaux
+    let icon = ICON_SIZE * scale;
+    let lives_size = vec2((data.lives + 1) as f32 * (icon + 6.0), icon);
+    let bottom_left = anchor(Corner::BottomLeft, screen, lives_size);
+    let (shield_texture, shield_color) = if data.shield_charges > 0 {
+        (&textures.shield_on, shield_tint(data.shield_charges))
+    } else if data.invincible {
+        (&textures.shield_off, WHITE)
+    } else {
+        (&textures.shield_dead, WHITE)
+    };
+    draw_icon(shield_texture, bottom_left, icon, shield_color);
+    let charges_text = data.shield_charges.to_string();
+    let charges_size = fonts.measure(&charges_text, TextStyle::Popup);
+    fonts.draw(
+        &charges_text,
+        bottom_left.x + (icon - charges_size.width) / 2.0,
+        bottom_left.y + (icon + charges_size.height) / 2.0,
+        TextStyle::Popup,
+        WHITE,
+    );
+    for i in 0..data.lives {
+        let x = bottom_left.x + (i + 1) as f32 * (icon + 6.0);
+        draw_icon(&textures.ship, vec2(x, bottom_left.y), icon, WHITE);
+    }
+
+    // En bas à droite : les bombes, avec les bonus en cours empilés au-dessus
+    let bombs_text = format!("{}: {}", tr(language, "hud.bombs"), data.bombs);
+    let bombs_width = fonts.measure(&bombs_text, TextStyle::Body).width;
+    let bottom_right = anchor(Corner::BottomRight, screen, vec2(bombs_width, body));
+    fonts.draw(
+        &bombs_text,
+        bottom_right.x,
+        bottom_right.y + body,
+        TextStyle::Body,
+        if data.bombs > 0 { ORANGE } else { GRAY },
+    );
+    let bar = BAR_SIZE * scale;
+    for (i, (kind, remaining)) in data.power_ups.iter().enumerate() {
+        let x = screen.x - MARGIN - bar.x;
+        let y = bottom_right.y - (i + 1) as f32 * (body + bar.y + 8.0);
+        fonts.draw(
+            tr(language, kind.hud_key()),
+            x,
+            y + body,
+            TextStyle::Popup,
+            kind.color(),
+        );
+        draw_rectangle(
+            x,
+            y + body + 4.0,
+            bar.x,
+            bar.y,
+            Color::new(1.0, 1.0, 1.0, 0.2),
+        );
+        draw_rectangle(
+            x,
+            y + body + 4.0,
+            bar.x * power_up_fraction(*kind, *remaining),
+            bar.y,
+            kind.color(),
+        );
+    }
+
+    // Affichage des points de vie du vaisseau mère, en barre en haut de l'écran
+    if let Some((hp, exposed)) = data.boss {
+        let width = screen.x * 0.5;
+        let x = (screen.x - width) / 2.0;
+        fonts.draw_centered(tr(language, "hud.boss"), 24.0, TextStyle::Body, WHITE);
+        draw_rectangle(x, 32.0, width, 8.0, Color::new(1.0, 1.0, 1.0, 0.2));
+        let color = if exposed { RED } else { GRAY };
+        draw_rectangle(x, 32.0, width * hp, 8.0, color);
+    }
+
+    // Annonce de la nouvelle vague, avec son compte à rebours, au centre de l'écran
+    if let Some(step) = data.countdown {
+        let center = screen.y / 2.0;
+        fonts.draw_centered(
+            &format!("{} {}", tr(language, "hud.wave"), data.wave),
+            center - 60.0,
+            TextStyle::Title,
+            WHITE,
+        );
+        fonts.draw_centered(&step.to_string(), center, TextStyle::Title, GOLD);
+    }
+
+    // Affichage du message "Touché!" au centre de l'écran
+    if data.hit {
+        fonts.draw_centered("Touché!", screen.y / 2.0, TextStyle::Heading, RED);
+    }
+}
+
+/// Dessine une texture réduite en icône carrée
+/// # Arguments
+/// - `texture`: la texture
+/// - `position`: le coin en haut à gauche de l'icône
+/// - `size`: le côté de l'icône
+/// - `color`: la teinte de l'icône
+fn draw_icon(texture: &Texture2D, position: Vec2, size: f32, color: Color) {
+    draw_texture_ex(
+        texture,
+        position.x,
+        position.y,
+        color,
+        DrawTextureParams {
+            dest_size: Some(Vec2::splat(size)),
+            ..Default::default()
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que les blocs restent accrochés à leur coin quand la fenêtre change de taille.
+    #[test]
+    fn test_blocks_follow_window_corners() {
+        let size = vec2(100.0, 30.0);
+        for screen in [vec2(800.0, 600.0), vec2(1920.0, 1080.0), vec2(320.0, 240.0)] {
+            let bottom_right = anchor(Corner::BottomRight, screen, size);
+            assert_eq!(bottom_right + size, screen - Vec2::splat(MARGIN));
+            assert_eq!(
+                anchor(Corner::TopRight, screen, size).x + size.x,
+                screen.x - MARGIN
+            );
+            assert_eq!(
+                anchor(Corner::BottomLeft, screen, size).y + size.y,
+                screen.y - MARGIN
+            );
+            assert_eq!(anchor(Corner::TopLeft, screen, size), Vec2::splat(MARGIN));
+        }
+    }
+
+    /// Vérifie que la barre d'un bonus se vide avec son temps restant.
+    #[test]
+    fn test_power_up_bar_empties() {
+        let duration = PowerUpKind::RapidFire.duration().unwrap();
+
+        assert_eq!(power_up_fraction(PowerUpKind::RapidFire, duration), 1.0);
+        assert_eq!(
+            power_up_fraction(PowerUpKind::RapidFire, duration / 2.0),
+            0.5
+        );
+        assert_eq!(power_up_fraction(PowerUpKind::RapidFire, -1.0), 0.0);
+        assert_eq!(power_up_fraction(PowerUpKind::ShieldRestore, 0.0), 1.0);
+    }
+}
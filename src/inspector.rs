@@ -0,0 +1,222 @@
+//! Module pour l'inspecteur d'objets de l'affichage de développement.
+//! Un clic sur un objet affiche un panneau avec son état, mis à jour à chaque image
+//! jusqu'à ce que le panneau soit fermé ou que l'objet disparaisse.
+use asteroid::stellarobject::{wrapped_distance, StellarObject};
+use asteroid::text::{Fonts, TextStyle};
+use macroquad::prelude::*;
+
+/// Distance maximale parcourue par un objet entre deux images pour qu'il soit encore suivi
+const TRACK_DISTANCE: f32 = 20.0;
+
+/// Énumération représentant les types d'objets inspectables
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EntityKind {
+    Spaceship,
+    Asteroid,
+    Missile,
+    BlackHole,
+    Ufo,
+    PowerUp,
+}
+
+impl EntityKind {
+    /// Fonction qui renvoie la couleur du cercle de collision des objets de ce type
+    /// # Returns
+    /// - `Color`: la couleur du cercle dans l'affichage des zones de collision
+    pub fn hitbox_color(self) -> Color {
+        match self {
+            EntityKind::Spaceship => BLUE,
+            EntityKind::Asteroid => GREEN,
+            EntityKind::Missile => RED,
+            EntityKind::BlackHole => PURPLE,
+            EntityKind::Ufo => ORANGE,
+            EntityKind::PowerUp => YELLOW,
+        }
+    }
+}
+
+/// Structure qui garde l'objet inspecté d'une image à l'autre
+/// # Champs
+/// - `selected`: le type de l'objet inspecté et sa dernière position connue
+#[derive(Default)]
+pub struct Inspector {
+    selected: Option<(EntityKind, Vec2)>,
+}
+
+impl Inspector {
+    /// Crée un inspecteur sans objet sélectionné
+    /// # Returns
+    /// - `Self`: l'inspecteur
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sélectionne l'objet sous le clic, ou ferme le panneau si le clic ne touche rien
+    /// # Arguments
+    /// - `point`: la position du clic
+    /// - `entities`: tous les objets inspectables
+    /// - `bounds`: la taille de l'écran
+    pub fn click(
+        &mut self,
+        point: Vec2,
+        entities: &[(EntityKind, &dyn StellarObject)],
+        bounds: Vec2,
+    ) {
+        self.selected = pick(point, entities, bounds).map(|index| {
+            let (kind, object) = entities[index];
+            (kind, object.get_pos())
+        });
+    }
+
+    /// Retrouve l'objet inspecté parmi les objets de l'image.
+    /// La sélection est abandonnée si aucun objet du même type n'est assez proche de sa dernière position.
+    /// # Arguments
+    /// - `entities`: tous les objets inspectables
+    /// - `bounds`: la taille de l'écran
+    /// # Returns
+    /// - `Option<usize>`: l'indice de l'objet inspecté dans `entities`
+    pub fn track(
+        &mut self,
+        entities: &[(EntityKind, &dyn StellarObject)],
+        bounds: Vec2,
+    ) -> Option<usize> {
+        let (kind, last_pos) = self.selected?;
+        let found = entities
+            .iter()
+            .enumerate()
+            .filter(|(_, (other_kind, _))| *other_kind == kind)
+            .map(|(index, (_, object))| {
+                (index, wrapped_distance(object.get_pos(), last_pos, bounds))
+            })
+            .filter(|(_, distance)| *distance <= TRACK_DISTANCE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index);
+
+        self.selected = found.map(|index| (kind, entities[index].1.get_pos()));
+        found
+    }
+}
+
+/// Trouve l'objet sous un point.
+/// Si plusieurs objets se chevauchent, le plus petit l'emporte pour que les missiles
+/// restent sélectionnables au-dessus des asteroides.
+/// # Arguments
+/// - `point`: la position du clic
+/// - `entities`: tous les objets inspectables
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Option<usize>`: l'indice de l'objet choisi dans `entities`
+pub fn pick(
+    point: Vec2,
+    entities: &[(EntityKind, &dyn StellarObject)],
+    bounds: Vec2,
+) -> Option<usize> {
+    entities
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, object))| {
+            wrapped_distance(object.get_pos(), point, bounds) <= object.radius()
+        })
+        .min_by(|(_, (_, a)), (_, (_, b))| a.radius().total_cmp(&b.radius()))
+        .map(|(index, _)| index)
+}
+
+/// Dessine l'objet inspecté en surbrillance et le panneau de ses champs à côté de lui
+/// # Arguments
+/// - `object`: l'objet inspecté
+/// - `fonts`: les polices du jeu
+pub fn draw_panel(object: &dyn StellarObject, fonts: &Fonts) {
+    let position = object.get_pos();
+    draw_circle_lines(position.x, position.y, object.radius() + 4.0, 2.0, YELLOW);
+
+    let lines: Vec<String> = object
+        .debug_fields()
+        .into_iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect();
+    let line_height = fonts.measure("Mg", TextStyle::Popup).height + 6.0;
+    let width = lines
+        .iter()
+        .map(|line| fonts.measure(line, TextStyle::Popup).width)
+        .fold(0.0, f32::max)
+        + 16.0;
+    let height = line_height * lines.len() as f32 + 10.0;
+
+    // Le panneau reste dans l'écran même quand l'objet est près d'un bord
+    let x = (position.x + object.radius() + 12.0).min(screen_width() - width);
+    let y = (position.y - height / 2.0).clamp(0.0, (screen_height() - height).max(0.0));
+    draw_rectangle(x, y, width, height, Color::new(0.0, 0.0, 0.0, 0.75));
+    draw_rectangle_lines(x, y, width, height, 1.0, YELLOW);
+    for (i, line) in lines.iter().enumerate() {
+        fonts.draw(
+            line,
+            x + 8.0,
+            y + 5.0 + line_height * (i as f32 + 1.0) - 6.0,
+            TextStyle::Popup,
+            WHITE,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asteroid::asteroid::{Asteroid, Size};
+    use asteroid::missile::Missile;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Vérifie que le plus petit objet l'emporte quand plusieurs objets se chevauchent.
+    #[test]
+    fn test_pick_prefers_smallest_radius() {
+        let asteroid = Asteroid::new_with_size(Size::Large, vec2(100.0, 100.0), Vec2::ZERO);
+        let missile = Missile::new(vec2(102.0, 100.0), 0.0);
+        let entities: Vec<(EntityKind, &dyn StellarObject)> = vec![
+            (EntityKind::Asteroid, &asteroid),
+            (EntityKind::Missile, &missile),
+        ];
+
+        assert_eq!(pick(vec2(101.0, 100.0), &entities, BOUNDS), Some(1));
+        assert_eq!(pick(vec2(130.0, 100.0), &entities, BOUNDS), Some(0));
+        assert_eq!(pick(vec2(300.0, 300.0), &entities, BOUNDS), None);
+    }
+
+    /// Vérifie que le clic passe par les bords de l'écran.
+    #[test]
+    fn test_pick_uses_wrapped_distance() {
+        let asteroid = Asteroid::new_with_size(Size::Small, vec2(5.0, 300.0), Vec2::ZERO);
+        let entities: Vec<(EntityKind, &dyn StellarObject)> =
+            vec![(EntityKind::Asteroid, &asteroid)];
+
+        assert_eq!(pick(vec2(795.0, 300.0), &entities, BOUNDS), Some(0));
+    }
+
+    /// Vérifie que l'inspecteur suit l'objet qui bouge et abandonne quand il disparait.
+    #[test]
+    fn test_track_follows_until_despawn() {
+        let mut inspector = Inspector::new();
+        let missile = Missile::new(vec2(100.0, 100.0), 0.0);
+        inspector.click(
+            vec2(100.0, 100.0),
+            &[(EntityKind::Missile, &missile as &dyn StellarObject)],
+            BOUNDS,
+        );
+
+        let moved = Missile::new(vec2(104.0, 100.0), 0.0);
+        let other = Missile::new(vec2(400.0, 100.0), 0.0);
+        let entities: Vec<(EntityKind, &dyn StellarObject)> =
+            vec![(EntityKind::Missile, &other), (EntityKind::Missile, &moved)];
+        assert_eq!(inspector.track(&entities, BOUNDS), Some(1));
+
+        let entities: Vec<(EntityKind, &dyn StellarObject)> = vec![(EntityKind::Missile, &other)];
+        assert_eq!(inspector.track(&entities, BOUNDS), None);
+        assert_eq!(
+            inspector.track(
+                &[(EntityKind::Missile, &moved as &dyn StellarObject)],
+                BOUNDS
+            ),
+            None,
+            "La sélection doit être abandonnée après la disparition de l'objet !"
+        );
+    }
+}
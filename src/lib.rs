@@ -0,0 +1,44 @@
+//! Bibliothèque du jeu : les objets de la partie et la logique qui les fait évoluer.
+//! Tout ce qui est ici peut être utilisé sans fenêtre, ce qui permet de tester
+//! et de simuler des parties. Le binaire s'occupe des entrées, de l'affichage et des sons.
+pub mod accounting;
+pub mod activity;
+pub mod assets;
+pub mod asteroid;
+pub mod audio;
+pub mod audio_manager;
+pub mod autopilot;
+pub mod background;
+pub mod black_hole;
+pub mod boss;
+pub mod boss_asteroid;
+pub mod comet;
+pub mod decals;
+pub mod difficulty;
+pub mod edge_indicator;
+pub mod effects;
+pub mod endless;
+pub mod feedback;
+pub mod game_event;
+pub mod game_mode;
+pub mod game_over;
+pub mod gold_asteroid;
+pub mod missile;
+pub mod platform;
+pub mod playfield;
+pub mod pool;
+pub mod powerup;
+pub mod remix;
+pub mod replay;
+pub mod run_stats;
+pub mod score;
+pub mod simulation;
+pub mod spaceship;
+pub mod spatial_grid;
+pub mod stellarobject;
+pub mod text;
+pub mod timestep;
+pub mod ufo;
+pub mod upgrade;
+pub mod weapon;
+pub mod world;
@@ -0,0 +1,384 @@
+//! Module pour gérer la traduction des textes affichés par le jeu.
+//! Les textes sont identifiés par une clé et traduits selon la langue choisie.
+
+/// Énumération représentant les langues disponibles
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Language {
+    #[default]
+    French,
+    English,
+}
+
+impl Language {
+    /// Fonction qui choisit la langue à partir d'un nom de locale (par exemple `en_US.UTF-8`)
+    /// # Arguments
+    /// - `name`: le nom de la locale
+    /// # Returns
+    /// - `Language`: l'anglais si la locale est anglaise, sinon le français
+    pub fn from_locale_name(name: &str) -> Self {
+        if name.to_lowercase().starts_with("en") {
+            Language::English
+        } else {
+            Language::French
+        }
+    }
+
+    /// Fonction qui choisit la langue à partir de la variable d'environnement `LANG`
+    /// # Returns
+    /// - `Language`: la langue du système, le français par défaut
+    pub fn from_env() -> Self {
+        std::env::var("LANG")
+            .map(|name| Self::from_locale_name(&name))
+            .unwrap_or_default()
+    }
+}
+
+/// Fonction qui traduit une clé de texte dans la langue demandée
+/// # Arguments
+/// - `language`: la langue dans laquelle traduire le texte
+/// - `key`: la clé identifiant le texte
+/// # Returns
+/// - `&str`: le texte traduit, ou la clé elle même si elle est inconnue
+pub fn tr(language: Language, key: &str) -> &str {
+    match (key, language) {
+        ("caption.asteroid_destroyed", Language::French) => "explosion d'astéroïde",
+        ("caption.asteroid_destroyed", Language::English) => "asteroid explosion",
+        ("caption.armor_hit", Language::French) => "tintement de blindage",
+        ("caption.armor_hit", Language::English) => "armor clink",
+        ("caption.shield_lost", Language::French) => "bouclier perdu",
+        ("caption.shield_lost", Language::English) => "shield lost",
+        ("caption.shield_restored", Language::French) => "bouclier rechargé",
+        ("caption.shield_restored", Language::English) => "shield recharged",
+        ("caption.overheated", Language::French) => "arme en surchauffe",
+        ("caption.overheated", Language::English) => "weapon overheated",
+        ("caption.dry_fire", Language::French) => "clic à vide",
+        ("caption.dry_fire", Language::English) => "dry click",
+        ("caption.new_wave", Language::French) => "nouvelle vague",
+        ("caption.new_wave", Language::English) => "new wave",
+        ("caption.black_hole_formed", Language::French) => "trou noir formé",
+        ("caption.black_hole_formed", Language::English) => "black hole formed",
+        ("caption.black_hole_warning", Language::French) => "grondement d'un trou noir",
+        ("caption.black_hole_warning", Language::English) => "black hole rumbling",
+        ("caption.ufo_destroyed", Language::French) => "soucoupe détruite",
+        ("caption.ufo_destroyed", Language::English) => "saucer destroyed",
+        ("caption.power_up", Language::French) => "bonus ramassé",
+        ("caption.power_up", Language::English) => "power-up collected",
+        ("caption.bomb", Language::French) => "explosion de la bombe",
+        ("caption.bomb", Language::English) => "bomb blast",
+        ("hud.bombs", Language::French) => "Bombes",
+        ("hud.bombs", Language::English) => "Bombs",
+        ("caption.turret_destroyed", Language::French) => "tourelle détruite",
+        ("caption.turret_destroyed", Language::English) => "turret destroyed",
+        ("caption.boss_destroyed", Language::French) => "vaisseau mère détruit",
+        ("caption.boss_destroyed", Language::English) => "mothership destroyed",
+        ("caption.boss_phase", Language::French) => "le vaisseau mère change de tactique",
+        ("caption.boss_phase", Language::English) => "mothership changes tactics",
+        ("caption.ship_destroyed", Language::French) => "vaisseau détruit",
+        ("caption.ship_destroyed", Language::English) => "ship destroyed",
+        ("settings.captions_on", Language::French) => "Sous-titres: Oui",
+        ("settings.captions_on", Language::English) => "Captions: On",
+        ("settings.captions_off", Language::French) => "Sous-titres: Non",
+        ("settings.captions_off", Language::English) => "Captions: Off",
+        ("settings.background_cover", Language::French) => "Fond: Recadré",
+        ("settings.background_cover", Language::English) => "Background: Cover",
+        ("settings.background_tiled", Language::French) => "Fond: Mosaïque",
+        ("settings.background_tiled", Language::English) => "Background: Tiled",
+        ("settings.feedback_normal", Language::French) => "Effets: Normaux",
+        ("settings.feedback_normal", Language::English) => "Effects: Normal",
+        ("settings.feedback_reduced", Language::French) => "Effets: Réduits",
+        ("settings.feedback_reduced", Language::English) => "Effects: Reduced",
+        ("settings.feedback_spectacle", Language::French) => "Effets: Spectacle",
+        ("settings.feedback_spectacle", Language::English) => "Effects: Spectacle",
+        ("settings.remix_on", Language::French) => "Revanche: Oui",
+        ("settings.remix_on", Language::English) => "Rematch: On",
+        ("settings.remix_off", Language::French) => "Revanche: Non",
+        ("settings.remix_off", Language::English) => "Rematch: Off",
+        ("gameover.destroyed_by", Language::French) => "Détruit par",
+        ("gameover.destroyed_by", Language::English) => "Destroyed by",
+        ("gameover.wave", Language::French) => "Vague atteinte",
+        ("gameover.wave", Language::English) => "Wave reached",
+        ("gameover.seed", Language::French) => "Graine",
+        ("gameover.seed", Language::English) => "Seed",
+        ("gameover.nemesis", Language::French) => "Cause la plus fréquente",
+        ("gameover.nemesis", Language::English) => "Most frequent cause",
+        ("achievement.title", Language::French) => "Succès",
+        ("achievement.title", Language::English) => "Achievements",
+        ("achievement.unlocked", Language::French) => "Succès débloqué !",
+        ("achievement.unlocked", Language::English) => "Achievement unlocked!",
+        ("achievement.first_asteroid", Language::French) => "Premier caillou",
+        ("achievement.first_asteroid", Language::English) => "First rock",
+        ("achievement.first_asteroid.desc", Language::French) => "Détruire un asteroide",
+        ("achievement.first_asteroid.desc", Language::English) => "Destroy an asteroid",
+        ("achievement.first_black_hole", Language::French) => "Horizon franchi",
+        ("achievement.first_black_hole", Language::English) => "Event horizon",
+        ("achievement.first_black_hole.desc", Language::French) => "Détruire un trou noir",
+        ("achievement.first_black_hole.desc", Language::English) => "Destroy a black hole",
+        ("achievement.ufo_hunter", Language::French) => "Chasseur de soucoupes",
+        ("achievement.ufo_hunter", Language::English) => "Saucer hunter",
+        ("achievement.ufo_hunter.desc", Language::French) => "Détruire une soucoupe",
+        ("achievement.ufo_hunter.desc", Language::English) => "Destroy a saucer",
+        ("achievement.wave_5", Language::French) => "Bien parti",
+        ("achievement.wave_5", Language::English) => "Off to a good start",
+        ("achievement.wave_5.desc", Language::French) => "Atteindre la vague 5",
+        ("achievement.wave_5.desc", Language::English) => "Reach wave 5",
+        ("achievement.wave_10", Language::French) => "Vétéran",
+        ("achievement.wave_10", Language::English) => "Veteran",
+        ("achievement.wave_10.desc", Language::French) => "Atteindre la vague 10",
+        ("achievement.wave_10.desc", Language::English) => "Reach wave 10",
+        ("achievement.sharpshooter", Language::French) => "Tireur d'élite",
+        ("achievement.sharpshooter", Language::English) => "Sharpshooter",
+        ("achievement.sharpshooter.desc", Language::French) => "Finir une vague en 10 tirs au plus",
+        ("achievement.sharpshooter.desc", Language::English) => {
+            "Clear a wave with 10 shots or fewer"
+        }
+        ("achievement.untouchable", Language::French) => "Intouchable",
+        ("achievement.untouchable", Language::English) => "Untouchable",
+        ("achievement.untouchable.desc", Language::French) => {
+            "Finir une vague sans perdre le bouclier"
+        }
+        ("achievement.untouchable.desc", Language::English) => {
+            "Clear a wave without losing the shield"
+        }
+        ("achievement.survivor", Language::French) => "Survivant",
+        ("achievement.survivor", Language::English) => "Survivor",
+        ("achievement.survivor.desc", Language::French) => "Survivre 5 minutes",
+        ("achievement.survivor.desc", Language::English) => "Survive 5 minutes",
+        ("achievement.score_500", Language::French) => "Demi-millier",
+        ("achievement.score_500", Language::English) => "Half a thousand",
+        ("achievement.score_500.desc", Language::French) => "Marquer 500 points",
+        ("achievement.score_500.desc", Language::English) => "Score 500 points",
+        ("achievement.demolition", Language::French) => "Démolition",
+        ("achievement.demolition", Language::English) => "Demolition",
+        ("achievement.demolition.desc", Language::French) => {
+            "Détruire 100 asteroides en une partie"
+        }
+        ("achievement.demolition.desc", Language::English) => "Destroy 100 asteroids in one run",
+        ("achievement.collector", Language::French) => "Collectionneur",
+        ("achievement.collector", Language::English) => "Collector",
+        ("achievement.collector.desc", Language::French) => "Ramasser 5 bonus en une partie",
+        ("achievement.collector.desc", Language::English) => "Collect 5 power-ups in one run",
+        ("achievement.boss_slayer", Language::French) => "Tombeur de géant",
+        ("achievement.boss_slayer", Language::English) => "Giant slayer",
+        ("achievement.boss_slayer.desc", Language::French) => "Détruire le vaisseau mère",
+        ("achievement.boss_slayer.desc", Language::English) => "Destroy the mothership",
+        ("gameover.time", Language::French) => "Temps de survie",
+        ("gameover.time", Language::English) => "Time survived",
+        ("gameover.shots", Language::French) => "Tirs",
+        ("gameover.shots", Language::English) => "Shots",
+        ("gameover.accuracy", Language::French) => "Précision",
+        ("gameover.accuracy", Language::English) => "Accuracy",
+        ("gameover.asteroids", Language::French) => "Asteroides (G/M/P)",
+        ("gameover.asteroids", Language::English) => "Asteroids (L/M/S)",
+        ("gameover.black_holes", Language::French) => "Trous noirs (détruits/créés)",
+        ("gameover.black_holes", Language::English) => "Black holes (destroyed/created)",
+        ("reason.asteroid_large", Language::French) => "grand astéroïde",
+        ("reason.asteroid_large", Language::English) => "large asteroid",
+        ("reason.asteroid_medium", Language::French) => "astéroïde moyen",
+        ("reason.asteroid_medium", Language::English) => "medium asteroid",
+        ("reason.asteroid_small", Language::French) => "petit astéroïde",
+        ("reason.asteroid_small", Language::English) => "small asteroid",
+        ("reason.black_hole", Language::French) => "trou noir",
+        ("reason.black_hole", Language::English) => "black hole",
+        ("reason.enemy_fire", Language::French) => "tir ennemi",
+        ("reason.enemy_fire", Language::English) => "enemy fire",
+        ("reason.mothership", Language::French) => "vaisseau mère",
+        ("reason.mothership", Language::English) => "mothership",
+        ("reason.comet", Language::French) => "comète",
+        ("reason.comet", Language::English) => "comet",
+        ("pause.manual", Language::French) => "Pause",
+        ("pause.manual", Language::English) => "Paused",
+        ("settings.difficulty", Language::French) => "Difficulté",
+        ("settings.difficulty", Language::English) => "Difficulty",
+        ("difficulty.easy", Language::French) => "Facile",
+        ("difficulty.easy", Language::English) => "Easy",
+        ("difficulty.normal", Language::French) => "Normal",
+        ("difficulty.normal", Language::English) => "Normal",
+        ("difficulty.hard", Language::French) => "Difficile",
+        ("difficulty.hard", Language::English) => "Hard",
+        ("settings.mode", Language::French) => "Mode",
+        ("settings.mode", Language::English) => "Mode",
+        ("mode.classic", Language::French) => "Classique",
+        ("mode.classic", Language::English) => "Classic",
+        ("mode.endless", Language::French) => "Sans fin",
+        ("mode.endless", Language::English) => "Endless",
+        ("upgrade.title", Language::French) => "Vague terminée ! Choisissez une amélioration",
+        ("upgrade.title", Language::English) => "Wave cleared! Choose an upgrade",
+        ("upgrade.missile_speed", Language::French) => "Missiles plus rapides (+10 %)",
+        ("upgrade.missile_speed", Language::English) => "Faster missiles (+10%)",
+        ("upgrade.fire_rate", Language::French) => "Recharge plus courte (-15 %)",
+        ("upgrade.fire_rate", Language::English) => "Shorter cooldown (-15%)",
+        ("upgrade.shield_charge", Language::French) => "Charge de bouclier (+1)",
+        ("upgrade.shield_charge", Language::English) => "Shield charge (+1)",
+        ("upgrade.turn_rate", Language::French) => "Rotation plus rapide (+10 %)",
+        ("upgrade.turn_rate", Language::English) => "Faster turning (+10%)",
+        ("quit.confirm", Language::French) => "Quitter la partie ?",
+        ("quit.confirm", Language::English) => "Quit the game?",
+        ("quit.yes", Language::French) => "Oui (O)",
+        ("quit.yes", Language::English) => "Yes (Y)",
+        ("quit.no", Language::French) => "Non (N)",
+        ("quit.no", Language::English) => "No (N)",
+        ("settings.title", Language::French) => "Réglages",
+        ("settings.title", Language::English) => "Settings",
+        ("settings.master_volume", Language::French) => "Volume général",
+        ("settings.master_volume", Language::English) => "Master volume",
+        ("settings.sfx_volume", Language::French) => "Effets sonores",
+        ("settings.sfx_volume", Language::English) => "Sound effects",
+        ("settings.music_volume", Language::French) => "Musique",
+        ("settings.music_volume", Language::English) => "Music",
+        ("settings.muted", Language::French) => "Son coupé (M)",
+        ("settings.muted", Language::English) => "Muted (M)",
+        ("settings.unmuted", Language::French) => "Son actif (M)",
+        ("settings.unmuted", Language::English) => "Sound on (M)",
+        ("settings.edge_indicators_on", Language::French) => "Alertes aux bords : oui",
+        ("settings.edge_indicators_on", Language::English) => "Edge warnings: on",
+        ("settings.edge_indicators_off", Language::French) => "Alertes aux bords : non",
+        ("settings.edge_indicators_off", Language::English) => "Edge warnings: off",
+        ("controls.title", Language::French) => "Contrôles",
+        ("controls.title", Language::English) => "Controls",
+        ("controls.rotate_left", Language::French) => "Tourner à gauche",
+        ("controls.rotate_left", Language::English) => "Rotate left",
+        ("controls.rotate_right", Language::French) => "Tourner à droite",
+        ("controls.rotate_right", Language::English) => "Rotate right",
+        ("controls.thrust_forward", Language::French) => "Avancer",
+        ("controls.thrust_forward", Language::English) => "Thrust",
+        ("controls.thrust_backward", Language::French) => "Reculer",
+        ("controls.thrust_backward", Language::English) => "Reverse",
+        ("controls.fire", Language::French) => "Tirer",
+        ("controls.fire", Language::English) => "Fire",
+        ("controls.pause", Language::French) => "Pause",
+        ("controls.pause", Language::English) => "Pause",
+        ("controls.bomb", Language::French) => "Bombe",
+        ("controls.bomb", Language::English) => "Bomb",
+        ("controls.waiting", Language::French) => "appuyez sur une touche",
+        ("controls.waiting", Language::English) => "press a key",
+        ("controls.arrows", Language::French) => "Flèches",
+        ("controls.arrows", Language::English) => "Arrow keys",
+        ("controls.zqsd", Language::French) => "ZQSD",
+        ("controls.zqsd", Language::English) => "ZQSD",
+        ("attract.title", Language::French) => "ASTEROIDS — DÉMONSTRATION",
+        ("attract.title", Language::English) => "ASTEROIDS — DEMO",
+        ("attract.press_key", Language::French) => "Appuyez sur une touche",
+        ("attract.press_key", Language::English) => "Press any key",
+        ("pause.idle", Language::French) => "Inactif — partie en pause",
+        ("pause.idle", Language::English) => "Idle — game paused",
+        ("gameover.new_record", Language::French) => "Nouveau record !",
+        ("gameover.new_record", Language::English) => "New record!",
+        ("gameover.high_scores", Language::French) => "Meilleurs scores",
+        ("gameover.high_scores", Language::English) => "High scores",
+        ("hud.score", Language::French) => "Score",
+        ("hud.score", Language::English) => "Score",
+        ("hud.player2", Language::French) => "J2",
+        ("hud.player2", Language::English) => "P2",
+        ("hud.wave", Language::French) => "Vague",
+        ("hud.wave", Language::English) => "Wave",
+        ("hud.time", Language::French) => "Temps",
+        ("hud.time", Language::English) => "Time",
+        ("hud.activity_empty", Language::French) => "Inactif : points ÷2",
+        ("hud.activity_empty", Language::English) => "Idle: half points",
+        ("hud.boss", Language::French) => "Vaisseau mère",
+        ("hud.boss", Language::English) => "Mothership",
+        ("hud.shield_restore", Language::French) => "Bouclier",
+        ("hud.shield_restore", Language::English) => "Shield",
+        ("hud.rapid_fire", Language::French) => "Tir rapide",
+        ("hud.rapid_fire", Language::English) => "Rapid fire",
+        ("hud.triple_shot", Language::French) => "Tir triple",
+        ("hud.triple_shot", Language::English) => "Triple shot",
+        ("hud.homing", Language::French) => "Tête chercheuse",
+        ("hud.homing", Language::English) => "Homing",
+        ("popup.absorbed", Language::French) => "absorbé",
+        ("popup.absorbed", Language::English) => "absorbed",
+        ("popup.shield_restore", Language::French) => "Bouclier!",
+        ("popup.shield_restore", Language::English) => "Shield!",
+        ("popup.rapid_fire", Language::French) => "Tir rapide!",
+        ("popup.rapid_fire", Language::English) => "Rapid fire!",
+        ("popup.triple_shot", Language::French) => "Tir triple!",
+        ("popup.triple_shot", Language::English) => "Triple shot!",
+        ("popup.homing", Language::French) => "Tête chercheuse!",
+        ("popup.homing", Language::English) => "Homing!",
+        ("victory.title", Language::French) => "VICTOIRE !",
+        ("victory.title", Language::English) => "VICTORY!",
+        ("victory.score", Language::French) => "Score final",
+        ("victory.score", Language::English) => "Final score",
+        ("victory.continue", Language::French) => "Continuer en mode sans fin",
+        ("victory.continue", Language::English) => "Continue in endless mode",
+        ("gameover.menu", Language::French) => "Menu",
+        ("gameover.menu", Language::English) => "Menu",
+        ("narration.focus", Language::French) => "Bouton",
+        ("narration.focus", Language::English) => "Button",
+        ("narration.activate", Language::French) => "Activé",
+        ("narration.activate", Language::English) => "Activated",
+        ("narration.wave", Language::French) => "Vague",
+        ("narration.wave", Language::English) => "Wave",
+        ("narration.game_over", Language::French) => "Partie terminée, score",
+        ("narration.game_over", Language::English) => "Game over, score",
+        ("settings.mouse_aim_on", Language::French) => "Visée : souris",
+        ("settings.mouse_aim_on", Language::English) => "Aim: mouse",
+        ("settings.mouse_aim_off", Language::French) => "Visée : clavier",
+        ("settings.mouse_aim_off", Language::English) => "Aim: keyboard",
+        ("settings.two_players_on", Language::French) => "2 Joueurs",
+        ("settings.two_players_on", Language::English) => "2 Players",
+        ("settings.two_players_off", Language::French) => "1 Joueur",
+        ("settings.two_players_off", Language::English) => "1 Player",
+        ("tip.title", Language::French) => "Astuce",
+        ("tip.title", Language::English) => "Tip",
+        ("tip.black_hole", Language::French) => "Les trous noirs se referment après quelques tirs",
+        ("tip.black_hole", Language::English) => "Black holes close after a few shots",
+        ("tip.wave_start", Language::French) => "Vous êtes invincible brièvement en début de vague",
+        ("tip.wave_start", Language::English) => "You are briefly invincible when a wave starts",
+        ("tip.accuracy", Language::French) => "Visez avant de tirer : chaque missile compte",
+        ("tip.accuracy", Language::English) => "Aim before you shoot: every missile counts",
+        ("session.title", Language::French) => "Résumé de la session",
+        ("session.title", Language::English) => "Session summary",
+        ("session.runs", Language::French) => "Parties jouées",
+        ("session.runs", Language::English) => "Runs played",
+        ("session.best_score", Language::French) => "Meilleur score",
+        ("session.best_score", Language::English) => "Best score",
+        ("session.asteroids_destroyed", Language::French) => "Asteroides détruits",
+        ("session.asteroids_destroyed", Language::English) => "Asteroids destroyed",
+        ("session.compare", Language::French) => "points par rapport à la dernière session",
+        ("session.compare", Language::English) => "points compared to the last session",
+        ("session.first", Language::French) => "Première session, pas encore de comparaison",
+        ("session.first", Language::English) => "First session, nothing to compare yet",
+        _ => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie qu'une clé connue est traduite dans les deux langues.
+    #[test]
+    fn test_known_key_is_translated() {
+        assert_eq!(
+            tr(Language::French, "caption.shield_lost"),
+            "bouclier perdu"
+        );
+        assert_eq!(tr(Language::English, "caption.shield_lost"), "shield lost");
+    }
+
+    /// Vérifie que le texte de chaque bonus ramassé est traduit dans les deux langues.
+    #[test]
+    fn test_power_up_labels_are_translated() {
+        for kind in asteroid::powerup::PowerUpKind::ALL {
+            for language in [Language::French, Language::English] {
+                assert_ne!(tr(language, kind.label()), kind.label());
+            }
+        }
+        assert_eq!(tr(Language::English, "popup.rapid_fire"), "Rapid fire!");
+    }
+
+    /// Vérifie le choix de la langue à partir du nom de la locale.
+    #[test]
+    fn test_language_from_locale_name() {
+        assert_eq!(Language::from_locale_name("en_US.UTF-8"), Language::English);
+        assert_eq!(Language::from_locale_name("fr_FR.UTF-8"), Language::French);
+        assert_eq!(Language::from_locale_name("C"), Language::French);
+    }
+
+    /// Vérifie qu'une clé inconnue est renvoyée telle quelle.
+    #[test]
+    fn test_unknown_key_falls_back_to_key() {
+        assert_eq!(tr(Language::French, "inconnu"), "inconnu");
+    }
+}
@@ -0,0 +1,80 @@
+//! Module pour le journal du jeu, écrit sur la sortie d'erreur avec la bibliothèque `log`.
+//! Le niveau est choisi par la variable d'environnement `RUST_LOG` : `off`, `error`, `warn`,
+//! `info` (par défaut), `debug` ou `trace`. Le niveau `info` suit les changements d'écran,
+//! les vagues et les fins de partie, le niveau `trace` ajoute chaque collision de la partie.
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Variable d'environnement qui choisit le niveau du journal
+const LOG_ENV: &str = "RUST_LOG";
+/// Niveau du journal quand la variable d'environnement est absente
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Info;
+
+/// Structure représentant le journal, qui écrit chaque message sur une ligne
+struct Logger;
+
+/// Le journal du jeu, installé une fois pour toutes au lancement
+static LOGGER: Logger = Logger;
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "[{:<5} {}] {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Fonction qui lit le niveau du journal
+/// # Arguments
+/// - `value`: la valeur de la variable d'environnement, ou `None` si elle est absente
+/// # Returns
+/// - `Result<LevelFilter, LevelFilter>`: le niveau demandé, ou le niveau par défaut
+///   en erreur si la valeur est illisible
+fn parse_level(value: Option<&str>) -> Result<LevelFilter, LevelFilter> {
+    match value {
+        None => Ok(DEFAULT_LEVEL),
+        Some(value) => value.trim().parse().map_err(|_| DEFAULT_LEVEL),
+    }
+}
+
+/// Installe le journal, au niveau choisi par la variable d'environnement `RUST_LOG`
+pub fn init() {
+    let value = std::env::var(LOG_ENV).ok();
+    let level = parse_level(value.as_deref());
+    if log::set_logger(&LOGGER).is_err() {
+        return;
+    }
+    log::set_max_level(level.unwrap_or_else(|level| level));
+    if level.is_err() {
+        log::warn!(
+            "Niveau de journal illisible pour {LOG_ENV} : {}, niveau {DEFAULT_LEVEL} utilisé",
+            value.unwrap_or_default()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que le niveau est lu sans tenir compte des majuscules,
+    /// et qu'une valeur absente ou illisible donne le niveau par défaut.
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(parse_level(Some("trace")), Ok(LevelFilter::Trace));
+        assert_eq!(parse_level(Some(" WARN ")), Ok(LevelFilter::Warn));
+        assert_eq!(parse_level(Some("off")), Ok(LevelFilter::Off));
+        assert_eq!(parse_level(None), Ok(DEFAULT_LEVEL));
+        assert_eq!(parse_level(Some("bavard")), Err(DEFAULT_LEVEL));
+    }
+}
@@ -1,833 +1,2401 @@
-//! Ce module contient les fonctionnalités principales pour gérer notre jeu
-
-use asteroid::Asteroid;
-use black_hole::BlackHole;
-use macroquad::audio::{load_sound, play_sound, PlaySoundParams, Sound};
+//! Ce module contient les fonctionnalités principales pour gérer notre jeu :
+//! les entrées du joueur, l'affichage, les sons et les écrans de menu.
+//! La logique de la partie se trouve dans la bibliothèque, dans le module `world`.
+
+use achievements::{
+    achievements_path, Achievement, AchievementEvent, AchievementToasts, AchievementTracker,
+    Achievements,
+};
+use asteroid::accounting::Accounting;
+use asteroid::assets::{set_assets_dir, Assets};
+use asteroid::asteroid::Asteroid;
+use asteroid::audio::SoundEvent;
+use asteroid::audio_manager::{AudioManager, Music};
+use asteroid::background::{draw_background, BackgroundMode};
+use asteroid::black_hole::BlackHole;
+use asteroid::decals::DecalLayer;
+use asteroid::edge_indicator::{draw_edge_indicators, edge_indicators};
+use asteroid::feedback::FeedbackProfile;
+use asteroid::game_mode::GameMode;
+use asteroid::missile::Missile;
+use asteroid::platform::CAN_QUIT;
+use asteroid::playfield::{
+    playfield_bounds, playfield_camera, shaken_camera, REFERENCE_RESOLUTION,
+};
+use asteroid::pool::Pool;
+use asteroid::powerup::PowerUp;
+use asteroid::remix::capture;
+use asteroid::replay::{FileRecorder, ReplayFrame, ReplayHeader, ReplayPlayer};
+use asteroid::run_stats::{self, RunStats};
+use asteroid::spaceship::{Spaceship, SpaceshipTextures};
+use asteroid::stellarobject::StellarObject;
+use asteroid::text::{ui_scale, Fonts, TextStyle};
+use asteroid::timestep::{FixedTimestep, FIXED_DT};
+use asteroid::ufo::Ufo;
+use asteroid::upgrade::{UpgradeKind, UPGRADE_CHOICES};
+use asteroid::world::{TemporaryText, World, WorldTextures};
+use attention::{AttentionTracker, PauseReason};
+use attract::{demo_world, AttractMode};
+use bindings::{bindings_path, key_name, Action, KeyBindings};
+use captions::CaptionStack;
+use cli::CliOptions;
+use display::{display_path, startup_display, window_icon, DisplaySettings};
+use fps::FpsCounter;
+use game_state::{GameState, RunSummary, SoundLatches, VictorySummary};
+use highscores::{highscores_path, stats_path, today, HighScoreEntry, HighScores};
+use hud::{draw_hud, HudData};
+use inspector::{draw_panel, EntityKind, Inspector};
+use locale::{tr, Language};
 use macroquad::prelude::*;
-use missile::Missile;
-use spaceship::Spaceship;
-use stellarobject::StellarObject;
-
-mod asteroid;
-mod black_hole;
-mod missile;
-mod spaceship;
-mod stellarobject;
-
-/// Énumération représentant les différents états du jeu.
-enum GameState {
-    StartScreen,
-    Playing,
-    GameOver,
-}
-/// Structure permettant de gérer les textes temporaires affichés à l'écran
-/// # Champs
-/// - `text`: le texte lui même
-/// - `position`: position du texte
-/// - `color`: couleur du texte à l'écran
-/// - `lifetime`: durée de vie du texte à l'écran
-struct TemporaryText {
-    text: String,
-    position: Vec2,
-    color: Color,
-    lifetime: f32, // Temps restant avant disparition
-}
-
-/// Charge les différents sons
-/// # Arguments
-/// - `Sound`: Référence à chaque son.
-/// # Returns
-/// - `asteroid_destroyed`, son pour l'asteroids détruit
-/// - `missile_sound`, son quand on se fait touché
-/// - `shield_lost`, son quand on perd le shield
-/// - `start_game`, son quand on lance la partie
-/// - `game_over`, son quand on perd la partie
-async fn load_sounds() -> (Sound, Sound, Sound, Sound, Sound, Sound) {
-    let asteroid_destroyed = load_sound("assets/audio/asteroid_destroyed.wav")
-        .await
-        .unwrap();
-    let shield_lost = load_sound("assets/audio/shield_lost.wav").await.unwrap();
-    let missile_sound = load_sound("assets/audio/missile_sound.wav").await.unwrap();
-    let start_game = load_sound("assets/audio/start_game.wav").await.unwrap();
-    let game_over = load_sound("assets/audio/game_over.wav").await.unwrap();
-    let new_wave = load_sound("assets/audio/new_wave.wav").await.unwrap();
-
-    (
-        asteroid_destroyed,
-        shield_lost,
-        missile_sound,
-        start_game,
-        game_over,
-        new_wave,
-    )
+use menu::{draw_button, draw_slider, slider_value, MenuLayout, BUTTON_SIZE};
+use narration::{Announcement, Narration};
+use profile::{profile_path, Profile};
+use radar::draw_radar;
+use session::{format_thousands, Session};
+use settings::Settings;
+use tips::{tip_for, MistakeTracker, Tip, TipEvent};
+use toast::Toast;
+use volume::{volume_path, VolumeSettings};
+
+mod achievements;
+mod attention;
+mod attract;
+mod bindings;
+mod captions;
+mod cli;
+mod display;
+mod fps;
+mod game_state;
+mod highscores;
+mod hud;
+mod inspector;
+mod locale;
+mod logger;
+mod menu;
+mod narration;
+mod profile;
+mod radar;
+mod session;
+mod settings;
+mod tips;
+mod toast;
+mod volume;
+
+/// Angle maximal (en radians) dont le vaisseau tourne à chaque image vers la souris
+const MOUSE_TURN_RATE: f32 = 0.1;
+
+/// Énumération représentant le choix du joueur sur un écran de menu
+#[derive(Clone, Copy, PartialEq)]
+enum MenuChoice {
+    Stay,
+    Play,
+    Continue,
+    Menu,
+    Controls,
+    Settings,
+    Achievements,
+    Quit,
 }
 
-/// Charge une texture d'arrière-plan du jeu.
-/// # Returns
-/// - `Texture2D` : Texture d'arrière-plan chargée.
-/// # Panics
-/// Panique si la texture ne peut pas être chargée.
-async fn load_background_texture() -> Texture2D {
-    let texture = load_texture("assets/background.png").await;
-    match texture {
-        Ok(tex) => tex,
-        Err(err) => {
-            eprintln!("Erreur lors du chargement de la texture : {:?}", err);
-            panic!("Échec du chargement de la texture");
-        }
+///Fonction qui dessine les asteroides
+/// # Arguments
+/// - `asteroids`: contient tous les asteroides du jeu
+/// - `bounds`: la taille de l'aire de jeu
+/// - `textures`: les textures des asteroides
+fn draw_asteroids(asteroids: &[Asteroid], bounds: Vec2, textures: &[Texture2D]) {
+    for asteroid in asteroids {
+        asteroid.draw(bounds, textures);
     }
 }
 
-/// Charge une texture d'arrière-plan pour l'écran de démarage.
-/// # Returns
-/// - `Texture2D` : Texture d'arrière-plan chargée.
-/// # Panics
-/// Panique si la texture ne peut pas être chargée.
-async fn load_background_texture_start() -> Texture2D {
-    let texture = load_texture("assets/background_start.png").await;
-    match texture {
-        Ok(tex) => tex,
-        Err(err) => {
-            eprintln!("Erreur lors du chargement de la texture : {:?}", err);
-            panic!("Échec du chargement de la texture");
-        }
+///Fonction qui dessine les bonus
+/// # Arguments
+/// - `power_ups`: contient tous les bonus du jeu
+fn draw_power_ups(power_ups: &[PowerUp]) {
+    for power_up in power_ups {
+        power_up.draw();
     }
 }
 
-/// Charge une texture d'arrière-plan pour l'écran de fin.
-/// # Returns
-/// - `Texture2D` : Texture d'arrière-plan chargée.
-/// # Panics
-/// Panique si la texture ne peut pas être chargée.
-async fn load_background_texture_dead() -> Texture2D {
-    let texture = load_texture("assets/background_dead.png").await;
-    match texture {
-        Ok(tex) => tex,
-        Err(err) => {
-            eprintln!("Erreur lors du chargement de la texture : {:?}", err);
-            panic!("Échec du chargement de la texture");
-        }
+///Fonction qui dessine les soucoupes
+/// # Arguments
+/// - `ufos`: contient toutes les soucoupes du jeu
+fn draw_ufos(ufos: &[Ufo]) {
+    for ufo in ufos {
+        ufo.draw();
     }
 }
 
-/// Dessine une texture en tant qu'arrière-plan.
+/// Fonction qui dessine tous les missiles
 /// # Arguments
-/// - `background_texture`: Référence à une texture d'arrière-plan à dessiner.
-fn draw_background(background_texture: &Texture2D) {
-    // effacer l'arriere plan avec une couleur unie
-    clear_background(BLACK);
-
-    // met à l'échelle par rapport à la taille de l'écran
-    let scale_x = screen_width() / background_texture.width();
-    let scale_y = screen_height() / background_texture.height();
+/// - `missiles`: contient tous les missiles du jeu
+/// - `texture`: la texture des missiles, si elle a pu être chargée
+fn draw_missiles(missiles: &[Missile], texture: Option<&Texture2D>) {
+    for missile in missiles.iter().filter(|m| m.active) {
+        missile.draw(texture);
+    }
+}
 
-    let scale = scale_x.max(scale_y);
+///Fonction qui gère le dessin des différents objets sur l'écran de jeu,
+/// avec la caméra de l'aire de jeu
+/// # Arguments
+/// - `world`: la partie, dont le vaisseau, les asteroides et les trous noirs sont dessinés
+fn draw(world: &World) {
+    world.spaceship.draw(world.bounds);
+    draw_asteroids(&world.asteroids, world.bounds, world.asteroid_textures());
 
-    // calculer la largeur et la hauteur de l'image redimensionnée
-    let width = background_texture.width() * scale;
-    let height = background_texture.height() * scale;
+    for blackhole in &world.black_holes {
+        blackhole.draw();
+    }
+}
 
-    // dessiner la texture redimensionnee pour qu'elle couvre l'ecran
-    draw_texture_ex(
-        background_texture, // Texture d'arrière-plan
-        0.0,
-        0.0,
+/// Dessine la partie de démonstration à la place de l'écran de démarrage,
+/// avec le titre du jeu et l'invitation à appuyer sur une touche
+/// # Arguments
+/// - `demo`: la partie de démonstration
+/// - `background`: le fond de l'écran de démarrage
+/// - `missile_texture`: la texture des missiles, si elle a pu être chargée
+/// - `language`: la langue des textes
+/// - `fonts`: les polices du jeu
+fn draw_demo(
+    demo: &World,
+    background: &Texture2D,
+    missile_texture: Option<&Texture2D>,
+    language: Language,
+    fonts: &Fonts,
+) {
+    draw_background(background);
+    set_camera(&playfield_camera(demo.bounds));
+    draw(demo);
+    draw_missiles(&demo.missiles, missile_texture);
+    draw_power_ups(&demo.power_ups);
+    demo.effects.draw();
+    set_default_camera();
+
+    fonts.draw_centered(
+        tr(language, "attract.title"),
+        screen_height() * 0.2,
+        TextStyle::Title,
         WHITE,
-        DrawTextureParams {
-            dest_size: Some(Vec2::new(width, height)), // Taille de destination
-            ..Default::default()
-        },
     );
+    // L'invitation clignote, comme sur les bornes d'arcade
+    if get_time().fract() < 0.6 {
+        fonts.draw_centered(
+            tr(language, "attract.press_key"),
+            screen_height() * 0.85,
+            TextStyle::Body,
+            WHITE,
+        );
+    }
 }
 
-///Fonction qui dessine les asteroides
+///Fonction qui lit les commandes du joueur pour un pas de la partie
 /// # Arguments
-/// - `asteroids`: contient tous les asteroides du jeu
-fn draw_asteroids(asteroids: &[Asteroid]) {
-    for asteroid in asteroids {
-        asteroid.draw();
+/// - `spaceship`: contient une instance du vaisseau, pour viser avec la souris
+///   et tourner à la vitesse de ses améliorations
+/// - `bindings`: les touches associées aux commandes
+/// - `aim`: la position de la souris dans le terrain de jeu, si le joueur vise avec la souris
+/// - `click_fire`: permet de savoir si le clic gauche peut tirer, ce qui n'est pas le cas
+///   juste après un écran de menu, pour que le clic sur un bouton ne tire pas
+/// # Returns
+/// - `ReplayFrame`: les commandes du pas, appliquées ensuite à la partie et enregistrées
+fn read_input(
+    spaceship: &Spaceship,
+    bindings: &KeyBindings,
+    aim: Option<Vec2>,
+    click_fire: bool,
+) -> ReplayFrame {
+    let turn = if let Some(target) = aim {
+        spaceship.turn_angle_towards(target, MOUSE_TURN_RATE * spaceship.upgrades.turn_rate)
+    } else {
+        let mut turn = 0.0;
+        if is_key_down(bindings.key(Action::RotateRight)) {
+            turn += spaceship.turn_rate();
+        }
+        if is_key_down(bindings.key(Action::RotateLeft)) {
+            turn -= spaceship.turn_rate();
+        }
+        turn
+    };
+    let mouse_fire = aim.is_some() && click_fire && is_mouse_button_pressed(MouseButton::Left);
+    ReplayFrame {
+        dt: FIXED_DT,
+        turn,
+        thrust_forward: is_key_down(bindings.key(Action::ThrustForward)),
+        thrust_backward: is_key_down(bindings.key(Action::ThrustBackward)),
+        fire: is_key_pressed(bindings.key(Action::Fire)) || mouse_fire,
+        bomb: is_key_pressed(bindings.key(Action::Bomb)),
+        pause: is_key_pressed(bindings.key(Action::Pause)),
+        // Échap ne quitte jamais directement : il demande d'abord confirmation
+        quit: is_key_pressed(KeyCode::Escape),
     }
 }
 
-///Fonction qui gère le dessin des différents objets sur l'écran de jeu
+///Fonction qui lit les commandes du second joueur pour l'image, en coopération :
+/// ZQSD (ou WASD) pour tourner et pousser, Ctrl gauche pour tirer. Le second joueur n'a pas de bombe.
 /// # Arguments
-/// - `spaceship`: contient l'objet vaisseau
-/// - `asteroids`: contient tous les objets Asteroids du jeu
-/// - `missiles`: contient tous les objets Missile du jeu
-/// - `wave`: correspond au numéro de vague
-/// - `score`: contient le score actuel du joueur
-/// - `temporary_texts`: contient tous nos textes temporaires
-/// - `black_holes`: contient tous nos trous noirs
-fn draw(
-    spaceship: &Spaceship,
-    asteroids: &[Asteroid],
-    missiles: &[Missile],
-    black_hole: &[BlackHole],
-    wave: u32,
-    score: i32,
-    temporary_texts: &[TemporaryText],
-) {
-    spaceship.draw();
-    draw_asteroids(asteroids);
-    for missile in missiles {
-        missile.draw();
+/// - `dt`: la durée de l'image
+/// - `turn_rate`: l'angle dont le vaisseau tourne à chaque image, accéléré par les améliorations
+/// # Returns
+/// - `ReplayFrame`: les commandes de l'image, appliquées ensuite au vaisseau du second joueur
+fn read_partner_input(dt: f32, turn_rate: f32) -> ReplayFrame {
+    let mut turn = 0.0;
+    if is_key_down(KeyCode::D) {
+        turn += turn_rate;
     }
-
-    for blackhole in black_hole {
-        blackhole.draw();
+    if is_key_down(KeyCode::Q) || is_key_down(KeyCode::A) {
+        turn -= turn_rate;
     }
+    ReplayFrame {
+        dt,
+        turn,
+        thrust_forward: is_key_down(KeyCode::Z) || is_key_down(KeyCode::W),
+        thrust_backward: is_key_down(KeyCode::S),
+        fire: is_key_pressed(KeyCode::LeftControl),
+        ..ReplayFrame::default()
+    }
+}
 
-    // Affichage du texte avec le numéro de vague
-    draw_text(&format!("Vague: {}", wave), 10.0, 20.0, 30.0, WHITE);
-
-    // Affichage du texte pour le score
-    draw_text(&format!("Score: {}", score), 10.0, 50.0, 30.0, WHITE);
+/// Commence l'enregistrement d'une partie qui vient d'être recommencée,
+/// si l'option `--record` est active
+/// # Arguments
+/// - `settings`: les réglages du jeu
+/// - `world`: la partie, juste recommencée
+/// # Returns
+/// - `Option<FileRecorder>`: l'enregistreur, ou `None` sans enregistrement
+fn start_recording(settings: &Settings, world: &mut World) -> Option<FileRecorder> {
+    let path = settings.record.as_ref()?;
+    world.spaceship.inherit_velocity = !settings.arcade_missiles;
+    FileRecorder::create(path, &ReplayHeader::from_world(world))
+        .map_err(|err| log::error!("Erreur lors de la création du replay : {:?}", err))
+        .ok()
+}
 
-    // Affichage du message "Touché!" au centre de l'écran
-    if spaceship.hit {
-        let text = "Touché!";
-        let text_size = 30.0;
-        let text_width = measure_text(text, None, text_size as u16, 1.0).width;
-        let x = (screen_width() - text_width) / 2.0;
-        let y = screen_height() / 2.0;
-        draw_text(text, x, y, text_size, RED);
+/// Termine l'enregistrement en cours, s'il y en a un, en écrivant la fin du replay
+/// # Arguments
+/// - `recorder`: l'enregistrement en cours
+fn finish_recording(recorder: &mut Option<FileRecorder>) {
+    if let Some(Err(err)) = recorder.take().map(FileRecorder::finish) {
+        log::error!("Erreur lors de l'écriture du replay : {:?}", err);
     }
+}
 
-    draw_temporary_texts(temporary_texts);
+/// Fonction qui indique si le joueur utilise une des commandes du jeu
+/// # Arguments
+/// - `bindings`: les touches associées aux commandes
+/// # Returns
+/// - `bool`: Retourne `true` si une touche ou un bouton utilisé par le jeu est enfoncé
+fn player_input_active(bindings: &KeyBindings) -> bool {
+    Action::ALL
+        .into_iter()
+        .any(|action| is_key_down(bindings.key(action)))
+        || is_mouse_button_down(MouseButton::Left)
 }
 
-///Fonction qui gère l'entrée des touches par le joueur
+/// Fonction qui rassemble tous les objets que l'inspecteur de développement peut sélectionner
+/// # Arguments
+/// - `spaceship`: contient une instance du vaisseau
+/// - `asteroids`: contient tous les asteroides du jeu
+/// - `missiles`: contient tous les missiles du jeu
+/// - `black_holes`: contient tous nos trous noirs
+/// - `ufos`: contient toutes les soucoupes
+/// - `power_ups`: contient tous les bonus
 /// # Returns
-/// - `bool`: Retourne `true` si la touche escape est appuyé sinon `false`.
-fn handle_input(
-    spaceship: &mut Spaceship,
-    missiles: &mut Vec<Missile>,
-    missile_sound: &Sound,
-) -> bool {
-    if is_key_down(KeyCode::Escape) {
-        return true;
-    }
+/// - `Vec<(EntityKind, &dyn StellarObject)>`: les objets avec leur type
+fn inspectable_entities<'a>(
+    spaceship: &'a Spaceship,
+    asteroids: &'a [Asteroid],
+    missiles: &'a [Missile],
+    black_holes: &'a [BlackHole],
+    ufos: &'a [Ufo],
+    power_ups: &'a [PowerUp],
+) -> Vec<(EntityKind, &'a dyn StellarObject)> {
+    let mut entities: Vec<(EntityKind, &dyn StellarObject)> =
+        vec![(EntityKind::Spaceship, spaceship)];
+    entities.extend(asteroids.iter().map(|a| (EntityKind::Asteroid, a as _)));
+    entities.extend(missiles.iter().map(|m| (EntityKind::Missile, m as _)));
+    entities.extend(black_holes.iter().map(|b| (EntityKind::BlackHole, b as _)));
+    entities.extend(ufos.iter().map(|u| (EntityKind::Ufo, u as _)));
+    entities.extend(power_ups.iter().map(|p| (EntityKind::PowerUp, p as _)));
+    entities
+}
 
-    if is_key_down(KeyCode::Right) {
-        spaceship.rotate(0.05);
-    }
-    if is_key_down(KeyCode::Left) {
-        spaceship.rotate(-0.05);
-    }
-    if is_key_down(KeyCode::Up) {
-        spaceship.apply_thrust(0.01);
+/// Dessine l'affichage de développement : le nombre d'objets, leurs compteurs et l'inspecteur.
+/// Un clic gauche sélectionne l'objet sous la souris, un clic dans le vide ferme le panneau.
+/// # Arguments
+/// - `inspector`: l'inspecteur qui garde l'objet sélectionné
+/// - `entities`: tous les objets inspectables
+/// - `accounting`: les compteurs des objets créés et supprimés
+/// - `camera`: la caméra de l'aire de jeu, utilisée pour dessiner l'affichage
+/// - `bounds`: la taille de l'aire de jeu
+/// - `fonts`: les polices du jeu
+fn draw_dev_overlay(
+    inspector: &mut Inspector,
+    entities: &[(EntityKind, &dyn StellarObject)],
+    accounting: &Accounting,
+    camera: &Camera2D,
+    bounds: Vec2,
+    fonts: &Fonts,
+) {
+    if is_mouse_button_pressed(MouseButton::Left) {
+        let point = camera.screen_to_world(mouse_position().into());
+        inspector.click(point, entities, bounds);
     }
-    if is_key_down(KeyCode::Down) {
-        spaceship.apply_thrust(-0.01);
+
+    set_camera(camera);
+    fonts.draw(
+        &format!("DEV - {} objets - F3 pour fermer", entities.len()),
+        10.0,
+        bounds.y - 10.0,
+        TextStyle::Popup,
+        YELLOW,
+    );
+    // Objets actifs et longueur de chaque liste : un écart qui dure signale une fuite
+    for (i, (name, counter)) in accounting.entries().into_iter().enumerate() {
+        fonts.draw(
+            &format!(
+                "{}: {} actifs / {} en liste (+{} -{})",
+                name, counter.active, counter.len, counter.spawned, counter.despawned
+            ),
+            10.0,
+            bounds.y - 30.0 - i as f32 * 16.0,
+            TextStyle::Popup,
+            YELLOW,
+        );
     }
-    if !is_key_down(KeyCode::Up) && !is_key_down(KeyCode::Down) {
-        // Ralentir progressivement
-        if spaceship.velocity.length() > 0.0 {
-            let direction = spaceship.velocity.normalize();
-            spaceship.velocity -= direction * 0.005;
-        }
+    if let Some(index) = inspector.track(entities, bounds) {
+        draw_panel(entities[index].1, fonts);
     }
-    if is_key_pressed(KeyCode::Space) {
-        let missile = Missile::new(spaceship.get_pos(), spaceship.rotation);
-        play_sound(
-            missile_sound,
-            PlaySoundParams {
-                looped: false,
-                volume: 0.5,
-            },
-        );
-        missiles.push(missile);
+    set_default_camera();
+}
+
+/// Dessine le cercle de collision de chaque objet, sous la caméra de l'aire de jeu,
+/// et une ligne dans la direction du vaisseau
+/// # Arguments
+/// - `world`: la partie en cours
+fn draw_hitboxes(world: &World) {
+    let entities = inspectable_entities(
+        &world.spaceship,
+        &world.asteroids,
+        &world.missiles,
+        &world.black_holes,
+        &world.ufos,
+        &world.power_ups,
+    );
+    for (kind, entity) in entities {
+        entity.debug_draw(kind.hitbox_color());
     }
+    let ship = &world.spaceship;
+    // La ligne dépasse du cercle d'un rayon, pour rester visible devant la texture
+    let pos = ship.get_pos();
+    let facing = pos + (ship.nose() - pos) * 2.0;
+    draw_line(pos.x, pos.y, facing.x, facing.y, 1.0, BLUE);
+}
 
-    false
+/// Fonction qui décrit l'occupation d'une réserve pour l'affichage de développement
+/// # Arguments
+/// - `pool`: la réserve
+/// # Returns
+/// - `String`: les objets vivants, les places déjà créées et le nombre maximal de places
+fn pool_usage<T>(pool: &Pool<T>) -> String {
+    format!(
+        "{} / {} / {}",
+        pool.len(),
+        pool.len() + pool.free(),
+        pool.max()
+    )
 }
 
-///Fonction qui met à jour le mouvement des différents objets
+/// Dessine les mesures de l'affichage de développement, en colonne dans le coin en haut à droite,
+/// sous le score : images par seconde, nombre d'objets et état du vaisseau
 /// # Arguments
-/// - `spaceship`: contient une instance du vaisseau
-/// - `asteroids`: contient une instance de tous les asteroids du jeu
-/// - `missiles`: contient une instance de tous les missiles du jeu
-/// - `black_holes`: contient tous nos trous noirs
-fn update_model(
-    spaceship: &mut Spaceship,
-    asteroids: &mut Vec<Asteroid>,
-    missiles: &mut Vec<Missile>,
-    black_holes: &mut Vec<BlackHole>,
-) {
-    for asteroid in asteroids.iter_mut() {
-        asteroid.move_obj(); // Utilisation trait
+/// - `world`: la partie en cours
+/// - `fps`: la mesure des images par seconde
+/// - `fonts`: les polices du jeu
+fn draw_frame_stats(world: &World, fps: &FpsCounter, fonts: &Fonts) {
+    let ship = &world.spaceship;
+    let (pos, velocity) = (ship.get_pos(), ship.velocity);
+    let lines = [
+        ("fps", format!("{:.0}", fps.fps())),
+        ("asteroids", world.asteroids.len().to_string()),
+        ("missiles", pool_usage(&world.missiles)),
+        ("black_holes", world.black_holes.len().to_string()),
+        ("texts", pool_usage(&world.temporary_texts)),
+        ("ship_pos", format!("{:.0}, {:.0}", pos.x, pos.y)),
+        ("ship_vel", format!("{:.1}, {:.1}", velocity.x, velocity.y)),
+        ("ship_rot", format!("{:.2}", ship.rotation)),
+        ("speed_x", format!("{:.2}", world.asteroid_speed_factor())),
+    ];
+    let right = screen_width() - 10.0;
+    let left = right - 220.0;
+    draw_rectangle(
+        left - 6.0,
+        100.0,
+        right - left + 12.0,
+        lines.len() as f32 * 18.0 + 8.0,
+        Color::new(0.0, 0.0, 0.0, 0.5),
+    );
+    for (i, (label, value)) in lines.iter().enumerate() {
+        let y = 120.0 + i as f32 * 18.0;
+        fonts.draw(label, left, y, TextStyle::Popup, YELLOW);
+        // Les valeurs sont alignées à droite, pour que les chiffres restent en colonne
+        let width = fonts.measure(value, TextStyle::Popup).width;
+        fonts.draw(value, right - width, y, TextStyle::Popup, YELLOW);
     }
-    asteroids.retain(|a| a.active);
+}
 
-    black_holes.retain(|b| b.active);
+/// Dessine un voile sombre sur la partie avec un message de pause au centre
+/// # Arguments
+/// - `message`: le message affiché
+/// - `fonts`: les polices du jeu
+fn draw_pause_overlay(message: &str, fonts: &Fonts) {
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_width(),
+        screen_height(),
+        Color::new(0.0, 0.0, 0.0, 0.6),
+    );
+    fonts.draw_centered(message, screen_height() / 2.0, TextStyle::Title, WHITE);
+}
 
-    spaceship.move_obj(); // Utilisation trait
+/// Dessine la demande de confirmation pour quitter la partie, par-dessus la partie en pause.
+/// Le joueur répond avec les boutons, ou avec les touches O (ou Y) et N ; Échap revient à la partie.
+/// # Arguments
+/// - `language`: la langue des textes
+/// - `narration`: le journal de narration, qui annonce les boutons
+/// - `fonts`: les polices du jeu
+/// # Returns
+/// - `Option<bool>`: `Some(true)` pour quitter la partie, `Some(false)` pour la reprendre,
+///   `None` tant que le joueur n'a pas répondu
+fn draw_quit_overlay(language: Language, narration: &mut Narration, fonts: &Fonts) -> Option<bool> {
+    draw_pause_overlay(tr(language, "quit.confirm"), fonts);
+    let center = vec2(screen_width(), screen_height()) / 2.0;
+    let yes_button = Rect::new(
+        center.x - BUTTON_SIZE.x - 10.0,
+        center.y + 40.0,
+        BUTTON_SIZE.x,
+        BUTTON_SIZE.y,
+    );
+    let no_button = Rect::new(
+        center.x + 10.0,
+        center.y + 40.0,
+        BUTTON_SIZE.x,
+        BUTTON_SIZE.y,
+    );
+    let yes_label = tr(language, "quit.yes");
+    let no_label = tr(language, "quit.no");
+    draw_button(yes_button, RED, yes_label, TextStyle::Heading, WHITE, fonts);
+    draw_button(no_button, GRAY, no_label, TextStyle::Heading, WHITE, fonts);
+    narration.menu(
+        "quit",
+        &[yes_button, no_button],
+        &[yes_label, no_label],
+        language,
+    );
 
-    for missile in missiles.iter_mut() {
-        missile.move_obj(); // Utilisation trait
+    let mouse_pos = mouse_position().into();
+    let clicked = is_mouse_button_pressed(MouseButton::Left);
+    if is_key_pressed(KeyCode::O)
+        || is_key_pressed(KeyCode::Y)
+        || (clicked && yes_button.contains(mouse_pos))
+    {
+        Some(true)
+    } else if is_key_pressed(KeyCode::N)
+        || is_key_pressed(KeyCode::Escape)
+        || (clicked && no_button.contains(mouse_pos))
+    {
+        Some(false)
+    } else {
+        None
     }
-    missiles.retain(|m| m.active);
 }
 
-///Fonction qui gère la collision entre deux objets
+/// Dessine le choix d'une amélioration entre deux vagues, par-dessus la partie en pause.
+/// Le joueur choisit avec les boutons, ou avec les touches 1, 2 et 3.
 /// # Arguments
-/// - `obj1`: contient un objet stellaire
-/// - `obj2`: contient un autre objet stellaire
+/// - `choices`: les améliorations proposées
+/// - `language`: la langue des textes
+/// - `narration`: le journal de narration, qui annonce les boutons
+/// - `fonts`: les polices du jeu
 /// # Returns
-/// - `bool`: Retourne `true` si il y a une collision sinon `false`.
-pub fn check_collision_between(obj1: &mut dyn StellarObject, obj2: &mut dyn StellarObject) -> bool {
-    let distance = obj1.get_pos().distance(obj2.get_pos());
-    let collision_distance = obj1.radius() + obj2.radius();
-
-    if distance < collision_distance {
-        obj1.handle_collision();
-        obj2.handle_collision();
-        return true;
+/// - `Option<usize>`: l'indice de l'amélioration choisie, `None` tant que le joueur n'a pas choisi
+fn draw_upgrade_overlay(
+    choices: &[UpgradeKind; UPGRADE_CHOICES],
+    language: Language,
+    narration: &mut Narration,
+    fonts: &Fonts,
+) -> Option<usize> {
+    draw_pause_overlay(tr(language, "upgrade.title"), fonts);
+    let center = vec2(screen_width(), screen_height()) / 2.0;
+    let size = vec2(BUTTON_SIZE.x * 1.5, BUTTON_SIZE.y);
+    let buttons: Vec<Rect> = (0..UPGRADE_CHOICES)
+        .map(|i| {
+            Rect::new(
+                center.x - size.x / 2.0,
+                center.y + 40.0 + i as f32 * (size.y + 10.0),
+                size.x,
+                size.y,
+            )
+        })
+        .collect();
+    let labels: Vec<String> = choices
+        .iter()
+        .enumerate()
+        .map(|(i, kind)| format!("{}. {}", i + 1, tr(language, kind.label_key())))
+        .collect();
+    for (button, label) in buttons.iter().zip(&labels) {
+        draw_button(*button, DARKBLUE, label, TextStyle::Body, WHITE, fonts);
     }
-    false
+    let label_refs: Vec<&str> = labels.iter().map(String::as_str).collect();
+    narration.menu("upgrade", &buttons, &label_refs, language);
+
+    let mouse_pos = mouse_position().into();
+    let clicked = is_mouse_button_pressed(MouseButton::Left);
+    let keys = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3];
+    (0..UPGRADE_CHOICES)
+        .find(|&i| is_key_pressed(keys[i]) || (clicked && buttons[i].contains(mouse_pos)))
 }
 
-/// Fonction qui gère toutes les collissions qui peuvent se produire dans le jeu.
+/// Gère l'affichage de l'écran de démarrage.
 /// # Arguments
-/// - `spaceship`: contient notre asteroid avec ses propriétés
-/// - `asteroids`: contient tous nos asteroid
-/// - `missiles`: contient tous nos missiles
-/// - `black_holes`: contient tous les trous noirs
-/// - `score`: contient le score actuel du joueur
-/// - `shield_lost`: son quand on perd notre bouclier
-/// - `asteroid_destroyed`: son quand on détruit un asteroid
-/// - `temporary_texts`: contient tous nos textes temporaires pour afficher le score
+/// - `background_texture_start`: Texture d'arrière-plan pour l'écran de démarrage.
+/// - `settings`: les réglages du jeu, modifiables depuis cet écran
+/// - `remix_available`: indique si un champ de la partie précédente peut être repris
+/// - `fonts`: les polices du jeu
 /// # Returns
-/// - `bool`: Retourne `true` si il y a une collision sinon `false`.
-async fn check_collision(
-    spaceship: &mut Spaceship,
-    asteroids: &mut Vec<Asteroid>,
-    missiles: &mut [Missile],
-    black_holes: &mut Vec<BlackHole>,
-    score: &mut i32,
-    shield_lost: &Sound,
-    asteroid_destroyed: &Sound,
-    temporary_texts: &mut Vec<TemporaryText>,
-) -> bool {
-    if spaceship.invincible {
-        return false;
-    }
-
-    let mut asteroids_to_split = Vec::new();
-
-    // Collision entre Asteroids et  SpaceShip
-    for asteroid in asteroids.iter_mut() {
-        if check_collision_between(asteroid, spaceship) {
-            if spaceship.active {
-                play_sound(
-                    shield_lost,
-                    PlaySoundParams {
-                        looped: false,
-                        volume: 1.5,
-                    },
-                );
-                *score -= 5;
-                temporary_texts.push(TemporaryText {
-                    text: "-5".to_string(),
-                    position: spaceship.get_pos() + Vec2::new(20.0, 20.0),
-                    color: RED,
-                    lifetime: 1.0,
-                });
+/// - `MenuChoice`: `Play` si l'utilisateur commence la partie, `Quit` s'il quitte le jeu,
+///   `Controls` ou `Settings` pour ouvrir l'écran des contrôles ou des réglages, sinon `Stay`.
+async fn draw_start_screen(
+    background_texture_start: &Texture2D,
+    settings: &mut Settings,
+    remix_available: bool,
+    narration: &mut Narration,
+    fonts: &Fonts,
+) -> MenuChoice {
+    draw_background(background_texture_start);
 
-                black_holes
-                    .push(BlackHole::new(asteroid.get_pos(), asteroid.get_size().scale()).await);
+    let screen = vec2(screen_width(), screen_height());
+    let center = (screen - BUTTON_SIZE) / 2.0;
+    let button =
+        |dx: f32, dy: f32| Rect::new(center.x + dx, center.y + dy, BUTTON_SIZE.x, BUTTON_SIZE.y);
+    let layout = MenuLayout::new(
+        screen,
+        screen.y * 0.2,
+        vec![
+            button(0.0, -50.0),
+            button(BUTTON_SIZE.x + 20.0, -50.0),
+            button(0.0, 50.0),
+            button(0.0, 120.0),
+            button(0.0, 180.0),
+            button(0.0, 240.0),
+            button(BUTTON_SIZE.x + 20.0, 120.0),
+            button(BUTTON_SIZE.x + 20.0, 180.0),
+            button(BUTTON_SIZE.x + 20.0, 240.0),
+            button(BUTTON_SIZE.x + 20.0, 50.0),
+            button(-BUTTON_SIZE.x - 20.0, -50.0),
+            button(-BUTTON_SIZE.x - 20.0, 50.0),
+            button(-BUTTON_SIZE.x - 20.0, 120.0),
+        ],
+    );
+    let [play_button, remix_button, quit_button, captions_button, background_button, feedback_button, aim_button, controls_button, difficulty_button, settings_button, achievements_button, players_button, mode_button] =
+        layout.buttons[..]
+    else {
+        unreachable!("Le menu de démarrage a treize boutons");
+    };
+
+    let title_y = if layout.compact {
+        screen.y * 0.2 - 10.0
+    } else {
+        center.y - 150.0
+    };
+    fonts.draw_centered(
+        "Asteroids Game",
+        title_y,
+        layout.label_style(TextStyle::Title),
+        WHITE,
+    );
 
-                return false;
-            } else {
-                return true;
-            }
-        }
+    let heading = layout.label_style(TextStyle::Heading);
+    let body = layout.label_style(TextStyle::Body);
+    draw_button(play_button, GREEN, "Jouer", heading, WHITE, fonts);
+    let remix_label = if settings.remix && remix_available {
+        "settings.remix_on"
+    } else {
+        "settings.remix_off"
+    };
+    draw_button(
+        remix_button,
+        if remix_available { GRAY } else { DARKGRAY },
+        tr(settings.language, remix_label),
+        body,
+        if remix_available { WHITE } else { GRAY },
+        fonts,
+    );
+    if CAN_QUIT {
+        draw_button(quit_button, RED, "Quitter", heading, WHITE, fonts);
     }
-    // Collision entre Trou Noir et Spaceship
-    for black_hole in black_holes.iter_mut() {
-        if check_collision_between(black_hole, spaceship) {
-            return true;
+    let captions_label = if settings.captions {
+        "settings.captions_on"
+    } else {
+        "settings.captions_off"
+    };
+    draw_button(
+        captions_button,
+        GRAY,
+        tr(settings.language, captions_label),
+        body,
+        WHITE,
+        fonts,
+    );
+    let background_label = match settings.background_mode {
+        BackgroundMode::Cover => "settings.background_cover",
+        BackgroundMode::Tiled => "settings.background_tiled",
+    };
+    draw_button(
+        background_button,
+        GRAY,
+        tr(settings.language, background_label),
+        body,
+        WHITE,
+        fonts,
+    );
+    draw_button(
+        feedback_button,
+        GRAY,
+        tr(settings.language, settings.feedback.key()),
+        body,
+        WHITE,
+        fonts,
+    );
+    let aim_label = if settings.mouse_aim {
+        "settings.mouse_aim_on"
+    } else {
+        "settings.mouse_aim_off"
+    };
+    draw_button(
+        aim_button,
+        GRAY,
+        tr(settings.language, aim_label),
+        body,
+        WHITE,
+        fonts,
+    );
+    draw_button(
+        controls_button,
+        GRAY,
+        tr(settings.language, "controls.title"),
+        body,
+        WHITE,
+        fonts,
+    );
+    let difficulty_label = format!(
+        "{}: {}",
+        tr(settings.language, "settings.difficulty"),
+        tr(settings.language, settings.difficulty.key())
+    );
+    draw_button(
+        difficulty_button,
+        GRAY,
+        &difficulty_label,
+        body,
+        WHITE,
+        fonts,
+    );
+    draw_button(
+        settings_button,
+        GRAY,
+        tr(settings.language, "settings.title"),
+        body,
+        WHITE,
+        fonts,
+    );
+    draw_button(
+        achievements_button,
+        GRAY,
+        tr(settings.language, "achievement.title"),
+        body,
+        WHITE,
+        fonts,
+    );
+    let players_label = if settings.two_players {
+        "settings.two_players_on"
+    } else {
+        "settings.two_players_off"
+    };
+    draw_button(
+        players_button,
+        GRAY,
+        tr(settings.language, players_label),
+        body,
+        WHITE,
+        fonts,
+    );
+    let mode_label = format!(
+        "{}: {}",
+        tr(settings.language, "settings.mode"),
+        tr(settings.language, settings.mode.key())
+    );
+    draw_button(mode_button, GRAY, &mode_label, body, WHITE, fonts);
+    narration.menu(
+        "start",
+        &layout.buttons,
+        &[
+            "Jouer",
+            tr(settings.language, remix_label),
+            "Quitter",
+            tr(settings.language, captions_label),
+            tr(settings.language, background_label),
+            tr(settings.language, settings.feedback.key()),
+            tr(settings.language, aim_label),
+            tr(settings.language, "controls.title"),
+            &difficulty_label,
+            tr(settings.language, "settings.title"),
+            tr(settings.language, "achievement.title"),
+            tr(settings.language, players_label),
+            &mode_label,
+        ],
+        settings.language,
+    );
+
+    if is_mouse_button_pressed(MouseButton::Left) {
+        let mouse_pos = mouse_position().into();
+        if play_button.contains(mouse_pos) {
+            return MenuChoice::Play; // Start the game
+        } else if CAN_QUIT && quit_button.contains(mouse_pos) {
+            return MenuChoice::Quit; // Quit the game
+        } else if captions_button.contains(mouse_pos) {
+            settings.captions = !settings.captions;
+        } else if background_button.contains(mouse_pos) {
+            settings.background_mode = match settings.background_mode {
+                BackgroundMode::Cover => BackgroundMode::Tiled,
+                BackgroundMode::Tiled => BackgroundMode::Cover,
+            };
+        } else if feedback_button.contains(mouse_pos) {
+            settings.feedback = settings.feedback.next();
+        } else if aim_button.contains(mouse_pos) {
+            settings.mouse_aim = !settings.mouse_aim;
+        } else if difficulty_button.contains(mouse_pos) {
+            settings.difficulty = settings.difficulty.next();
+        } else if controls_button.contains(mouse_pos) {
+            return MenuChoice::Controls;
+        } else if settings_button.contains(mouse_pos) {
+            return MenuChoice::Settings;
+        } else if achievements_button.contains(mouse_pos) {
+            return MenuChoice::Achievements;
+        } else if players_button.contains(mouse_pos) {
+            settings.two_players = !settings.two_players;
+        } else if mode_button.contains(mouse_pos) {
+            settings.mode = settings.mode.next();
+        } else if remix_available && remix_button.contains(mouse_pos) {
+            settings.remix = !settings.remix;
         }
     }
-    // Collision entre Asteroids et Trou Noir
-    for asteroid in asteroids.iter_mut() {
-        for black_hole in black_holes.iter_mut() {
-            if check_collision_between(asteroid, black_hole) {
-                play_sound(
-                    asteroid_destroyed,
-                    PlaySoundParams {
-                        looped: false,
-                        volume: 0.7,
-                    },
-                );
-            }
-        }
+
+    MenuChoice::Stay
+}
+
+/// Gère l'affichage de l'écran de fin.
+/// # Arguments
+/// - `background_texture_dead`: Texture d'arrière-plan pour l'écran de fin.
+/// - `summary`: le résumé de la partie terminée
+/// - `profile`: le profil du joueur, pour afficher ce qui le tue le plus souvent
+/// - `high_scores`: le tableau des meilleurs scores
+/// - `language`: la langue des textes
+/// - `fonts`: les polices du jeu
+/// # Returns
+/// - `MenuChoice`: `Play` pour relancer la partie, `Menu` pour revenir à l'écran de démarrage,
+///   `Quit` pour quitter le jeu, sinon `Stay`.
+async fn draw_game_over_screen(
+    background_texture_dead: &Texture2D,
+    summary: &RunSummary,
+    profile: &Profile,
+    high_scores: &HighScores,
+    language: Language,
+    narration: &mut Narration,
+    fonts: &Fonts,
+) -> MenuChoice {
+    let reason = summary.reason;
+    draw_background(background_texture_dead);
+
+    let screen = vec2(screen_width(), screen_height());
+    let center = (screen - BUTTON_SIZE) / 2.0;
+    let button = |dy: f32| Rect::new(center.x, center.y + dy, BUTTON_SIZE.x, BUTTON_SIZE.y);
+    let layout = MenuLayout::new(
+        screen,
+        screen.y * 0.35,
+        vec![button(-50.0), button(10.0), button(70.0)],
+    );
+    let [replay_button, menu_button, quit_button] = layout.buttons[..] else {
+        unreachable!("L'écran de fin a trois boutons");
+    };
+    // Sans la place habituelle, seuls le titre, la raison et les boutons sont affichés
+    let (title_y, reason_y) = if layout.compact {
+        (screen.y * 0.15, screen.y * 0.27)
+    } else {
+        (center.y - 150.0, center.y - 110.0)
+    };
+
+    if summary.new_record && !layout.compact {
+        fonts.draw_centered(
+            tr(language, "gameover.new_record"),
+            center.y - 190.0,
+            TextStyle::Heading,
+            GOLD,
+        );
     }
-    // Collision entre Trou Noir et missiles
-    for black_hole in black_holes.iter_mut() {
-        for j in (0..missiles.len()).rev() {
-            let missile = &mut missiles[j];
-            if check_collision_between(missile, black_hole) && !black_hole.active {
-                temporary_texts.push(TemporaryText {
-                    text: "+10".to_string(),
-                    position: black_hole.get_pos() + Vec2::new(20.0, 20.0),
-                    color: GREEN,
-                    lifetime: 0.4,
-                });
-                *score += 10;
-            }
-        }
+    fonts.draw_centered(
+        "Game Over",
+        title_y,
+        layout.label_style(TextStyle::Title),
+        WHITE,
+    );
+
+    // Affichage de la raison de la destruction, précédée d'une petite icône
+    let reason_text = format!(
+        "{}: {}",
+        tr(language, "gameover.destroyed_by"),
+        tr(language, reason.key())
+    );
+    let body = layout.label_style(TextStyle::Body);
+    let reason_width = fonts.measure(&reason_text, body).width;
+    fonts.draw_centered(&reason_text, reason_y, body, WHITE);
+    draw_circle(
+        (screen.x - reason_width) / 2.0 - 16.0,
+        reason_y - 8.0,
+        8.0,
+        reason.icon_color(),
+    );
+
+    let heading = layout.label_style(TextStyle::Heading);
+    draw_button(replay_button, GREEN, "Rejouer", heading, WHITE, fonts);
+    draw_button(
+        menu_button,
+        GRAY,
+        tr(language, "gameover.menu"),
+        heading,
+        WHITE,
+        fonts,
+    );
+    if CAN_QUIT {
+        draw_button(quit_button, RED, "Quitter", heading, WHITE, fonts);
     }
-    // Collision entre Asteroids et missiles
-    for asteroid in asteroids.iter_mut() {
-        for j in (0..missiles.len()).rev() {
-            let missile = &mut missiles[j];
-            if check_collision_between(asteroid, missile) {
-                play_sound(
-                    asteroid_destroyed,
-                    PlaySoundParams {
-                        looped: false,
-                        volume: 0.7,
-                    },
-                );
-                temporary_texts.push(TemporaryText {
-                    text: "+1".to_string(),
-                    position: asteroid.get_pos() + Vec2::new(20.0, 20.0),
-                    color: GREEN,
-                    lifetime: 0.4,
-                });
-                *score += 1;
 
-                if let Some((child1, child2)) = asteroid.split() {
-                    asteroids_to_split.push(child1);
-                    asteroids_to_split.push(child2);
-                }
-                break;
-            }
+    if !layout.compact {
+        // Le mode sans fin n'a pas de vagues : son nom les remplace
+        let progress = if summary.mode.has_waves() {
+            format!("{}: {}", tr(language, "gameover.wave"), summary.wave)
+        } else {
+            tr(language, summary.mode.key()).to_string()
+        };
+        let mut details = format!(
+            "{}  -  {}: {}",
+            progress,
+            tr(language, "gameover.seed"),
+            summary.seed
+        );
+        if let Some((nemesis, count)) = profile.most_frequent_death() {
+            details.push_str(&format!(
+                "  -  {}: {} ({})",
+                tr(language, "gameover.nemesis"),
+                tr(language, nemesis),
+                count
+            ));
+        }
+        fonts.draw_centered(&details, center.y - 80.0, TextStyle::Body, LIGHTGRAY);
+
+        // Affichage des statistiques de la partie sous les boutons
+        for (i, line) in stats_lines(&summary.stats, language).iter().enumerate() {
+            fonts.draw_centered(
+                line,
+                center.y + 144.0 + i as f32 * 24.0,
+                TextStyle::Body,
+                LIGHTGRAY,
+            );
+        }
+
+        // Puis du tableau des meilleurs scores
+        fonts.draw_centered(
+            &format!(
+                "{}  -  {}",
+                tr(language, "gameover.high_scores"),
+                tr(language, summary.mode.key())
+            ),
+            center.y + 200.0,
+            TextStyle::Body,
+            GOLD,
+        );
+        for (i, entry) in high_scores.entries(summary.mode).enumerate() {
+            let wave = if entry.mode.has_waves() {
+                format!("  -  {} {}", tr(language, "hud.wave"), entry.wave)
+            } else {
+                String::new()
+            };
+            let line = format!(
+                "{}. {}{}  -  {}  -  {}",
+                i + 1,
+                entry.score,
+                wave,
+                tr(language, entry.difficulty.key()),
+                entry.date
+            );
+            fonts.draw_centered(
+                &line,
+                center.y + 228.0 + i as f32 * 24.0,
+                TextStyle::Body,
+                WHITE,
+            );
         }
     }
 
-    // Ajouter les nouveaux astéroïdes qui sortent d'un split avec missile à la liste asteroids
-    asteroids.extend(asteroids_to_split);
+    narration.menu(
+        "game_over",
+        &layout.buttons,
+        &["Rejouer", tr(language, "gameover.menu"), "Quitter"],
+        language,
+    );
+    if is_mouse_button_pressed(MouseButton::Left) {
+        let mouse_pos = mouse_position().into();
+        if replay_button.contains(mouse_pos) {
+            return MenuChoice::Play; // Restart the game
+        } else if menu_button.contains(mouse_pos) {
+            return MenuChoice::Menu; // Back to the start screen
+        } else if CAN_QUIT && quit_button.contains(mouse_pos) {
+            return MenuChoice::Quit; // Quit the game
+        }
+    }
 
-    false
+    MenuChoice::Stay
 }
 
-/// Gère le centrage du texte en fonction de la taille de la fenêtre
+/// Fonction qui met en forme les statistiques d'une partie pour l'écran de fin
 /// # Arguments
-/// - `text`: le texte.
-/// - `y`: la hauteur.
-/// - `font_size`: la taille de la police.
-/// - `color`: la couleur du texte.
-fn draw_centered_text(text: &str, y: f32, font_size: f32, color: Color) {
-    let text_width = measure_text(text, None, font_size as u16, 1.0).width;
-    let x = (screen_width() - text_width) / 2.0;
-    draw_text(text, x, y, font_size, color);
+/// - `stats`: les statistiques de la partie
+/// - `language`: la langue des textes
+/// # Returns
+/// - `[String; 2]`: les tirs et le temps de survie, puis les objets détruits
+fn stats_lines(stats: &RunStats, language: Language) -> [String; 2] {
+    let accuracy = stats
+        .accuracy()
+        .map_or_else(|| "-".to_string(), |accuracy| format!("{accuracy:.0} %"));
+    let [large, medium, small] = stats.asteroids_destroyed;
+    [
+        format!(
+            "{}: {:.0} s  -  {}: {}  -  {}: {}",
+            tr(language, "gameover.time"),
+            stats.time_survived,
+            tr(language, "gameover.shots"),
+            stats.missiles_fired,
+            tr(language, "gameover.accuracy"),
+            accuracy
+        ),
+        format!(
+            "{}: {large}/{medium}/{small}  -  {}: {}/{}",
+            tr(language, "gameover.asteroids"),
+            tr(language, "gameover.black_holes"),
+            stats.black_holes_destroyed,
+            stats.black_holes_created
+        ),
+    ]
 }
 
-/// Gère l'affichage de l'écran de démarrage.
+/// Gère l'affichage de l'écran de victoire, après la destruction du vaisseau mère.
 /// # Arguments
-/// - `background_texture_start`: Texture d'arrière-plan pour l'écran de démarrage.
+/// - `background_texture`: Texture d'arrière-plan pour l'écran de victoire.
+/// - `summary`: le résumé de la partie gagnée
+/// - `language`: la langue des textes
+/// - `fonts`: les polices du jeu
 /// # Returns
-/// - `bool`: Retourne `true` si l'utilisateur commence la partie, sinon `false`.
-async fn draw_start_screen(background_texture_start: &Texture2D) -> bool {
-    draw_background(background_texture_start);
-
-    let button_width = 200.0;
-    let button_height = 50.0;
-    let center_x = (screen_width() - button_width) / 2.0;
-    let center_y = (screen_height() - button_height) / 2.0;
-
-    draw_centered_text("Asteroids Game", center_y - 150.0, 40.0, WHITE);
-
-    let play_button = Rect::new(center_x, center_y - 50.0, button_width, button_height);
-    let quit_button = Rect::new(center_x, center_y + 50.0, button_width, button_height);
+/// - `MenuChoice`: `Continue` pour continuer en mode sans fin, `Menu` pour revenir à l'écran de démarrage,
+///   `Quit` pour quitter le jeu, sinon `Stay`.
+async fn draw_victory_screen(
+    background_texture: &Texture2D,
+    summary: &VictorySummary,
+    language: Language,
+    narration: &mut Narration,
+    fonts: &Fonts,
+) -> MenuChoice {
+    draw_background(background_texture);
+
+    let screen = vec2(screen_width(), screen_height());
+    let center = (screen - BUTTON_SIZE) / 2.0;
+    let button = |dy: f32| {
+        Rect::new(
+            center.x - BUTTON_SIZE.x / 2.0,
+            center.y + dy,
+            BUTTON_SIZE.x * 2.0,
+            BUTTON_SIZE.y,
+        )
+    };
+    let layout = MenuLayout::new(
+        screen,
+        screen.y * 0.35,
+        vec![button(-10.0), button(50.0), button(110.0)],
+    );
+    let [continue_button, menu_button, quit_button] = layout.buttons[..] else {
+        unreachable!("L'écran de victoire a trois boutons");
+    };
+    let (title_y, score_y) = if layout.compact {
+        (screen.y * 0.15, screen.y * 0.27)
+    } else {
+        (center.y - 150.0, center.y - 100.0)
+    };
+
+    if summary.new_record && !layout.compact {
+        fonts.draw_centered(
+            tr(language, "gameover.new_record"),
+            center.y - 190.0,
+            TextStyle::Heading,
+            GOLD,
+        );
+    }
+    fonts.draw_centered(
+        tr(language, "victory.title"),
+        title_y,
+        layout.label_style(TextStyle::Title),
+        GOLD,
+    );
+    fonts.draw_centered(
+        &format!("{}: {}", tr(language, "victory.score"), summary.score),
+        score_y,
+        layout.label_style(TextStyle::Heading),
+        WHITE,
+    );
+    if !layout.compact {
+        let details = format!(
+            "{}: {}  -  {}: {}",
+            tr(language, "gameover.wave"),
+            summary.wave,
+            tr(language, "session.asteroids_destroyed"),
+            summary.asteroids_destroyed
+        );
+        fonts.draw_centered(&details, center.y - 60.0, TextStyle::Body, LIGHTGRAY);
+    }
 
-    draw_rectangle(
-        play_button.x,
-        play_button.y,
-        play_button.w,
-        play_button.h,
+    let heading = layout.label_style(TextStyle::Heading);
+    let body = layout.label_style(TextStyle::Body);
+    draw_button(
+        continue_button,
         GREEN,
+        tr(language, "victory.continue"),
+        body,
+        WHITE,
+        fonts,
     );
-    draw_centered_text("Jouer", play_button.y + 35.0, 30.0, WHITE);
-    draw_rectangle(
-        quit_button.x,
-        quit_button.y,
-        quit_button.w,
-        quit_button.h,
-        RED,
+    draw_button(
+        menu_button,
+        GRAY,
+        tr(language, "gameover.menu"),
+        heading,
+        WHITE,
+        fonts,
     );
-    draw_centered_text("Quitter", quit_button.y + 35.0, 30.0, WHITE);
+    if CAN_QUIT {
+        draw_button(quit_button, RED, "Quitter", heading, WHITE, fonts);
+    }
 
+    narration.menu(
+        "victory",
+        &layout.buttons,
+        &[
+            tr(language, "victory.continue"),
+            tr(language, "gameover.menu"),
+            "Quitter",
+        ],
+        language,
+    );
     if is_mouse_button_pressed(MouseButton::Left) {
         let mouse_pos = mouse_position().into();
-        if play_button.contains(mouse_pos) {
-            return true; // Start the game
-        } else if quit_button.contains(mouse_pos) {
-            std::process::exit(0); // Quit the game
+        if continue_button.contains(mouse_pos) {
+            return MenuChoice::Continue;
+        } else if menu_button.contains(mouse_pos) {
+            return MenuChoice::Menu;
+        } else if CAN_QUIT && quit_button.contains(mouse_pos) {
+            return MenuChoice::Quit;
         }
     }
 
-    false
+    MenuChoice::Stay
 }
 
-/// Gère l'affichage de l'écran de fin.
+/// Gère l'affichage de l'écran des contrôles, où le joueur choisit la touche de chaque commande.
+/// Un clic sur une commande attend la prochaine touche, que la touche Échap annule.
 /// # Arguments
-/// - `background_texture_start`: Texture d'arrière-plan pour l'écran de démarrage.
+/// - `background_texture`: Texture d'arrière-plan pour l'écran des contrôles.
+/// - `bindings`: les touches associées aux commandes, modifiables depuis cet écran
+/// - `waiting`: la commande qui attend sa nouvelle touche, s'il y en a une
+/// - `language`: la langue des textes
+/// - `narration`: le journal de narration
+/// - `fonts`: les polices du jeu
 /// # Returns
-/// - `bool`: Retourne `true` si l'utilisateur relance la partie, sinon `false`.
-async fn draw_game_over_screen(background_texture_dead: &Texture2D) -> bool {
-    draw_background(background_texture_dead);
+/// - `MenuChoice`: `Menu` pour revenir à l'écran de démarrage, sinon `Stay`.
+async fn draw_controls_screen(
+    background_texture: &Texture2D,
+    bindings: &mut KeyBindings,
+    waiting: &mut Option<Action>,
+    language: Language,
+    narration: &mut Narration,
+    fonts: &Fonts,
+) -> MenuChoice {
+    draw_background(background_texture);
+
+    if let Some(action) = *waiting {
+        if let Some(key) = get_last_key_pressed() {
+            if key == KeyCode::Escape {
+                *waiting = None;
+            } else if key_name(key).is_some() {
+                bindings.bind(action, key);
+                *waiting = None;
+            }
+        }
+    }
+
+    let screen = vec2(screen_width(), screen_height());
+    let center = screen / 2.0;
+    let top = (center.y - 190.0).max(0.0);
+    let action_button = |i: usize| Rect::new(center.x - 260.0, top + i as f32 * 60.0, 300.0, 50.0);
+    let side_button = |i: usize| {
+        Rect::new(
+            center.x + 60.0,
+            top + i as f32 * 60.0,
+            BUTTON_SIZE.x,
+            BUTTON_SIZE.y,
+        )
+    };
+    let mut normal: Vec<Rect> = (0..Action::ALL.len()).map(action_button).collect();
+    normal.extend((0..3).map(side_button));
+    let layout = MenuLayout::new(screen, screen.y * 0.12, normal);
+    let (action_buttons, side_buttons) = layout.buttons.split_at(Action::ALL.len());
+    let [arrows_button, zqsd_button, back_button] = side_buttons[..] else {
+        unreachable!("L'écran des contrôles a trois boutons à droite");
+    };
+
+    if !layout.compact {
+        fonts.draw_centered(
+            tr(language, "controls.title"),
+            top - 30.0,
+            TextStyle::Title,
+            WHITE,
+        );
+    }
+    let body = layout.label_style(TextStyle::Body);
+    let mut labels: Vec<String> = Action::ALL
+        .into_iter()
+        .map(|action| {
+            let key = if *waiting == Some(action) {
+                tr(language, "controls.waiting")
+            } else {
+                key_name(bindings.key(action)).unwrap_or("?")
+            };
+            format!("{}: {key}", tr(language, action.key()))
+        })
+        .collect();
+    labels.extend(
+        ["controls.arrows", "controls.zqsd", "gameover.menu"]
+            .map(|key| tr(language, key).to_string()),
+    );
+    for (i, (button, label)) in action_buttons.iter().zip(&labels).enumerate() {
+        let color = if *waiting == Some(Action::ALL[i]) {
+            GOLD
+        } else {
+            GRAY
+        };
+        draw_button(*button, color, label, body, WHITE, fonts);
+    }
+    let side_labels = &labels[Action::ALL.len()..];
+    draw_button(arrows_button, GRAY, &side_labels[0], body, WHITE, fonts);
+    draw_button(zqsd_button, GRAY, &side_labels[1], body, WHITE, fonts);
+    draw_button(back_button, RED, &side_labels[2], body, WHITE, fonts);
+    let label_refs: Vec<&str> = labels.iter().map(String::as_str).collect();
+    narration.menu("controls", &layout.buttons, &label_refs, language);
 
-    let button_width = 200.0;
-    let button_height = 50.0;
-    let center_x = (screen_width() - button_width) / 2.0;
-    let center_y = (screen_height() - button_height) / 2.0;
+    if is_mouse_button_pressed(MouseButton::Left) {
+        let mouse_pos = mouse_position().into();
+        if let Some(i) = action_buttons.iter().position(|b| b.contains(mouse_pos)) {
+            *waiting = Some(Action::ALL[i]);
+        } else if arrows_button.contains(mouse_pos) {
+            *bindings = KeyBindings::default();
+        } else if zqsd_button.contains(mouse_pos) {
+            *bindings = KeyBindings::zqsd();
+        } else if back_button.contains(mouse_pos) {
+            *waiting = None;
+            return MenuChoice::Menu;
+        }
+    }
 
-    draw_centered_text("Game Over", center_y - 150.0, 40.0, WHITE);
+    MenuChoice::Stay
+}
 
-    let replay_button = Rect::new(center_x, center_y - 50.0, button_width, button_height);
-    let quit_button = Rect::new(center_x, center_y + 50.0, button_width, button_height);
+/// Gère l'affichage de l'écran des réglages du volume et de l'affichage.
+/// Les curseurs suivent le pointeur tant que le bouton gauche reste enfoncé dessus.
+/// # Arguments
+/// - `background_texture`: Texture d'arrière-plan pour l'écran des réglages.
+/// - `volume`: les réglages du volume, modifiables depuis cet écran
+/// - `edge_indicators`: permet d'afficher les indicateurs aux bords de l'écran, modifiable ici
+/// - `language`: la langue des textes
+/// - `narration`: le journal de narration
+/// - `fonts`: les polices du jeu
+/// # Returns
+/// - `MenuChoice`: `Menu` pour revenir à l'écran de démarrage, sinon `Stay`.
+async fn draw_settings_screen(
+    background_texture: &Texture2D,
+    volume: &mut VolumeSettings,
+    edge_indicators: &mut bool,
+    language: Language,
+    narration: &mut Narration,
+    fonts: &Fonts,
+) -> MenuChoice {
+    draw_background(background_texture);
+
+    let screen = vec2(screen_width(), screen_height());
+    let center = screen / 2.0;
+    let top = (center.y - 150.0).max(0.0);
+    let row = |i: usize, width: f32| {
+        Rect::new(
+            center.x - width / 2.0,
+            top + i as f32 * 70.0,
+            width,
+            BUTTON_SIZE.y,
+        )
+    };
+    let layout = MenuLayout::new(
+        screen,
+        screen.y * 0.12,
+        vec![
+            row(0, 400.0),
+            row(1, 400.0),
+            row(2, 400.0),
+            row(3, BUTTON_SIZE.x),
+            row(4, 400.0),
+            row(5, BUTTON_SIZE.x),
+        ],
+    );
+    let [master_slider, sfx_slider, music_slider, mute_button, edge_button, back_button] =
+        layout.buttons[..]
+    else {
+        unreachable!("L'écran des réglages a trois curseurs et trois boutons");
+    };
+
+    if is_mouse_button_down(MouseButton::Left) {
+        let mouse_pos: Vec2 = mouse_position().into();
+        if master_slider.contains(mouse_pos) {
+            volume.master = slider_value(master_slider, mouse_pos.x);
+        } else if sfx_slider.contains(mouse_pos) {
+            volume.sfx = slider_value(sfx_slider, mouse_pos.x);
+        } else if music_slider.contains(mouse_pos) {
+            volume.music = slider_value(music_slider, mouse_pos.x);
+        }
+    }
 
-    draw_rectangle(
-        replay_button.x,
-        replay_button.y,
-        replay_button.w,
-        replay_button.h,
-        GREEN,
+    if !layout.compact {
+        fonts.draw_centered(
+            tr(language, "settings.title"),
+            top - 30.0,
+            TextStyle::Title,
+            WHITE,
+        );
+    }
+    let body = layout.label_style(TextStyle::Body);
+    let percent = |value: f32| (value * 100.0).round() as u32;
+    let master_label = format!(
+        "{}: {}%",
+        tr(language, "settings.master_volume"),
+        percent(volume.master)
     );
-    draw_centered_text("Rejouer", replay_button.y + 35.0, 30.0, WHITE);
-    draw_rectangle(
-        quit_button.x,
-        quit_button.y,
-        quit_button.w,
-        quit_button.h,
+    let sfx_label = format!(
+        "{}: {}%",
+        tr(language, "settings.sfx_volume"),
+        percent(volume.sfx)
+    );
+    let music_label = format!(
+        "{}: {}%",
+        tr(language, "settings.music_volume"),
+        percent(volume.music)
+    );
+    let mute_label = tr(
+        language,
+        if volume.muted {
+            "settings.muted"
+        } else {
+            "settings.unmuted"
+        },
+    );
+    draw_slider(master_slider, volume.master, &master_label, body, fonts);
+    draw_slider(sfx_slider, volume.sfx, &sfx_label, body, fonts);
+    draw_slider(music_slider, volume.music, &music_label, body, fonts);
+    draw_button(mute_button, GRAY, mute_label, body, WHITE, fonts);
+    let edge_label = tr(
+        language,
+        if *edge_indicators {
+            "settings.edge_indicators_on"
+        } else {
+            "settings.edge_indicators_off"
+        },
+    );
+    draw_button(edge_button, GRAY, edge_label, body, WHITE, fonts);
+    draw_button(
+        back_button,
         RED,
+        tr(language, "gameover.menu"),
+        body,
+        WHITE,
+        fonts,
+    );
+    narration.menu(
+        "settings",
+        &layout.buttons,
+        &[
+            &master_label,
+            &sfx_label,
+            &music_label,
+            mute_label,
+            edge_label,
+            tr(language, "gameover.menu"),
+        ],
+        language,
     );
-    draw_centered_text("Quitter", quit_button.y + 35.0, 30.0, WHITE);
 
     if is_mouse_button_pressed(MouseButton::Left) {
         let mouse_pos = mouse_position().into();
-        if replay_button.contains(mouse_pos) {
-            return true; // Restart the game
-        } else if quit_button.contains(mouse_pos) {
-            std::process::exit(0); // Quit the game
+        if mute_button.contains(mouse_pos) {
+            volume.muted = !volume.muted;
+        } else if edge_button.contains(mouse_pos) {
+            *edge_indicators = !*edge_indicators;
+        } else if back_button.contains(mouse_pos) {
+            return MenuChoice::Menu;
         }
     }
 
-    false
+    MenuChoice::Stay
+}
+
+/// Gère l'affichage de la liste des succès, débloqués en couleur et verrouillés en gris.
+/// # Arguments
+/// - `background_texture`: Texture d'arrière-plan de l'écran
+/// - `achievements`: les succès débloqués par le joueur
+/// - `language`: la langue des textes
+/// - `narration`: la narration des menus
+/// - `fonts`: les polices du jeu
+/// # Returns
+/// - `MenuChoice`: `Menu` pour revenir à l'écran de démarrage, sinon `Stay`
+async fn draw_achievements_screen(
+    background_texture: &Texture2D,
+    achievements: &Achievements,
+    language: Language,
+    narration: &mut Narration,
+    fonts: &Fonts,
+) -> MenuChoice {
+    draw_background(background_texture);
+
+    let screen = vec2(screen_width(), screen_height());
+    let row_height = 34.0;
+    let top = (screen.y / 2.0 - 230.0).max(0.0);
+    let bottom = top + Achievement::ALL.len() as f32 * row_height;
+    let layout = MenuLayout::new(
+        screen,
+        screen.y * 0.85,
+        vec![Rect::new(
+            (screen.x - BUTTON_SIZE.x) / 2.0,
+            bottom + 10.0,
+            BUTTON_SIZE.x,
+            BUTTON_SIZE.y,
+        )],
+    );
+    let [back_button] = layout.buttons[..] else {
+        unreachable!("L'écran des succès a un bouton");
+    };
+
+    let unlocked = Achievement::ALL
+        .iter()
+        .filter(|achievement| achievements.is_unlocked(**achievement))
+        .count();
+    fonts.draw_centered(
+        &format!(
+            "{} ({unlocked}/{})",
+            tr(language, "achievement.title"),
+            Achievement::ALL.len()
+        ),
+        top - 10.0,
+        layout.label_style(TextStyle::Heading),
+        WHITE,
+    );
+    let left = (screen.x / 2.0 - 280.0).max(10.0);
+    for (i, achievement) in Achievement::ALL.iter().enumerate() {
+        let y = top + (i as f32 + 1.0) * row_height;
+        let (icon, name_color, text_color) = if achievements.is_unlocked(*achievement) {
+            (achievement.icon_color(), GOLD, WHITE)
+        } else {
+            (DARKGRAY, GRAY, GRAY)
+        };
+        draw_circle(left + 10.0, y - 7.0, 10.0, icon);
+        let name = tr(language, achievement.key());
+        fonts.draw(name, left + 30.0, y, TextStyle::Body, name_color);
+        fonts.draw(
+            tr(language, achievement.description_key()),
+            left + 40.0 + fonts.measure(name, TextStyle::Body).width,
+            y,
+            TextStyle::Body,
+            text_color,
+        );
+    }
+
+    draw_button(
+        back_button,
+        RED,
+        tr(language, "gameover.menu"),
+        layout.label_style(TextStyle::Body),
+        WHITE,
+        fonts,
+    );
+    narration.menu(
+        "achievements",
+        &layout.buttons,
+        &[tr(language, "gameover.menu")],
+        language,
+    );
+
+    if is_mouse_button_pressed(MouseButton::Left) && back_button.contains(mouse_position().into()) {
+        return MenuChoice::Menu;
+    }
+    MenuChoice::Stay
+}
+
+/// Sauvegarde les réglages du volume, en signalant les erreurs sans interrompre le jeu
+/// # Arguments
+/// - `volume`: les réglages du volume
+fn save_volume(volume: &VolumeSettings) {
+    if let Err(err) = volume.save(&volume_path()) {
+        log::error!("Erreur lors de la sauvegarde du volume : {:?}", err);
+    }
+}
+
+/// Sauvegarde les réglages de la fenêtre, en signalant les erreurs sans interrompre le jeu
+/// # Arguments
+/// - `display`: les réglages de la fenêtre
+fn save_display(display: &DisplaySettings) {
+    if let Err(err) = display.save(&display_path()) {
+        log::error!("Erreur lors de la sauvegarde de la fenêtre : {:?}", err);
+    }
 }
 
-/// Lance une nouvelle vague d'astéroïdes.
+/// Enregistre une partie terminée dans les meilleurs scores, dans la session
+/// et dans le journal des statistiques
 /// # Arguments
-/// - `asteroids`: Vecteur mutable contenant les astéroïdes.
-/// - `wave`: Numéro de la vague actuelle.
-async fn start_new_wave(asteroids: &mut Vec<Asteroid>, wave: u32) {
-    let num_asteroids = 5 + (wave - 1);
-    for _ in 0..num_asteroids {
-        asteroids.push(Asteroid::new().await);
+/// - `world`: la partie terminée
+/// - `high_scores`: le tableau des meilleurs scores
+/// - `session`: la session en cours
+/// # Returns
+/// - `bool`: `true` si la partie a battu le meilleur score
+fn record_run(world: &World, high_scores: &mut HighScores, session: &mut Session) -> bool {
+    let stats = world.run_stats();
+    session
+        .stats
+        .record_run(world.score, stats.total_asteroids());
+    // En coopération, chaque joueur garde son propre score dans le tableau
+    let partner_score = world.partner.as_ref().map(|_| world.partner_score);
+    let scores = std::iter::once(world.score).chain(partner_score);
+    let best = scores.clone().max().unwrap_or(world.score);
+    let new_record = high_scores.is_record(best, world.mode);
+    for score in scores {
+        high_scores.insert(HighScoreEntry {
+            score,
+            wave: world.wave,
+            difficulty: world.difficulty,
+            mode: world.mode,
+            date: today(),
+        });
+    }
+    if let Err(err) = high_scores.save(&highscores_path()) {
+        log::error!("Erreur lors de la sauvegarde des scores : {:?}", err);
     }
+    let row = stats.csv_row(&today(), world.difficulty, world.seed);
+    if let Err(err) = run_stats::append_csv(&stats_path(), &row) {
+        log::error!("Erreur lors de la sauvegarde des statistiques : {:?}", err);
+    }
+    new_record
+}
+
+/// Crée la notification qui résume la session en cours
+/// # Arguments
+/// - `session`: la session en cours
+/// - `profile`: le profil du joueur, qui garde la session précédente
+/// - `language`: la langue des textes
+/// # Returns
+/// - `Toast`: la notification à afficher sur l'écran de démarrage
+fn session_toast(session: &Session, profile: &Profile, language: Language) -> Toast {
+    let stats = &session.stats;
+    let separator = match language {
+        Language::French => ' ',
+        Language::English => ',',
+    };
+    let mut lines = vec![
+        format!("{}: {}", tr(language, "session.runs"), stats.runs),
+        format!(
+            "{}: {}",
+            tr(language, "session.best_score"),
+            stats.best_score
+        ),
+        format!(
+            "{}: {}",
+            tr(language, "session.asteroids_destroyed"),
+            stats.asteroids_destroyed
+        ),
+    ];
+    lines.push(match stats.compare(profile.last_session.as_ref()) {
+        Some(delta) => format!(
+            "{}{} {}",
+            if delta < 0 { "-" } else { "+" },
+            format_thousands(delta.unsigned_abs(), separator),
+            tr(language, "session.compare")
+        ),
+        None => tr(language, "session.first").to_string(),
+    });
+    Toast::new(tr(language, "session.title").to_string(), lines)
 }
 
 /// Fonction qui dessine les textes temporaires
-/// Ici le score ajouté
+/// Ici le score ajouté, ou un texte traduit quand le texte est une clé de traduction
 /// # Arguments
 /// - `temporary_texts`: contient tous nos textes à affichier
-fn draw_temporary_texts(temporary_texts: &[TemporaryText]) {
+/// - `language`: la langue des textes
+/// - `feedback`: le profil de retour, qui règle la taille des textes
+/// - `camera`: la caméra de l'aire de jeu, pour placer les textes sans les réduire
+/// - `fonts`: les polices du jeu
+fn draw_temporary_texts(
+    temporary_texts: &[TemporaryText],
+    language: Language,
+    feedback: FeedbackProfile,
+    camera: &Camera2D,
+    fonts: &Fonts,
+) {
     for temp_text in temporary_texts {
-        draw_text(
-            &temp_text.text,
-            temp_text.position.x,
-            temp_text.position.y,
-            20.0, // Taille de la police
-            temp_text.color,
+        let position = camera.world_to_screen(temp_text.position);
+        fonts.draw_scaled(
+            tr(language, &temp_text.text),
+            position.x,
+            position.y,
+            TextStyle::Popup,
+            feedback.popup_scale(),
+            Color {
+                a: temp_text.color.a * temp_text.alpha(),
+                ..temp_text.color
+            },
         );
     }
 }
 
-/// Fonction qui met à jour nos textes temporaires,
-/// càd les fait disparaitre au bout d'un temps donné
+/// Fonction qui dessine la pile des sous-titres dans le coin en bas à droite de l'écran.
+/// Une flèche indique la direction de l'événement par rapport au vaisseau quand elle est connue.
 /// # Arguments
-/// - `temporary_texts`: contient tous nos textes temporaires
-fn update_temporary_texts(temporary_texts: &mut Vec<TemporaryText>) {
-    for text in temporary_texts.iter_mut() {
-        text.lifetime -= get_frame_time();
+/// - `captions`: contient les sous-titres à afficher
+/// - `ship_pos`: la position du vaisseau, utilisée pour orienter les flèches
+/// - `fonts`: les polices du jeu
+fn draw_captions(captions: &CaptionStack, ship_pos: Vec2, fonts: &Fonts) {
+    let font_size = TextStyle::Body.size(ui_scale(screen_height()));
+    let anchor = vec2(screen_width() - 20.0, screen_height() - 20.0);
+    for line in captions.layout(anchor, font_size + 6.0) {
+        let text_width = fonts.measure(line.text, TextStyle::Body).width;
+        let x = line.position.x - text_width;
+        let color = Color::new(1.0, 1.0, 1.0, line.alpha);
+        fonts.draw(line.text, x, line.position.y, TextStyle::Body, color);
+
+        if let Some(direction) = line
+            .source
+            .and_then(|source| (source - ship_pos).try_normalize())
+        {
+            let center = vec2(x - 16.0, line.position.y - font_size / 4.0);
+            let tip = center + direction * 8.0;
+            let side = direction.perp() * 4.0;
+            let base = center - direction * 4.0;
+            draw_triangle(tip, base + side, base - side, color);
+        }
     }
-    temporary_texts.retain(|text| text.lifetime > 0.0);
 }
 
-#[macroquad::main("Spaceship and Asteroids")]
-async fn main() {
-    let (asteroid_destroyed, shield_lost, missile_sound, start_game, game_over, new_wave) =
-        load_sounds().await;
-    let mut start_game_sound: bool = false;
-    let mut end_game_sound: bool = false;
-    let background_texture = load_background_texture().await;
-    let background_texture_start = load_background_texture_start().await;
-    let background_texture_dead = load_background_texture_dead().await;
-    let mut temporary_texts: Vec<TemporaryText> = Vec::new();
-    let mut game_state = GameState::StartScreen;
-    let mut spaceship = Spaceship::new().await;
-    let mut asteroids: Vec<Asteroid> = Vec::new();
-    let mut missiles: Vec<Missile> = Vec::new();
-    let mut black_holes: Vec<BlackHole> = Vec::new();
-    let mut wave = 1;
-    let mut score: i32 = 0;
+/// Prépare une nouvelle partie en mode classique : la partie repart de la première vague
+/// avec un nouveau vaisseau, et le son de début de partie pourra être rejoué.
+/// # Arguments
+/// - `world`: la partie
+/// - `ship_textures`: les textures du nouveau vaisseau
+/// - `sound_latches`: les sons de changement d'état déjà joués
+/// - `seed`: la graine de la nouvelle partie
+/// - `mode`: le mode de jeu de la nouvelle partie
+fn reset_game(
+    world: &mut World,
+    ship_textures: &SpaceshipTextures,
+    sound_latches: &mut SoundLatches,
+    seed: u64,
+    mode: GameMode,
+) {
+    world.bounds = playfield_bounds(vec2(screen_width(), screen_height()));
+    world.seed = seed;
+    world.set_mode(mode);
+    world.reset(Spaceship::new(world.bounds, ship_textures.clone()));
+    sound_latches.reset();
+}
 
-    start_new_wave(&mut asteroids, wave).await;
+/// Fonction qui décrit la fenêtre du jeu, ouverte à la taille de référence de l'aire de jeu,
+/// ou en plein écran selon les réglages sauvegardés et l'option `--fullscreen`
+/// # Returns
+/// - `Conf`: la configuration de la fenêtre
+fn window_conf() -> Conf {
+    Conf {
+        window_title: "Asteroids Game".to_string(),
+        window_width: REFERENCE_RESOLUTION.x as i32,
+        window_height: REFERENCE_RESOLUTION.y as i32,
+        window_resizable: true,
+        // Les options invalides sont signalées au lancement de la partie, qui arrête le jeu
+        fullscreen: startup_display(
+            CliOptions::parse(std::env::args().skip(1)).is_ok_and(|options| options.fullscreen),
+        )
+        .fullscreen,
+        icon: Some(window_icon()),
+        ..Default::default()
+    }
+}
 
+#[macroquad::main(window_conf)]
+async fn main() {
+    let options = CliOptions::from_env_or_exit();
+    logger::init();
+    if let Some(dir) = &options.assets {
+        if !set_assets_dir(dir) {
+            log::warn!("Le dossier des ressources est déjà choisi, --assets {dir} est ignoré");
+        }
+    }
+    let mut audio = AudioManager::load().await;
+    let mut volume = VolumeSettings::load(&volume_path());
+    // L'option `--mute` coupe le son dès le lancement, la touche M le rétablit
+    volume.muted |= options.mute;
+    let mut display = startup_display(options.fullscreen);
+    let mut sound_latches = SoundLatches::default();
+    let mut assets = Assets::load().await;
+    let mut game_state = GameState::StartScreen;
+    let mut settings = Settings::new(&options);
+    // Toutes les textures sont chargées ici : la suite de la partie n'attend plus rien
+    let ship_textures = SpaceshipTextures::load().await;
+    let bounds = playfield_bounds(vec2(screen_width(), screen_height()));
+    let world_textures = WorldTextures::load().await;
+    let mut world = World::new(
+        Spaceship::new(bounds, ship_textures.clone()),
+        world_textures.clone(),
+        bounds,
+        settings.ufo_spawn_chance,
+    );
+    // Une graine imposée remplace celle tirée pour la première partie, et la surchauffe
+    // imposée doit être appliquée au vaisseau : la partie est recommencée avec les deux,
+    // à la vague de départ choisie
+    world.overheat = settings.overheat;
+    world.offer_upgrades = true;
+    world.start_wave = options.wave.unwrap_or(1);
+    if let Some(seed) = settings.seed {
+        world.seed = seed;
+    }
+    world.reset(Spaceship::new(bounds, ship_textures.clone()));
+    let mut attract = AttractMode::default();
+    let mut recorder: Option<FileRecorder> = None;
+    // Avec l'option `--replay`, le jeu commence par rejouer la partie enregistrée
+    let mut replay = settings.replay.as_ref().and_then(|path| {
+        ReplayPlayer::load(path)
+            .map_err(|err| log::error!("Erreur lors du chargement du replay : {:?}", err))
+            .ok()
+    });
+    if let Some(player) = &replay {
+        let ship = Spaceship::new(player.header.bounds, ship_textures.clone());
+        player.header.restart(&mut world, ship);
+        game_state = GameState::Playing;
+    }
+    let mut sound_events: Vec<SoundEvent> = Vec::new();
+    let mut tip_events: Vec<TipEvent> = Vec::new();
+    let mut narration = Narration::open(options.narration.as_ref());
+    let mut decal_layer = DecalLayer::new();
+    let mut bindings = KeyBindings::load(&bindings_path());
+    let mut captions = CaptionStack::new();
+    let mut profile = Profile::load(&profile_path());
+    let mut attention = AttentionTracker::new(world.spaceship.get_pos());
+    let mut high_scores = HighScores::load(&highscores_path());
+    let mut inspector = Inspector::new();
+    let mut fps = FpsCounter::default();
+    let mut session = Session::new();
+    let mut toast: Option<Toast> = None;
+    let mut mistakes = MistakeTracker::new();
+    let mut tip: Option<(&Tip, Toast)> = None;
+    let mut achievements = Achievements::load(&achievements_path());
+    let mut achievement_tracker = AchievementTracker::new();
+    let mut achievement_toasts = AchievementToasts::default();
+    let mut achievement_events: Vec<AchievementEvent> = Vec::new();
+    // Le résumé de la partie perdue, gardé pendant l'explosion du vaisseau
+    let mut death: Option<RunSummary> = None;
+
+    // Permet d'ignorer le clic qui a lancé la partie depuis un menu
+    let mut was_playing = false;
+    // La partie avance par pas fixes, quelle que soit la fréquence d'affichage
+    let mut timestep = FixedTimestep::default();
+    // Les tirs et bombes demandés pendant une image sans pas, joués au pas suivant
+    let mut queued_presses = ReplayFrame::default();
+    let mut queued_partner_presses = ReplayFrame::default();
+
+    let mut logged_state = game_state.name();
+    log::info!("Écran : {logged_state}");
     loop {
+        if game_state.name() != logged_state {
+            log::info!("Écran : {logged_state} -> {}", game_state.name());
+            logged_state = game_state.name();
+        }
+        let playing = matches!(game_state, GameState::Playing);
+        sound_events.extend(sound_latches.frame_sound(&game_state));
+        // La touche M coupe le son partout, sauf quand une commande attend sa nouvelle touche
+        let binding_key = matches!(game_state, GameState::Controls { waiting: Some(_) });
+        if is_key_pressed(KeyCode::M) && !binding_key {
+            volume.muted = !volume.muted;
+            save_volume(&volume);
+        }
+        // F11 ou Alt+Entrée passe du plein écran à la fenêtre partout ; l'aire de jeu suit
+        // la nouvelle taille de la fenêtre à l'image suivante
+        let alt = is_key_down(KeyCode::LeftAlt) || is_key_down(KeyCode::RightAlt);
+        if is_key_pressed(KeyCode::F11) || (alt && is_key_pressed(KeyCode::Enter)) {
+            display.fullscreen = !display.fullscreen;
+            set_fullscreen(display.fullscreen);
+            save_display(&display);
+        }
+        // Devient vrai quand la partie avance pendant cette image
+        let mut stepping = false;
         match game_state {
             GameState::StartScreen => {
-                if draw_start_screen(&background_texture_start).await {
-                    game_state = GameState::Playing;
+                let pressed = get_last_key_pressed().is_some()
+                    || is_mouse_button_pressed(MouseButton::Left)
+                    || is_mouse_button_pressed(MouseButton::Right);
+                let moved = mouse_delta_position() != Vec2::ZERO;
+                let demo_bounds = playfield_bounds(vec2(screen_width(), screen_height()));
+                // Pendant la démonstration, les boutons ne sont pas affichés : la touche
+                // ou le clic qui l'arrête ne peut pas choisir un bouton par accident
+                let choice = if let Some(demo) = &attract.demo {
+                    draw_demo(
+                        demo,
+                        &assets.background_start,
+                        assets.missile.as_ref(),
+                        settings.language,
+                        &assets.fonts,
+                    );
+                    MenuChoice::Stay
+                } else {
+                    draw_start_screen(
+                        &assets.background_start,
+                        &mut settings,
+                        !profile.last_field.is_empty(),
+                        &mut narration,
+                        &assets.fonts,
+                    )
+                    .await
+                };
+                attract.update(get_frame_time(), pressed, moved, demo_bounds, || {
+                    demo_world(&ship_textures, &world_textures, demo_bounds)
+                });
+                match choice {
+                    MenuChoice::Play => {
+                        // Le champ capturé ne fait pas partie des replays
+                        let remix = settings.remix
+                            && !profile.last_field.is_empty()
+                            && settings.record.is_none();
+                        if remix {
+                            // Le champ capturé n'est repris qu'une seule fois
+                            world.remix = std::mem::take(&mut profile.last_field);
+                            if let Err(err) = profile.save(&profile_path()) {
+                                log::error!("Erreur lors de la sauvegarde du profil : {:?}", err);
+                            }
+                        }
+                        // La difficulté et le mode choisis sont gardés pour les parties suivantes de la session.
+                        // Une partie enregistrée est recommencée pour ne dépendre que de sa graine.
+                        // Les replays n'enregistrent qu'un joueur : pas de coopération pendant un enregistrement.
+                        let recording = settings.record.is_some();
+                        let coop = settings.two_players && !recording;
+                        if remix
+                            || recording
+                            || world.difficulty != settings.difficulty
+                            || world.mode != settings.mode
+                            || coop != world.partner.is_some()
+                        {
+                            world.difficulty = settings.difficulty;
+                            world.set_mode(settings.mode);
+                            world.bounds = playfield_bounds(vec2(screen_width(), screen_height()));
+                            world.partner = coop.then(|| {
+                                Spaceship::second_player(world.bounds, ship_textures.clone())
+                            });
+                            world.reset(Spaceship::new(world.bounds, ship_textures.clone()));
+                        }
+                        recorder = start_recording(&settings, &mut world);
+                        game_state = GameState::Playing;
+                        toast = None;
+                    }
+                    MenuChoice::Controls => game_state = GameState::Controls { waiting: None },
+                    MenuChoice::Settings => game_state = GameState::Settings,
+                    MenuChoice::Achievements => game_state = GameState::Achievements,
+                    MenuChoice::Quit => break,
+                    MenuChoice::Stay | MenuChoice::Continue | MenuChoice::Menu => {}
+                }
+                if toast.as_ref().is_some_and(|t| t.draw(&assets.fonts)) {
+                    toast = None;
                 }
             }
             GameState::Playing => {
-                if !start_game_sound {
-                    play_sound(
-                        &start_game,
-                        PlaySoundParams {
-                            looped: false,
-                            volume: 1.0,
-                        },
-                    );
-                    start_game_sound = true; // Le son est joué une seule fois
+                world.effects.feedback = settings.feedback;
+                audio.feedback = settings.feedback;
+                let screen = vec2(screen_width(), screen_height());
+                // Pendant un replay, l'aire de jeu garde la taille enregistrée
+                if replay.is_none() {
+                    world.set_bounds(playfield_bounds(screen));
+                }
+                let camera = playfield_camera(world.bounds);
+                // La secousse ne décale que l'affichage, jamais les positions des objets
+                let shake = world.effects.shake_offset();
+                let view = shaken_camera(&camera, shake * world.bounds / screen);
+                set_camera(&shaken_camera(&playfield_camera(screen), shake));
+                assets.background.update(get_frame_time());
+                assets.background.draw(settings.background_mode);
+                decal_layer.sync(&world.decals, world.bounds);
+                set_camera(&view);
+                decal_layer.draw(world.bounds);
+                draw(&world);
+                if let Some(partner) = &world.partner {
+                    partner.draw(world.bounds);
+                }
+                draw_missiles(&world.missiles, assets.missile.as_ref());
+                draw_ufos(&world.ufos);
+                for comet in &world.comets {
+                    comet.draw(world.bounds);
+                }
+                draw_power_ups(&world.power_ups);
+                if let Some(boss) = &world.boss {
+                    boss.draw();
                 }
-                draw_background(&background_texture);
-                draw(
-                    &spaceship,
-                    &asteroids,
-                    &missiles,
-                    &black_holes,
-                    wave,
-                    score,
-                    &temporary_texts,
+                if let Some(boss_asteroid) = &world.boss_asteroid {
+                    boss_asteroid.draw(world.bounds, world.asteroid_textures());
+                }
+                if let Some(gold_asteroid) = &world.gold_asteroid {
+                    gold_asteroid.draw(world.bounds, world.asteroid_textures());
+                }
+                world.effects.draw();
+                if settings.edge_indicators {
+                    draw_edge_indicators(&edge_indicators(&world), world.bounds, get_time() as f32);
+                }
+                if settings.hitboxes {
+                    draw_hitboxes(&world);
+                }
+                set_default_camera();
+                draw_temporary_texts(
+                    &world.temporary_texts,
+                    settings.language,
+                    settings.feedback,
+                    &view,
+                    &assets.fonts,
                 );
+                if !settings.hide_hud {
+                    draw_hud(
+                        &HudData::from_world(&world),
+                        &ship_textures,
+                        settings.language,
+                        &assets.fonts,
+                    );
+                    if settings.radar {
+                        draw_radar(
+                            &world.spaceship,
+                            world.partner.as_ref(),
+                            &world.asteroids,
+                            &world.black_holes,
+                            &world.ufos,
+                            world.bounds,
+                        );
+                    }
+                }
+                world.effects.draw_flash();
+
+                if attention.paused() == Some(PauseReason::ConfirmQuit) {
+                    match draw_quit_overlay(settings.language, &mut narration, &assets.fonts) {
+                        Some(true) => {
+                            // La partie abandonnée compte quand même pour les scores
+                            finish_recording(&mut recorder);
+                            replay = None;
+                            record_run(&world, &mut high_scores, &mut session);
+                            game_state = GameState::StartScreen;
+                            if session.milestone_reached() {
+                                toast = Some(session_toast(&session, &profile, settings.language));
+                            }
+                            reset_game(
+                                &mut world,
+                                &ship_textures,
+                                &mut sound_latches,
+                                settings.next_seed(),
+                                settings.mode,
+                            );
+                            attention = AttentionTracker::new(world.spaceship.get_pos());
+                        }
+                        Some(false) => attention.resume(world.spaceship.get_pos()),
+                        None => {}
+                    }
+                } else if let Some(reason) = attention.paused() {
+                    let message = match reason {
+                        PauseReason::Idle => "pause.idle",
+                        PauseReason::Manual | PauseReason::ConfirmQuit => "pause.manual",
+                    };
+                    draw_pause_overlay(tr(settings.language, message), &assets.fonts);
+                    if get_last_key_pressed().is_some()
+                        || is_mouse_button_pressed(MouseButton::Left)
+                    {
+                        attention.resume(world.spaceship.get_pos());
+                    }
+                } else if let (None, Some(choices)) = (&replay, world.upgrade_choices) {
+                    // Pendant un replay, l'amélioration choisie est relue avec les images
+                    let choice = draw_upgrade_overlay(
+                        &choices,
+                        settings.language,
+                        &mut narration,
+                        &assets.fonts,
+                    );
+                    if let Some(index) = choice {
+                        world.choose_upgrade(index);
+                        if let Some(recorder) = &mut recorder {
+                            if let Err(err) = recorder.record_upgrade(index) {
+                                log::error!("Erreur lors de l'écriture du replay : {:?}", err);
+                            }
+                        }
+                        // Le temps passé à choisir ne compte pas comme de l'inactivité
+                        attention.resume(world.spaceship.get_pos());
+                    }
+                } else {
+                    stepping = true;
+                    let aim = settings
+                        .mouse_aim
+                        .then(|| camera.screen_to_world(mouse_position().into()));
+                    // Une touche pressée attend le prochain pas s'il n'y en a aucun pendant cette image
+                    let input = read_input(&world.spaceship, &bindings, aim, was_playing);
+                    if replay.is_none() {
+                        world.spaceship.inherit_velocity = !settings.arcade_missiles;
+                        queued_presses.queue_presses(&input);
+                    } else {
+                        queued_presses.pause |= input.pause;
+                        queued_presses.quit |= input.quit;
+                    }
+                    if let Some(partner) = &world.partner {
+                        let partner_input = read_partner_input(FIXED_DT, partner.turn_rate());
+                        queued_partner_presses.queue_presses(&partner_input);
+                    }
+                    for _ in 0..timestep.advance(get_frame_time()) {
+                        // Pendant un replay, les commandes viennent du fichier et non du clavier,
+                        // sauf la pause et l'abandon, laissés au spectateur
+                        let frame = match &mut replay {
+                            Some(player) => {
+                                player.next_frame(&mut world).map(|frame| ReplayFrame {
+                                    pause: std::mem::take(&mut queued_presses.pause),
+                                    quit: std::mem::take(&mut queued_presses.quit),
+                                    ..frame
+                                })
+                            }
+                            None => Some(queued_presses.take_presses(read_input(
+                                &world.spaceship,
+                                &bindings,
+                                aim,
+                                was_playing,
+                            ))),
+                        };
+                        let Some(frame) = frame else {
+                            replay = None;
+                            game_state = GameState::StartScreen;
+                            reset_game(
+                                &mut world,
+                                &ship_textures,
+                                &mut sound_latches,
+                                settings.next_seed(),
+                                settings.mode,
+                            );
+                            attention = AttentionTracker::new(world.spaceship.get_pos());
+                            break;
+                        };
+                        // Pendant l'explosion d'un vaisseau, ses commandes sont ignorées
+                        let dying = world.all_ships_down();
+                        if !world.spaceship.dying {
+                            frame.apply(&mut world);
+                        }
+                        let partner = world.partner.as_ref().filter(|p| !p.dying);
+                        if let Some((player, turn_rate)) =
+                            partner.map(|p| (p.player, p.turn_rate()))
+                        {
+                            queued_partner_presses
+                                .take_presses(read_partner_input(frame.dt, turn_rate))
+                                .apply_to(&mut world, player);
+                        }
+
+                        if let Some(reason) = world.handle_collisions() {
+                            tip_events.push(TipEvent::Died(reason));
+                            profile.record_death(reason.key());
+                            profile.last_field = capture(&world.asteroids);
+                            if let Err(err) = profile.save(&profile_path()) {
+                                log::error!("Erreur lors de la sauvegarde du profil : {:?}", err);
+                            }
+                            let new_record = record_run(&world, &mut high_scores, &mut session);
+                            narration.announce(
+                                Announcement::GameOver { score: world.score },
+                                settings.language,
+                            );
+                            log::info!(
+                                "Fin de partie : score {}, vague {} ({})",
+                                world.score,
+                                world.wave,
+                                reason.key()
+                            );
+                            log::info!("Rapport : {}", world.report_line(reason));
+                            // L'écran de fin attend la fin de l'explosion du vaisseau
+                            death = Some(RunSummary {
+                                reason,
+                                wave: world.wave,
+                                mode: world.mode,
+                                new_record,
+                                seed: world.seed,
+                                stats: world.run_stats(),
+                            });
+                        } else if world.victory {
+                            // La partie n'est enregistrée qu'en quittant l'écran de victoire,
+                            // car le joueur peut la continuer en mode sans fin
+                            game_state = GameState::Victory(VictorySummary {
+                                score: world.score,
+                                wave: world.wave,
+                                asteroids_destroyed: world.run_stats().total_asteroids(),
+                                new_record: high_scores.is_record(world.score, world.mode),
+                            });
+                        }
+
+                        if world.explosion_finished() {
+                            if let Some(summary) = death.take() {
+                                game_state = GameState::GameOver(summary);
+                            }
+                        }
+
+                        if !dying && frame.bomb {
+                            world.detonate_bomb();
+                        }
+
+                        world.update(frame.dt);
+                        mistakes.update(frame.dt);
+                        // Les succès ne se débloquent pas en regardant un replay
+                        if replay.is_none() {
+                            achievement_events.push(AchievementEvent::Progress(world.run_stats()));
+                        }
+                        sound_events.append(&mut world.sound_events);
+                        if let Some(recorder) = &mut recorder {
+                            if let Err(err) = recorder.record(&frame, &world) {
+                                log::error!("Erreur lors de l'écriture du replay : {:?}", err);
+                            }
+                        }
+                        // Le replay se termine avec la partie, après sa dernière image
+                        if matches!(game_state, GameState::GameOver(_)) {
+                            finish_recording(&mut recorder);
+                        }
+                        if let Some(Err(divergence)) =
+                            replay.as_mut().map(|player| player.check(&world))
+                        {
+                            log::warn!("{divergence}");
+                        }
+
+                        let ship_pos = world.spaceship.get_pos();
+                        let nearest_asteroid = world
+                            .asteroids
+                            .iter()
+                            .map(|a| a.get_pos().distance(ship_pos) - a.radius())
+                            .min_by(f32::total_cmp);
+                        // Un replay ne met jamais la partie en pause faute d'activité
+                        attention.update(
+                            frame.dt,
+                            player_input_active(&bindings) || replay.is_some(),
+                            ship_pos,
+                            nearest_asteroid,
+                        );
 
-                if handle_input(&mut spaceship, &mut missiles, &missile_sound) {
-                    break;
+                        if matches!(game_state, GameState::Playing) && !world.all_ships_down() {
+                            if frame.pause {
+                                attention.pause();
+                            }
+                            if frame.quit {
+                                attention.confirm_quit();
+                            }
+                        }
+
+                        // Les pas restants attendent la fin de la partie, de la pause ou du choix d'amélioration
+                        if !matches!(game_state, GameState::Playing)
+                            || attention.paused().is_some()
+                            || (replay.is_none() && world.upgrade_choices.is_some())
+                        {
+                            break;
+                        }
+                    }
                 }
 
-                if check_collision(
-                    &mut spaceship,
-                    &mut asteroids,
-                    &mut missiles,
-                    &mut black_holes,
-                    &mut score,
-                    &shield_lost,
-                    &asteroid_destroyed,
-                    &mut temporary_texts,
-                )
-                .await
-                {
-                    play_sound(
-                        &missile_sound,
-                        PlaySoundParams {
-                            looped: false,
-                            volume: 1.0,
-                        },
+                if is_key_pressed(KeyCode::F2) {
+                    settings.hide_hud = !settings.hide_hud;
+                }
+                if is_key_pressed(KeyCode::Tab) {
+                    settings.radar = !settings.radar;
+                }
+                if is_key_pressed(KeyCode::F4) {
+                    settings.hitboxes = !settings.hitboxes;
+                }
+                if is_key_pressed(KeyCode::F3) {
+                    settings.dev_overlay = !settings.dev_overlay;
+                }
+                if settings.dev_overlay {
+                    let entities = inspectable_entities(
+                        &world.spaceship,
+                        &world.asteroids,
+                        &world.missiles,
+                        &world.black_holes,
+                        &world.ufos,
+                        &world.power_ups,
+                    );
+                    draw_dev_overlay(
+                        &mut inspector,
+                        &entities,
+                        &world.accounting,
+                        &camera,
+                        world.bounds,
+                        &assets.fonts,
                     );
-                    game_state = GameState::GameOver;
                 }
-
-                if asteroids.is_empty() {
-                    temporary_texts.push(TemporaryText {
-                        text: "+10".to_string(),
-                        position: spaceship.get_pos() + Vec2::new(20.0, 20.0),
-                        color: GOLD,
-                        lifetime: 1.0,
-                    });
-
-                    score += 10;
-                    wave += 1;
-                    spaceship.shield = true;
-                    spaceship.invincible = true;
-                    spaceship.hit = false;
-                    spaceship.invincibility_timer = 1.0;
-                    play_sound(
-                        &new_wave,
-                        PlaySoundParams {
-                            looped: false,
-                            volume: 1.0,
-                        },
+            }
+            GameState::GameOver(summary) => {
+                let choice = draw_game_over_screen(
+                    &assets.background_dead,
+                    &summary,
+                    &profile,
+                    &high_scores,
+                    settings.language,
+                    &mut narration,
+                    &assets.fonts,
+                )
+                .await;
+                if choice == MenuChoice::Quit {
+                    break;
+                }
+                if choice == MenuChoice::Play || choice == MenuChoice::Menu {
+                    if choice == MenuChoice::Play {
+                        game_state = GameState::Playing;
+                    } else {
+                        game_state = GameState::StartScreen;
+                        if session.milestone_reached() {
+                            toast = Some(session_toast(&session, &profile, settings.language));
+                        }
+                    }
+                    replay = None;
+                    reset_game(
+                        &mut world,
+                        &ship_textures,
+                        &mut sound_latches,
+                        settings.next_seed(),
+                        settings.mode,
                     );
-                    start_new_wave(&mut asteroids, wave).await;
+                    if choice == MenuChoice::Play {
+                        recorder = start_recording(&settings, &mut world);
+                    }
+                    attention = AttentionTracker::new(world.spaceship.get_pos());
                 }
-
-                update_model(
-                    &mut spaceship,
-                    &mut asteroids,
-                    &mut missiles,
-                    &mut black_holes,
-                );
-
-                update_temporary_texts(&mut temporary_texts);
-
-                if spaceship.invincible {
-                    spaceship.invincibility_timer -= get_frame_time();
-                    if spaceship.invincibility_timer <= 0.0 {
-                        spaceship.invincible = false;
-                        spaceship.hit = false;
+            }
+            GameState::Controls { mut waiting } => {
+                let before = bindings;
+                let choice = draw_controls_screen(
+                    &assets.background_start,
+                    &mut bindings,
+                    &mut waiting,
+                    settings.language,
+                    &mut narration,
+                    &assets.fonts,
+                )
+                .await;
+                if bindings != before {
+                    if let Err(err) = bindings.save(&bindings_path()) {
+                        log::error!("Erreur lors de la sauvegarde des touches : {:?}", err);
                     }
                 }
+                game_state = if choice == MenuChoice::Menu {
+                    GameState::StartScreen
+                } else {
+                    GameState::Controls { waiting }
+                };
             }
-            GameState::GameOver => {
-                if !end_game_sound {
-                    play_sound(
-                        &game_over,
-                        PlaySoundParams {
-                            looped: false,
-                            volume: 1.0,
-                        },
-                    );
-                    end_game_sound = true;
+            GameState::Settings => {
+                let choice = draw_settings_screen(
+                    &assets.background_start,
+                    &mut volume,
+                    &mut settings.edge_indicators,
+                    settings.language,
+                    &mut narration,
+                    &assets.fonts,
+                )
+                .await;
+                if choice == MenuChoice::Menu {
+                    // Les curseurs changent à chaque image : le fichier n'est écrit qu'en quittant l'écran
+                    save_volume(&volume);
+                    game_state = GameState::StartScreen;
                 }
-                if draw_game_over_screen(&background_texture_dead).await {
-                    start_game_sound = false;
-                    game_state = GameState::Playing;
-                    if !start_game_sound {
-                        play_sound(
-                            &start_game,
-                            PlaySoundParams {
-                                looped: false,
-                                volume: 1.0,
-                            },
+            }
+            GameState::Achievements => {
+                let choice = draw_achievements_screen(
+                    &assets.background_start,
+                    &achievements,
+                    settings.language,
+                    &mut narration,
+                    &assets.fonts,
+                )
+                .await;
+                if choice == MenuChoice::Menu {
+                    game_state = GameState::StartScreen;
+                }
+            }
+            GameState::Victory(summary) => {
+                let choice = draw_victory_screen(
+                    &assets.background_start,
+                    &summary,
+                    settings.language,
+                    &mut narration,
+                    &assets.fonts,
+                )
+                .await;
+                match choice {
+                    MenuChoice::Continue => {
+                        // La partie continue sans vagues, avec l'apparition continue du mode sans fin
+                        world.victory = false;
+                        world.set_mode(GameMode::Endless);
+                        attention = AttentionTracker::new(world.spaceship.get_pos());
+                        game_state = GameState::Playing;
+                    }
+                    MenuChoice::Menu => {
+                        record_run(&world, &mut high_scores, &mut session);
+                        finish_recording(&mut recorder);
+                        replay = None;
+                        game_state = GameState::StartScreen;
+                        if session.milestone_reached() {
+                            toast = Some(session_toast(&session, &profile, settings.language));
+                        }
+                        reset_game(
+                            &mut world,
+                            &ship_textures,
+                            &mut sound_latches,
+                            settings.next_seed(),
+                            settings.mode,
                         );
-                        start_game_sound = true; // Le son est joué une seule fois
+                        attention = AttentionTracker::new(world.spaceship.get_pos());
+                    }
+                    MenuChoice::Quit => {
+                        record_run(&world, &mut high_scores, &mut session);
+                        break;
                     }
-                    spaceship = Spaceship::new().await;
-                    asteroids.clear();
-                    missiles.clear();
-                    black_holes.clear();
-                    wave = 1;
-                    score = 0;
-                    start_new_wave(&mut asteroids, wave).await;
+                    MenuChoice::Stay
+                    | MenuChoice::Play
+                    | MenuChoice::Controls
+                    | MenuChoice::Settings
+                    | MenuChoice::Achievements => {}
                 }
             }
         }
+        // Le temps passé hors du jeu, en pause ou à choisir une amélioration n'est pas rattrapé
+        if !stepping {
+            timestep.reset();
+            queued_presses = ReplayFrame::default();
+            queued_partner_presses = ReplayFrame::default();
+        }
 
-        next_frame().await;
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    /// Structure représentant un objet avec une position, un rayon, et un état actif/inactif.
-    struct Object {
-        position: Vec2,
-        radius: f32,
-        pub active: bool,
-    }
+        // Tous les sons, y compris ceux des collisions, passent par le gestionnaire audio
+        volume.apply(&mut audio);
+        // La musique calme des menus, celle de la partie, et le silence sur l'écran de fin
+        audio.set_music(match game_state {
+            GameState::Playing => Some(Music::Game),
+            GameState::GameOver(_) => None,
+            GameState::StartScreen
+            | GameState::Controls { .. }
+            | GameState::Settings
+            | GameState::Achievements
+            | GameState::Victory(_) => Some(Music::Menu),
+        });
+        audio.update_music(get_frame_time());
+        // Le réacteur ne tourne que pendant la partie, quand le vaisseau pousse
+        audio.set_engine(
+            matches!(game_state, GameState::Playing)
+                && attention.paused().is_none()
+                && world.spaceship.exhaust != 0,
+        );
 
-    impl Object {
-        /// Crée une nouvelle instance d'`Object`.
-        ///
-        /// # Arguments
-        /// - `position`: Position initiale de l'objet.
-        /// - `radius`: Rayon de l'objet.
-        fn new(position: Vec2, radius: f32) -> Self {
-            Self {
-                position,
-                active: true,
-                radius,
+        // Les sous-titres sont ajoutés indépendamment de la lecture du son,
+        // pour qu'ils restent visibles même quand le jeu est muet
+        for event in sound_events.drain(..) {
+            tip_events.extend(TipEvent::from_sound(event));
+            if replay.is_none() {
+                achievement_events.extend(AchievementEvent::from_sound(event));
+            }
+            match event {
+                SoundEvent::StartGame | SoundEvent::NewWave => {
+                    narration.announce(Announcement::WaveStarted(world.wave), settings.language)
+                }
+                SoundEvent::ShieldLost(_) => {
+                    narration.announce(Announcement::ShieldLost, settings.language)
+                }
+                _ => {}
+            }
+            audio.play_event(event);
+            if let Some(key) = event.caption_key() {
+                captions.push(tr(settings.language, key), event.position());
             }
         }
-    }
-
-    impl StellarObject for Object {
-        fn get_pos(&self) -> Vec2 {
-            self.position
+        for event in tip_events.drain(..) {
+            let Some(mistake) = mistakes.record(event) else {
+                continue;
+            };
+            if let Some(new_tip) = tip_for(mistake, &profile.acknowledged_tips) {
+                let lines = vec![tr(settings.language, new_tip.key).to_string()];
+                tip = Some((
+                    new_tip,
+                    Toast::new(tr(settings.language, "tip.title").to_string(), lines),
+                ));
+            }
         }
-
-        fn move_obj(&mut self) {
-            self.position.x += 10.0;
-            self.position.y += 10.0;
+        for event in achievement_events.drain(..) {
+            for achievement in achievement_tracker.record(event) {
+                if !achievements.unlock(achievement) {
+                    continue;
+                }
+                achievement_toasts.push(achievement, tr(settings.language, achievement.key()));
+                if let Err(err) = achievements.save(&achievements_path()) {
+                    log::error!("Erreur lors de la sauvegarde des succès : {:?}", err);
+                }
+            }
         }
-
-        fn radius(&self) -> f32 {
-            self.radius
+        achievement_toasts.update(get_frame_time());
+        achievement_toasts.draw(tr(settings.language, "achievement.unlocked"), &assets.fonts);
+        // L'astuce partage le coin de la notification de session, qui reste prioritaire
+        if toast.is_none() && tip.as_ref().is_some_and(|(_, t)| t.draw(&assets.fonts)) {
+            if let Some((closed, _)) = tip.take() {
+                profile.acknowledged_tips.insert(closed.id.to_string());
+                if let Err(err) = profile.save(&profile_path()) {
+                    log::error!("Erreur lors de la sauvegarde du profil : {:?}", err);
+                }
+            }
         }
-
-        fn handle_collision(&mut self) {
-            self.active = false;
+        was_playing = playing;
+        narration.update(get_frame_time());
+        captions.update(get_frame_time());
+        if settings.captions {
+            draw_captions(&captions, world.spaceship.get_pos(), &assets.fonts);
+        }
+        // Les mesures sont dessinées en dernier, par dessus tout le reste,
+        // et aucun texte n'est préparé quand l'affichage de développement est fermé
+        fps.record(get_frame_time());
+        if settings.dev_overlay && matches!(game_state, GameState::Playing) {
+            draw_frame_stats(&world, &fps, &assets.fonts);
         }
-    }
 
-    /// Vérifie que la fonction `check_collision_between` détecte correctement une collision entre deux objets.
-    ///
-    /// # Contexte
-    /// - `obj1` est initialisé à `(50.0, 100.0)` avec un rayon de `20.0`.
-    /// - `obj2` est initialisé à `(20.0, 70.0)` avec un rayon de `30.0`.
-    ///
-    /// # Comportement attendu
-    /// La collision doit être détectée si la distance entre les deux objets est inférieure ou égale à la somme de leurs rayons.
-    ///
-    /// # Panique
-    /// Le test échoue avec le message `"Il n'y a pas de collision !"` si aucune collision n'est détectée.
-    #[test]
-    fn test_check_collision() {
-        let mut obj1 = Object::new(vec2(50.0, 100.0), 20.0);
-        let mut obj2 = Object::new(vec2(20.0, 70.0), 30.0);
-
-        let expected_collision = check_collision_between(&mut obj1, &mut obj2);
-
-        assert!(expected_collision, "Il n'y a pas de collision !");
-    }
-
-    /// Vérifie que la méthode `get_pos` retourne correctement la position initiale de l'objet.
-    ///
-    /// # Contexte
-    /// - L'objet est initialisé avec la position `(50.0, 100.0)`.
-    ///
-    /// # Comportement attendu
-    /// La méthode `get_pos` doit retourner exactement la position initiale.
-    ///
-    /// # Panique
-    /// Le test échoue avec le message `"La position retournée par get_pos() est incorrecte !"`
-    /// si la position retournée diffère de la position attendue.
-    #[test]
-    fn test_get_position() {
-        let obj = Object::new(vec2(50.0, 100.0), 30.0);
-
-        let expected_position = vec2(50.0, 100.0);
-
-        assert_eq!(
-            obj.get_pos(),
-            expected_position,
-            "La position retournée par get_pos() est incorrecte !"
-        );
+        next_frame().await;
     }
 
-    /// Vérifie que la méthode `move_obj` déplace correctement l'objet.
-    ///
-    /// # Contexte
-    /// - L'objet est initialisé avec la position `(50.0, 100.0)`.
-    /// - La méthode `move_obj` doit ajouter `10.0` aux coordonnées **x** et **y**.
-    ///
-    /// # Comportement attendu
-    /// Après l'appel à `move_obj`, la nouvelle position doit être `(60.0, 110.0)`.
-    ///
-    /// # Panique
-    /// Le test échoue avec le message `"L'objet n'a pas été déplacé correctement !"`
-    /// si la nouvelle position ne correspond pas à la position attendue.
-    #[test]
-    fn test_move_object() {
-        let mut obj = Object::new(vec2(50.0, 100.0), 30.0);
-
-        let initial_position = obj.get_pos();
-
-        obj.move_obj();
-
-        let new_position = obj.get_pos();
-
-        assert_eq!(
-            new_position,
-            vec2(initial_position.x + 10.0, initial_position.y + 10.0),
-            "L'objet n'a pas été déplacé correctement !"
-        );
+    // Une partie enregistrée quittée depuis l'écran de victoire garde son replay
+    finish_recording(&mut recorder);
+    // Fermeture normale du jeu : la session devient la session précédente du profil
+    profile.rotate_session(&session.stats);
+    if let Err(err) = profile.save(&profile_path()) {
+        log::error!("Erreur lors de la sauvegarde du profil : {:?}", err);
     }
 }
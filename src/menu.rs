@@ -0,0 +1,268 @@
+//! Module pour placer les boutons des écrans de menu.
+//! Quand la fenêtre est trop petite pour la disposition habituelle, les boutons sont
+//! empilés sous le titre, plus petits, pour qu'ils ne se chevauchent jamais.
+use asteroid::text::{Fonts, TextStyle};
+use macroquad::prelude::*;
+
+/// Marge (en pixels) gardée autour des boutons empilés
+const MARGIN: f32 = 8.0;
+/// Taille normale d'un bouton de menu
+pub const BUTTON_SIZE: Vec2 = vec2(200.0, 50.0);
+
+/// Structure représentant la disposition d'un écran de menu
+/// # Champs
+/// - `buttons`: la position des boutons, dans l'ordre donné
+/// - `compact`: permet de savoir si les boutons ont été empilés faute de place
+pub struct MenuLayout {
+    pub buttons: Vec<Rect>,
+    pub compact: bool,
+}
+
+impl MenuLayout {
+    /// Place les boutons d'un menu : la disposition habituelle est gardée si tous les boutons
+    /// tiennent dans la fenêtre, sinon les boutons sont empilés entre `top` et le bas de la fenêtre.
+    /// # Arguments
+    /// - `screen`: la taille de la fenêtre
+    /// - `top`: la hauteur à partir de laquelle empiler les boutons, sous le titre
+    /// - `normal`: la disposition habituelle des boutons
+    /// # Returns
+    /// - `Self`: la disposition des boutons
+    pub fn new(screen: Vec2, top: f32, normal: Vec<Rect>) -> Self {
+        let fits = normal.iter().all(|button| {
+            button.x >= 0.0
+                && button.y >= 0.0
+                && button.right() <= screen.x
+                && button.bottom() <= screen.y
+        });
+        if fits {
+            return Self {
+                buttons: normal,
+                compact: false,
+            };
+        }
+
+        let count = normal.len().max(1) as f32;
+        let slot = ((screen.y - MARGIN - top) / count).max(0.0);
+        let width = BUTTON_SIZE.x.min(screen.x - 2.0 * MARGIN).max(0.0);
+        let height = (slot * 0.8).min(BUTTON_SIZE.y);
+        let buttons = (0..normal.len())
+            .map(|i| {
+                Rect::new(
+                    (screen.x - width) / 2.0,
+                    top + i as f32 * slot,
+                    width,
+                    height,
+                )
+            })
+            .collect();
+        Self {
+            buttons,
+            compact: true,
+        }
+    }
+
+    /// Fonction qui renvoie le style des textes des boutons
+    /// # Arguments
+    /// - `style`: le style utilisé dans la disposition habituelle
+    /// # Returns
+    /// - `TextStyle`: le style, plus petit si les boutons sont empilés
+    pub fn label_style(&self, style: TextStyle) -> TextStyle {
+        match (self.compact, style) {
+            (false, style) => style,
+            (true, TextStyle::Title) => TextStyle::Heading,
+            (true, TextStyle::Heading) => TextStyle::Body,
+            (true, _) => TextStyle::Popup,
+        }
+    }
+}
+
+/// Énumération représentant un changement dans un menu, pour la narration
+/// # Variantes
+/// - `Focus`: le pointeur est passé sur le bouton donné
+/// - `Activate`: le joueur a cliqué sur le bouton donné
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MenuEvent {
+    Focus(usize),
+    Activate(usize),
+}
+
+/// Structure qui suit le bouton survolé par le pointeur
+/// # Champs
+/// - `focused`: l'indice du bouton survolé, s'il y en a un
+#[derive(Clone, Copy, Default)]
+pub struct MenuFocus {
+    focused: Option<usize>,
+}
+
+impl MenuFocus {
+    /// Met à jour le bouton survolé et renvoie les changements
+    /// # Arguments
+    /// - `buttons`: la position des boutons du menu
+    /// - `mouse`: la position du pointeur
+    /// - `clicked`: permet de savoir si le joueur vient de cliquer
+    /// # Returns
+    /// - `Vec<MenuEvent>`: le nouveau bouton survolé, puis le bouton cliqué
+    pub fn update(&mut self, buttons: &[Rect], mouse: Vec2, clicked: bool) -> Vec<MenuEvent> {
+        let hovered = buttons.iter().position(|button| button.contains(mouse));
+        let mut events = Vec::new();
+        if hovered != self.focused {
+            events.extend(hovered.map(MenuEvent::Focus));
+            self.focused = hovered;
+        }
+        if clicked {
+            events.extend(hovered.map(MenuEvent::Activate));
+        }
+        events
+    }
+}
+
+/// Fonction qui renvoie la hauteur de la ligne de base du texte d'un bouton
+/// # Arguments
+/// - `button`: le bouton
+/// # Returns
+/// - `f32`: la hauteur à laquelle écrire le texte pour qu'il soit centré dans le bouton
+pub fn label_y(button: Rect) -> f32 {
+    button.y + button.h * 0.7
+}
+
+/// Dessine un bouton avec son texte centré
+/// # Arguments
+/// - `button`: la position du bouton
+/// - `color`: la couleur du bouton
+/// - `label`: le texte du bouton
+/// - `style`: le style du texte
+/// - `text_color`: la couleur du texte
+/// - `fonts`: les polices du jeu
+pub fn draw_button(
+    button: Rect,
+    color: Color,
+    label: &str,
+    style: TextStyle,
+    text_color: Color,
+    fonts: &Fonts,
+) {
+    draw_rectangle(button.x, button.y, button.w, button.h, color);
+    let width = fonts.measure(label, style).width;
+    fonts.draw(
+        label,
+        button.x + (button.w - width) / 2.0,
+        label_y(button),
+        style,
+        text_color,
+    );
+}
+
+/// Fonction qui renvoie la valeur d'un curseur à la position horizontale du pointeur
+/// # Arguments
+/// - `slider`: la position du curseur
+/// - `mouse_x`: la position horizontale du pointeur
+/// # Returns
+/// - `f32`: la valeur, entre 0 au bord gauche et 1 au bord droit
+pub fn slider_value(slider: Rect, mouse_x: f32) -> f32 {
+    if slider.w <= 0.0 {
+        return 0.0;
+    }
+    ((mouse_x - slider.x) / slider.w).clamp(0.0, 1.0)
+}
+
+/// Dessine un curseur : un rectangle rempli jusqu'à la valeur, avec son texte centré
+/// # Arguments
+/// - `slider`: la position du curseur
+/// - `value`: la valeur du curseur, entre 0 et 1
+/// - `label`: le texte du curseur
+/// - `style`: le style du texte
+/// - `fonts`: les polices du jeu
+pub fn draw_slider(slider: Rect, value: f32, label: &str, style: TextStyle, fonts: &Fonts) {
+    draw_rectangle(slider.x, slider.y, slider.w, slider.h, DARKGRAY);
+    draw_rectangle(slider.x, slider.y, slider.w * value, slider.h, GRAY);
+    draw_rectangle_lines(slider.x, slider.y, slider.w, slider.h, 2.0, WHITE);
+    draw_button(slider, BLANK, label, style, WHITE, fonts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Crée la disposition habituelle de six boutons, prévue pour une fenêtre de 800×600
+    fn normal_layout(screen: Vec2) -> Vec<Rect> {
+        let center = (screen - BUTTON_SIZE) / 2.0;
+        [-50.0, 50.0, 120.0, 180.0, 240.0]
+            .into_iter()
+            .map(|dy| Rect::new(center.x, center.y + dy, BUTTON_SIZE.x, BUTTON_SIZE.y))
+            .chain(std::iter::once(Rect::new(
+                center.x + BUTTON_SIZE.x + 20.0,
+                center.y - 50.0,
+                BUTTON_SIZE.x,
+                BUTTON_SIZE.y,
+            )))
+            .collect()
+    }
+
+    /// Vérifie qu'un changement de bouton survolé et un clic produisent un seul événement chacun.
+    #[test]
+    fn test_menu_focus_events() {
+        let buttons = normal_layout(vec2(1024.0, 768.0));
+        let inside = |i: usize| buttons[i].center();
+        let mut focus = MenuFocus::default();
+
+        assert_eq!(focus.update(&buttons, vec2(0.0, 0.0), false), vec![]);
+        assert_eq!(
+            focus.update(&buttons, inside(0), false),
+            vec![MenuEvent::Focus(0)]
+        );
+        assert_eq!(focus.update(&buttons, inside(0), false), vec![]);
+        assert_eq!(
+            focus.update(&buttons, inside(2), true),
+            vec![MenuEvent::Focus(2), MenuEvent::Activate(2)]
+        );
+        assert_eq!(focus.update(&buttons, vec2(0.0, 0.0), true), vec![]);
+        assert_eq!(
+            focus.update(&buttons, inside(2), false),
+            vec![MenuEvent::Focus(2)]
+        );
+    }
+
+    /// Vérifie que la disposition habituelle est gardée dans une grande fenêtre.
+    #[test]
+    fn test_large_window_keeps_normal_layout() {
+        let screen = vec2(1024.0, 768.0);
+        let layout = MenuLayout::new(screen, 100.0, normal_layout(screen));
+
+        assert!(!layout.compact);
+        assert_eq!(layout.buttons, normal_layout(screen));
+        assert_eq!(layout.label_style(TextStyle::Heading), TextStyle::Heading);
+    }
+
+    /// Vérifie qu'à 320×240 les boutons sont empilés dans la fenêtre sans se chevaucher.
+    #[test]
+    fn test_small_window_stacks_buttons() {
+        let screen = vec2(320.0, 240.0);
+        let layout = MenuLayout::new(screen, 40.0, normal_layout(screen));
+
+        assert!(layout.compact);
+        assert_eq!(layout.buttons.len(), 6);
+        assert_eq!(layout.label_style(TextStyle::Heading), TextStyle::Body);
+        for (i, button) in layout.buttons.iter().enumerate() {
+            assert!(button.x >= 0.0 && button.right() <= screen.x);
+            assert!(button.y >= 40.0 && button.bottom() <= screen.y);
+            for other in &layout.buttons[i + 1..] {
+                assert!(
+                    button.intersect(*other).is_none(),
+                    "Deux boutons se chevauchent !"
+                );
+            }
+        }
+    }
+
+    /// Vérifie que la valeur d'un curseur suit le pointeur et reste entre 0 et 1.
+    #[test]
+    fn test_slider_value_is_clamped() {
+        let slider = Rect::new(100.0, 0.0, 200.0, 30.0);
+
+        assert_eq!(slider_value(slider, 100.0), 0.0);
+        assert_eq!(slider_value(slider, 150.0), 0.25);
+        assert_eq!(slider_value(slider, 300.0), 1.0);
+        assert_eq!(slider_value(slider, 20.0), 0.0);
+        assert_eq!(slider_value(slider, 500.0), 1.0);
+    }
+}
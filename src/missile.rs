@@ -1,19 +1,55 @@
 //! Module pour gérer les missiles dans le jeu.
-//! Un missile est tiré par le vaisseau et se déplace dans une direction
-//! jusqu'à ce qu'il quitte l'écran ou touche un objet.
+//! Un missile est tiré par le vaisseau ou par un ennemi et se déplace dans une direction
+//! jusqu'à ce qu'il quitte l'écran, touche un objet ou atteigne la fin de sa durée de vie.
+//! L'image allongée du missile est purement visuelle : les collisions utilisent toujours son rayon.
+//! Un missile à tête chercheuse se tourne vers l'asteroide le plus proche et passe d'un bord
+//! de l'écran à l'autre, jusqu'à la fin de sa durée de vie.
+use crate::asteroid::Asteroid;
+use crate::stellarobject::wrapped_offset;
 use macroquad::prelude::*;
 
+/// Longueur de l'image du missile, en nombre de rayons
+const SPRITE_LENGTH_FACTOR: f32 = 5.0;
+/// Angle maximal (en radians) dont un missile à tête chercheuse tourne à chaque image
+pub const HOMING_TURN_RATE: f32 = 0.06;
+/// Durée de vie (en secondes) d'un missile à tête chercheuse
+pub const HOMING_LIFETIME: f32 = 3.0;
+/// Durée de vie (en secondes) par défaut des autres missiles, même s'ils sont encore à l'écran
+pub const MISSILE_LIFETIME: f32 = 2.5;
+/// Nombre maximal par défaut de missiles en vol, tous tireurs confondus : les plus anciens disparaissent
+pub const MAX_MISSILES: usize = 64;
+
+/// Énumération représentant le tireur d'un missile
+/// # Variantes
+/// - `Player`: le missile a été tiré par le vaisseau du joueur
+/// - `Enemy`: le missile a été tiré par un ennemi et ne peut toucher que le vaisseau
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MissileSource {
+    Player,
+    Enemy,
+}
+
 /// Structure représentant un missile.
 /// # Champs
 /// - `position`: la position du vaisseau en x et y
 /// - `velocity`: la vitesse du missile
 /// - `active`: permet de savoir si le missile est actif ou non
 /// - `radius`: le rayon du missile
+/// - `source`: le tireur du missile
+/// - `homing`: permet de savoir si le missile a une tête chercheuse
+/// - `age`: le temps écoulé depuis le tir
+/// - `hit`: permet de savoir si le missile a touché un objet
+/// - `player`: le numéro du joueur qui a tiré le missile, qui gagne ses points
 pub struct Missile {
     pub position: Vec2,
-    velocity: Vec2,
+    pub velocity: Vec2,
     pub active: bool,
     radius: f32,
+    pub source: MissileSource,
+    pub homing: bool,
+    age: f32,
+    pub hit: bool,
+    pub player: usize,
 }
 
 impl Missile {
@@ -29,27 +65,154 @@ impl Missile {
             velocity: vec2(rotation.cos(), rotation.sin()) * speed,
             active: true,
             radius: 2.0,
+            source: MissileSource::Player,
+            homing: false,
+            age: 0.0,
+            hit: false,
+            player: 0,
         }
     }
 
-    /// Dessine le missile sur l'écran.
-    pub fn draw(&self) {
-        draw_circle(self.position.x, self.position.y, self.radius, RED);
+    /// Crée un missile tiré par un vaisseau en mouvement, qui garde la vitesse du vaisseau
+    /// en plus de sa propre vitesse.
+    ///
+    /// # Arguments
+    /// - `position`: Position initiale du missile.
+    /// - `rotation`: Rotation (en radians) pour déterminer la direction du missile.
+    /// - `base_velocity`: La vitesse du tireur au moment du tir.
+    pub fn with_base_velocity(position: Vec2, rotation: f32, base_velocity: Vec2) -> Self {
+        let mut missile = Self::new(position, rotation);
+        missile.velocity += base_velocity;
+        missile
+    }
+
+    /// Crée un missile ennemi, plus lent que ceux du vaisseau.
+    ///
+    /// # Arguments
+    /// - `position`: Position initiale du missile.
+    /// - `rotation`: Rotation (en radians) pour déterminer la direction du missile.
+    pub fn enemy(position: Vec2, rotation: f32) -> Self {
+        let speed = 3.0;
+        Self {
+            position,
+            velocity: vec2(rotation.cos(), rotation.sin()) * speed,
+            active: true,
+            radius: 3.0,
+            source: MissileSource::Enemy,
+            homing: false,
+            age: 0.0,
+            hit: false,
+            player: 0,
+        }
+    }
+
+    /// Fait vieillir le missile, qui disparait à la fin de sa durée de vie.
+    /// Un missile à tête chercheuse vit `HOMING_LIFETIME` et se tourne vers l'asteroide le plus proche,
+    /// d'au plus `HOMING_TURN_RATE`, sans changer sa vitesse. Sans cible, il va tout droit.
+    /// Les distances passent par les bords de l'écran, comme le missile.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `lifetime`: la durée de vie (en secondes) d'un missile sans tête chercheuse
+    /// - `asteroids`: tous les asteroides du jeu
+    /// - `bounds`: la taille de l'écran
+    pub fn update(&mut self, dt: f32, lifetime: f32, asteroids: &[Asteroid], bounds: Vec2) {
+        self.age += dt;
+        let lifetime = if self.homing {
+            HOMING_LIFETIME
+        } else {
+            lifetime
+        };
+        if self.age >= lifetime {
+            self.active = false;
+            return;
+        }
+        if !self.homing {
+            return;
+        }
+        let target = asteroids
+            .iter()
+            .filter(|a| a.active && !a.incubating())
+            .map(|a| wrapped_offset(self.position, a.get_pos(), bounds))
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+        if let Some(offset) = target {
+            let turn = self
+                .velocity
+                .angle_between(offset)
+                .clamp(-HOMING_TURN_RATE, HOMING_TURN_RATE);
+            self.velocity = Vec2::from_angle(turn).rotate(self.velocity);
+        }
+    }
+
+    /// Fonction qui renvoie l'orientation du missile, celle de sa vitesse
+    /// # Returns
+    /// - `f32`: l'angle (en radians) de la direction du missile
+    pub fn orientation(&self) -> f32 {
+        self.velocity.y.atan2(self.velocity.x)
+    }
+
+    /// Fonction qui renvoie les extrémités de l'image allongée du missile, centrée sur sa position
+    /// # Returns
+    /// - `(Vec2, Vec2)`: la queue puis la tête du missile
+    pub fn capsule_ends(&self) -> (Vec2, Vec2) {
+        let half_length = (self.radius * SPRITE_LENGTH_FACTOR - self.radius * 2.0) / 2.0;
+        let direction = Vec2::from_angle(self.orientation()) * half_length;
+        (self.position - direction, self.position + direction)
+    }
+
+    /// Dessine le missile sur l'écran, tourné dans la direction de sa vitesse.
+    /// Sans texture, le missile est dessiné comme une capsule avec des lignes et des cercles.
+    /// # Arguments
+    /// - `texture`: la texture du missile, si elle a pu être chargée
+    pub fn draw(&self, texture: Option<&Texture2D>) {
+        let color = match self.source {
+            MissileSource::Player => RED,
+            MissileSource::Enemy => LIME,
+        };
+        match texture {
+            Some(texture) => {
+                let size = vec2(self.radius * SPRITE_LENGTH_FACTOR, self.radius * 2.0);
+                draw_texture_ex(
+                    texture,
+                    self.position.x - size.x / 2.0,
+                    self.position.y - size.y / 2.0,
+                    color,
+                    DrawTextureParams {
+                        dest_size: Some(size),
+                        rotation: self.orientation(),
+                        ..Default::default()
+                    },
+                );
+            }
+            None => {
+                let (tail, head) = self.capsule_ends();
+                draw_line(tail.x, tail.y, head.x, head.y, self.radius * 2.0, color);
+                draw_circle(tail.x, tail.y, self.radius, color);
+                draw_circle(head.x, head.y, self.radius, color);
+            }
+        }
     }
 
     /// Désactive le missile s'il quitte l'écran.
-    fn wrap_around_screen(&mut self) {
-        if self.position.x < 0.0
-            || self.position.x > screen_width()
+    /// Un missile à tête chercheuse passe de l'autre côté de l'écran.
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    fn wrap_around_screen(&mut self, bounds: Vec2) {
+        if self.homing {
+            self.position = vec2(
+                self.position.x.rem_euclid(bounds.x),
+                self.position.y.rem_euclid(bounds.y),
+            );
+        } else if self.position.x < 0.0
+            || self.position.x > bounds.x
             || self.position.y < 0.0
-            || self.position.y > screen_height()
+            || self.position.y > bounds.y
         {
             self.active = false;
         }
     }
 }
 
-use crate::stellarobject::StellarObject; // Utilise le trait StellarObject
+use crate::stellarobject::{debug_field, format_vec2, StellarObject}; // Utilise le trait StellarObject
 
 impl StellarObject for Missile {
     /// Retourne la position de l'objet.
@@ -61,12 +224,30 @@ impl StellarObject for Missile {
         self.position
     }
 
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
     /// Met a jour la position de l'objet.
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
-    fn move_obj(&mut self) {
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
         self.position += self.velocity;
-        self.wrap_around_screen();
+        self.wrap_around_screen(bounds);
     }
 
     /// Retourne le rayon de l'objet.
@@ -83,5 +264,127 @@ impl StellarObject for Missile {
     /// - `&mut self`: une instance de l'objet stellaire
     fn handle_collision(&mut self) {
         self.active = false;
+        self.hit = true;
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état du missile pour l'inspecteur de développement.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec<(String, String)>`: la liste des champs, avec leur nom et leur valeur
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "Missile"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.velocity)),
+            debug_field("radius", format!("{:.1}", self.radius)),
+            debug_field("active", self.active),
+            debug_field("source", format!("{:?}", self.source)),
+            debug_field("homing", self.homing),
+            debug_field("hit", self.hit),
+            debug_field("age", format!("{:.2}", self.age)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::asteroid::Size;
+    use std::f32::consts::FRAC_PI_2;
+
+    /// Vérifie qu'un missile immobile, qui ne quitte jamais l'écran, disparait à la fin de sa durée de vie.
+    #[test]
+    fn test_still_missile_expires() {
+        let mut missile = Missile::new(vec2(400.0, 300.0), 0.0);
+        missile.velocity = Vec2::ZERO;
+        let dt = 0.1;
+
+        for _ in 0..(MISSILE_LIFETIME / dt) as u32 - 1 {
+            missile.update(dt, MISSILE_LIFETIME, &[], vec2(800.0, 600.0));
+            assert!(missile.active);
+        }
+        missile.update(dt * 1.5, MISSILE_LIFETIME, &[], vec2(800.0, 600.0));
+        assert!(
+            !missile.active,
+            "Le missile doit disparaitre après sa durée de vie !"
+        );
+    }
+    /// Vérifie que l'orientation du missile suit sa vitesse.
+    #[test]
+    fn test_orientation_follows_velocity() {
+        let missile = Missile::new(Vec2::ZERO, FRAC_PI_2);
+        assert!((missile.orientation() - FRAC_PI_2).abs() < 1e-5);
+
+        let mut missile = Missile::enemy(Vec2::ZERO, 0.0);
+        missile.velocity = vec2(-1.0, 0.0);
+        assert!((missile.orientation().abs() - std::f32::consts::PI).abs() < 1e-5);
+    }
+
+    /// Vérifie que la capsule de secours est centrée sur le missile et alignée avec sa vitesse.
+    #[test]
+    fn test_capsule_ends_are_aligned_with_velocity() {
+        let missile = Missile::new(vec2(100.0, 100.0), FRAC_PI_2);
+        let (tail, head) = missile.capsule_ends();
+
+        assert!(((tail + head) / 2.0 - missile.position).length() < 1e-4);
+        assert!(head.y > tail.y, "La tête doit être devant le missile !");
+        assert!((head.x - tail.x).abs() < 1e-4);
+        // Avec les cercles aux extrémités, la capsule mesure la longueur de l'image
+        let length = head.distance(tail) + missile.radius() * 2.0;
+        assert!((length - missile.radius() * SPRITE_LENGTH_FACTOR).abs() < 1e-4);
+    }
+
+    /// Vérifie qu'un missile à tête chercheuse tiré à côté d'un asteroide se tourne vers lui,
+    /// sans changer de vitesse, puis disparait au bout de `HOMING_LIFETIME`.
+    #[test]
+    fn test_homing_missile_converges_on_asteroid() {
+        let bounds = vec2(800.0, 600.0);
+        let asteroids = vec![Asteroid::new_with_size(
+            Size::Small,
+            vec2(400.0, 300.0),
+            Vec2::ZERO,
+        )];
+        let mut missile = Missile::new(vec2(100.0, 100.0), 0.0);
+        missile.homing = true;
+        let speed = missile.velocity.length();
+
+        let error = |missile: &Missile| {
+            let offset = wrapped_offset(missile.position, asteroids[0].get_pos(), bounds);
+            missile.velocity.angle_between(offset).abs()
+        };
+        let mut previous = error(&missile);
+        for _ in 0..10 {
+            missile.update(1.0 / 60.0, MISSILE_LIFETIME, &asteroids, bounds);
+            missile.move_obj(bounds);
+            let current = error(&missile);
+            assert!(
+                current < previous,
+                "Le missile doit se tourner vers sa cible !"
+            );
+            previous = current;
+        }
+        for _ in 0..20 {
+            missile.update(1.0 / 60.0, MISSILE_LIFETIME, &asteroids, bounds);
+            missile.move_obj(bounds);
+        }
+        assert!(error(&missile) < 0.05);
+        assert!((missile.velocity.length() - speed).abs() < 1e-3);
+
+        missile.update(HOMING_LIFETIME, MISSILE_LIFETIME, &asteroids, bounds);
+        assert!(
+            !missile.active,
+            "Le missile qui rate sa cible doit disparaitre !"
+        );
     }
 }
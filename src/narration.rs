@@ -0,0 +1,335 @@
+//! Module pour le journal de narration, destiné aux lecteurs d'écran.
+//! Quand il est activé (option `--narrate` ou `--narrate-file=<chemin>`), chaque changement
+//! de bouton survolé, chaque clic dans un menu et les annonces importantes de la partie
+//! sont écrits sous forme de lignes de texte, qu'un outil externe peut surveiller et lire à voix haute.
+//! Sans option, aucune ligne n'est produite et la partie n'est pas modifiée.
+use crate::locale::{tr, Language};
+use crate::menu::{MenuEvent, MenuFocus};
+use macroquad::prelude::*;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+/// Durée minimale (en secondes) entre deux annonces de bouton survolé
+const FOCUS_INTERVAL: f32 = 0.3;
+/// Durée (en secondes) pendant laquelle une ligne identique à la précédente est ignorée
+const REPEAT_INTERVAL: f32 = 1.0;
+
+/// Énumération représentant une annonce du journal de narration
+/// # Variantes
+/// - `Focus`: le pointeur est passé sur le bouton portant ce texte
+/// - `Activate`: le joueur a cliqué sur le bouton portant ce texte
+/// - `WaveStarted`: la vague donnée commence
+/// - `ShieldLost`: le vaisseau a perdu son bouclier
+/// - `GameOver`: la partie est terminée, avec le score donné
+#[derive(Clone, PartialEq, Debug)]
+pub enum Announcement {
+    Focus(String),
+    Activate(String),
+    WaveStarted(u32),
+    ShieldLost,
+    GameOver { score: i32 },
+}
+
+impl Announcement {
+    /// Fonction qui renvoie le texte de l'annonce
+    /// # Arguments
+    /// - `language`: la langue du texte
+    /// # Returns
+    /// - `String`: la ligne à écrire dans le journal
+    pub fn text(&self, language: Language) -> String {
+        match self {
+            Announcement::Focus(label) => {
+                format!("{}: {label}", tr(language, "narration.focus"))
+            }
+            Announcement::Activate(label) => {
+                format!("{}: {label}", tr(language, "narration.activate"))
+            }
+            Announcement::WaveStarted(wave) => {
+                format!("{}: {wave}", tr(language, "narration.wave"))
+            }
+            Announcement::ShieldLost => tr(language, "caption.shield_lost").to_string(),
+            Announcement::GameOver { score } => {
+                format!("{}: {score}", tr(language, "narration.game_over"))
+            }
+        }
+    }
+
+    /// Fonction qui permet de savoir si l'annonce peut arriver plusieurs fois par seconde
+    /// # Returns
+    /// - `bool`: `true` pour les annonces de bouton survolé, qui sont limitées
+    fn is_high_frequency(&self) -> bool {
+        matches!(self, Announcement::Focus(_))
+    }
+}
+
+/// Énumération représentant la destination du journal
+/// # Variantes
+/// - `Stdout`: la sortie standard (option `--narrate`)
+/// - `File`: un fichier, complété à chaque lancement (option `--narrate-file=<chemin>`)
+#[derive(Clone, PartialEq, Debug)]
+pub enum Backend {
+    Stdout,
+    File(PathBuf),
+}
+
+impl Backend {
+    /// Fonction qui choisit la destination du journal à partir des arguments de la ligne de commande
+    /// # Arguments
+    /// - `args`: les arguments, sans le nom de l'exécutable
+    /// # Returns
+    /// - `Option<Backend>`: la destination, ou `None` si la narration n'est pas demandée
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Option<Self> {
+        args.into_iter().find_map(|arg| {
+            if arg == "--narrate" {
+                Some(Backend::Stdout)
+            } else {
+                arg.strip_prefix("--narrate-file=")
+                    .map(|path| Backend::File(PathBuf::from(path)))
+            }
+        })
+    }
+
+    /// Ouvre la destination du journal
+    /// # Returns
+    /// - `io::Result<Box<dyn Write>>`: la destination, ou une erreur si le fichier n'a pas pu être ouvert
+    fn open(&self) -> io::Result<Box<dyn Write>> {
+        match self {
+            Backend::Stdout => Ok(Box::new(io::stdout())),
+            Backend::File(path) => Ok(Box::new(
+                OpenOptions::new().create(true).append(true).open(path)?,
+            )),
+        }
+    }
+}
+
+/// Structure qui écrit les annonces, en limitant celles qui arrivent trop souvent
+/// # Champs
+/// - `out`: la destination des lignes
+/// - `clock`: le temps écoulé depuis la création du journal
+/// - `last_focus`: le moment de la dernière annonce de bouton survolé
+/// - `pending_focus`: la dernière annonce de bouton survolé retenue, écrite dès que possible
+/// - `last_line`: la dernière ligne écrite et le moment où elle l'a été
+pub struct NarrationSink<W: Write> {
+    out: W,
+    clock: f32,
+    last_focus: Option<f32>,
+    pending_focus: Option<String>,
+    last_line: Option<(String, f32)>,
+}
+
+impl<W: Write> NarrationSink<W> {
+    /// Crée un journal qui écrit dans la destination donnée
+    /// # Arguments
+    /// - `out`: la destination des lignes
+    /// # Returns
+    /// - `Self`: le journal
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            clock: 0.0,
+            last_focus: None,
+            pending_focus: None,
+            last_line: None,
+        }
+    }
+
+    /// Ajoute une annonce au journal.
+    /// Une annonce de bouton survolé trop proche de la précédente est retenue, et remplacée
+    /// par la suivante : seul le dernier bouton survolé est annoncé.
+    /// # Arguments
+    /// - `announcement`: l'annonce
+    /// - `language`: la langue du texte
+    pub fn emit(&mut self, announcement: &Announcement, language: Language) {
+        let line = announcement.text(language);
+        if !announcement.is_high_frequency() {
+            self.write(line);
+            return;
+        }
+        if self
+            .last_focus
+            .is_some_and(|time| self.clock - time < FOCUS_INTERVAL)
+        {
+            self.pending_focus = Some(line);
+        } else {
+            self.pending_focus = None;
+            self.last_focus = Some(self.clock);
+            self.write(line);
+        }
+    }
+
+    /// Fait avancer l'horloge du journal et écrit l'annonce retenue quand c'est possible
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.clock += dt;
+        if self
+            .last_focus
+            .is_some_and(|time| self.clock - time < FOCUS_INTERVAL)
+        {
+            return;
+        }
+        if let Some(line) = self.pending_focus.take() {
+            self.last_focus = Some(self.clock);
+            self.write(line);
+        }
+    }
+
+    /// Écrit une ligne, sauf si elle répète la précédente depuis moins de `REPEAT_INTERVAL`
+    /// # Arguments
+    /// - `line`: la ligne à écrire
+    fn write(&mut self, line: String) {
+        if self
+            .last_line
+            .as_ref()
+            .is_some_and(|(last, time)| *last == line && self.clock - time < REPEAT_INTERVAL)
+        {
+            return;
+        }
+        // Le journal ne doit jamais interrompre la partie : les erreurs d'écriture sont ignorées
+        let _ = writeln!(self.out, "{line}").and_then(|_| self.out.flush());
+        self.last_line = Some((line, self.clock));
+    }
+}
+
+/// Structure regroupant le journal de narration et le suivi des menus
+/// # Champs
+/// - `sink`: le journal, ou `None` si la narration est désactivée
+/// - `focus`: le bouton survolé dans le menu affiché
+/// - `screen`: le nom du menu affiché, pour oublier le bouton survolé en changeant d'écran
+pub struct Narration {
+    sink: Option<NarrationSink<Box<dyn Write>>>,
+    focus: MenuFocus,
+    screen: &'static str,
+}
+
+impl Narration {
+    /// Crée la narration demandée par les options de la ligne de commande
+    /// # Arguments
+    /// - `backend`: la destination du journal, ou `None` si la narration n'est pas demandée
+    /// # Returns
+    /// - `Self`: la narration, désactivée sans destination ou si le fichier ne s'ouvre pas
+    pub fn open(backend: Option<&Backend>) -> Self {
+        let sink = backend.and_then(|backend| {
+            backend
+                .open()
+                .map_err(|err| {
+                    log::error!(
+                        "Erreur lors de l'ouverture du journal de narration : {:?}",
+                        err
+                    )
+                })
+                .ok()
+                .map(NarrationSink::new)
+        });
+        Self {
+            sink,
+            focus: MenuFocus::default(),
+            screen: "",
+        }
+    }
+
+    /// Ajoute une annonce au journal, si la narration est activée
+    /// # Arguments
+    /// - `announcement`: l'annonce
+    /// - `language`: la langue du texte
+    pub fn announce(&mut self, announcement: Announcement, language: Language) {
+        if let Some(sink) = &mut self.sink {
+            sink.emit(&announcement, language);
+        }
+    }
+
+    /// Annonce les changements dans le menu affiché, si la narration est activée
+    /// # Arguments
+    /// - `screen`: le nom du menu
+    /// - `buttons`: la position des boutons
+    /// - `labels`: le texte des boutons, dans le même ordre
+    /// - `language`: la langue du texte
+    pub fn menu(
+        &mut self,
+        screen: &'static str,
+        buttons: &[Rect],
+        labels: &[&str],
+        language: Language,
+    ) {
+        let Some(sink) = &mut self.sink else {
+            return;
+        };
+        if screen != self.screen {
+            self.screen = screen;
+            self.focus = MenuFocus::default();
+        }
+        let events = self.focus.update(
+            buttons,
+            mouse_position().into(),
+            is_mouse_button_pressed(MouseButton::Left),
+        );
+        for event in events {
+            let announcement = match event {
+                MenuEvent::Focus(i) => Announcement::Focus(labels[i].to_string()),
+                MenuEvent::Activate(i) => Announcement::Activate(labels[i].to_string()),
+            };
+            sink.emit(&announcement, language);
+        }
+    }
+
+    /// Fait avancer l'horloge du journal
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        if let Some(sink) = &mut self.sink {
+            sink.update(dt);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renvoie les lignes écrites dans le journal
+    fn lines(sink: &NarrationSink<Vec<u8>>) -> Vec<&str> {
+        std::str::from_utf8(&sink.out).unwrap().lines().collect()
+    }
+
+    /// Vérifie le texte des annonces et le choix de la destination.
+    #[test]
+    fn test_announcement_text_and_backend() {
+        let mut sink = NarrationSink::new(Vec::new());
+        sink.emit(&Announcement::WaveStarted(3), Language::English);
+        sink.emit(&Announcement::GameOver { score: 420 }, Language::French);
+        assert_eq!(lines(&sink), vec!["Wave: 3", "Partie terminée, score: 420"]);
+
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(Backend::from_args(args(&[])), None);
+        assert_eq!(
+            Backend::from_args(args(&["--narrate"])),
+            Some(Backend::Stdout)
+        );
+        assert_eq!(
+            Backend::from_args(args(&["--narrate-file=log.txt"])),
+            Some(Backend::File(PathBuf::from("log.txt")))
+        );
+    }
+
+    /// Vérifie qu'un balayage rapide du menu n'annonce que le premier et le dernier bouton,
+    /// et qu'une même annonce répétée aussitôt n'est écrite qu'une fois.
+    #[test]
+    fn test_focus_rate_limiting() {
+        let mut sink = NarrationSink::new(Vec::new());
+        for label in ["Jouer", "Quitter", "Sous-titres"] {
+            sink.emit(&Announcement::Focus(label.to_string()), Language::French);
+            sink.update(0.05);
+        }
+        sink.emit(&Announcement::ShieldLost, Language::French);
+        sink.emit(&Announcement::ShieldLost, Language::French);
+        for _ in 0..10 {
+            sink.update(0.05);
+        }
+
+        assert_eq!(
+            lines(&sink),
+            vec!["Bouton: Jouer", "bouclier perdu", "Bouton: Sous-titres"]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Module pour ce qui change entre le jeu de bureau et le jeu dans le navigateur (fonctionnalité `wasm`).
+//! Le navigateur n'a ni système de fichiers, ni horloge système, ni source d'entropie pour `rand`,
+//! et le jeu ne peut pas y fermer sa page : le reste du jeu passe par ces fonctions
+//! et ne dépend pas de la plateforme.
+//! Dans le navigateur, les fichiers de données sont gardés en mémoire le temps de la session :
+//! les réglages et les scores ne survivent pas au rechargement de la page.
+//! Les ressources sont intégrées au jeu, ou cherchées par des chemins relatifs à la page.
+use ::rand::Rng;
+use std::io;
+use std::path::Path;
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("La version navigateur se compile avec la fonctionnalité `wasm` : cargo build --target wasm32-unknown-unknown --features wasm");
+
+/// Permet de savoir si le jeu peut se fermer : dans le navigateur, c'est la page qui se ferme
+pub const CAN_QUIT: bool = !cfg!(feature = "wasm");
+
+/// Fichiers de données de la session, à la place du système de fichiers du navigateur
+#[cfg(feature = "wasm")]
+static FILES: std::sync::Mutex<Vec<(std::path::PathBuf, String)>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// Nombre de générateurs créés, mêlé à l'heure pour que deux générateurs ne tirent pas les mêmes nombres
+#[cfg(feature = "wasm")]
+static GENERATORS: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Fonction qui lit un fichier de données
+/// # Arguments
+/// - `path`: le chemin du fichier
+/// # Returns
+/// - `io::Result<String>`: le contenu du fichier, ou une erreur s'il n'existe pas
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    #[cfg(not(feature = "wasm"))]
+    {
+        std::fs::read_to_string(path)
+    }
+    #[cfg(feature = "wasm")]
+    {
+        FILES
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(file, _)| file == path)
+            .map(|(_, content)| content.clone())
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+/// Fonction qui écrit un fichier de données, en remplaçant son contenu
+/// # Arguments
+/// - `path`: le chemin du fichier
+/// - `content`: le contenu du fichier
+/// # Returns
+/// - `io::Result<()>`: une erreur si le fichier n'a pas pu être écrit
+pub fn write(path: &Path, content: &str) -> io::Result<()> {
+    #[cfg(not(feature = "wasm"))]
+    {
+        std::fs::write(path, content)
+    }
+    #[cfg(feature = "wasm")]
+    {
+        let mut files = FILES.lock().unwrap();
+        files.retain(|(file, _)| file != path);
+        files.push((path.to_path_buf(), content.to_string()));
+        Ok(())
+    }
+}
+
+/// Fonction qui renvoie l'heure actuelle
+/// # Returns
+/// - `u64`: le nombre de secondes depuis le 1er janvier 1970
+pub fn unix_seconds() -> u64 {
+    #[cfg(not(feature = "wasm"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0)
+    }
+    #[cfg(feature = "wasm")]
+    {
+        macroquad::miniquad::date::now() as u64
+    }
+}
+
+/// Fonction qui renvoie un générateur de nombres aléatoires imprévisible,
+/// pour ce qui ne dépend pas de la graine de la partie
+/// # Returns
+/// - `impl Rng`: le générateur
+pub fn entropy_rng() -> impl Rng {
+    #[cfg(not(feature = "wasm"))]
+    {
+        ::rand::thread_rng()
+    }
+    #[cfg(feature = "wasm")]
+    {
+        use ::rand::SeedableRng;
+        use std::sync::atomic::Ordering;
+        let count = GENERATORS.fetch_add(1, Ordering::Relaxed);
+        let time = macroquad::miniquad::date::now().to_bits();
+        ::rand::rngs::SmallRng::seed_from_u64(time ^ count.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+}
@@ -0,0 +1,92 @@
+//! Module pour adapter l'aire de jeu à la taille de la fenêtre.
+//! Les tailles, les vitesses et les marges des objets sont prévues pour une fenêtre de `REFERENCE_RESOLUTION`.
+//! Dans une autre fenêtre, l'aire de jeu est dessinée agrandie ou réduite d'un même facteur,
+//! pour que la partie se joue de la même façon quelle que soit la taille de la fenêtre.
+//! Tous les calculs de la partie, passage d'un bord à l'autre compris, se font dans l'aire de jeu.
+use macroquad::prelude::*;
+
+/// Taille de la fenêtre de référence, celle de la fenêtre par défaut,
+/// pour laquelle l'aire de jeu est dessinée sans agrandissement
+pub const REFERENCE_RESOLUTION: Vec2 = vec2(800.0, 600.0);
+
+/// Fonction qui calcule le facteur d'agrandissement de l'aire de jeu.
+/// Le tableau de bord utilise le même facteur, pour garder sa place par rapport aux objets.
+/// # Arguments
+/// - `screen`: la taille de la fenêtre
+/// # Returns
+/// - `f32`: le rapport entre la fenêtre et l'aire de jeu, `1` pour la fenêtre de référence
+pub fn playfield_factor(screen: Vec2) -> f32 {
+    let factor = (screen / REFERENCE_RESOLUTION).min_element();
+    // Une fenêtre réduite à rien ne doit pas donner une aire de jeu infinie
+    factor.max(0.05)
+}
+
+/// Fonction qui calcule la taille de l'aire de jeu pour une fenêtre.
+/// L'aire de jeu garde les proportions de la fenêtre.
+/// # Arguments
+/// - `screen`: la taille de la fenêtre
+/// # Returns
+/// - `Vec2`: la taille de l'aire de jeu, au moins `REFERENCE_RESOLUTION` sur les deux axes
+pub fn playfield_bounds(screen: Vec2) -> Vec2 {
+    screen / playfield_factor(screen)
+}
+
+/// Fonction qui crée la caméra montrant toute l'aire de jeu dans la fenêtre
+/// # Arguments
+/// - `bounds`: la taille de l'aire de jeu
+/// # Returns
+/// - `Camera2D`: la caméra, avec l'origine en haut à gauche comme la caméra par défaut
+pub fn playfield_camera(bounds: Vec2) -> Camera2D {
+    Camera2D {
+        target: bounds / 2.0,
+        zoom: vec2(2.0 / bounds.x, 2.0 / bounds.y),
+        ..Default::default()
+    }
+}
+
+/// Fonction qui décale une caméra, pour secouer l'affichage sans déplacer les objets
+/// # Arguments
+/// - `camera`: la caméra
+/// - `offset`: le décalage de l'affichage
+/// # Returns
+/// - `Camera2D`: la caméra décalée, qui dessine à l'écran
+pub fn shaken_camera(camera: &Camera2D, offset: Vec2) -> Camera2D {
+    Camera2D {
+        target: camera.target - offset,
+        zoom: camera.zoom,
+        rotation: camera.rotation,
+        offset: camera.offset,
+        viewport: camera.viewport,
+        render_target: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que la fenêtre de référence n'est pas agrandie et qu'une fenêtre deux fois plus
+    /// grande montre la même aire de jeu, agrandie deux fois.
+    #[test]
+    fn test_reference_window_is_unscaled() {
+        assert_eq!(playfield_factor(REFERENCE_RESOLUTION), 1.0);
+        assert_eq!(playfield_bounds(REFERENCE_RESOLUTION), REFERENCE_RESOLUTION);
+
+        let screen = REFERENCE_RESOLUTION * 2.0;
+        assert_eq!(playfield_factor(screen), 2.0);
+        assert_eq!(playfield_bounds(screen), REFERENCE_RESOLUTION);
+    }
+
+    /// Vérifie qu'une fenêtre de 320×240 joue sur une aire de jeu assez grande,
+    /// avec les proportions de la fenêtre.
+    #[test]
+    fn test_small_window_is_scaled_down() {
+        let screen = vec2(320.0, 240.0);
+        let bounds = playfield_bounds(screen);
+
+        assert!((playfield_factor(screen) - 0.4).abs() < 1e-6);
+        assert!((bounds.x - REFERENCE_RESOLUTION.x).abs() < 1e-3);
+        assert!(bounds.y >= REFERENCE_RESOLUTION.y);
+        assert!((bounds.x / bounds.y - 320.0 / 240.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,183 @@
+//! Module pour les réserves d'objets réutilisés, comme les missiles et les textes temporaires.
+//! Les objets vivants sont gardés au début de la réserve, dans leur ordre d'arrivée :
+//! la réserve s'utilise comme une liste des seuls objets vivants. Un objet retiré n'est pas
+//! détruit mais rangé à la fin, et sa place est reprise par le prochain objet ajouté,
+//! ce qui évite d'allouer à chaque tir ou à chaque texte.
+use std::ops::{Deref, DerefMut};
+
+/// Structure représentant une réserve d'objets de taille bornée
+/// # Champs
+/// - `slots`: toutes les places déjà créées, les objets vivants d'abord, puis les places libres
+/// - `live`: le nombre d'objets vivants
+/// - `max`: le nombre maximal de places, réservées dès la création
+pub struct Pool<T> {
+    slots: Vec<T>,
+    live: usize,
+    max: usize,
+}
+
+impl<T> Pool<T> {
+    /// Crée une réserve vide, avec la place pour `max` objets
+    /// # Arguments
+    /// - `max`: le nombre maximal d'objets vivants en même temps
+    /// # Returns
+    /// - `Self`: la réserve vide
+    pub fn new(max: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(max),
+            live: 0,
+            max,
+        }
+    }
+
+    /// Fonction qui renvoie le nombre maximal d'objets de la réserve
+    /// # Returns
+    /// - `usize`: le nombre maximal d'objets
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Fonction qui renvoie le nombre de places réservées en mémoire
+    /// # Returns
+    /// - `usize`: la capacité de la réserve, qui ne dépasse pas `max`
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Fonction qui renvoie le nombre de places libres déjà créées, prêtes à être reprises
+    /// # Returns
+    /// - `usize`: le nombre de places libres
+    pub fn free(&self) -> usize {
+        self.slots.len() - self.live
+    }
+
+    /// Prend une place pour un nouvel objet, à la fin des objets vivants.
+    /// Une place libre est reprise telle quelle : c'est à l'appelant de la remplir.
+    /// # Arguments
+    /// - `make`: crée l'objet quand aucune place libre n'existe encore
+    /// # Returns
+    /// - `Option<&mut T>`: la place prise, ou `None` si la réserve est pleine
+    pub fn acquire(&mut self, make: impl FnOnce() -> T) -> Option<&mut T> {
+        if self.live == self.slots.len() {
+            if self.slots.len() >= self.max {
+                return None;
+            }
+            self.slots.push(make());
+        }
+        self.live += 1;
+        Some(&mut self.slots[self.live - 1])
+    }
+
+    /// Ajoute un objet à la fin des objets vivants, en reprenant une place libre si possible
+    /// # Arguments
+    /// - `value`: l'objet à ajouter
+    /// # Returns
+    /// - `bool`: `true` si l'objet a été ajouté, `false` si la réserve est pleine
+    pub fn push(&mut self, value: T) -> bool {
+        if self.live < self.slots.len() {
+            self.slots[self.live] = value;
+        } else if self.slots.len() < self.max {
+            self.slots.push(value);
+        } else {
+            return false;
+        }
+        self.live += 1;
+        true
+    }
+
+    /// Rend les places des objets qui ne vérifient pas `keep`.
+    /// Les objets gardés restent dans le même ordre, comme avec `Vec::retain`.
+    /// # Arguments
+    /// - `keep`: la condition pour garder un objet
+    /// # Returns
+    /// - `usize`: le nombre d'objets retirés
+    pub fn release(&mut self, mut keep: impl FnMut(&T) -> bool) -> usize {
+        let before = self.live;
+        let mut kept = 0;
+        for i in 0..self.live {
+            if keep(&self.slots[i]) {
+                self.slots.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.live = kept;
+        before - kept
+    }
+
+    /// Rend les places de tous les objets
+    /// # Returns
+    /// - `usize`: le nombre d'objets retirés
+    pub fn clear(&mut self) -> usize {
+        std::mem::replace(&mut self.live, 0)
+    }
+}
+
+impl<T> Extend<T> for Pool<T> {
+    /// Ajoute des objets tant que la réserve n'est pas pleine
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if !self.push(value) {
+                break;
+            }
+        }
+    }
+}
+
+impl<T> Deref for Pool<T> {
+    type Target = [T];
+
+    /// Les objets vivants, dans leur ordre d'arrivée
+    fn deref(&self) -> &[T] {
+        &self.slots[..self.live]
+    }
+}
+
+impl<T> DerefMut for Pool<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.slots[..self.live]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::missile::Missile;
+    use crate::stellarobject::StellarObject;
+    use macroquad::prelude::*;
+
+    /// Vérifie que les objets gardés restent dans l'ordre et que les places rendues sont reprises.
+    #[test]
+    fn test_release_keeps_order_and_reuses_slots() {
+        let mut pool = Pool::new(4);
+        pool.extend([1, 2, 3, 4, 5]);
+        assert_eq!(&pool[..], &[1, 2, 3, 4]);
+
+        assert_eq!(pool.release(|n| n % 2 == 0), 2);
+        assert_eq!(&pool[..], &[2, 4]);
+        assert_eq!(pool.free(), 2);
+
+        assert!(pool.push(6));
+        *pool.acquire(|| 0).unwrap() = 8;
+        assert_eq!(&pool[..], &[2, 4, 6, 8]);
+        assert!(!pool.push(10));
+        assert_eq!(pool.capacity(), 4);
+    }
+
+    /// Tire puis laisse sortir de l'écran 10 000 missiles :
+    /// la réserve ne dépasse jamais sa taille maximale.
+    #[test]
+    fn test_missile_pool_stays_bounded() {
+        let bounds = vec2(800.0, 600.0);
+        let max = 128;
+        let mut missiles = Pool::new(max);
+        for i in 0..10_000 {
+            assert!(missiles.push(Missile::new(bounds / 2.0, i as f32 * 0.1)));
+            for missile in missiles.iter_mut() {
+                missile.move_obj(bounds);
+            }
+            missiles.release(|m| m.active);
+            assert!(missiles.capacity() <= max);
+        }
+        assert!(missiles.free() > 0);
+    }
+}
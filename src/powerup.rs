@@ -0,0 +1,382 @@
+//! Module pour les bonus laissés par les asteroides détruits.
+//! Un bonus dérive lentement sur l'écran et disparait au bout de quelques secondes :
+//! le vaisseau le ramasse en passant dessus.
+use crate::platform::entropy_rng;
+use crate::weapon::TRIPLE_SHOT_TIME;
+use ::rand::Rng;
+use macroquad::prelude::*;
+
+/// Probabilité par défaut qu'un asteroide détruit laisse un bonus
+pub const DEFAULT_DROP_CHANCE: f64 = 0.1;
+/// Durée de vie (en secondes) d'un bonus qui n'est pas ramassé
+pub const LIFETIME: f32 = 8.0;
+/// Durée (en secondes) pendant laquelle un bonus clignote avant de disparaitre
+const BLINK_TIME: f32 = 2.0;
+/// Vitesse de dérive d'un bonus
+const DRIFT_SPEED: f32 = 0.4;
+/// Durée (en secondes) du tir rapide
+pub const RAPID_FIRE_TIME: f32 = 10.0;
+/// Durée (en secondes) des missiles à tête chercheuse
+pub const HOMING_TIME: f32 = 8.0;
+/// Première vague où les bonus de missiles à tête chercheuse peuvent apparaitre
+pub const HOMING_MIN_WAVE: u32 = 4;
+
+/// Énumération représentant les types de bonus
+/// # Variantes
+/// - `ShieldRestore`: rend son bouclier au vaisseau
+/// - `RapidFire`: divise par deux le temps de recharge de l'arme pendant `RAPID_FIRE_TIME`
+/// - `TripleShot`: chaque tir est une salve de trois missiles pendant `TRIPLE_SHOT_TIME`
+/// - `Homing`: les missiles tirés ont une tête chercheuse pendant `HOMING_TIME`,
+///   seulement à partir de la vague `HOMING_MIN_WAVE`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PowerUpKind {
+    ShieldRestore,
+    RapidFire,
+    TripleShot,
+    Homing,
+}
+
+impl PowerUpKind {
+    /// Tous les types de bonus, tirés au hasard quand un bonus apparait
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::ShieldRestore,
+        PowerUpKind::RapidFire,
+        PowerUpKind::TripleShot,
+        PowerUpKind::Homing,
+    ];
+
+    /// Fonction qui renvoie la première vague où le bonus peut apparaitre
+    /// # Returns
+    /// - `u32`: le numéro de la vague
+    pub fn min_wave(self) -> u32 {
+        match self {
+            PowerUpKind::Homing => HOMING_MIN_WAVE,
+            _ => 1,
+        }
+    }
+
+    /// Fonction qui renvoie la clé du texte affiché quand le bonus est ramassé
+    /// # Returns
+    /// - `&'static str`: la clé de traduction du texte du bonus
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerUpKind::ShieldRestore => "popup.shield_restore",
+            PowerUpKind::RapidFire => "popup.rapid_fire",
+            PowerUpKind::TripleShot => "popup.triple_shot",
+            PowerUpKind::Homing => "popup.homing",
+        }
+    }
+
+    /// Fonction qui renvoie la durée de l'effet du bonus
+    /// # Returns
+    /// - `Option<f32>`: la durée en secondes, ou `None` si l'effet est immédiat
+    pub fn duration(self) -> Option<f32> {
+        match self {
+            PowerUpKind::ShieldRestore => None,
+            PowerUpKind::RapidFire => Some(RAPID_FIRE_TIME),
+            PowerUpKind::TripleShot => Some(TRIPLE_SHOT_TIME),
+            PowerUpKind::Homing => Some(HOMING_TIME),
+        }
+    }
+
+    /// Fonction qui renvoie la clé de traduction du nom de l'effet, affiché dans le tableau de bord
+    /// # Returns
+    /// - `&'static str`: la clé du nom de l'effet
+    pub fn hud_key(self) -> &'static str {
+        match self {
+            PowerUpKind::ShieldRestore => "hud.shield_restore",
+            PowerUpKind::RapidFire => "hud.rapid_fire",
+            PowerUpKind::TripleShot => "hud.triple_shot",
+            PowerUpKind::Homing => "hud.homing",
+        }
+    }
+
+    /// Fonction qui renvoie la couleur du bonus
+    /// # Returns
+    /// - `Color`: la couleur du bonus et de son texte
+    pub fn color(self) -> Color {
+        match self {
+            PowerUpKind::ShieldRestore => SKYBLUE,
+            PowerUpKind::RapidFire => ORANGE,
+            PowerUpKind::TripleShot => VIOLET,
+            PowerUpKind::Homing => LIME,
+        }
+    }
+}
+
+/// Structure regroupant les effets des bonus en cours sur le vaisseau
+/// # Champs
+/// - `timers`: chaque effet en cours avec son temps restant, dans l'ordre où ils ont été ramassés
+#[derive(Clone, Default, Debug)]
+pub struct ActiveEffects {
+    timers: Vec<(PowerUpKind, f32)>,
+}
+
+impl ActiveEffects {
+    /// Active l'effet d'un bonus. Un effet déjà en cours repart pour toute sa durée,
+    /// sans s'additionner au temps restant.
+    /// # Arguments
+    /// - `kind`: le type du bonus
+    /// - `duration`: la durée de l'effet, en secondes
+    pub fn activate(&mut self, kind: PowerUpKind, duration: f32) {
+        match self.timers.iter_mut().find(|(active, _)| *active == kind) {
+            Some((_, timer)) => *timer = duration,
+            None => self.timers.push((kind, duration)),
+        }
+    }
+
+    /// Fait avancer le temps des effets et retire ceux qui sont terminés
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        for (_, timer) in self.timers.iter_mut() {
+            *timer -= dt;
+        }
+        self.timers.retain(|(_, timer)| *timer > 0.0);
+    }
+
+    /// Fonction qui renvoie le temps restant d'un effet
+    /// # Arguments
+    /// - `kind`: le type du bonus
+    /// # Returns
+    /// - `Option<f32>`: le temps restant en secondes, ou `None` si l'effet n'est pas en cours
+    pub fn remaining(&self, kind: PowerUpKind) -> Option<f32> {
+        self.timers
+            .iter()
+            .find(|(active, _)| *active == kind)
+            .map(|(_, timer)| *timer)
+    }
+
+    /// Fonction qui permet de savoir si un effet est en cours
+    /// # Arguments
+    /// - `kind`: le type du bonus
+    /// # Returns
+    /// - `bool`: `true` si l'effet est en cours
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.remaining(kind).is_some()
+    }
+
+    /// Fonction qui renvoie les effets en cours, pour le tableau de bord
+    /// # Returns
+    /// - `&[(PowerUpKind, f32)]`: les effets avec leur temps restant
+    pub fn timers(&self) -> &[(PowerUpKind, f32)] {
+        &self.timers
+    }
+}
+
+/// Structure représentant un bonus
+/// # Champs
+/// - `position`: la position du bonus
+/// - `velocity`: la vitesse de dérive du bonus
+/// - `kind`: le type du bonus
+/// - `timer`: le temps restant avant la disparition du bonus
+/// - `active`: permet de savoir si le bonus est encore là ou non
+/// - `radius`: le rayon du bonus
+pub struct PowerUp {
+    position: Vec2,
+    velocity: Vec2,
+    pub kind: PowerUpKind,
+    pub timer: f32,
+    pub active: bool,
+    radius: f32,
+}
+
+impl PowerUp {
+    /// Crée un bonus qui dérive dans une direction choisie au hasard
+    /// # Arguments
+    /// - `position`: la position initiale du bonus
+    /// - `kind`: le type du bonus
+    /// # Returns
+    /// - `Self`: le bonus
+    pub fn new(position: Vec2, kind: PowerUpKind) -> Self {
+        Self::drifting(position, kind, &mut entropy_rng())
+    }
+
+    /// Crée un bonus qui dérive dans une direction tirée par un générateur donné
+    /// # Arguments
+    /// - `position`: la position initiale du bonus
+    /// - `kind`: le type du bonus
+    /// - `rng`: le générateur qui tire la direction
+    /// # Returns
+    /// - `Self`: le bonus
+    fn drifting(position: Vec2, kind: PowerUpKind, rng: &mut impl Rng) -> Self {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        Self {
+            position,
+            velocity: Vec2::from_angle(angle) * DRIFT_SPEED,
+            kind,
+            timer: LIFETIME,
+            active: true,
+            radius: 15.0,
+        }
+    }
+
+    /// Crée un bonus d'un type choisi au hasard parmi ceux qui peuvent apparaitre pendant la vague
+    /// # Arguments
+    /// - `position`: la position initiale du bonus
+    /// - `wave`: le numéro de la vague en cours
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `Self`: le bonus
+    pub fn random(position: Vec2, wave: u32, rng: &mut impl Rng) -> Self {
+        let kinds: Vec<PowerUpKind> = PowerUpKind::ALL
+            .into_iter()
+            .filter(|kind| kind.min_wave() <= wave)
+            .collect();
+        let kind = kinds[rng.gen_range(0..kinds.len())];
+        Self::drifting(position, kind, rng)
+    }
+
+    /// Fait vieillir le bonus, qui disparait à la fin de sa durée de vie
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.timer -= dt;
+        if self.timer <= 0.0 {
+            self.active = false;
+        }
+    }
+
+    /// Fonction qui dessine le bonus, qui clignote juste avant de disparaitre
+    pub fn draw(&self) {
+        if self.timer < BLINK_TIME && (self.timer * 8.0) as i32 % 2 == 0 {
+            return;
+        }
+        let color = self.kind.color();
+        draw_circle(
+            self.position.x,
+            self.position.y,
+            self.radius,
+            Color::new(color.r, color.g, color.b, 0.25),
+        );
+        draw_circle_lines(self.position.x, self.position.y, self.radius, 2.0, color);
+        draw_circle_lines(
+            self.position.x,
+            self.position.y,
+            self.radius * 0.5,
+            2.0,
+            color,
+        );
+    }
+}
+
+use crate::stellarobject::{debug_field, format_vec2, StellarObject}; // Utilise le trait StellarObject
+
+impl StellarObject for PowerUp {
+    /// Retourne la position de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: un vecteur avec la position x et y de l'objet stellaire
+    fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    /// Met a jour la position de l'objet, qui passe d'un bord de l'écran à l'autre.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
+        self.position += self.velocity;
+        self.position = (self.position + bounds) % bounds;
+    }
+
+    /// Retourne le rayon de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `f32`: le rayon de l'objet stellaire
+    fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Gere la collision avec un autre objet : le bonus est ramassé.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    fn handle_collision(&mut self) {
+        self.active = false;
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état du bonus pour l'inspecteur de développement.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec<(String, String)>`: la liste des champs, avec leur nom et leur valeur
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "PowerUp"),
+            debug_field("kind", format!("{:?}", self.kind)),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.velocity)),
+            debug_field("radius", format!("{:.1}", self.radius)),
+            debug_field("active", self.active),
+            debug_field("timer", format!("{:.2}", self.timer)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie qu'un bonus qui n'est pas ramassé disparait au bout de `LIFETIME`.
+    #[test]
+    fn test_power_up_expires() {
+        let mut power_up = PowerUp::new(Vec2::ZERO, PowerUpKind::ShieldRestore);
+
+        power_up.update(LIFETIME - 1.0);
+        assert!(power_up.active);
+        power_up.update(1.0);
+        assert!(!power_up.active, "Le bonus doit disparaitre !");
+    }
+
+    /// Vérifie qu'un effet s'active, repart pour toute sa durée quand il est ramassé à nouveau,
+    /// puis se termine.
+    #[test]
+    fn test_active_effect_activation_refresh_and_expiry() {
+        let mut effects = ActiveEffects::default();
+        assert!(!effects.is_active(PowerUpKind::RapidFire));
+
+        effects.activate(PowerUpKind::RapidFire, RAPID_FIRE_TIME);
+        effects.update(6.0);
+        assert_eq!(effects.remaining(PowerUpKind::RapidFire), Some(4.0));
+
+        // Un deuxième bonus repart à 10 secondes, sans donner 14 secondes
+        effects.activate(PowerUpKind::RapidFire, RAPID_FIRE_TIME);
+        assert_eq!(
+            effects.remaining(PowerUpKind::RapidFire),
+            Some(RAPID_FIRE_TIME)
+        );
+        assert_eq!(effects.timers().len(), 1, "Les effets ne s'empilent pas !");
+
+        effects.update(RAPID_FIRE_TIME);
+        assert!(!effects.is_active(PowerUpKind::RapidFire));
+        assert!(effects.timers().is_empty());
+    }
+}
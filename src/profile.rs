@@ -0,0 +1,209 @@
+//! Module pour gérer le profil du joueur, sauvegardé entre les parties.
+//! Le profil est un simple fichier texte avec une entrée `clé=valeur` par ligne.
+use crate::session::SessionStats;
+use asteroid::platform;
+use asteroid::remix::AsteroidSnapshot;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier du profil
+const PROFILE_FILE: &str = "profile.txt";
+
+/// Structure représentant le profil du joueur
+/// # Champs
+/// - `deaths`: le nombre de morts pour chaque raison de fin de partie
+/// - `last_session`: les statistiques de la dernière session terminée, s'il y en a une
+/// - `last_field`: les asteroides présents à la dernière mort, repris par le mode « Revanche »
+/// - `acknowledged_tips`: les identifiants des astuces fermées par le joueur, qui ne reviennent plus
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Profile {
+    pub deaths: BTreeMap<String, u32>,
+    pub last_session: Option<SessionStats>,
+    pub last_field: Vec<AsteroidSnapshot>,
+    pub acknowledged_tips: BTreeSet<String>,
+}
+
+impl Profile {
+    /// Charge le profil depuis un fichier.
+    /// Un fichier absent ou des lignes illisibles donnent un profil vide plutôt qu'une erreur.
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `Self`: le profil chargé
+    pub fn load(path: &Path) -> Self {
+        let mut profile = Self::default();
+        let Ok(content) = platform::read_to_string(path) else {
+            return profile;
+        };
+        for line in content.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            if let (Some(reason), Ok(count)) = (key.strip_prefix("death."), value.parse()) {
+                profile.deaths.insert(reason.to_string(), count);
+            } else if let Some(field) = key.strip_prefix("session.") {
+                let session = profile
+                    .last_session
+                    .get_or_insert_with(SessionStats::default);
+                match field {
+                    "runs" => session.runs = value.parse().unwrap_or(0),
+                    "best_score" => session.best_score = value.parse().unwrap_or(0),
+                    "asteroids_destroyed" => {
+                        session.asteroids_destroyed = value.parse().unwrap_or(0)
+                    }
+                    _ => {}
+                }
+            } else if let Some(tip) = key.strip_prefix("tip.") {
+                profile.acknowledged_tips.insert(tip.to_string());
+            } else if key.starts_with("field.") {
+                profile
+                    .last_field
+                    .extend(AsteroidSnapshot::from_line(value));
+            }
+        }
+        profile
+    }
+
+    /// Sauvegarde le profil dans un fichier
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `io::Result<()>`: une erreur si le fichier n'a pas pu être écrit
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut content = String::new();
+        for (reason, count) in &self.deaths {
+            content.push_str(&format!("death.{reason}={count}\n"));
+        }
+        if let Some(session) = &self.last_session {
+            content.push_str(&format!("session.runs={}\n", session.runs));
+            content.push_str(&format!("session.best_score={}\n", session.best_score));
+            content.push_str(&format!(
+                "session.asteroids_destroyed={}\n",
+                session.asteroids_destroyed
+            ));
+        }
+        for tip in &self.acknowledged_tips {
+            content.push_str(&format!("tip.{tip}=1\n"));
+        }
+        for (index, snapshot) in self.last_field.iter().enumerate() {
+            content.push_str(&format!("field.{index}={}\n", snapshot.to_line()));
+        }
+        platform::write(path, &content)
+    }
+
+    /// Remplace la session précédente par celle qui se termine.
+    /// Une session sans partie jouée ne remplace pas la précédente.
+    /// # Arguments
+    /// - `session`: les statistiques de la session qui se termine
+    pub fn rotate_session(&mut self, session: &SessionStats) {
+        if session.runs > 0 {
+            self.last_session = Some(session.clone());
+        }
+    }
+
+    /// Enregistre une mort pour la raison donnée
+    /// # Arguments
+    /// - `reason`: la clé de la raison de la fin de partie
+    pub fn record_death(&mut self, reason: &str) {
+        *self.deaths.entry(reason.to_string()).or_insert(0) += 1;
+    }
+
+    /// Fonction qui renvoie la raison de mort la plus fréquente
+    /// # Returns
+    /// - `Option<(&str, u32)>`: la clé de la raison et son nombre, ou `None` si aucune mort
+    pub fn most_frequent_death(&self) -> Option<(&str, u32)> {
+        self.deaths
+            .iter()
+            .max_by_key(|(_, count)| **count)
+            .map(|(reason, count)| (reason.as_str(), *count))
+    }
+}
+
+/// Fonction qui renvoie le chemin d'un fichier de données placé à côté de l'exécutable
+/// # Arguments
+/// - `file_name`: le nom du fichier
+/// # Returns
+/// - `PathBuf`: le chemin du fichier, ou simplement son nom si l'exécutable est introuvable
+pub fn data_path(file_name: &str) -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(file_name)))
+        .unwrap_or_else(|| PathBuf::from(file_name))
+}
+
+/// Fonction qui renvoie le chemin du profil du joueur
+/// # Returns
+/// - `PathBuf`: le chemin du fichier du profil
+pub fn profile_path() -> PathBuf {
+    data_path(PROFILE_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asteroid::asteroid::Size;
+    use macroquad::prelude::vec2;
+    use std::fs;
+
+    /// Vérifie qu'un profil sauvegardé puis rechargé est identique.
+    #[test]
+    fn test_profile_round_trip() {
+        let path = std::env::temp_dir().join("asteroid_test_profile_round_trip.txt");
+        let mut profile = Profile::default();
+        profile.record_death("reason.black_hole");
+        profile.record_death("reason.black_hole");
+        profile.record_death("reason.asteroid_large");
+        profile.last_field = vec![
+            AsteroidSnapshot {
+                size: Size::Large,
+                position: vec2(120.0, 80.5),
+                velocity: vec2(-0.5, 1.0),
+            },
+            AsteroidSnapshot {
+                size: Size::Small,
+                position: vec2(640.0, 410.0),
+                velocity: vec2(2.0, -0.25),
+            },
+        ];
+        profile.acknowledged_tips.insert("black_hole".to_string());
+
+        profile.save(&path).unwrap();
+        let loaded = Profile::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, profile, "Le profil rechargé est différent !");
+        assert_eq!(loaded.most_frequent_death(), Some(("reason.black_hole", 2)));
+    }
+
+    /// Vérifie qu'un fichier absent donne un profil vide.
+    #[test]
+    fn test_missing_profile_is_empty() {
+        let path = std::env::temp_dir().join("asteroid_test_profile_missing.txt");
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(Profile::load(&path), Profile::default());
+    }
+
+    /// Vérifie que la session terminée remplace la précédente et survit à la sauvegarde.
+    #[test]
+    fn test_session_rotation_on_exit() {
+        let path = std::env::temp_dir().join("asteroid_test_profile_session.txt");
+        let mut profile = Profile::default();
+        let session = SessionStats {
+            runs: 3,
+            best_score: 240,
+            asteroids_destroyed: 51,
+        };
+
+        profile.rotate_session(&SessionStats::default());
+        assert_eq!(profile.last_session, None, "Une session vide est ignorée !");
+
+        profile.rotate_session(&session);
+        profile.save(&path).unwrap();
+        let loaded = Profile::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.last_session, Some(session));
+    }
+}
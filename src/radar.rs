@@ -0,0 +1,153 @@
+//! Module pour le radar, une carte réduite de toute l'aire de jeu dans le coin en haut à droite.
+//! Le radar lit les mêmes listes d'objets que le dessin de la partie : il ne peut pas s'en écarter.
+//! Les missiles n'y figurent pas. La touche Tab l'affiche ou le cache.
+use crate::hud::{anchor, Corner};
+use asteroid::asteroid::{Asteroid, Size};
+use asteroid::black_hole::BlackHole;
+use asteroid::spaceship::{Spaceship, SECOND_PLAYER_TINT};
+use asteroid::stellarobject::StellarObject;
+use asteroid::text::{hud_scale, TextStyle};
+use asteroid::ufo::Ufo;
+use macroquad::prelude::*;
+
+/// Taille (en pixels) du radar, à l'échelle du tableau de bord
+pub const RADAR_SIZE: Vec2 = vec2(120.0, 90.0);
+/// Couleur du fond du radar, semi-transparent
+const BACKGROUND: Color = Color::new(0.0, 0.1, 0.2, 0.45);
+/// Taille (en pixels) du triangle du vaisseau sur le radar
+const SHIP_SIZE: f32 = 5.0;
+
+/// Fonction qui place le radar en haut à droite de la fenêtre, sous le score
+/// # Arguments
+/// - `screen`: la taille de la fenêtre
+/// # Returns
+/// - `Rect`: le cadre du radar, à l'échelle du tableau de bord
+pub fn radar_rect(screen: Vec2) -> Rect {
+    let scale = hud_scale(screen);
+    let size = RADAR_SIZE * scale;
+    // Le score, le second joueur et le combo occupent jusqu'à trois lignes
+    let below_score = TextStyle::Heading.size(scale) * 3.5;
+    let corner = anchor(Corner::TopRight, screen, size) + vec2(0.0, below_score);
+    Rect::new(corner.x, corner.y, size.x, size.y)
+}
+
+/// Fonction qui place un point de l'aire de jeu sur le radar
+/// # Arguments
+/// - `position`: la position dans l'aire de jeu
+/// - `bounds`: la taille de l'aire de jeu
+/// - `rect`: le cadre du radar
+/// # Returns
+/// - `Vec2`: le point du radar, toujours dans son cadre, même pour un objet qui sort de l'écran
+pub fn project(position: Vec2, bounds: Vec2, rect: Rect) -> Vec2 {
+    let relative = (position / bounds).clamp(Vec2::ZERO, Vec2::ONE);
+    rect.point() + relative * rect.size()
+}
+
+/// Fonction qui renvoie le rayon (en pixels) du point d'un asteroide sur le radar
+/// # Arguments
+/// - `size`: la taille de l'asteroide
+/// # Returns
+/// - `f32`: le rayon du point
+fn dot_radius(size: Size) -> f32 {
+    match size {
+        Size::Large => 3.0,
+        Size::Medium => 2.0,
+        Size::Small => 1.2,
+    }
+}
+
+/// Dessine le vaisseau sur le radar, en triangle tourné comme lui
+/// # Arguments
+/// - `spaceship`: le vaisseau
+/// - `bounds`: la taille de l'aire de jeu
+/// - `rect`: le cadre du radar
+/// - `color`: la couleur du triangle
+fn draw_ship(spaceship: &Spaceship, bounds: Vec2, rect: Rect, color: Color) {
+    let center = project(spaceship.get_pos(), bounds, rect);
+    let direction = Vec2::from_angle(spaceship.rotation);
+    let back = center - direction * SHIP_SIZE * 0.7;
+    let side = direction.perp() * SHIP_SIZE * 0.6;
+    draw_triangle(
+        center + direction * SHIP_SIZE,
+        back + side,
+        back - side,
+        color,
+    );
+}
+
+/// Dessine le radar par dessus tout le reste, avec la caméra par défaut
+/// # Arguments
+/// - `spaceship`: le vaisseau du joueur
+/// - `partner`: le vaisseau du second joueur, en coopération
+/// - `asteroids`: les asteroides, en points de la taille de l'asteroide
+/// - `black_holes`: les trous noirs, en cercles violets
+/// - `ufos`: les soucoupes
+/// - `bounds`: la taille de l'aire de jeu
+pub fn draw_radar(
+    spaceship: &Spaceship,
+    partner: Option<&Spaceship>,
+    asteroids: &[Asteroid],
+    black_holes: &[BlackHole],
+    ufos: &[Ufo],
+    bounds: Vec2,
+) {
+    let rect = radar_rect(vec2(screen_width(), screen_height()));
+    draw_rectangle(rect.x, rect.y, rect.w, rect.h, BACKGROUND);
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, GRAY);
+    let scale = rect.w / bounds.x;
+
+    for black_hole in black_holes {
+        let point = project(black_hole.get_pos(), bounds, rect);
+        let radius = (black_hole.radius() * scale).max(2.0);
+        draw_circle_lines(point.x, point.y, radius, 1.5, PURPLE);
+    }
+    for asteroid in asteroids.iter().filter(|a| a.active) {
+        let point = project(asteroid.get_pos(), bounds, rect);
+        draw_circle(point.x, point.y, dot_radius(asteroid.get_size()), LIGHTGRAY);
+    }
+    for ufo in ufos.iter().filter(|u| u.active) {
+        let point = project(ufo.get_pos(), bounds, rect);
+        draw_rectangle(point.x - 2.5, point.y - 1.5, 5.0, 3.0, MAGENTA);
+    }
+    if let Some(partner) = partner.filter(|p| p.active) {
+        draw_ship(partner, bounds, rect, SECOND_PLAYER_TINT);
+    }
+    if spaceship.active {
+        draw_ship(spaceship, bounds, rect, GREEN);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que les coins et le centre de l'aire de jeu tombent aux coins et au centre du radar,
+    /// et qu'un objet hors de l'écran reste au bord du radar.
+    #[test]
+    fn test_projection_corners() {
+        let bounds = vec2(800.0, 600.0);
+        let rect = Rect::new(500.0, 40.0, RADAR_SIZE.x, RADAR_SIZE.y);
+
+        assert_eq!(project(Vec2::ZERO, bounds, rect), rect.point());
+        assert_eq!(project(bounds, bounds, rect), rect.point() + rect.size());
+        assert_eq!(project(bounds / 2.0, bounds, rect), rect.center());
+        assert_eq!(
+            project(vec2(-30.0, 650.0), bounds, rect),
+            vec2(rect.x, rect.bottom())
+        );
+        assert_eq!(
+            project(vec2(830.0, -10.0), bounds, rect),
+            vec2(rect.right(), rect.y)
+        );
+    }
+
+    /// Vérifie que le radar reste entier dans la fenêtre, quelle que soit sa taille.
+    #[test]
+    fn test_radar_stays_in_window() {
+        for screen in [vec2(800.0, 600.0), vec2(1920.0, 1080.0), vec2(320.0, 240.0)] {
+            let rect = radar_rect(screen);
+            assert!(rect.x >= 0.0 && rect.right() <= screen.x);
+            assert!(rect.y >= 0.0 && rect.bottom() <= screen.y);
+        }
+    }
+}
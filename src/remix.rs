@@ -0,0 +1,151 @@
+//! Module pour le mode « Revanche » : la première vague d'une partie reprend,
+//! en miroir, les asteroides présents à la mort du joueur lors de la partie précédente.
+use crate::asteroid::{Asteroid, Size, BASE_SPEED};
+use crate::stellarobject::StellarObject;
+use macroquad::prelude::*;
+
+/// Nombre maximal d'asteroides gardés dans le champ capturé
+pub const MAX_SNAPSHOTS: usize = 12;
+
+/// Structure représentant un asteroide capturé, sans texture, pour être sauvegardé dans le profil
+/// # Champs
+/// - `size`: la taille de l'asteroide
+/// - `position`: la position de l'asteroide
+/// - `velocity`: la vitesse de l'asteroide
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AsteroidSnapshot {
+    pub size: Size,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+impl AsteroidSnapshot {
+    /// Met en forme l'asteroide capturé pour le fichier du profil
+    /// # Returns
+    /// - `String`: la taille, la position et la vitesse séparées par des `;`
+    pub fn to_line(&self) -> String {
+        format!(
+            "{:?};{};{};{};{}",
+            self.size, self.position.x, self.position.y, self.velocity.x, self.velocity.y
+        )
+    }
+
+    /// Lit un asteroide capturé depuis une ligne du fichier du profil
+    /// # Arguments
+    /// - `line`: la ligne écrite par `to_line`
+    /// # Returns
+    /// - `Option<Self>`: l'asteroide capturé, ou `None` si la ligne est illisible
+    pub fn from_line(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split(';').collect();
+        let [size, x, y, vx, vy] = fields[..] else {
+            return None;
+        };
+        let size = match size {
+            "Large" => Size::Large,
+            "Medium" => Size::Medium,
+            "Small" => Size::Small,
+            _ => return None,
+        };
+        Some(Self {
+            size,
+            position: vec2(x.parse().ok()?, y.parse().ok()?),
+            velocity: vec2(vx.parse().ok()?, vy.parse().ok()?),
+        })
+    }
+}
+
+/// Capture les asteroides présents à la mort du joueur
+/// # Arguments
+/// - `asteroids`: les asteroides de la partie
+/// # Returns
+/// - `Vec<AsteroidSnapshot>`: au plus `MAX_SNAPSHOTS` asteroides capturés
+pub fn capture(asteroids: &[Asteroid]) -> Vec<AsteroidSnapshot> {
+    asteroids
+        .iter()
+        .take(MAX_SNAPSHOTS)
+        .map(|asteroid| AsteroidSnapshot {
+            size: asteroid.get_size(),
+            position: asteroid.get_pos(),
+            velocity: asteroid.get_speed(),
+        })
+        .collect()
+}
+
+/// Transforme le champ capturé en première vague : chaque asteroide est placé en miroir
+/// par rapport au centre de l'écran et sa vitesse est ramenée à celle de la première vague.
+/// # Arguments
+/// - `field`: les asteroides capturés à la mort du joueur
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<AsteroidSnapshot>`: les asteroides de la première vague, au plus `MAX_SNAPSHOTS`
+pub fn remix(field: &[AsteroidSnapshot], bounds: Vec2) -> Vec<AsteroidSnapshot> {
+    field
+        .iter()
+        .take(MAX_SNAPSHOTS)
+        .map(|snapshot| AsteroidSnapshot {
+            size: snapshot.size,
+            position: bounds - snapshot.position,
+            velocity: (-snapshot.velocity).normalize_or_zero()
+                * BASE_SPEED
+                * snapshot.size.speed_multiplier(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Vérifie le miroir par rapport au centre et la vitesse ramenée à la normale.
+    #[test]
+    fn test_remix_mirrors_and_renormalizes() {
+        let field = [AsteroidSnapshot {
+            size: Size::Medium,
+            position: vec2(100.0, 50.0),
+            velocity: vec2(3.0, 4.0),
+        }];
+
+        let wave = remix(&field, BOUNDS);
+
+        assert_eq!(wave.len(), 1);
+        assert_eq!(wave[0].size, Size::Medium);
+        assert_eq!(wave[0].position, vec2(700.0, 550.0));
+        let speed = BASE_SPEED * Size::MEDIUM_SPEED;
+        assert!((wave[0].velocity.length() - speed).abs() < 1e-5);
+        assert!((wave[0].velocity - vec2(-0.6, -0.8) * speed).length() < 1e-5);
+    }
+
+    /// Vérifie que la vague reprise ne dépasse pas `MAX_SNAPSHOTS` asteroides.
+    #[test]
+    fn test_remix_is_capped() {
+        let field = vec![
+            AsteroidSnapshot {
+                size: Size::Small,
+                position: Vec2::ZERO,
+                velocity: Vec2::X,
+            };
+            MAX_SNAPSHOTS + 5
+        ];
+
+        assert_eq!(remix(&field, BOUNDS).len(), MAX_SNAPSHOTS);
+    }
+
+    /// Vérifie qu'un asteroide capturé survit à sa mise en forme pour le profil.
+    #[test]
+    fn test_snapshot_line_round_trip() {
+        let snapshot = AsteroidSnapshot {
+            size: Size::Large,
+            position: vec2(12.5, 300.0),
+            velocity: vec2(-1.25, 0.5),
+        };
+
+        assert_eq!(
+            AsteroidSnapshot::from_line(&snapshot.to_line()),
+            Some(snapshot)
+        );
+        assert_eq!(AsteroidSnapshot::from_line("Huge;1;2;3;4"), None);
+        assert_eq!(AsteroidSnapshot::from_line("Small;1;2"), None);
+    }
+}
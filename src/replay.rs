@@ -0,0 +1,802 @@
+//! Module pour enregistrer une partie et la rejouer.
+//! Grâce à la graine de la partie, il suffit d'enregistrer les commandes de chaque image :
+//! rejouées dans le même ordre, avec les mêmes durées d'image, elles redonnent la même partie.
+//! Toutes les `CHECK_INTERVAL` images, une empreinte des positions est aussi enregistrée,
+//! pour repérer une partie rejouée qui ne suit plus l'originale.
+//!
+//! Le fichier commence par un en-tête (`REPLAY_MAGIC`, version, graine, difficulté, mode de jeu,
+//! missiles arcade, surchauffe, vague de départ, taille de l'aire de jeu), suivi d'enregistrements précédés d'un octet de type :
+//! une image (durée, rotation, boutons, pause et abandon), une nouvelle taille de l'aire de jeu, une empreinte,
+//! ou l'amélioration choisie entre deux vagues.
+use crate::audio::SoundEvent;
+use crate::difficulty::Difficulty;
+use crate::game_mode::GameMode;
+use crate::spaceship::Spaceship;
+use crate::stellarobject::StellarObject;
+use crate::world::World;
+use macroquad::prelude::*;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+
+/// Premiers octets d'un fichier de replay
+pub const REPLAY_MAGIC: &[u8; 4] = b"ARPL";
+/// Version du format des fichiers de replay
+pub const REPLAY_VERSION: u8 = 5;
+/// Nombre d'images entre deux empreintes des positions
+pub const CHECK_INTERVAL: u32 = 60;
+/// Nombre maximal d'images enregistrées, 20 minutes à 60 images par seconde
+pub const MAX_REPLAY_FRAMES: u32 = 20 * 60 * 60;
+
+/// Type d'enregistrement : les commandes d'une image
+const FRAME_TAG: u8 = 0;
+/// Type d'enregistrement : la nouvelle taille de l'aire de jeu
+const BOUNDS_TAG: u8 = 1;
+/// Type d'enregistrement : l'empreinte des positions
+const CHECK_TAG: u8 = 2;
+/// Type d'enregistrement : l'indice de l'amélioration choisie
+const UPGRADE_TAG: u8 = 3;
+
+/// Bits des boutons d'une image
+const THRUST_FORWARD: u8 = 1;
+const THRUST_BACKWARD: u8 = 2;
+const FIRE: u8 = 4;
+const BOMB: u8 = 8;
+const PAUSE: u8 = 16;
+const QUIT: u8 = 32;
+
+/// Structure représentant les commandes du joueur pendant une image
+/// # Champs
+/// - `dt`: la durée de l'image, en secondes
+/// - `turn`: l'angle dont le vaisseau tourne, en radians, au clavier comme à la souris
+/// - `thrust_forward`: permet de savoir si le vaisseau avance
+/// - `thrust_backward`: permet de savoir si le vaisseau recule
+/// - `fire`: permet de savoir si le joueur tire
+/// - `bomb`: permet de savoir si le joueur fait exploser une bombe
+/// - `pause`: permet de savoir si le joueur met la partie en pause
+/// - `quit`: permet de savoir si le joueur demande à quitter la partie
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ReplayFrame {
+    pub dt: f32,
+    pub turn: f32,
+    pub thrust_forward: bool,
+    pub thrust_backward: bool,
+    pub fire: bool,
+    pub bomb: bool,
+    pub pause: bool,
+    pub quit: bool,
+}
+
+impl ReplayFrame {
+    /// Applique les commandes de l'image au vaisseau : rotation, poussée ou freinage, et tir.
+    /// La bombe est laissée à l'appelant, qui la fait exploser après les collisions,
+    /// comme la pause et l'abandon, qui arrêtent la partie sans la changer.
+    /// # Arguments
+    /// - `world`: la partie
+    pub fn apply(&self, world: &mut World) {
+        self.apply_to(world, world.spaceship.player);
+    }
+
+    /// Applique les commandes de l'image au vaisseau d'un joueur, en coopération
+    /// # Arguments
+    /// - `world`: la partie
+    /// - `player`: le numéro du joueur qui commande le vaisseau
+    pub fn apply_to(&self, world: &mut World, player: usize) {
+        let ship = match &mut world.partner {
+            Some(partner) if partner.player == player => partner,
+            _ => &mut world.spaceship,
+        };
+        ship.rotate(self.turn);
+        if self.thrust_forward {
+            ship.apply_thrust(0.01);
+        }
+        if self.thrust_backward {
+            ship.apply_thrust(-0.01);
+        }
+        if !self.thrust_forward && !self.thrust_backward {
+            // Ralentir progressivement
+            ship.apply_drag();
+        }
+        ship.weapon.update(self.dt);
+        if self.fire {
+            if ship.fire(&mut world.missiles) {
+                world.sound_events.push(SoundEvent::MissileFired);
+                world.effects.muzzle_flash(ship.nose(), ship.rotation);
+                if ship.weapon.overheated() {
+                    world.sound_events.push(SoundEvent::Overheated);
+                }
+            } else if ship.weapon.dry_fire() {
+                world.sound_events.push(SoundEvent::DryFire);
+            }
+        }
+    }
+
+    /// Retient les touches pressées pendant une image affichée : à haute fréquence, une image
+    /// peut ne jouer aucun pas, et la touche n'est plus pressée à l'image suivante
+    /// # Arguments
+    /// - `input`: les commandes lues pendant l'image affichée
+    pub fn queue_presses(&mut self, input: &ReplayFrame) {
+        self.fire |= input.fire;
+        self.bomb |= input.bomb;
+        self.pause |= input.pause;
+        self.quit |= input.quit;
+    }
+
+    /// Remplace les touches pressées des commandes d'un pas par celles retenues, puis les oublie :
+    /// elles ne comptent que pour le premier pas qui suit
+    /// # Arguments
+    /// - `frame`: les commandes du pas, dont seules les touches maintenues sont gardées
+    /// # Returns
+    /// - `ReplayFrame`: les commandes du pas, avec les touches retenues
+    pub fn take_presses(&mut self, frame: ReplayFrame) -> ReplayFrame {
+        ReplayFrame {
+            fire: std::mem::take(&mut self.fire),
+            bomb: std::mem::take(&mut self.bomb),
+            pause: std::mem::take(&mut self.pause),
+            quit: std::mem::take(&mut self.quit),
+            ..frame
+        }
+    }
+
+    /// Fonction qui range les boutons de l'image dans un octet
+    /// # Returns
+    /// - `u8`: les bits des boutons enfoncés
+    fn buttons(&self) -> u8 {
+        [
+            (self.thrust_forward, THRUST_FORWARD),
+            (self.thrust_backward, THRUST_BACKWARD),
+            (self.fire, FIRE),
+            (self.bomb, BOMB),
+            (self.pause, PAUSE),
+            (self.quit, QUIT),
+        ]
+        .into_iter()
+        .filter(|(down, _)| *down)
+        .fold(0, |bits, (_, bit)| bits | bit)
+    }
+}
+
+/// Structure représentant l'en-tête d'un replay : tout ce qu'il faut pour recommencer la partie
+/// # Champs
+/// - `seed`: la graine de la partie
+/// - `difficulty`: la difficulté de la partie
+/// - `mode`: le mode de jeu de la partie
+/// - `inherit_velocity`: permet de savoir si les missiles gardent la vitesse du vaisseau
+/// - `overheat`: permet de savoir si la surchauffe de l'arme est imposée
+/// - `start_wave`: la vague par laquelle la partie commence
+/// - `bounds`: la taille de l'aire de jeu au début de la partie
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ReplayHeader {
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub mode: GameMode,
+    pub inherit_velocity: bool,
+    pub overheat: bool,
+    pub start_wave: u32,
+    pub bounds: Vec2,
+}
+
+impl ReplayHeader {
+    /// Crée l'en-tête d'une partie qui commence
+    /// # Arguments
+    /// - `world`: la partie, juste recommencée
+    /// # Returns
+    /// - `Self`: l'en-tête
+    pub fn from_world(world: &World) -> Self {
+        Self {
+            seed: world.seed,
+            difficulty: world.difficulty,
+            mode: world.mode,
+            inherit_velocity: world.spaceship.inherit_velocity,
+            overheat: world.overheat,
+            start_wave: world.start_wave,
+            bounds: world.bounds,
+        }
+    }
+
+    /// Recommence une partie telle qu'elle était au début de l'enregistrement
+    /// # Arguments
+    /// - `world`: la partie à recommencer
+    /// - `spaceship`: le nouveau vaisseau, placé au centre de l'aire de jeu enregistrée
+    pub fn restart(&self, world: &mut World, mut spaceship: Spaceship) {
+        spaceship.inherit_velocity = self.inherit_velocity;
+        world.seed = self.seed;
+        world.difficulty = self.difficulty;
+        world.overheat = self.overheat;
+        world.start_wave = self.start_wave;
+        world.bounds = self.bounds;
+        world.set_mode(self.mode);
+        world.reset(spaceship);
+    }
+}
+
+/// Fonction qui calcule l'empreinte des positions de la partie.
+/// Les nombres sont pris bit à bit : la moindre différence change l'empreinte.
+/// # Arguments
+/// - `world`: la partie
+/// # Returns
+/// - `u64`: l'empreinte (FNV-1a)
+pub fn world_hash(world: &World) -> u64 {
+    let ship = &world.spaceship;
+    let mut values = vec![
+        ship.get_pos().x,
+        ship.get_pos().y,
+        ship.velocity().x,
+        ship.velocity().y,
+        ship.rotation,
+    ];
+    for asteroid in &world.asteroids {
+        values.extend([asteroid.get_pos().x, asteroid.get_pos().y]);
+    }
+    let counts = [
+        world.asteroids.len() as u64,
+        world.score as u64,
+        world.wave as u64,
+    ];
+    values
+        .iter()
+        .flat_map(|value| value.to_bits().to_le_bytes())
+        .chain(counts.iter().flat_map(|count| count.to_le_bytes()))
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+/// Structure qui écrit les commandes de chaque image d'une partie
+/// # Champs
+/// - `out`: la destination du replay
+/// - `frames`: le nombre d'images enregistrées
+/// - `bounds`: la dernière taille de l'aire de jeu enregistrée
+pub struct ReplayRecorder<W: Write> {
+    out: W,
+    frames: u32,
+    bounds: Vec2,
+}
+
+/// Enregistreur qui écrit le replay dans un fichier
+pub type FileRecorder = ReplayRecorder<BufWriter<File>>;
+
+impl FileRecorder {
+    /// Crée le fichier du replay, en remplaçant le replay précédent
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// - `header`: l'en-tête de la partie
+    /// # Returns
+    /// - `io::Result<Self>`: l'enregistreur, ou une erreur si le fichier n'a pas pu être créé
+    pub fn create(path: &Path, header: &ReplayHeader) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), header)
+    }
+}
+
+impl<W: Write> ReplayRecorder<W> {
+    /// Crée un enregistreur et écrit l'en-tête
+    /// # Arguments
+    /// - `out`: la destination du replay
+    /// - `header`: l'en-tête de la partie
+    /// # Returns
+    /// - `io::Result<Self>`: l'enregistreur, ou une erreur d'écriture
+    pub fn new(mut out: W, header: &ReplayHeader) -> io::Result<Self> {
+        out.write_all(REPLAY_MAGIC)?;
+        out.write_all(&[REPLAY_VERSION])?;
+        out.write_all(&header.seed.to_le_bytes())?;
+        let id = header.difficulty.id().as_bytes();
+        out.write_all(&[id.len() as u8])?;
+        out.write_all(id)?;
+        let mode = header.mode.id().as_bytes();
+        out.write_all(&[mode.len() as u8])?;
+        out.write_all(mode)?;
+        out.write_all(&[header.inherit_velocity as u8])?;
+        out.write_all(&[header.overheat as u8])?;
+        out.write_all(&header.start_wave.to_le_bytes())?;
+        write_vec2(&mut out, header.bounds)?;
+        Ok(Self {
+            out,
+            frames: 0,
+            bounds: header.bounds,
+        })
+    }
+
+    /// Fonction qui renvoie le nombre d'images enregistrées
+    /// # Returns
+    /// - `u32`: le nombre d'images
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Fonction qui indique si le replay a atteint `MAX_REPLAY_FRAMES`
+    /// # Returns
+    /// - `bool`: `true` si les images suivantes ne sont plus enregistrées
+    pub fn is_full(&self) -> bool {
+        self.frames >= MAX_REPLAY_FRAMES
+    }
+
+    /// Enregistre une image, une fois la partie mise à jour.
+    /// Au-delà de `MAX_REPLAY_FRAMES`, les images sont ignorées et le replay est vidé une fois.
+    /// # Arguments
+    /// - `frame`: les commandes de l'image
+    /// - `world`: la partie après l'image
+    /// # Returns
+    /// - `io::Result<()>`: une erreur d'écriture, s'il y en a une
+    pub fn record(&mut self, frame: &ReplayFrame, world: &World) -> io::Result<()> {
+        if self.is_full() {
+            return Ok(());
+        }
+        if world.bounds != self.bounds {
+            self.bounds = world.bounds;
+            self.out.write_all(&[BOUNDS_TAG])?;
+            write_vec2(&mut self.out, world.bounds)?;
+        }
+        self.out.write_all(&[FRAME_TAG])?;
+        self.out.write_all(&frame.dt.to_le_bytes())?;
+        self.out.write_all(&frame.turn.to_le_bytes())?;
+        self.out.write_all(&[frame.buttons()])?;
+        self.frames += 1;
+        if self.frames.is_multiple_of(CHECK_INTERVAL) {
+            self.out.write_all(&[CHECK_TAG])?;
+            self.out.write_all(&world_hash(world).to_le_bytes())?;
+        }
+        if self.is_full() {
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Enregistre l'amélioration choisie entre deux vagues, avant l'image qui la suit
+    /// # Arguments
+    /// - `index`: l'indice de l'amélioration choisie
+    /// # Returns
+    /// - `io::Result<()>`: une erreur d'écriture, s'il y en a une
+    pub fn record_upgrade(&mut self, index: usize) -> io::Result<()> {
+        if self.is_full() {
+            return Ok(());
+        }
+        self.out.write_all(&[UPGRADE_TAG, index as u8])
+    }
+
+    /// Termine le replay en vidant ce qui reste à écrire
+    /// # Returns
+    /// - `io::Result<W>`: la destination du replay, ou une erreur d'écriture
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// Écrit les deux coordonnées d'un vecteur
+fn write_vec2(out: &mut impl Write, value: Vec2) -> io::Result<()> {
+    out.write_all(&value.x.to_le_bytes())?;
+    out.write_all(&value.y.to_le_bytes())
+}
+
+/// Enregistrement d'un replay relu
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Record {
+    Frame(ReplayFrame),
+    Bounds(Vec2),
+    Check(u64),
+    Upgrade(usize),
+}
+
+/// Structure représentant une partie rejouée qui ne suit plus l'originale
+/// # Champs
+/// - `frame`: l'image après laquelle les empreintes diffèrent
+/// - `expected`: l'empreinte enregistrée
+/// - `found`: l'empreinte de la partie rejouée
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Divergence {
+    pub frame: u32,
+    pub expected: u64,
+    pub found: u64,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "le replay diverge à l'image {} (empreinte {:016x} au lieu de {:016x})",
+            self.frame, self.found, self.expected
+        )
+    }
+}
+
+/// Structure qui relit les commandes d'un replay, image par image
+/// # Champs
+/// - `header`: l'en-tête du replay
+/// - `records`: tous les enregistrements du replay
+/// - `cursor`: l'indice du prochain enregistrement
+/// - `frames`: le nombre d'images déjà rejouées
+pub struct ReplayPlayer {
+    pub header: ReplayHeader,
+    records: Vec<Record>,
+    cursor: usize,
+    frames: u32,
+}
+
+impl ReplayPlayer {
+    /// Charge un replay depuis un fichier
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `io::Result<Self>`: le lecteur, ou une erreur si le fichier est absent ou invalide
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::read(File::open(path)?)
+    }
+
+    /// Lit un replay. Un replay coupé au milieu d'un enregistrement garde ses images complètes.
+    /// # Arguments
+    /// - `input`: la source du replay
+    /// # Returns
+    /// - `io::Result<Self>`: le lecteur, ou une erreur si l'en-tête est invalide
+    pub fn read(mut input: impl Read) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        let mut reader = ByteReader { bytes: &bytes };
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        if reader.take(4) != Some(REPLAY_MAGIC) || reader.byte() != Some(REPLAY_VERSION) {
+            return Err(invalid("ce fichier n'est pas un replay de cette version"));
+        }
+        let header = (|| {
+            let seed = reader.u64()?;
+            let length = reader.byte()? as usize;
+            let id = std::str::from_utf8(reader.take(length)?).ok()?;
+            let difficulty = Difficulty::from_id(id)?;
+            let length = reader.byte()? as usize;
+            let mode = std::str::from_utf8(reader.take(length)?).ok()?;
+            Some(ReplayHeader {
+                seed,
+                difficulty,
+                mode: GameMode::from_id(mode)?,
+                inherit_velocity: reader.byte()? != 0,
+                overheat: reader.byte()? != 0,
+                start_wave: reader.u32()?,
+                bounds: reader.vec2()?,
+            })
+        })()
+        .ok_or_else(|| invalid("en-tête du replay invalide"))?;
+
+        let mut records = Vec::new();
+        while let Some(tag) = reader.byte() {
+            let record = match tag {
+                FRAME_TAG => (|| {
+                    let dt = reader.f32()?;
+                    let turn = reader.f32()?;
+                    let buttons = reader.byte()?;
+                    Some(Record::Frame(ReplayFrame {
+                        dt,
+                        turn,
+                        thrust_forward: buttons & THRUST_FORWARD != 0,
+                        thrust_backward: buttons & THRUST_BACKWARD != 0,
+                        fire: buttons & FIRE != 0,
+                        bomb: buttons & BOMB != 0,
+                        pause: buttons & PAUSE != 0,
+                        quit: buttons & QUIT != 0,
+                    }))
+                })(),
+                BOUNDS_TAG => reader.vec2().map(Record::Bounds),
+                CHECK_TAG => reader.u64().map(Record::Check),
+                UPGRADE_TAG => reader.byte().map(|index| Record::Upgrade(index as usize)),
+                _ => return Err(invalid("enregistrement inconnu dans le replay")),
+            };
+            match record {
+                Some(record) => records.push(record),
+                None => break,
+            }
+        }
+        Ok(Self {
+            header,
+            records,
+            cursor: 0,
+            frames: 0,
+        })
+    }
+
+    /// Fonction qui renvoie les commandes de la prochaine image.
+    /// Une nouvelle taille de l'aire de jeu ou une amélioration enregistrées avant l'image
+    /// sont appliquées à la partie.
+    /// # Arguments
+    /// - `world`: la partie rejouée
+    /// # Returns
+    /// - `Option<ReplayFrame>`: les commandes, ou `None` à la fin du replay
+    pub fn next_frame(&mut self, world: &mut World) -> Option<ReplayFrame> {
+        while let Some(record) = self.records.get(self.cursor) {
+            self.cursor += 1;
+            match *record {
+                Record::Frame(frame) => {
+                    self.frames += 1;
+                    return Some(frame);
+                }
+                Record::Bounds(bounds) => world.set_bounds(bounds),
+                Record::Upgrade(index) => {
+                    world.choose_upgrade(index);
+                }
+                Record::Check(_) => {}
+            }
+        }
+        None
+    }
+
+    /// Compare la partie rejouée à l'empreinte enregistrée après l'image, s'il y en a une
+    /// # Arguments
+    /// - `world`: la partie rejouée, après l'image
+    /// # Returns
+    /// - `Result<(), Divergence>`: une erreur si la partie ne suit plus l'originale
+    pub fn check(&mut self, world: &World) -> Result<(), Divergence> {
+        if let Some(Record::Check(expected)) = self.records.get(self.cursor) {
+            self.cursor += 1;
+            let found = world_hash(world);
+            if found != *expected {
+                return Err(Divergence {
+                    frame: self.frames,
+                    expected: *expected,
+                    found,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Structure qui lit un replay octet par octet
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// Fonction qui renvoie les `count` octets suivants, ou `None` s'il n'y en a plus assez
+    fn take(&mut self, count: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < count {
+            return None;
+        }
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)?.try_into().ok().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take(8)?.try_into().ok().map(u64::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.take(4)?.try_into().ok().map(f32::from_le_bytes)
+    }
+
+    fn vec2(&mut self) -> Option<Vec2> {
+        Some(vec2(self.f32()?, self.f32()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::dummy_texture;
+    use crate::spaceship::SpaceshipTextures;
+    use crate::timestep::{FixedTimestep, FIXED_DT};
+    use crate::world::WorldTextures;
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+    const DT: f32 = 1.0 / 60.0;
+
+    /// Crée un vaisseau au centre de l'écran, sans fenêtre
+    fn test_spaceship() -> Spaceship {
+        Spaceship::new(
+            BOUNDS,
+            SpaceshipTextures {
+                ship: dummy_texture(),
+                shield_on: dummy_texture(),
+                shield_off: dummy_texture(),
+                shield_dead: dummy_texture(),
+            },
+        )
+    }
+
+    /// Crée une partie sans soucoupe, recommencée avec la graine donnée
+    fn test_world(seed: u64) -> World {
+        let textures = WorldTextures {
+            asteroids: vec![dummy_texture()],
+            black_hole: dummy_texture(),
+            ufo: dummy_texture(),
+        };
+        let mut world = World::new(test_spaceship(), textures, BOUNDS, 0.0);
+        world.seed = seed;
+        world.reset(test_spaceship());
+        world
+    }
+
+    /// Fait avancer la partie d'une image, comme le jeu
+    fn step(world: &mut World, frame: &ReplayFrame) {
+        if !world.spaceship.dying {
+            frame.apply(world);
+        }
+        world.handle_collisions();
+        if frame.bomb {
+            world.detonate_bomb();
+        }
+        world.update(frame.dt);
+    }
+
+    /// Commandes scriptées : le vaisseau tourne, avance par moments, tire sans arrêt,
+    /// fait exploser une bombe, et le joueur met une fois la partie en pause
+    fn scripted_frame(i: u32) -> ReplayFrame {
+        ReplayFrame {
+            dt: DT + (i % 3) as f32 * 0.001,
+            turn: if i % 120 < 60 { 0.05 } else { -0.03 },
+            thrust_forward: i % 90 < 20,
+            thrust_backward: false,
+            fire: true,
+            bomb: i == 200,
+            pause: i == 300,
+            quit: false,
+        }
+    }
+
+    /// Enregistre une partie scriptée
+    /// # Returns
+    /// - `(Vec<u8>, u64)`: le replay et l'empreinte finale de la partie
+    fn record_run(frames: u32) -> (Vec<u8>, u64) {
+        let mut world = test_world(7);
+        let mut recorder =
+            ReplayRecorder::new(Vec::new(), &ReplayHeader::from_world(&world)).unwrap();
+        for i in 0..frames {
+            let frame = scripted_frame(i);
+            step(&mut world, &frame);
+            recorder.record(&frame, &world).unwrap();
+        }
+        (recorder.finish().unwrap(), world_hash(&world))
+    }
+
+    /// Vérifie qu'un tir ne part qu'une fois par temps de recharge, avec son bruit,
+    /// et qu'un vaisseau sans commande ne bouge pas.
+    #[test]
+    fn test_fire_respects_cooldown() {
+        let mut world = test_world(1);
+        let start = world.spaceship.get_pos();
+        let fire = ReplayFrame {
+            dt: DT,
+            fire: true,
+            ..ReplayFrame::default()
+        };
+
+        fire.apply(&mut world);
+        assert_eq!(world.missiles.len(), 1);
+        assert_eq!(world.sound_events, [SoundEvent::MissileFired]);
+        fire.apply(&mut world);
+        assert_eq!(world.missiles.len(), 1, "L'arme doit se recharger !");
+        ReplayFrame {
+            dt: crate::weapon::FIRE_COOLDOWN,
+            ..ReplayFrame::default()
+        }
+        .apply(&mut world);
+        fire.apply(&mut world);
+        assert_eq!(world.missiles.len(), 2);
+        assert_eq!(world.spaceship.get_pos(), start);
+    }
+
+    /// Vérifie qu'un tir pressé pendant une image affichée qui ne joue aucun pas,
+    /// comme à 144 Hz, part au pas suivant, et une seule fois.
+    #[test]
+    fn test_press_waits_for_next_step() {
+        let mut world = test_world(1);
+        let mut timestep = FixedTimestep::default();
+        let mut queued = ReplayFrame::default();
+        let mut fired_at = Vec::new();
+        for image in 0..12 {
+            let input = ReplayFrame {
+                dt: FIXED_DT,
+                fire: image == 0,
+                ..ReplayFrame::default()
+            };
+            queued.queue_presses(&input);
+            let steps = timestep.advance(1.0 / 144.0);
+            if image == 0 {
+                assert_eq!(steps, 0, "La première image ne joue aucun pas !");
+            }
+            for _ in 0..steps {
+                let frame = queued.take_presses(input);
+                frame.apply(&mut world);
+                if frame.fire {
+                    fired_at.push(image);
+                }
+            }
+        }
+        assert_eq!(fired_at, [2]);
+        assert_eq!(world.missiles.len(), 1);
+    }
+
+    /// Vérifie qu'une partie rejouée suit exactement l'originale, jusqu'à la dernière image,
+    /// avec la pause à la même image.
+    #[test]
+    fn test_replay_reproduces_run() {
+        let (bytes, final_hash) = record_run(600);
+        let mut player = ReplayPlayer::read(&bytes[..]).unwrap();
+        assert_eq!(player.header.seed, 7);
+
+        let mut world = test_world(1);
+        player.header.restart(&mut world, test_spaceship());
+        let mut frames = 0;
+        while let Some(frame) = player.next_frame(&mut world) {
+            step(&mut world, &frame);
+            assert_eq!(player.check(&world), Ok(()));
+            assert_eq!(frame.pause, frames == 300);
+            frames += 1;
+        }
+
+        assert_eq!(frames, 600);
+        assert_eq!(world_hash(&world), final_hash);
+    }
+
+    /// Vérifie qu'une commande modifiée est repérée à la première empreinte qui suit.
+    #[test]
+    fn test_tampered_replay_diverges() {
+        let (mut bytes, _) = record_run(200);
+        // Le premier enregistrement suit l'en-tête : l'octet de ses boutons coupe le tir
+        let header_length = 4 + 1 + 8 + 1 + "normal".len() + 1 + "classic".len() + 1 + 1 + 4 + 8;
+        assert_eq!(bytes[header_length], FRAME_TAG);
+        bytes[header_length + 9] = 0;
+        let mut player = ReplayPlayer::read(&bytes[..]).unwrap();
+
+        let mut world = test_world(1);
+        player.header.restart(&mut world, test_spaceship());
+        let mut divergence = None;
+        while let Some(frame) = player.next_frame(&mut world) {
+            step(&mut world, &frame);
+            if let Err(err) = player.check(&world) {
+                divergence = Some(err);
+                break;
+            }
+        }
+
+        assert_eq!(divergence.map(|d| d.frame), Some(CHECK_INTERVAL));
+    }
+
+    /// Vérifie qu'un fichier qui n'est pas un replay est refusé,
+    /// et qu'un replay coupé garde ses images complètes.
+    #[test]
+    fn test_invalid_and_truncated_replays() {
+        assert!(ReplayPlayer::read(&b"pas un replay"[..]).is_err());
+
+        let (bytes, _) = record_run(10);
+        let mut player = ReplayPlayer::read(&bytes[..bytes.len() - 3]).unwrap();
+        let mut world = test_world(1);
+        let mut frames = 0;
+        while player.next_frame(&mut world).is_some() {
+            frames += 1;
+        }
+        assert_eq!(frames, 9);
+    }
+
+    /// Vérifie que le replay cesse de grandir après `MAX_REPLAY_FRAMES` images.
+    #[test]
+    fn test_recorder_caps_file_growth() {
+        let world = test_world(1);
+        let mut recorder =
+            ReplayRecorder::new(Vec::new(), &ReplayHeader::from_world(&world)).unwrap();
+        let frame = ReplayFrame {
+            dt: DT,
+            ..ReplayFrame::default()
+        };
+        for _ in 0..MAX_REPLAY_FRAMES + 100 {
+            recorder.record(&frame, &world).unwrap();
+        }
+
+        assert!(recorder.is_full());
+        assert_eq!(recorder.frames(), MAX_REPLAY_FRAMES);
+        let bytes = recorder.finish().unwrap();
+        let frames = ReplayPlayer::read(&bytes[..])
+            .unwrap()
+            .records
+            .iter()
+            .filter(|r| matches!(r, Record::Frame(_)))
+            .count();
+        assert_eq!(frames, MAX_REPLAY_FRAMES as usize);
+    }
+}
@@ -0,0 +1,173 @@
+//! Module pour les statistiques d'une partie, affichées sur l'écran de fin
+//! et ajoutées à un journal CSV pour suivre ses progrès d'une partie à l'autre.
+//!
+//! La précision compte les missiles du joueur, pas les objets touchés : un missile qui sépare
+//! un grand asteroide en deux compte une seule fois, et un missile aspiré par un trou noir
+//! compte comme un tir réussi parce qu'il l'a endommagé.
+use crate::asteroid::Size;
+use crate::difficulty::Difficulty;
+use crate::missile::{Missile, MissileSource};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Première ligne du journal CSV des parties
+pub const CSV_HEADER: &str = "date,difficulty,seed,score,wave,time_survived,missiles_fired,\
+missile_hits,accuracy,large_destroyed,medium_destroyed,small_destroyed,\
+black_holes_created,black_holes_destroyed";
+
+/// Structure représentant les statistiques d'une partie
+/// # Champs
+/// - `missiles_fired`: le nombre de missiles tirés par le joueur et arrivés en fin de course
+/// - `missile_hits`: le nombre de ces missiles qui ont touché quelque chose
+/// - `asteroids_destroyed`: le nombre d'asteroides détruits, grands, moyens puis petits
+/// - `black_holes_created`: le nombre de trous noirs apparus
+/// - `black_holes_destroyed`: le nombre de trous noirs détruits
+/// - `time_survived`: le temps de jeu (en secondes) jusqu'à la destruction du vaisseau
+/// - `highest_wave`: la vague la plus avancée atteinte
+/// - `score`: le score de la partie
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct RunStats {
+    pub missiles_fired: u32,
+    pub missile_hits: u32,
+    pub asteroids_destroyed: [u32; 3],
+    pub black_holes_created: u32,
+    pub black_holes_destroyed: u32,
+    pub time_survived: f32,
+    pub highest_wave: u32,
+    pub score: i32,
+}
+
+impl RunStats {
+    /// Compte un missile du joueur arrivé en fin de course, qu'il ait touché ou non.
+    /// Les missiles ennemis sont ignorés.
+    /// # Arguments
+    /// - `missile`: le missile
+    pub fn record_missile(&mut self, missile: &Missile) {
+        if missile.source == MissileSource::Player {
+            self.missiles_fired += 1;
+            self.missile_hits += missile.hit as u32;
+        }
+    }
+
+    /// Compte un asteroide détruit
+    /// # Arguments
+    /// - `size`: la taille de l'asteroide
+    pub fn record_asteroid(&mut self, size: Size) {
+        let index = match size {
+            Size::Large => 0,
+            Size::Medium => 1,
+            Size::Small => 2,
+        };
+        self.asteroids_destroyed[index] += 1;
+    }
+
+    /// Fonction qui renvoie le nombre total d'asteroides détruits
+    /// # Returns
+    /// - `u32`: le nombre d'asteroides détruits, toutes tailles confondues
+    pub fn total_asteroids(&self) -> u32 {
+        self.asteroids_destroyed.iter().sum()
+    }
+
+    /// Fonction qui renvoie la précision du joueur
+    /// # Returns
+    /// - `Option<f32>`: le pourcentage de missiles qui ont touché, ou `None` sans aucun tir
+    pub fn accuracy(&self) -> Option<f32> {
+        (self.missiles_fired > 0)
+            .then(|| self.missile_hits as f32 * 100.0 / self.missiles_fired as f32)
+    }
+
+    /// Fonction qui renvoie la ligne CSV de la partie, dans l'ordre de `CSV_HEADER`
+    /// # Arguments
+    /// - `date`: la date de la partie
+    /// - `difficulty`: la difficulté de la partie
+    /// - `seed`: la graine de la partie
+    /// # Returns
+    /// - `String`: la ligne, sans retour à la ligne
+    pub fn csv_row(&self, date: &str, difficulty: Difficulty, seed: u64) -> String {
+        let [large, medium, small] = self.asteroids_destroyed;
+        format!(
+            "{date},{},{seed},{},{},{:.1},{},{},{:.1},{large},{medium},{small},{},{}",
+            difficulty.id(),
+            self.score,
+            self.highest_wave,
+            self.time_survived,
+            self.missiles_fired,
+            self.missile_hits,
+            self.accuracy().unwrap_or(0.0),
+            self.black_holes_created,
+            self.black_holes_destroyed
+        )
+    }
+}
+
+/// Ajoute une ligne au journal CSV des parties, en écrivant d'abord l'en-tête
+/// si le journal est nouveau
+/// # Arguments
+/// - `path`: le chemin du journal
+/// - `row`: la ligne de la partie
+/// # Returns
+/// - `io::Result<()>`: une erreur si le journal n'a pas pu être écrit
+pub fn append_csv(path: &Path, row: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{CSV_HEADER}")?;
+    }
+    writeln!(file, "{row}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stellarobject::StellarObject;
+    use macroquad::prelude::*;
+
+    /// Vérifie que seuls les missiles du joueur comptent, et qu'un missile qui a touché
+    /// compte une seule fois dans la précision.
+    #[test]
+    fn test_accuracy_counts_player_missiles() {
+        let mut stats = RunStats::default();
+        assert_eq!(stats.accuracy(), None);
+
+        let mut hit = Missile::new(Vec2::ZERO, 0.0);
+        hit.handle_collision();
+        let missed = Missile::new(Vec2::ZERO, 0.0);
+        let mut enemy = Missile::enemy(Vec2::ZERO, 0.0);
+        enemy.handle_collision();
+        for missile in [&hit, &missed, &missed, &missed, &enemy] {
+            stats.record_missile(missile);
+        }
+
+        assert_eq!((stats.missiles_fired, stats.missile_hits), (4, 1));
+        assert_eq!(stats.accuracy(), Some(25.0));
+    }
+
+    /// Vérifie que le journal reçoit son en-tête une seule fois, puis une ligne par partie.
+    #[test]
+    fn test_csv_appends_rows_after_header() {
+        let path = std::env::temp_dir().join("asteroid_test_stats.csv");
+        let _ = std::fs::remove_file(&path);
+        let mut stats = RunStats {
+            highest_wave: 3,
+            score: 42,
+            ..RunStats::default()
+        };
+        stats.record_asteroid(Size::Large);
+        stats.record_asteroid(Size::Small);
+
+        let row = stats.csv_row("2024-05-01", Difficulty::Hard, 7);
+        append_csv(&path, &row).unwrap();
+        append_csv(&path, &row).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines, [CSV_HEADER, row.as_str(), row.as_str()]);
+        assert_eq!(row, "2024-05-01,hard,7,42,3,0.0,0,0,0.0,1,0,1,0,0");
+        assert_eq!(
+            CSV_HEADER.split(',').count(),
+            row.split(',').count(),
+            "Chaque colonne a un titre !"
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
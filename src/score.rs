@@ -0,0 +1,125 @@
+//! Module pour le multiplicateur de combo.
+//! Chaque asteroide détruit moins de `COMBO_WINDOW` secondes après le précédent augmente
+//! le multiplicateur des points, jusqu'à `MAX_MULTIPLIER`. Le combo retombe à x1 si le joueur
+//! attend trop longtemps, si le vaisseau est touché ou si une nouvelle vague commence.
+use macroquad::prelude::*;
+
+/// Durée (en secondes) pendant laquelle l'asteroide suivant prolonge le combo
+pub const COMBO_WINDOW: f32 = 2.0;
+/// Multiplicateur maximal du combo
+pub const MAX_MULTIPLIER: u32 = 5;
+
+/// Structure représentant le combo en cours
+/// # Champs
+/// - `multiplier`: le multiplicateur appliqué au dernier asteroide détruit
+/// - `timer`: le temps restant pour détruire l'asteroide suivant sans perdre le combo
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ScoreSystem {
+    pub multiplier: u32,
+    timer: f32,
+}
+
+impl Default for ScoreSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScoreSystem {
+    /// Crée un combo vide, au multiplicateur x1
+    /// # Returns
+    /// - `Self`: le combo
+    pub fn new() -> Self {
+        Self {
+            multiplier: 1,
+            timer: 0.0,
+        }
+    }
+
+    /// Compte un asteroide détruit : le multiplicateur augmente si le combo est encore en cours
+    /// # Returns
+    /// - `u32`: le multiplicateur à appliquer aux points de cet asteroide
+    pub fn record_kill(&mut self) -> u32 {
+        self.multiplier = if self.timer > 0.0 {
+            (self.multiplier + 1).min(MAX_MULTIPLIER)
+        } else {
+            1
+        };
+        self.timer = COMBO_WINDOW;
+        self.multiplier
+    }
+
+    /// Fait avancer le temps du combo, qui retombe à x1 à la fin de `COMBO_WINDOW`
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.timer = (self.timer - dt).max(0.0);
+        if self.timer == 0.0 {
+            self.multiplier = 1;
+        }
+    }
+
+    /// Casse le combo, quand le vaisseau est touché ou qu'une nouvelle vague commence
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Fonction qui renvoie la couleur des points gagnés avec un multiplicateur
+    /// # Arguments
+    /// - `multiplier`: le multiplicateur
+    /// # Returns
+    /// - `Color`: vert sans combo, puis de plus en plus chaud
+    pub fn color(multiplier: u32) -> Color {
+        match multiplier {
+            0 | 1 => GREEN,
+            2 => YELLOW,
+            3 => ORANGE,
+            4 => RED,
+            _ => MAGENTA,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Joue une suite de destructions aux instants donnés et renvoie les points gagnés.
+    fn score_for(kill_times: &[f32]) -> u32 {
+        let mut combo = ScoreSystem::new();
+        let mut clock = 0.0;
+        let mut score = 0;
+        for time in kill_times {
+            combo.update(time - clock);
+            clock = *time;
+            score += combo.record_kill();
+        }
+        score
+    }
+
+    /// Vérifie les points d'une série rapide, limitée à `MAX_MULTIPLIER`,
+    /// et d'une série trop lente pour faire un combo.
+    #[test]
+    fn test_combo_scores() {
+        assert_eq!(score_for(&[0.0, 1.0, 2.5, 4.0]), 1 + 2 + 3 + 4);
+        assert_eq!(
+            score_for(&[0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0]),
+            1 + 2 + 3 + 4 + 5 + 5 + 5
+        );
+        assert_eq!(score_for(&[0.0, 2.5, 5.0]), 3);
+        assert_eq!(score_for(&[0.0, 1.0, 3.5, 4.0]), 1 + 2 + 1 + 2);
+    }
+
+    /// Vérifie qu'un vaisseau touché casse le combo.
+    #[test]
+    fn test_hit_resets_combo() {
+        let mut combo = ScoreSystem::new();
+        combo.record_kill();
+        combo.record_kill();
+        assert_eq!(combo.multiplier, 2);
+
+        combo.reset();
+
+        assert_eq!(combo.record_kill(), 1);
+    }
+}
@@ -0,0 +1,167 @@
+//! Module pour les statistiques de la session de jeu.
+//! Une session commence au lancement du jeu et regroupe toutes les parties jouées jusqu'à sa fermeture.
+
+/// Nombres de parties à partir desquels le résumé de la session est affiché
+pub const MILESTONES: [u32; 5] = [3, 5, 10, 20, 50];
+
+/// Structure représentant les statistiques cumulées d'une session
+/// # Champs
+/// - `runs`: le nombre de parties jouées
+/// - `best_score`: le meilleur score de la session
+/// - `asteroids_destroyed`: le nombre total d'asteroides détruits
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct SessionStats {
+    pub runs: u32,
+    pub best_score: i32,
+    pub asteroids_destroyed: u32,
+}
+
+impl SessionStats {
+    /// Ajoute une partie terminée aux statistiques
+    /// # Arguments
+    /// - `score`: le score de la partie
+    /// - `asteroids_destroyed`: le nombre d'asteroides détruits pendant la partie
+    pub fn record_run(&mut self, score: i32, asteroids_destroyed: u32) {
+        self.best_score = if self.runs == 0 {
+            score
+        } else {
+            self.best_score.max(score)
+        };
+        self.runs += 1;
+        self.asteroids_destroyed += asteroids_destroyed;
+    }
+
+    /// Compare le meilleur score de cette session à celui de la session précédente
+    /// # Arguments
+    /// - `previous`: les statistiques de la session précédente, s'il y en a une
+    /// # Returns
+    /// - `Option<i32>`: l'écart de meilleur score, ou `None` s'il n'y a rien à comparer
+    pub fn compare(&self, previous: Option<&SessionStats>) -> Option<i32> {
+        previous
+            .filter(|previous| previous.runs > 0)
+            .map(|previous| self.best_score - previous.best_score)
+    }
+}
+
+/// Structure représentant la session en cours
+/// # Champs
+/// - `stats`: les statistiques de la session
+/// - `next_milestone`: l'indice du prochain palier de `MILESTONES` à atteindre
+#[derive(Default)]
+pub struct Session {
+    pub stats: SessionStats,
+    next_milestone: usize,
+}
+
+impl Session {
+    /// Crée une session vide
+    /// # Returns
+    /// - `Self`: la session
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indique si un nouveau palier vient d'être atteint.
+    /// Chaque palier n'est signalé qu'une fois, même si plusieurs sont franchis d'un coup.
+    /// # Returns
+    /// - `bool`: `true` si le résumé doit être affiché
+    pub fn milestone_reached(&mut self) -> bool {
+        let mut reached = false;
+        while MILESTONES
+            .get(self.next_milestone)
+            .is_some_and(|milestone| self.stats.runs >= *milestone)
+        {
+            self.next_milestone += 1;
+            reached = true;
+        }
+        reached
+    }
+}
+
+/// Met en forme un nombre avec un séparateur de milliers, sans signe
+/// # Arguments
+/// - `value`: le nombre
+/// - `separator`: le séparateur de milliers
+/// # Returns
+/// - `String`: le nombre mis en forme, par exemple `2 340`
+pub fn format_thousands(value: u32, separator: char) -> String {
+    let digits = value.to_string();
+    let mut formatted = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(separator);
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie le cumul des parties, y compris une session où tous les scores sont négatifs.
+    #[test]
+    fn test_record_runs() {
+        let mut stats = SessionStats::default();
+        stats.record_run(-5, 2);
+        assert_eq!(stats.best_score, -5);
+
+        stats.record_run(120, 30);
+        stats.record_run(80, 10);
+
+        assert_eq!(
+            stats,
+            SessionStats {
+                runs: 3,
+                best_score: 120,
+                asteroids_destroyed: 42,
+            }
+        );
+    }
+
+    /// Vérifie la comparaison avec la session précédente, et l'absence de comparaison la première fois.
+    #[test]
+    fn test_compare_with_previous_session() {
+        let mut stats = SessionStats::default();
+        stats.record_run(2500, 10);
+
+        assert_eq!(stats.compare(None), None);
+        assert_eq!(stats.compare(Some(&SessionStats::default())), None);
+
+        let previous = SessionStats {
+            runs: 4,
+            best_score: 160,
+            asteroids_destroyed: 50,
+        };
+        assert_eq!(stats.compare(Some(&previous)), Some(2340));
+    }
+
+    /// Vérifie que chaque palier n'est signalé qu'une fois.
+    #[test]
+    fn test_milestones_are_shown_once() {
+        let mut session = Session::new();
+        session.stats.runs = 2;
+        assert!(!session.milestone_reached());
+
+        session.stats.runs = 3;
+        assert!(session.milestone_reached());
+        assert!(!session.milestone_reached());
+
+        session.stats.runs = 12;
+        assert!(
+            session.milestone_reached(),
+            "Les paliers 5 et 10 sont franchis !"
+        );
+        assert!(!session.milestone_reached());
+    }
+
+    /// Vérifie la mise en forme des milliers.
+    #[test]
+    fn test_format_thousands() {
+        assert_eq!(format_thousands(0, ' '), "0");
+        assert_eq!(format_thousands(999, ' '), "999");
+        assert_eq!(format_thousands(2340, ' '), "2 340");
+        assert_eq!(format_thousands(1234567, ','), "1,234,567");
+    }
+}
@@ -0,0 +1,93 @@
+//! Module pour gérer les réglages du jeu choisis par le joueur
+use crate::cli::CliOptions;
+use crate::locale::Language;
+use asteroid::background::BackgroundMode;
+use asteroid::difficulty::Difficulty;
+use asteroid::feedback::FeedbackProfile;
+use asteroid::game_mode::GameMode;
+use asteroid::ufo::DEFAULT_SPAWN_CHANCE;
+use asteroid::world::random_seed;
+use std::path::PathBuf;
+
+/// Structure représentant les réglages du jeu
+/// # Champs
+/// - `captions`: permet de savoir si les sous-titres des sons sont affichés ou non
+/// - `language`: la langue des textes du jeu
+/// - `background_mode`: la façon de dessiner l'arrière-plan de la partie
+/// - `ufo_spawn_chance`: la probabilité qu'une soucoupe apparaisse au début de chaque vague
+/// - `hide_hud`: permet de cacher le tableau de bord, pour les captures d'écran (touche F2)
+/// - `edge_indicators`: permet d'annoncer par un chevron au bord de l'écran les dangers
+///   sur le point d'y apparaître en passant d'un bord à l'autre
+/// - `radar`: permet d'afficher le radar de toute l'aire de jeu en haut à droite (touche Tab)
+/// - `hitboxes`: permet de dessiner le cercle de collision de chaque objet (touche F4)
+/// - `dev_overlay`: permet de savoir si l'affichage de développement est actif (touche F3)
+/// - `feedback`: le profil de retour visuel et sonore, qui inclut le mode mouvements réduits
+/// - `remix`: permet de reprendre le champ de la partie précédente comme première vague
+/// - `mouse_aim`: permet de viser avec la souris et de tirer avec le clic gauche
+/// - `two_players`: permet de jouer à deux sur le même clavier, le second joueur avec ZQSD
+///   et Ctrl gauche
+/// - `difficulty`: la difficulté choisie sur l'écran de démarrage (ou par l'option `--difficulty`),
+///   gardée pendant toute la session
+/// - `mode`: le mode de jeu choisi sur l'écran de démarrage (ou par l'option `--mode`),
+///   gardé pendant toute la session
+/// - `arcade_missiles`: les missiles partent toujours à la même vitesse, sans garder celle du vaisseau
+///   (option `--arcade-missiles`)
+/// - `overheat`: l'arme surchauffe quelle que soit la difficulté (option `--overheat`)
+/// - `seed`: la graine imposée à toutes les parties (option `--seed N`), sinon chaque partie
+///   tire la sienne au hasard
+/// - `record`: le fichier où enregistrer le replay de chaque partie (option `--record chemin`)
+/// - `replay`: le replay à rejouer au lancement du jeu (option `--replay chemin`)
+#[derive(Clone, Default)]
+pub struct Settings {
+    pub captions: bool,
+    pub language: Language,
+    pub background_mode: BackgroundMode,
+    pub ufo_spawn_chance: f64,
+    pub hide_hud: bool,
+    pub edge_indicators: bool,
+    pub radar: bool,
+    pub hitboxes: bool,
+    pub dev_overlay: bool,
+    pub feedback: FeedbackProfile,
+    pub remix: bool,
+    pub mouse_aim: bool,
+    pub two_players: bool,
+    pub difficulty: Difficulty,
+    pub mode: GameMode,
+    pub arcade_missiles: bool,
+    pub overheat: bool,
+    pub seed: Option<u64>,
+    pub record: Option<PathBuf>,
+    pub replay: Option<PathBuf>,
+}
+
+impl Settings {
+    /// Crée les réglages par défaut, dans la langue du système, avec les options de la ligne de commande
+    /// # Arguments
+    /// - `options`: les options de la ligne de commande
+    /// # Returns
+    /// - `Self`: les réglages du jeu
+    pub fn new(options: &CliOptions) -> Self {
+        Self {
+            language: Language::from_env(),
+            ufo_spawn_chance: DEFAULT_SPAWN_CHANCE,
+            edge_indicators: true,
+            radar: true,
+            difficulty: options.difficulty.unwrap_or_default(),
+            mode: options.mode.unwrap_or_default(),
+            arcade_missiles: options.arcade_missiles,
+            overheat: options.overheat,
+            seed: options.seed,
+            record: options.record.clone(),
+            replay: options.replay.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Fonction qui renvoie la graine d'une nouvelle partie
+    /// # Returns
+    /// - `u64`: la graine imposée, ou une graine tirée au hasard
+    pub fn next_seed(&self) -> u64 {
+        self.seed.unwrap_or_else(random_seed)
+    }
+}
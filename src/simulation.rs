@@ -0,0 +1,283 @@
+//! Module pour les simulations de parties sans fenêtre ni son, pilotées par le pilote automatique.
+//! Chaque image est un pas de temps fixe, comme dans le jeu : une simulation avec la même graine
+//! donne toujours la même partie ; seules les particules, tirées au hasard hors de la graine,
+//! changent d'une fois à l'autre.
+//! Le binaire `simulate` enchaîne des parties pour équilibrer la difficulté
+//! et pour trouver les paniques qui n'apparaissent qu'au bout de longues parties.
+use crate::assets::dummy_texture;
+use crate::autopilot;
+use crate::difficulty::Difficulty;
+use crate::game_mode::GameMode;
+use crate::game_over::GameOverReason;
+use crate::playfield::REFERENCE_RESOLUTION;
+use crate::spaceship::{Spaceship, SpaceshipTextures};
+use crate::timestep::FIXED_DT;
+use crate::upgrade::UPGRADE_CHOICES;
+use crate::world::{World, WorldTextures};
+
+/// Structure représentant les réglages d'une simulation
+/// # Champs
+/// - `minutes`: la durée maximale d'une partie, en minutes de jeu
+/// - `difficulty`: la difficulté des parties
+/// - `mode`: le mode de jeu des parties
+/// - `ufo_spawn_chance`: la probabilité qu'une soucoupe apparaisse au début de chaque vague
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SimulationConfig {
+    pub minutes: f32,
+    pub difficulty: Difficulty,
+    pub mode: GameMode,
+    pub ufo_spawn_chance: f64,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            minutes: 10.0,
+            difficulty: Difficulty::default(),
+            mode: GameMode::default(),
+            ufo_spawn_chance: crate::ufo::DEFAULT_SPAWN_CHANCE,
+        }
+    }
+}
+
+/// Structure représentant les nombres maximaux d'objets vus pendant une partie
+/// # Champs
+/// - `asteroids`: les asteroides actifs
+/// - `missiles`: les missiles en vol, du joueur et des soucoupes
+/// - `ufos`: les soucoupes actives
+/// - `black_holes`: les trous noirs
+/// - `particles`: les particules des effets
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct EntityPeaks {
+    pub asteroids: usize,
+    pub missiles: usize,
+    pub ufos: usize,
+    pub black_holes: usize,
+    pub particles: usize,
+}
+
+impl EntityPeaks {
+    /// Retient les nombres d'objets d'une image, s'ils dépassent les maximums
+    /// # Arguments
+    /// - `world`: la partie
+    fn record(&mut self, world: &World) {
+        let active_asteroids = world.asteroids.iter().filter(|a| a.active).count();
+        self.asteroids = self.asteroids.max(active_asteroids);
+        self.missiles = self.missiles.max(world.missiles.iter().count());
+        self.ufos = self
+            .ufos
+            .max(world.ufos.iter().filter(|u| u.active).count());
+        self.black_holes = self.black_holes.max(world.black_holes.len());
+        self.particles = self.particles.max(world.effects.particle_count());
+    }
+
+    /// Garde le plus grand des deux maximums pour chaque type d'objet
+    /// # Arguments
+    /// - `other`: les maximums d'une autre partie
+    /// # Returns
+    /// - `Self`: les maximums des deux parties
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            asteroids: self.asteroids.max(other.asteroids),
+            missiles: self.missiles.max(other.missiles),
+            ufos: self.ufos.max(other.ufos),
+            black_holes: self.black_holes.max(other.black_holes),
+            particles: self.particles.max(other.particles),
+        }
+    }
+}
+
+/// Structure représentant le résultat d'une partie simulée
+/// # Champs
+/// - `seed`: la graine de la partie
+/// - `wave`: la vague atteinte
+/// - `score`: le score final
+/// - `frames`: le nombre d'images simulées
+/// - `reason`: la raison de la fin de la partie, ou `None` si elle a duré jusqu'au bout ou a été gagnée
+/// - `victory`: permet de savoir si le vaisseau mère a été détruit
+/// - `asteroid_frames`: la somme, sur toutes les images, du nombre d'asteroides actifs
+/// - `peaks`: les nombres maximaux d'objets
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RunReport {
+    pub seed: u64,
+    pub wave: u32,
+    pub score: i32,
+    pub frames: u32,
+    pub reason: Option<GameOverReason>,
+    pub victory: bool,
+    pub asteroid_frames: u64,
+    pub peaks: EntityPeaks,
+}
+
+impl RunReport {
+    /// Fonction qui renvoie la durée de la partie
+    /// # Returns
+    /// - `f32`: la durée, en secondes de jeu
+    pub fn seconds(&self) -> f32 {
+        self.frames as f32 * FIXED_DT
+    }
+}
+
+/// Fonction qui joue une partie avec le pilote automatique, jusqu'à la destruction du vaisseau,
+/// la victoire ou la fin du temps. Les améliorations proposées sont choisies selon la vague.
+/// # Arguments
+/// - `seed`: la graine de la partie
+/// - `config`: les réglages de la simulation
+/// # Returns
+/// - `RunReport`: le résultat de la partie
+pub fn simulate_run(seed: u64, config: &SimulationConfig) -> RunReport {
+    let ship_textures = SpaceshipTextures {
+        ship: dummy_texture(),
+        shield_on: dummy_texture(),
+        shield_off: dummy_texture(),
+        shield_dead: dummy_texture(),
+    };
+    let textures = WorldTextures {
+        asteroids: vec![dummy_texture()],
+        black_hole: dummy_texture(),
+        ufo: dummy_texture(),
+    };
+    let bounds = REFERENCE_RESOLUTION;
+    let mut world = World::new(
+        Spaceship::new(bounds, ship_textures.clone()),
+        textures,
+        bounds,
+        config.ufo_spawn_chance,
+    );
+    world.seed = seed;
+    world.difficulty = config.difficulty;
+    world.offer_upgrades = true;
+    world.set_mode(config.mode);
+    world.reset(Spaceship::new(bounds, ship_textures));
+
+    let max_frames = (config.minutes * 60.0 / FIXED_DT).round() as u32;
+    let mut frames = 0;
+    let mut asteroid_frames = 0;
+    let mut peaks = EntityPeaks::default();
+    let mut reason = None;
+    while frames < max_frames && !world.victory {
+        if world.upgrade_choices.is_some() {
+            world.choose_upgrade(world.wave as usize % UPGRADE_CHOICES);
+        }
+        autopilot::drive(&mut world, FIXED_DT);
+        reason = world.handle_collisions();
+        world.update(FIXED_DT);
+        world.sound_events.clear();
+
+        frames += 1;
+        asteroid_frames += world.asteroids.iter().filter(|a| a.active).count() as u64;
+        peaks.record(&world);
+        if reason.is_some() {
+            break;
+        }
+    }
+    RunReport {
+        seed,
+        wave: world.wave,
+        score: world.score,
+        frames,
+        reason,
+        victory: world.victory,
+        asteroid_frames,
+        peaks,
+    }
+}
+
+/// Structure résumant plusieurs parties simulées
+/// # Champs
+/// - `runs`: le nombre de parties
+/// - `waves`: la plus petite, la moyenne et la plus grande vague atteinte
+/// - `scores`: le plus petit score, le premier quartile, la médiane, le troisième quartile et le plus grand
+/// - `asteroids_per_frame`: le nombre moyen d'asteroides actifs par image
+/// - `peaks`: les nombres maximaux d'objets, toutes parties confondues
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SimulationSummary {
+    pub runs: usize,
+    pub waves: (u32, f32, u32),
+    pub scores: [i32; 5],
+    pub asteroids_per_frame: f32,
+    pub peaks: EntityPeaks,
+}
+
+impl SimulationSummary {
+    /// Résume des parties simulées
+    /// # Arguments
+    /// - `reports`: les résultats des parties
+    /// # Returns
+    /// - `Option<Self>`: le résumé, ou `None` s'il n'y a aucune partie
+    pub fn new(reports: &[RunReport]) -> Option<Self> {
+        let waves: Vec<u32> = reports.iter().map(|r| r.wave).collect();
+        let mut scores: Vec<i32> = reports.iter().map(|r| r.score).collect();
+        scores.sort_unstable();
+        let quantile = |q: f32| scores[((scores.len() - 1) as f32 * q).round() as usize];
+        let frames: u64 = reports.iter().map(|r| r.frames as u64).sum();
+        let asteroid_frames: u64 = reports.iter().map(|r| r.asteroid_frames).sum();
+        Some(Self {
+            runs: reports.len(),
+            waves: (
+                *waves.iter().min()?,
+                waves.iter().sum::<u32>() as f32 / waves.len() as f32,
+                *waves.iter().max()?,
+            ),
+            scores: [0.0, 0.25, 0.5, 0.75, 1.0].map(quantile),
+            asteroids_per_frame: asteroid_frames as f32 / frames.max(1) as f32,
+            peaks: reports
+                .iter()
+                .fold(EntityPeaks::default(), |peaks, r| peaks.max(r.peaks)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie qu'une partie simulée est la même pour une même graine,
+    /// et qu'elle s'arrête à la fin du temps si le vaisseau survit.
+    #[test]
+    fn test_simulation_is_deterministic() {
+        let config = SimulationConfig {
+            minutes: 0.5,
+            ..SimulationConfig::default()
+        };
+
+        let first = simulate_run(7, &config);
+        let mut second = simulate_run(7, &config);
+        second.peaks.particles = first.peaks.particles;
+        assert_eq!(first, second);
+        assert!(first.frames <= 1800);
+        if first.reason.is_none() && !first.victory {
+            assert_eq!(first.frames, 1800);
+        }
+        assert!(first.peaks.asteroids > 0);
+    }
+
+    /// Vérifie les quartiles des scores et les moyennes du résumé.
+    #[test]
+    fn test_summary_statistics() {
+        let report = |seed, wave, score| RunReport {
+            seed,
+            wave,
+            score,
+            frames: 100,
+            reason: None,
+            victory: false,
+            asteroid_frames: 100 * wave as u64,
+            peaks: EntityPeaks {
+                asteroids: wave as usize,
+                ..EntityPeaks::default()
+            },
+        };
+        let reports: Vec<RunReport> = (1..=5)
+            .map(|i| report(i, i as u32, i as i32 * 100))
+            .collect();
+
+        let summary = SimulationSummary::new(&reports).unwrap();
+        assert_eq!(summary.runs, 5);
+        assert_eq!(summary.waves, (1, 3.0, 5));
+        assert_eq!(summary.scores, [100, 200, 300, 400, 500]);
+        assert_eq!(summary.asteroids_per_frame, 3.0);
+        assert_eq!(summary.peaks.asteroids, 5);
+        assert_eq!(SimulationSummary::new(&[]), None);
+    }
+}
@@ -1,74 +1,329 @@
 //! Module pour gérer le vaisseau spatial.
 //! Le vaisseau peut se déplacer, tourner, utiliser un bouclier et devenir temporairement invincible.
+//! Détruit, il explose pendant `DEATH_SEQUENCE_TIME` avant la fin de la partie.
+use crate::assets::{load_required_texture, Placeholder};
+use crate::missile::Missile;
+use crate::pool::Pool;
+use crate::powerup::{ActiveEffects, PowerUpKind};
+use crate::upgrade::Upgrades;
+use crate::weapon::{triple_shot_angles, WeaponState, FIRE_COOLDOWN, RAPID_FIRE_FACTOR};
 use macroquad::prelude::*;
 use std::f32::consts::PI;
 
+/// Vitesse maximale par défaut du vaisseau
+pub const MAX_SPEED: f32 = 6.0;
+/// Angle (en radians) dont le vaisseau tourne à chaque image avec les touches de rotation
+pub const TURN_RATE: f32 = 0.05;
+/// Vitesse donnée au vaisseau par un choc sur son bouclier, par pixel de taille de l'asteroide
+pub const KNOCKBACK_FACTOR: f32 = 0.04;
+/// Fraction de la vitesse perdue à chaque image quand le vaisseau ne pousse pas
+pub const DRAG: f32 = 0.015;
+/// Durée (en secondes) par défaut de l'invincibilité après la perte du bouclier
+pub const HIT_INVINCIBILITY: f32 = 2.0;
+/// Durée (en secondes) de l'explosion du vaisseau détruit, avant l'écran de fin de partie
+pub const DEATH_SEQUENCE_TIME: f32 = 1.5;
+/// Part du rayon dessiné utilisée pour les collisions : la texture du vaisseau est carrée,
+/// mais le vaisseau est une flèche qui n'en remplit que le centre
+pub const SHIP_COLLISION_FACTOR: f32 = 0.7;
+/// Nombre de charges du bouclier plein : chaque choc en consomme une
+pub const MAX_SHIELD_CHARGES: u32 = 3;
+/// Temps (en secondes) par défaut sans être touché avant que le bouclier perdu ne revienne
+pub const SHIELD_REGEN_TIME: f32 = 10.0;
+/// Durée (en secondes) du scintillement du bouclier qui revient
+const SHIELD_SHIMMER_TIME: f32 = 0.4;
+/// Vitesse en dessous de laquelle un vaisseau qui ralentit s'arrête
+const STOP_SPEED: f32 = 0.01;
+/// Décalage de la position de départ du vaisseau du second joueur par rapport au centre
+pub const SECOND_PLAYER_OFFSET: Vec2 = vec2(0.0, 80.0);
+/// Teinte du vaisseau du second joueur, pour le distinguer du premier
+pub const SECOND_PLAYER_TINT: Color = Color::new(0.5, 0.9, 1.0, 1.0);
+
 /// Structure représentant le vaisseau spatial du joueur.
 /// # Champs
 /// - `position`: la position du spaceship
 /// - `velocity`: la vitesse du spaceship
 /// - `rotation`: l'angle de rotation du spaceship
-/// - `shield`: booleen permettant de savoir si le shield est actif ou non
+/// - `shield_charges`: le nombre de chocs que le bouclier peut encore encaisser,
+///   jusqu'à `MAX_SHIELD_CHARGES`
 /// - `invincible`: booleen permettant de savoir si le vaisseau est invincible ou non
 /// - `ìnvincibily_timer`: compteur la durée de l'invincibilité du vaisseau
+/// - `invincibility_time`: la durée de l'invincibilité qui suit la perte du bouclier
+/// - `shield_regen_time`: le temps sans être touché avant qu'une charge du bouclier perdu ne revienne
+/// - `shield_regen_timer`: le temps restant avant que la charge ne revienne
+/// - `shimmer_timer`: le temps restant du scintillement du bouclier qui vient de revenir
 /// - `hit`: booleen pour savoir si on a été touché
 /// - `active`: permet de savoir si le vaisseau est acitf ou non
-/// - `radius`: le rayon du vaisseau
-/// - `texture`: la texture du spaceship
-/// - `texture_shield_on`: la texture du bouclier actif
-/// - `texture_shield_off`: la texture du bouclier quand le spaceship est invincible
-/// - `texture_shield_dead`: la texture du bouclier detruit
+/// - `dying`: permet de savoir si le vaisseau détruit est en train d'exploser ; il n'est alors
+///   plus dessiné, ne répond plus aux commandes et ne peut plus rien toucher
+/// - `death_timer`: le temps restant de l'explosion
+/// - `radius`: le rayon de la texture du vaisseau, réduit par `SHIP_COLLISION_FACTOR` pour les collisions
+/// - `textures`: les textures du vaisseau et de son bouclier
+/// - `weapon`: l'état de l'arme du vaisseau
+/// - `active_effects`: les effets des bonus en cours
+/// - `upgrades`: les améliorations choisies entre les vagues, gardées jusqu'à la fin de la partie
+/// - `max_speed`: la vitesse maximale du vaisseau
+/// - `knockback`: la vitesse donnée par un choc sur le bouclier, par pixel de taille de l'asteroide
+/// - `inherit_velocity`: permet de savoir si les missiles gardent la vitesse du vaisseau,
+///   ou partent toujours à la même vitesse comme dans le jeu d'arcade
+/// - `thrusting`: le sens de la poussée appliquée depuis la dernière mise à jour (1 en avant, -1 en arrière, 0 sans poussée)
+/// - `exhaust`: le sens de la poussée de la dernière mise à jour, affiché par la flamme du réacteur
+/// - `flame_frames`: le nombre d'images depuis que la flamme est allumée, pour la faire vaciller
+/// - `player`: le numéro du joueur qui pilote le vaisseau, 0 pour le premier joueur
+/// - `tint`: la teinte de la texture du vaisseau
 pub struct Spaceship {
     position: Vec2,
     pub velocity: Vec2,
     pub rotation: f32,
-    pub shield: bool,
+    pub shield_charges: u32,
     pub invincible: bool,
     pub invincibility_timer: f32,
+    pub invincibility_time: f32,
+    pub shield_regen_time: f32,
+    pub shield_regen_timer: f32,
+    shimmer_timer: f32,
     pub hit: bool,
     pub active: bool,
+    pub dying: bool,
+    pub death_timer: f32,
     radius: f32,
-    texture: Texture2D,
-    texture_shield_on: Texture2D,
-    texture_shield_off: Texture2D,
-    texture_shield_dead: Texture2D,
+    textures: SpaceshipTextures,
+    pub weapon: WeaponState,
+    pub active_effects: ActiveEffects,
+    pub upgrades: Upgrades,
+    pub max_speed: f32,
+    pub knockback: f32,
+    pub inherit_velocity: bool,
+    pub thrusting: i8,
+    pub exhaust: i8,
+    flame_frames: u32,
+    pub player: usize,
+    pub tint: Color,
+}
+
+/// Structure regroupant les textures du vaisseau
+/// # Champs
+/// - `ship`: la texture du spaceship
+/// - `shield_on`: la texture du bouclier actif
+/// - `shield_off`: la texture du bouclier quand le spaceship est invincible
+/// - `shield_dead`: la texture du bouclier detruit
+#[derive(Clone)]
+pub struct SpaceshipTextures {
+    pub ship: Texture2D,
+    pub shield_on: Texture2D,
+    pub shield_off: Texture2D,
+    pub shield_dead: Texture2D,
+}
+
+impl SpaceshipTextures {
+    /// Charge les textures du vaisseau
+    /// # Returns
+    /// - `Self`: les textures du vaisseau et de son bouclier, avec des dessins à la place
+    ///   des textures manquantes
+    pub async fn load() -> Self {
+        Self {
+            ship: load_required_texture("spaceship.png", Placeholder::Ship).await,
+            shield_on: load_required_texture("shield_on.png", Placeholder::Shield).await,
+            shield_off: load_required_texture("shield_off.png", Placeholder::Shield).await,
+            shield_dead: load_required_texture("shield_dead.png", Placeholder::Shield).await,
+        }
+    }
 }
 
 impl Spaceship {
-    /// Crée un nouveau vaisseau positionné au centre de l'écran.
-    /// # Returns
-    /// - `Self`: Un objet spaceship positionné au milieu de l'écran, avec un bouclier
-    pub async fn new() -> Self {
-        let texture = load_texture("assets/spaceship.png").await.unwrap();
-        let texture_shield_on = load_texture("assets/shield_on.png").await.unwrap();
-        let texture_shield_off = load_texture("assets/shield_off.png").await.unwrap();
-        let texture_shield_dead = load_texture("assets/shield_dead.png").await.unwrap();
+    /// Crée un nouveau vaisseau positionné au centre de l'aire de jeu.
+    /// # Arguments
+    /// - `bounds`: la taille de l'aire de jeu
+    /// - `textures`: les textures du vaisseau et de son bouclier, déjà chargées
+    /// # Returns
+    /// - `Self`: Un objet spaceship positionné au milieu de l'aire de jeu, avec un bouclier
+    pub fn new(bounds: Vec2, textures: SpaceshipTextures) -> Self {
+        Self::with_textures(bounds / 2.0, textures)
+    }
+
+    /// Crée un nouveau vaisseau à partir de textures déjà chargées.
+    /// # Arguments
+    /// - `position`: la position initiale du vaisseau
+    /// - `textures`: les textures du vaisseau et de son bouclier
+    /// # Returns
+    /// - `Self`: Un objet spaceship immobile, avec un bouclier
+    pub fn with_textures(position: Vec2, textures: SpaceshipTextures) -> Self {
         Self {
-            position: vec2(screen_width() / 2.0, screen_height() / 2.0),
+            position,
             velocity: vec2(0.0, 0.0),
             rotation: 0.0,
-            shield: true, // Bouclier activé au départ
+            shield_charges: MAX_SHIELD_CHARGES, // Bouclier plein au départ
             invincible: false,
             invincibility_timer: 0.0,
+            invincibility_time: HIT_INVINCIBILITY,
+            shield_regen_time: SHIELD_REGEN_TIME,
+            shield_regen_timer: SHIELD_REGEN_TIME,
+            shimmer_timer: 0.0,
             hit: false,
             active: true,
+            dying: false,
+            death_timer: 0.0,
             radius: 25.0,
-            texture,
-            texture_shield_on,
-            texture_shield_off,
-            texture_shield_dead,
+            textures,
+            weapon: WeaponState::default(),
+            active_effects: ActiveEffects::default(),
+            upgrades: Upgrades::default(),
+            max_speed: MAX_SPEED,
+            knockback: KNOCKBACK_FACTOR,
+            inherit_velocity: true,
+            thrusting: 0,
+            exhaust: 0,
+            flame_frames: 0,
+            player: 0,
+            tint: WHITE,
+        }
+    }
+
+    /// Crée le vaisseau du second joueur, teinté, sous le centre de l'aire de jeu
+    /// # Arguments
+    /// - `bounds`: la taille de l'aire de jeu
+    /// - `textures`: les textures du vaisseau et de son bouclier
+    /// # Returns
+    /// - `Self`: le vaisseau du second joueur, avec un bouclier
+    pub fn second_player(bounds: Vec2, textures: SpaceshipTextures) -> Self {
+        let mut ship = Self::with_textures(spawn_position(1, bounds), textures);
+        ship.player = 1;
+        ship.tint = SECOND_PLAYER_TINT;
+        ship
+    }
+
+    /// Fait revenir le vaisseau détruit à sa position de départ, avec son bouclier
+    /// et une courte invincibilité. Les bonus en cours sont perdus.
+    /// # Arguments
+    /// - `bounds`: la taille de l'aire de jeu
+    pub fn respawn(&mut self, bounds: Vec2) {
+        self.position = spawn_position(self.player, bounds);
+        self.velocity = Vec2::ZERO;
+        self.rotation = 0.0;
+        self.restore_shield();
+        self.active = true;
+        self.dying = false;
+        self.death_timer = 0.0;
+        self.invincible = true;
+        self.invincibility_timer = self.invincibility_time;
+        self.hit = false;
+        // La surchauffe est une règle de la partie, elle n'est pas perdue avec le vaisseau
+        let overheat = self.weapon.overheat;
+        self.weapon = WeaponState::default();
+        self.weapon.overheat = overheat;
+        self.active_effects = ActiveEffects::default();
+    }
+
+    /// Ramène le vaisseau dans l'aire de jeu s'il en est sorti, quand l'aire de jeu rétrécit.
+    /// Un vaisseau dans l'aire de jeu ne bouge pas.
+    /// # Arguments
+    /// - `bounds`: la nouvelle taille de l'aire de jeu
+    pub fn clamp_into(&mut self, bounds: Vec2) {
+        if self.position.cmplt(bounds).all() {
+            return;
+        }
+        let margin = Vec2::splat(self.radius).min(bounds / 2.0);
+        self.position = self.position.clamp(margin, bounds - margin);
+    }
+
+    /// Fait avancer les minuteries du vaisseau : bonus, explosion et invincibilité
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update_timers(&mut self, dt: f32) {
+        self.active_effects.update(dt);
+        if self.dying {
+            self.death_timer -= dt;
+        }
+        if self.invincible {
+            self.invincibility_timer -= dt;
+            if self.invincibility_timer <= 0.0 {
+                self.invincible = false;
+                self.hit = false;
+            }
         }
     }
 
+    /// Permet de savoir si le bouclier peut encore encaisser un choc
+    /// # Returns
+    /// - `bool`: `true` s'il reste au moins une charge
+    pub fn has_shield(&self) -> bool {
+        self.shield_charges > 0
+    }
+
+    /// Remplit le bouclier du vaisseau, et recommence le temps avant sa prochaine recharge
+    pub fn restore_shield(&mut self) {
+        self.add_shield_charges(MAX_SHIELD_CHARGES);
+    }
+
+    /// Rend des charges au bouclier, sans dépasser `MAX_SHIELD_CHARGES`,
+    /// et recommence le temps avant sa prochaine recharge
+    /// # Arguments
+    /// - `charges`: le nombre de charges rendues
+    pub fn add_shield_charges(&mut self, charges: u32) {
+        self.shield_charges = (self.shield_charges + charges).min(MAX_SHIELD_CHARGES);
+        self.shield_regen_timer = self.shield_regen_time;
+    }
+
+    /// Fait avancer la recharge du bouclier : quand le bouclier est vide, une charge revient après
+    /// `shield_regen_time` secondes sans être touché, avec un court scintillement
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// # Returns
+    /// - `bool`: `true` si le bouclier est revenu pendant cette image
+    pub fn update_shield_regen(&mut self, dt: f32) -> bool {
+        self.shimmer_timer = (self.shimmer_timer - dt).max(0.0);
+        if self.has_shield() || self.dying || !self.active {
+            return false;
+        }
+        self.shield_regen_timer -= dt;
+        if self.shield_regen_timer > 0.0 {
+            return false;
+        }
+        self.add_shield_charges(1);
+        self.shimmer_timer = SHIELD_SHIMMER_TIME;
+        true
+    }
+
+    /// Fonction qui renvoie l'avancement de la recharge du bouclier
+    /// # Returns
+    /// - `Option<f32>`: la part de la recharge déjà faite, entre 0 et 1,
+    ///   ou `None` si le bouclier a encore des charges
+    pub fn shield_regen_progress(&self) -> Option<f32> {
+        (!self.has_shield()).then(|| {
+            (1.0 - self.shield_regen_timer / self.shield_regen_time.max(f32::EPSILON))
+                .clamp(0.0, 1.0)
+        })
+    }
+
     /// Dessine le vaisseau et ses effets visuels (bouclier, invincibilité).
+    /// Un vaisseau qui chevauche un bord est aussi dessiné de l'autre côté de l'écran.
+    /// # Arguments
+    /// - `&self`: instance de vaisseau
+    /// - `bounds`: la taille de l'aire de jeu
+    pub fn draw(&self, bounds: Vec2) {
+        // Le vaisseau détruit a volé en éclats, seule son explosion est dessinée
+        if self.dying {
+            return;
+        }
+        // Le bouclier dépasse du vaisseau, c'est lui qui détermine les bords touchés
+        for offset in wrap_offsets(self.position, self.radius * 1.5, bounds) {
+            self.draw_at(self.position + offset);
+        }
+    }
+
+    /// Dessine le vaisseau et ses effets visuels à une position donnée.
     /// # Arguments
     /// - `&self`: instance de vaisseau
-    pub fn draw(&self) {
+    /// - `position`: la position à laquelle dessiner le vaisseau
+    fn draw_at(&self, position: Vec2) {
+        // La flamme est dessinée avant le vaisseau, pour passer derrière lui
+        if self.exhaust != 0 {
+            self.draw_flame(position);
+        }
         draw_texture_ex(
-            &self.texture,
-            self.position.x - self.radius,
-            self.position.y - self.radius,
-            WHITE,
+            &self.textures.ship,
+            position.x - self.radius,
+            position.y - self.radius,
+            self.tint,
             DrawTextureParams {
                 dest_size: Some(vec2(self.radius * 2.0, self.radius * 2.0)),
                 rotation: self.rotation + PI / 2.0,
@@ -76,24 +331,24 @@ impl Spaceship {
             },
         );
 
-        // Afficher le bouclier si actif
-        if self.shield {
+        // Afficher le bouclier si actif, teinté selon ses charges
+        if self.has_shield() {
             draw_texture_ex(
-                &self.texture_shield_on,
-                self.position.x - self.radius * 1.5,
-                self.position.y - self.radius * 1.5,
-                WHITE,
+                &self.textures.shield_on,
+                position.x - self.radius * 1.5,
+                position.y - self.radius * 1.5,
+                shield_tint(self.shield_charges),
                 DrawTextureParams {
                     dest_size: Some(vec2(self.radius * 3.0, self.radius * 3.0)),
                     rotation: self.rotation + PI / 2.0,
                     ..Default::default()
                 },
             );
-        } else if !self.shield && !self.invincible {
+        } else if !self.invincible {
             draw_texture_ex(
-                &self.texture_shield_dead,
-                self.position.x - self.radius * 1.5,
-                self.position.y - self.radius * 1.5,
+                &self.textures.shield_dead,
+                position.x - self.radius * 1.5,
+                position.y - self.radius * 1.5,
                 WHITE,
                 DrawTextureParams {
                     dest_size: Some(vec2(self.radius * 3.0, self.radius * 3.0)),
@@ -102,12 +357,35 @@ impl Spaceship {
                 },
             );
         }
+        // Un arc fin autour du vaisseau montre la recharge du bouclier
+        if let Some(progress) = self.shield_regen_progress() {
+            draw_arc(
+                position.x,
+                position.y,
+                32,
+                self.radius * 1.4,
+                -90.0,
+                2.0,
+                360.0 * progress,
+                Color::new(0.5, 0.8, 1.0, 0.6),
+            );
+        }
+        if self.shimmer_timer > 0.0 {
+            let fade = self.shimmer_timer / SHIELD_SHIMMER_TIME;
+            draw_circle_lines(
+                position.x,
+                position.y,
+                self.radius * (1.5 + (1.0 - fade) * 0.3),
+                3.0,
+                Color::new(0.8, 0.95, 1.0, fade),
+            );
+        }
         // Afficher l'invincibilité si active
         if self.invincible {
             draw_texture_ex(
-                &self.texture_shield_off,
-                self.position.x - self.radius * 1.5,
-                self.position.y - self.radius * 1.5,
+                &self.textures.shield_off,
+                position.x - self.radius * 1.5,
+                position.y - self.radius * 1.5,
                 WHITE,
                 DrawTextureParams {
                     dest_size: Some(vec2(self.radius * 3.0, self.radius * 3.0)),
@@ -118,13 +396,154 @@ impl Spaceship {
         }
     }
 
-    /// Applique une poussée pour déplacer le vaisseau.
+    /// Dessine la flamme du réacteur, à l'arrière du vaisseau quand il avance
+    /// et à l'avant quand il recule. Sa longueur change toutes les quelques images.
+    /// # Arguments
+    /// - `&self`: instance de vaisseau
+    /// - `position`: la position à laquelle le vaisseau est dessiné
+    fn draw_flame(&self, position: Vec2) {
+        let direction = Vec2::from_angle(self.rotation) * self.exhaust as f32;
+        let side = direction.perp() * self.radius * 0.35;
+        let base = position - direction * self.radius * 0.8;
+        let flicker = if (self.flame_frames / 4).is_multiple_of(2) {
+            1.0
+        } else {
+            1.3
+        };
+        let length = self.radius * 0.8 * flicker;
+        draw_triangle(base + side, base - side, base - direction * length, ORANGE);
+        draw_triangle(
+            base + side * 0.5,
+            base - side * 0.5,
+            base - direction * length * 0.6,
+            YELLOW,
+        );
+    }
+
+    /// Applique une poussée pour déplacer le vaisseau, sans dépasser `max_speed`.
     /// # Arguments
     /// - `&mut self`: instance mutable du vaisseau afin de changer sa vitesse
     /// - `amount`: montant correspondant à l'augmentation de la vitesse
     pub fn apply_thrust(&mut self, amount: f32) {
         let thrust = vec2(self.rotation.cos(), self.rotation.sin()) * amount;
-        self.velocity += thrust;
+        self.velocity = (self.velocity + thrust).clamp_length_max(self.max_speed);
+        self.thrusting = amount.signum() as i8;
+    }
+
+    /// Fait exploser le vaisseau détruit : il s'arrête et disparait pendant `DEATH_SEQUENCE_TIME`
+    /// # Arguments
+    /// - `&mut self`: instance mutable du vaisseau
+    pub fn explode(&mut self) {
+        self.dying = true;
+        self.death_timer = DEATH_SEQUENCE_TIME;
+        self.velocity = Vec2::ZERO;
+        self.thrusting = 0;
+        self.exhaust = 0;
+    }
+
+    /// Fonction qui permet de savoir si l'explosion du vaisseau est terminée
+    /// # Returns
+    /// - `bool`: `true` si le vaisseau a explosé et que la fin de partie peut s'afficher
+    pub fn explosion_finished(&self) -> bool {
+        self.dying && self.death_timer <= 0.0
+    }
+
+    /// Fonction qui permet de savoir si le vaisseau peut être touché
+    /// # Returns
+    /// - `bool`: `false` pendant l'invincibilité et pendant l'explosion
+    pub fn can_be_hit(&self) -> bool {
+        !self.invincible && !self.dying
+    }
+
+    /// Ajoute une impulsion à la vitesse du vaisseau, sans dépasser `max_speed`
+    /// # Arguments
+    /// - `&mut self`: instance mutable du vaisseau afin de changer sa vitesse
+    /// - `impulse`: la vitesse ajoutée
+    pub fn apply_impulse(&mut self, impulse: Vec2) {
+        self.velocity = (self.velocity + impulse).clamp_length_max(self.max_speed);
+    }
+
+    /// Ralentit le vaisseau quand il ne pousse pas : il perd une fraction `DRAG` de sa vitesse,
+    /// sans jamais repartir en arrière, et s'arrête une fois presque immobile.
+    /// # Arguments
+    /// - `&mut self`: instance mutable du vaisseau afin de changer sa vitesse
+    pub fn apply_drag(&mut self) {
+        self.velocity *= 1.0 - DRAG;
+        if self.velocity.length() < STOP_SPEED {
+            self.velocity = Vec2::ZERO;
+        }
+    }
+
+    /// Retient la poussée appliquée depuis la dernière mise à jour pour afficher la flamme,
+    /// puis l'oublie : le vaisseau ne pousse que tant que le joueur garde la touche enfoncée.
+    /// # Arguments
+    /// - `&mut self`: instance mutable du vaisseau
+    pub fn update_thrust(&mut self) {
+        self.flame_frames = if self.thrusting != 0 && self.thrusting == self.exhaust {
+            self.flame_frames + 1
+        } else {
+            0
+        };
+        self.exhaust = self.thrusting;
+        self.thrusting = 0;
+    }
+
+    /// Tire un missile depuis le nez du vaisseau, dans sa direction, si l'arme le permet.
+    /// Le missile garde la vitesse du vaisseau, sauf si `inherit_velocity` est désactivé.
+    /// Pendant le tir rapide, l'arme se recharge deux fois plus vite.
+    /// Pendant le tir triple, chaque tir est une salve de trois missiles en éventail.
+    /// Pendant l'effet de tête chercheuse, les missiles tirés se tournent vers les asteroides.
+    /// Les améliorations réduisent le temps de recharge et accélèrent les missiles,
+    /// sans changer la vitesse gardée du vaisseau.
+    /// # Arguments
+    /// - `&mut self`: instance mutable du vaisseau afin de recharger son arme
+    /// - `missiles`: contient tous les missiles du jeu
+    /// # Returns
+    /// - `bool`: `true` si un missile ou une salve a été tiré
+    pub fn fire(&mut self, missiles: &mut Pool<Missile>) -> bool {
+        let cooldown = if self.active_effects.is_active(PowerUpKind::RapidFire) {
+            FIRE_COOLDOWN * RAPID_FIRE_FACTOR
+        } else {
+            FIRE_COOLDOWN
+        } * self.upgrades.fire_cooldown;
+        let triple = triple_shot_angles(self.rotation);
+        let rotations = if self.active_effects.is_active(PowerUpKind::TripleShot) {
+            &triple[..]
+        } else {
+            &triple[..1]
+        };
+        let base_velocity = if self.inherit_velocity {
+            self.velocity
+        } else {
+            Vec2::ZERO
+        };
+        let first = missiles.len();
+        let fired =
+            self.weapon
+                .try_fire_volley(self.nose(), rotations, base_velocity, missiles, cooldown);
+        let homing = self.active_effects.is_active(PowerUpKind::Homing);
+        for missile in &mut missiles[first..] {
+            missile.homing = homing;
+            missile.player = self.player;
+            missile.velocity =
+                base_velocity + (missile.velocity - base_velocity) * self.upgrades.missile_speed;
+        }
+        fired
+    }
+
+    /// Fonction qui renvoie la position du nez du vaisseau, d'où partent les éclairs de tir
+    /// # Returns
+    /// - `Vec2`: la position du nez du vaisseau
+    pub fn nose(&self) -> Vec2 {
+        self.position + Vec2::from_angle(self.rotation) * self.radius
+    }
+
+    /// Fonction qui renvoie l'angle dont le vaisseau tourne à chaque image avec les touches,
+    /// accéléré par les améliorations
+    /// # Returns
+    /// - `f32`: l'angle de rotation, en radians
+    pub fn turn_rate(&self) -> f32 {
+        TURN_RATE * self.upgrades.turn_rate
     }
 
     /// Tourne le vaisseau d'un angle donné.
@@ -135,20 +554,74 @@ impl Spaceship {
         self.rotation += angle;
     }
 
+    /// Tourne le vaisseau vers une cible, sans dépasser un angle maximal, par le plus court chemin.
+    /// # Arguments
+    /// - `&mut self`: instance mutable du vaisseau afin de changer son angle de rotation
+    /// - `target`: la position visée
+    /// - `max_angle`: l'angle maximal de rotation
+    pub fn turn_towards(&mut self, target: Vec2, max_angle: f32) {
+        self.rotate(self.turn_angle_towards(target, max_angle));
+    }
+
+    /// Fonction qui renvoie l'angle dont `turn_towards` tournerait le vaisseau
+    /// # Arguments
+    /// - `target`: la position visée
+    /// - `max_angle`: l'angle maximal de rotation
+    /// # Returns
+    /// - `f32`: l'angle de rotation, nul si la cible est sur le vaisseau
+    pub fn turn_angle_towards(&self, target: Vec2, max_angle: f32) -> f32 {
+        let offset = target - self.position;
+        if offset == Vec2::ZERO {
+            return 0.0;
+        }
+        let wanted = offset.y.atan2(offset.x);
+        let difference = (wanted - self.rotation + PI).rem_euclid(2.0 * PI) - PI;
+        difference.clamp(-max_angle, max_angle)
+    }
+
     /// Gère la transition du vaisseau autour de l'écran.
     /// # Arguments
     /// - `position`: un vecteur correspond à la position du vaisseau en x et y
+    /// - `bounds`: la taille de l'écran
     /// # Returns
     /// - `Vec2`: un vecteur contenant x et y correspondant à la nouvelle position du vaisseau
-    fn wrap_around_screen(position: Vec2) -> Vec2 {
+    fn wrap_around_screen(position: Vec2, bounds: Vec2) -> Vec2 {
         vec2(
-            (position.x + screen_width()) % screen_width(),
-            (position.y + screen_height()) % screen_height(),
+            (position.x + bounds.x) % bounds.x,
+            (position.y + bounds.y) % bounds.y,
         )
     }
 }
 
-use crate::stellarobject::StellarObject;
+/// Fonction qui renvoie la teinte du bouclier selon ses charges :
+/// bleu quand il est plein, puis jaune, puis rouge pour la dernière charge
+/// # Arguments
+/// - `charges`: le nombre de charges du bouclier
+/// # Returns
+/// - `Color`: la teinte de la texture du bouclier
+pub fn shield_tint(charges: u32) -> Color {
+    match charges {
+        0 | 1 => Color::new(1.0, 0.35, 0.3, 1.0),
+        2 => Color::new(1.0, 0.9, 0.3, 1.0),
+        _ => Color::new(0.5, 0.8, 1.0, 1.0),
+    }
+}
+
+/// Fonction qui renvoie la position de départ du vaisseau d'un joueur
+/// # Arguments
+/// - `player`: le numéro du joueur, 0 pour le premier joueur
+/// - `bounds`: la taille de l'aire de jeu
+/// # Returns
+/// - `Vec2`: le centre de l'aire de jeu pour le premier joueur, un peu plus bas pour le second
+pub fn spawn_position(player: usize, bounds: Vec2) -> Vec2 {
+    if player == 0 {
+        bounds / 2.0
+    } else {
+        bounds / 2.0 + SECOND_PLAYER_OFFSET
+    }
+}
+
+use crate::stellarobject::{debug_field, format_vec2, wrap_offsets, StellarObject};
 
 impl StellarObject for Spaceship {
     /// Retourne la position de l'objet.
@@ -160,34 +633,248 @@ impl StellarObject for Spaceship {
         self.position
     }
 
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
     /// Met a jour la position de l'objet.
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
-    fn move_obj(&mut self) {
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
         self.position += self.velocity;
-        self.position = Self::wrap_around_screen(self.position);
+        self.position = Self::wrap_around_screen(self.position, bounds);
     }
 
     /// Retourne le rayon de l'objet.
     /// # Arguments
     /// - `&self`: une instance de l'objet stellaire
     /// # Returns
-    /// - `f32`: le rayon de l'objet stellaire
+    /// - `f32`: le rayon de collision, plus petit que la texture carrée du vaisseau
     fn radius(&self) -> f32 {
-        self.radius
+        self.radius * SHIP_COLLISION_FACTOR
     }
 
     /// Gere la collision avec un autre objet.
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
     fn handle_collision(&mut self) {
-        if self.shield {
-            self.shield = false;
+        self.shield_regen_timer = self.shield_regen_time;
+        if self.has_shield() {
+            self.shield_charges -= 1;
             self.invincible = true;
-            self.invincibility_timer = 2.0;
+            self.invincibility_timer = self.invincibility_time;
             self.hit = true;
         } else {
             self.active = false;
         }
     }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif, `false` une fois détruit sans bouclier
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état du vaisseau pour l'inspecteur de développement.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec<(String, String)>`: la liste des champs, avec leur nom et leur valeur
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "Spaceship"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.velocity)),
+            debug_field("radius", format!("{:.1}", self.radius())),
+            debug_field("active", self.active),
+            debug_field("rotation", format!("{:.2}", self.rotation)),
+            debug_field("shield_charges", self.shield_charges),
+            debug_field("invincible", self.invincible),
+            debug_field(
+                "invincibility_timer",
+                format!("{:.2}", self.invincibility_timer),
+            ),
+            debug_field(
+                "shield_regen_timer",
+                format!("{:.2}", self.shield_regen_timer),
+            ),
+            debug_field("hit", self.hit),
+            debug_field("dying", self.dying),
+            debug_field("exhaust", self.exhaust),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::dummy_texture;
+    use crate::world::MISSILE_POOL_SIZE;
+
+    /// Crée un vaisseau immobile au centre de l'aire de jeu, sans fenêtre.
+    fn test_spaceship() -> Spaceship {
+        Spaceship::with_textures(
+            vec2(400.0, 300.0),
+            SpaceshipTextures {
+                ship: dummy_texture(),
+                shield_on: dummy_texture(),
+                shield_off: dummy_texture(),
+                shield_dead: dummy_texture(),
+            },
+        )
+    }
+
+    /// Vérifie que la vitesse ne dépasse jamais la vitesse maximale, même en poussant longtemps.
+    #[test]
+    fn test_speed_is_capped() {
+        let mut spaceship = test_spaceship();
+        for _ in 0..2000 {
+            spaceship.apply_thrust(0.01);
+            assert!(spaceship.velocity.length() <= MAX_SPEED + 1e-4);
+        }
+        assert!((spaceship.velocity.length() - MAX_SPEED).abs() < 1e-4);
+    }
+
+    /// Vérifie qu'un vaisseau qui dérive ralentit sans jamais repartir en arrière, puis s'arrête.
+    #[test]
+    fn test_drifting_ship_stops_without_oscillating() {
+        let mut spaceship = test_spaceship();
+        spaceship.velocity = vec2(3.0, -1.0);
+        let direction = spaceship.velocity.normalize();
+
+        let mut previous = spaceship.velocity.length();
+        for _ in 0..1000 {
+            spaceship.apply_drag();
+            let speed = spaceship.velocity.length();
+            assert!(speed <= previous, "Le vaisseau ne doit pas accélérer !");
+            assert!(
+                spaceship.velocity.dot(direction) >= 0.0,
+                "Le vaisseau ne doit pas repartir en arrière !"
+            );
+            previous = speed;
+        }
+        assert_eq!(spaceship.velocity, Vec2::ZERO);
+    }
+
+    /// Vérifie que le missile part du nez du vaisseau et garde sa vitesse,
+    /// sauf quand les missiles d'arcade sont demandés.
+    #[test]
+    fn test_missile_inherits_ship_velocity() {
+        let mut spaceship = test_spaceship();
+        spaceship.velocity = vec2(2.0, 1.0);
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+
+        assert!(spaceship.fire(&mut missiles));
+        assert_eq!(missiles[0].position, spaceship.nose());
+        let own_velocity = Missile::new(Vec2::ZERO, spaceship.rotation).velocity;
+        assert_eq!(missiles[0].velocity, own_velocity + spaceship.velocity);
+
+        spaceship.inherit_velocity = false;
+        spaceship.weapon.update(FIRE_COOLDOWN);
+        assert!(spaceship.fire(&mut missiles));
+        assert_eq!(missiles[1].velocity, own_velocity);
+    }
+
+    /// Vérifie que chaque amélioration change le comportement du vaisseau :
+    /// missiles plus rapides, recharge plus courte et rotation plus rapide.
+    #[test]
+    fn test_upgrades_change_behaviour() {
+        use crate::upgrade::{
+            UpgradeKind, FIRE_COOLDOWN_REDUCTION, MISSILE_SPEED_BONUS, TURN_RATE_BONUS,
+        };
+        let mut spaceship = test_spaceship();
+        spaceship.velocity = vec2(2.0, 1.0);
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+        let own_speed = Missile::new(Vec2::ZERO, 0.0).velocity.length();
+
+        spaceship.upgrades.apply(UpgradeKind::MissileSpeed);
+        assert!(spaceship.fire(&mut missiles));
+        let speed = (missiles[0].velocity - spaceship.velocity).length();
+        assert!((speed - own_speed * (1.0 + MISSILE_SPEED_BONUS)).abs() < 1e-4);
+
+        spaceship.upgrades.apply(UpgradeKind::FireRate);
+        spaceship.weapon.update(FIRE_COOLDOWN);
+        assert!(spaceship.fire(&mut missiles));
+        spaceship
+            .weapon
+            .update(FIRE_COOLDOWN * (1.0 - FIRE_COOLDOWN_REDUCTION) + 1e-4);
+        assert!(
+            spaceship.fire(&mut missiles),
+            "La recharge doit être plus courte !"
+        );
+
+        assert_eq!(spaceship.turn_rate(), TURN_RATE);
+        spaceship.upgrades.apply(UpgradeKind::TurnRate);
+        assert!((spaceship.turn_rate() - TURN_RATE * (1.0 + TURN_RATE_BONUS)).abs() < 1e-6);
+    }
+
+    /// Vérifie la recharge du bouclier : vidé, une charge revient après
+    /// `shield_regen_time` secondes sans être touché, et un choc pendant la recharge
+    /// la fait recommencer.
+    #[test]
+    fn test_shield_regenerates_after_quiet_time() {
+        let mut spaceship = test_spaceship();
+        spaceship.shield_charges = 1;
+        assert_eq!(spaceship.shield_regen_progress(), None);
+        assert!(!spaceship.update_shield_regen(SHIELD_REGEN_TIME));
+
+        spaceship.handle_collision();
+        assert!(!spaceship.has_shield() && spaceship.active);
+        assert!(!spaceship.update_shield_regen(SHIELD_REGEN_TIME / 2.0));
+        assert_eq!(spaceship.shield_regen_progress(), Some(0.5));
+
+        spaceship.invincible = false;
+        spaceship.shield_charges = 1;
+        spaceship.handle_collision();
+        assert_eq!(spaceship.shield_regen_progress(), Some(0.0));
+        assert!(!spaceship.update_shield_regen(SHIELD_REGEN_TIME - 0.1));
+        assert!(spaceship.update_shield_regen(0.2));
+        assert_eq!(spaceship.shield_charges, 1);
+        assert_eq!(spaceship.shield_regen_timer, SHIELD_REGEN_TIME);
+
+        spaceship.handle_collision();
+        assert!(
+            !spaceship.has_shield() && spaceship.active,
+            "Le bouclier rechargé protège !"
+        );
+    }
+
+    /// Vérifie que le bouclier encaisse `MAX_SHIELD_CHARGES` chocs, chacun suivi
+    /// de l'invincibilité, et que le quatrième choc détruit le vaisseau.
+    #[test]
+    fn test_four_consecutive_hits() {
+        let mut spaceship = test_spaceship();
+        assert_eq!(spaceship.shield_charges, MAX_SHIELD_CHARGES);
+
+        for charges in (0..MAX_SHIELD_CHARGES).rev() {
+            spaceship.handle_collision();
+            assert_eq!(spaceship.shield_charges, charges);
+            assert!(spaceship.active);
+            assert!(spaceship.invincible);
+            assert_eq!(spaceship.invincibility_timer, HIT_INVINCIBILITY);
+            spaceship.update_timers(HIT_INVINCIBILITY);
+            assert!(!spaceship.invincible);
+        }
+        assert!(!spaceship.has_shield());
+
+        spaceship.handle_collision();
+        assert!(!spaceship.active, "Le quatrième choc détruit le vaisseau !");
+    }
 }
@@ -0,0 +1,187 @@
+//! Module pour la grille qui accélère la recherche des collisions.
+//! Les objets sont rangés dans des cases au moins aussi grandes que la plus grande distance
+//! de collision : deux objets qui se touchent sont alors dans la même case ou dans deux cases
+//! voisines, en tenant compte des bords de l'écran qui bouclent. Seules ces paires sont ensuite
+//! testées par `check_collision_between`.
+use crate::stellarobject::{wrapped_distance, StellarObject};
+use macroquad::prelude::*;
+
+/// Structure représentant une grille uniforme sur l'aire de jeu
+/// # Champs
+/// - `cell`: la taille d'une case, en pixels
+/// - `cols`: le nombre de colonnes
+/// - `rows`: le nombre de lignes
+/// - `cells`: les indices des objets rangés dans chaque case, ligne par ligne
+pub struct SpatialGrid {
+    cell: Vec2,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl SpatialGrid {
+    /// Range des objets dans une grille
+    /// # Arguments
+    /// - `positions`: la position des objets, dont l'indice est gardé dans la grille
+    /// - `bounds`: la taille de l'aire de jeu
+    /// - `min_cell`: la taille minimale d'une case, au moins la plus grande distance de collision
+    /// # Returns
+    /// - `Self`: la grille remplie
+    pub fn build(positions: impl IntoIterator<Item = Vec2>, bounds: Vec2, min_cell: f32) -> Self {
+        let min_cell = min_cell.max(1.0);
+        // Les cases remplissent exactement l'aire de jeu, pour que les voisins bouclent sur les bords
+        let cols = ((bounds.x / min_cell).floor() as usize).max(1);
+        let rows = ((bounds.y / min_cell).floor() as usize).max(1);
+        let mut grid = Self {
+            cell: vec2(bounds.x / cols as f32, bounds.y / rows as f32),
+            cols,
+            rows,
+            cells: vec![Vec::new(); cols * rows],
+        };
+        for (index, position) in positions.into_iter().enumerate() {
+            let (col, row) = grid.cell_of(position);
+            grid.cells[row * cols + col].push(index);
+        }
+        grid
+    }
+
+    /// Fonction qui renvoie la case d'une position
+    /// # Arguments
+    /// - `position`: la position, éventuellement un peu en dehors de l'aire de jeu
+    /// # Returns
+    /// - `(usize, usize)`: la colonne et la ligne de la case
+    fn cell_of(&self, position: Vec2) -> (usize, usize) {
+        let wrap = |value: f32, size: f32, count: usize| {
+            ((value / size).floor() as i64).rem_euclid(count as i64) as usize
+        };
+        (
+            wrap(position.x, self.cell.x, self.cols),
+            wrap(position.y, self.cell.y, self.rows),
+        )
+    }
+
+    /// Fonction qui renvoie les objets de la case d'une position et des huit cases voisines,
+    /// en passant par les bords de l'écran
+    /// # Arguments
+    /// - `position`: la position autour de laquelle chercher
+    /// # Returns
+    /// - `Vec<usize>`: les indices des objets, chacun une seule fois, triés par ordre croissant
+    pub fn candidates(&self, position: Vec2) -> Vec<usize> {
+        let (col, row) = self.cell_of(position);
+        let neighbours = |center: usize, count: usize| {
+            let mut list: Vec<usize> = [count - 1, 0, 1]
+                .iter()
+                .map(|delta| (center + delta) % count)
+                .collect();
+            // Sur une grille de moins de trois cases, les voisins se répètent
+            list.sort_unstable();
+            list.dedup();
+            list
+        };
+        let mut found = Vec::new();
+        for r in neighbours(row, self.rows) {
+            for c in neighbours(col, self.cols) {
+                found.extend_from_slice(&self.cells[r * self.cols + c]);
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+}
+
+/// Fonction qui renvoie le plus grand rayon d'un groupe d'objets
+/// # Arguments
+/// - `objects`: les objets
+/// # Returns
+/// - `f32`: le plus grand rayon, ou 0 si le groupe est vide
+pub fn largest_radius<T: StellarObject>(objects: &[T]) -> f32 {
+    objects.iter().map(|o| o.radius()).fold(0.0, f32::max)
+}
+
+/// Fonction qui trouve toutes les paires d'objets qui se chevauchent entre deux groupes,
+/// en ne testant que les paires proches dans la grille
+/// # Arguments
+/// - `first`: la position et le rayon des objets du premier groupe
+/// - `second`: la position et le rayon des objets du second groupe
+/// - `bounds`: la taille de l'aire de jeu
+/// # Returns
+/// - `Vec<(usize, usize)>`: les indices des paires qui se chevauchent, triées
+pub fn overlapping_pairs(
+    first: &[(Vec2, f32)],
+    second: &[(Vec2, f32)],
+    bounds: Vec2,
+) -> Vec<(usize, usize)> {
+    let largest = |objects: &[(Vec2, f32)]| objects.iter().map(|(_, r)| *r).fold(0.0, f32::max);
+    let grid = SpatialGrid::build(
+        second.iter().map(|(pos, _)| *pos),
+        bounds,
+        largest(first) + largest(second),
+    );
+    let mut pairs = Vec::new();
+    for (i, (pos, radius)) in first.iter().enumerate() {
+        for j in grid.candidates(*pos) {
+            let (other, other_radius) = second[j];
+            if wrapped_distance(*pos, other, bounds) < radius + other_radius {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::StdRng;
+    use ::rand::{Rng, SeedableRng};
+
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Crée des objets au hasard, toujours les mêmes pour une même graine
+    fn random_objects(rng: &mut StdRng, count: usize, radii: &[f32]) -> Vec<(Vec2, f32)> {
+        (0..count)
+            .map(|i| {
+                let pos = vec2(rng.gen_range(0.0..BOUNDS.x), rng.gen_range(0.0..BOUNDS.y));
+                (pos, radii[i % radii.len()])
+            })
+            .collect()
+    }
+
+    /// Vérifie que la grille trouve exactement les mêmes paires que le test de toutes les paires,
+    /// y compris les paires qui se touchent à travers un bord de l'écran.
+    #[test]
+    fn test_grid_finds_same_pairs_as_brute_force() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let asteroids = random_objects(&mut rng, 200, &[40.0, 28.0, 17.0]);
+        let missiles = random_objects(&mut rng, 100, &[5.0]);
+        let mut brute_force = Vec::new();
+        for (i, (a, ra)) in asteroids.iter().enumerate() {
+            for (j, (m, rm)) in missiles.iter().enumerate() {
+                if wrapped_distance(*a, *m, BOUNDS) < ra + rm {
+                    brute_force.push((i, j));
+                }
+            }
+        }
+
+        assert!(!brute_force.is_empty());
+        assert_eq!(
+            overlapping_pairs(&asteroids, &missiles, BOUNDS),
+            brute_force
+        );
+
+        let across_edge = overlapping_pairs(
+            &[(vec2(2.0, 300.0), 10.0)],
+            &[(vec2(795.0, 300.0), 5.0)],
+            BOUNDS,
+        );
+        assert_eq!(across_edge, vec![(0, 0)]);
+    }
+
+    /// Vérifie qu'une grille plus petite que trois cases ne renvoie pas deux fois le même objet.
+    #[test]
+    fn test_small_grid_has_no_duplicates() {
+        let grid = SpatialGrid::build([vec2(10.0, 10.0), vec2(700.0, 500.0)], BOUNDS, 500.0);
+
+        assert_eq!(grid.candidates(vec2(400.0, 300.0)), vec![0, 1]);
+    }
+}
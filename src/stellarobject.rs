@@ -10,10 +10,26 @@ pub trait StellarObject {
     /// - `Vec2`: un vecteur avec la position x et y de l'objet stellaire
     fn get_pos(&self) -> Vec2;
 
+    /// Retourne la vitesse de l'objet, nulle par défaut pour un objet immobile.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        Vec2::ZERO
+    }
+
+    /// Change la vitesse de l'objet. Un objet immobile l'ignore.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, _velocity: Vec2) {}
+
     /// Met a jour la position de l'objet.
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
-    fn move_obj(&mut self);
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2);
 
     /// Retourne le rayon de l'objet.
     /// # Arguments
@@ -26,4 +42,167 @@ pub trait StellarObject {
     /// # Arguments
     /// - `&mut self`: une instance de l'objet stellaire
     fn handle_collision(&mut self);
+
+    /// Permet de savoir si l'objet est toujours dans la partie : un objet inactif
+    /// est retiré de sa liste à la fin de l'image. Un objet est actif par défaut.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        true
+    }
+
+    /// Retourne l'état de l'objet sous forme de champs lisibles, pour l'inspecteur de développement.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec<(String, String)>`: la liste des champs, avec leur nom et leur valeur
+    fn debug_fields(&self) -> Vec<(String, String)>;
+
+    /// Dessine le cercle de collision de l'objet, pour vérifier qu'il correspond à sa texture.
+    /// Le cercle est tiré de `get_pos` et `radius` : tout nouvel objet l'obtient sans rien écrire.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// - `color`: la couleur du cercle
+    fn debug_draw(&self, color: Color) {
+        let position = self.get_pos();
+        draw_circle_lines(position.x, position.y, self.radius(), 1.0, color);
+    }
+}
+
+/// Construit un champ de l'inspecteur
+/// # Arguments
+/// - `name`: le nom du champ
+/// - `value`: la valeur du champ
+/// # Returns
+/// - `(String, String)`: le nom et la valeur mis en forme
+pub fn debug_field(name: &str, value: impl std::fmt::Display) -> (String, String) {
+    (name.to_string(), value.to_string())
+}
+
+/// Met en forme un vecteur pour l'inspecteur
+/// # Arguments
+/// - `vector`: le vecteur
+/// # Returns
+/// - `String`: le vecteur avec une décimale
+pub fn format_vec2(vector: Vec2) -> String {
+    format!("({:.1}, {:.1})", vector.x, vector.y)
+}
+
+/// Calcule la distance entre deux points sur un écran qui boucle sur ses bords
+/// # Arguments
+/// - `a`: le premier point
+/// - `b`: le second point
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `f32`: la plus courte distance entre les deux points, en passant éventuellement par les bords
+pub fn wrapped_distance(a: Vec2, b: Vec2, bounds: Vec2) -> f32 {
+    let dx = (a.x - b.x).rem_euclid(bounds.x);
+    let dy = (a.y - b.y).rem_euclid(bounds.y);
+    vec2(dx.min(bounds.x - dx), dy.min(bounds.y - dy)).length()
+}
+
+/// Calcule le plus court déplacement d'un point vers un autre sur un écran qui boucle sur ses bords
+/// # Arguments
+/// - `from`: le point de départ
+/// - `to`: le point d'arrivée
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec2`: le déplacement, qui passe par un bord quand c'est plus court
+pub fn wrapped_offset(from: Vec2, to: Vec2, bounds: Vec2) -> Vec2 {
+    let shortest = |delta: f32, size: f32| {
+        let delta = delta.rem_euclid(size);
+        if delta > size / 2.0 {
+            delta - size
+        } else {
+            delta
+        }
+    };
+    vec2(
+        shortest(to.x - from.x, bounds.x),
+        shortest(to.y - from.y, bounds.y),
+    )
+}
+
+/// Calcule les décalages auxquels dessiner un objet qui chevauche un bord de l'écran,
+/// pour qu'il apparaisse aussi de l'autre côté.
+/// # Arguments
+/// - `position`: la position de l'objet
+/// - `radius`: le rayon du cercle qui contient l'image de l'objet
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<Vec2>`: les décalages, en commençant toujours par le décalage nul (jusqu'à quatre copies)
+pub fn wrap_offsets(position: Vec2, radius: f32, bounds: Vec2) -> Vec<Vec2> {
+    let mut xs = vec![0.0];
+    if position.x - radius < 0.0 {
+        xs.push(bounds.x);
+    } else if position.x + radius > bounds.x {
+        xs.push(-bounds.x);
+    }
+    let mut ys = vec![0.0];
+    if position.y - radius < 0.0 {
+        ys.push(bounds.y);
+    } else if position.y + radius > bounds.y {
+        ys.push(-bounds.y);
+    }
+
+    ys.iter()
+        .flat_map(|y| xs.iter().map(move |x| vec2(*x, *y)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que la distance passe par les bords de l'écran quand c'est plus court.
+    #[test]
+    fn test_wrapped_distance() {
+        let bounds = vec2(800.0, 600.0);
+
+        assert_eq!(
+            wrapped_distance(vec2(100.0, 100.0), vec2(130.0, 140.0), bounds),
+            50.0
+        );
+        assert_eq!(
+            wrapped_distance(vec2(10.0, 300.0), vec2(790.0, 300.0), bounds),
+            20.0
+        );
+        assert_eq!(
+            wrapped_distance(vec2(400.0, 5.0), vec2(400.0, 595.0), bounds),
+            10.0
+        );
+    }
+
+    /// Vérifie qu'un objet entièrement dans l'écran n'est dessiné qu'une fois.
+    #[test]
+    fn test_wrap_offsets_inside_screen() {
+        let bounds = vec2(800.0, 600.0);
+
+        assert_eq!(
+            wrap_offsets(vec2(400.0, 300.0), 50.0, bounds),
+            vec![Vec2::ZERO]
+        );
+        assert_eq!(wrap_offsets(vec2(2.0, 2.0), 2.0, bounds), vec![Vec2::ZERO]);
+    }
+
+    /// Vérifie les copies d'un objet qui chevauche un bord ou un coin de l'écran.
+    #[test]
+    fn test_wrap_offsets_on_edges() {
+        let bounds = vec2(800.0, 600.0);
+
+        assert_eq!(
+            wrap_offsets(vec2(790.0, 300.0), 20.0, bounds),
+            vec![Vec2::ZERO, vec2(-800.0, 0.0)]
+        );
+        assert_eq!(
+            wrap_offsets(vec2(400.0, 10.0), 20.0, bounds),
+            vec![Vec2::ZERO, vec2(0.0, 600.0)]
+        );
+
+        let corner = wrap_offsets(vec2(10.0, 590.0), 20.0, bounds);
+        assert_eq!(corner.len(), 4);
+        assert!(corner.contains(&vec2(800.0, -600.0)));
+    }
 }
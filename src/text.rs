@@ -0,0 +1,267 @@
+//! Module pour dessiner les textes du jeu avec les polices chargées.
+//! Si une police n'a pas pu être chargée, la police intégrée de macroquad est utilisée à la place.
+use crate::assets::load_asset;
+use crate::playfield::{playfield_factor, REFERENCE_RESOLUTION};
+use macroquad::prelude::*;
+
+/// Nom de la police normale, dans le dossier des ressources
+const REGULAR_FONT: &str = "fonts/DejaVuSans.ttf";
+/// Nom de la police grasse, utilisée pour les titres
+const BOLD_FONT: &str = "fonts/DejaVuSans-Bold.ttf";
+/// Échelle minimale du tableau de bord, pour que son texte reste lisible dans une toute petite fenêtre
+const MIN_HUD_SCALE: f32 = 0.5;
+
+/// Énumération représentant les styles de texte de l'interface
+/// # Variantes
+/// - `Title`: les titres des écrans
+/// - `Heading`: les boutons et les textes importants
+/// - `Body`: les textes courants, comme le tableau de bord ou les détails
+/// - `Popup`: les petits textes temporaires qui apparaissent pendant la partie
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TextStyle {
+    Title,
+    Heading,
+    Body,
+    Popup,
+}
+
+impl TextStyle {
+    /// Fonction qui renvoie la taille de police du style
+    /// # Arguments
+    /// - `ui_scale`: l'échelle de l'interface
+    /// # Returns
+    /// - `f32`: la taille de la police
+    pub fn size(self, ui_scale: f32) -> f32 {
+        let base = match self {
+            TextStyle::Title => 40.0,
+            TextStyle::Heading => 30.0,
+            TextStyle::Body => 22.0,
+            TextStyle::Popup => 20.0,
+        };
+        (base * ui_scale).round()
+    }
+
+    /// Fonction qui indique si le style utilise la police grasse
+    /// # Returns
+    /// - `bool`: `true` pour les titres
+    pub fn is_bold(self) -> bool {
+        matches!(self, TextStyle::Title | TextStyle::Heading)
+    }
+}
+
+/// Fonction qui calcule l'échelle de l'interface à partir de la hauteur de la fenêtre
+/// # Arguments
+/// - `screen_height`: la hauteur de la fenêtre
+/// # Returns
+/// - `f32`: l'échelle, bornée pour que le texte reste lisible et tienne dans les boutons
+pub fn ui_scale(screen_height: f32) -> f32 {
+    (screen_height / REFERENCE_RESOLUTION.y).clamp(0.75, 1.25)
+}
+
+/// Fonction qui calcule l'échelle du tableau de bord, celle de l'aire de jeu.
+/// Contrairement aux menus, dont les boutons ont une taille fixe, le tableau de bord
+/// grandit avec la fenêtre pour garder la même place par rapport aux objets.
+/// # Arguments
+/// - `screen`: la taille de la fenêtre
+/// # Returns
+/// - `f32`: l'échelle, au moins `MIN_HUD_SCALE`
+pub fn hud_scale(screen: Vec2) -> f32 {
+    playfield_factor(screen).max(MIN_HUD_SCALE)
+}
+
+/// Structure regroupant les polices du jeu
+/// # Champs
+/// - `regular`: la police normale, ou `None` si elle n'a pas pu être chargée
+/// - `bold`: la police grasse, ou `None` si elle n'a pas pu être chargée
+/// - `scale`: l'échelle imposée aux textes, sinon celle de l'interface
+#[derive(Clone, Default)]
+pub struct Fonts {
+    regular: Option<Font>,
+    bold: Option<Font>,
+    scale: Option<f32>,
+}
+
+impl Fonts {
+    /// Charge les polices du jeu.
+    /// Une police absente n'est pas une erreur, elle sera remplacée par la police intégrée.
+    /// # Returns
+    /// - `Self`: les polices chargées
+    pub async fn load() -> Self {
+        Self {
+            regular: load_optional_font(REGULAR_FONT).await,
+            bold: load_optional_font(BOLD_FONT).await,
+            scale: None,
+        }
+    }
+
+    /// Crée une copie des polices qui dessine tous les textes à une échelle donnée
+    /// # Arguments
+    /// - `scale`: l'échelle des textes, à la place de celle de l'interface
+    /// # Returns
+    /// - `Self`: les polices à l'échelle
+    pub fn with_scale(&self, scale: f32) -> Self {
+        Self {
+            scale: Some(scale),
+            ..self.clone()
+        }
+    }
+
+    /// Fonction qui renvoie l'échelle des textes
+    /// # Returns
+    /// - `f32`: l'échelle imposée, ou celle de l'interface pour la fenêtre actuelle
+    pub fn scale(&self) -> f32 {
+        self.scale.unwrap_or_else(|| ui_scale(screen_height()))
+    }
+
+    /// Fonction qui renvoie la police à utiliser pour un style.
+    /// Les titres se rabattent sur la police normale si la police grasse manque.
+    /// # Arguments
+    /// - `style`: le style du texte
+    /// # Returns
+    /// - `Option<&Font>`: la police, ou `None` pour utiliser la police intégrée
+    pub fn font_for(&self, style: TextStyle) -> Option<&Font> {
+        if style.is_bold() {
+            self.bold.as_ref().or(self.regular.as_ref())
+        } else {
+            self.regular.as_ref()
+        }
+    }
+
+    /// Mesure un texte avec les mêmes paramètres que ceux utilisés pour le dessiner
+    /// # Arguments
+    /// - `text`: le texte
+    /// - `style`: le style du texte
+    /// # Returns
+    /// - `TextDimensions`: les dimensions du texte
+    pub fn measure(&self, text: &str, style: TextStyle) -> TextDimensions {
+        let size = style.size(self.scale());
+        measure_text(text, self.font_for(style), size as u16, 1.0)
+    }
+
+    /// Dessine un texte à la position donnée
+    /// # Arguments
+    /// - `text`: le texte
+    /// - `x`: la position horizontale du début du texte
+    /// - `y`: la position verticale de la ligne de base
+    /// - `style`: le style du texte
+    /// - `color`: la couleur du texte
+    pub fn draw(&self, text: &str, x: f32, y: f32, style: TextStyle, color: Color) {
+        self.draw_scaled(text, x, y, style, 1.0, color);
+    }
+
+    /// Dessine un texte agrandi ou réduit par rapport à la taille de son style
+    /// # Arguments
+    /// - `text`: le texte
+    /// - `x`: la position horizontale du début du texte
+    /// - `y`: la position verticale de la ligne de base
+    /// - `style`: le style du texte
+    /// - `scale`: le multiplicateur de la taille du style
+    /// - `color`: la couleur du texte
+    pub fn draw_scaled(
+        &self,
+        text: &str,
+        x: f32,
+        y: f32,
+        style: TextStyle,
+        scale: f32,
+        color: Color,
+    ) {
+        let size = style.size(self.scale() * scale);
+        draw_text_ex(
+            text,
+            x,
+            y,
+            TextParams {
+                font: self.font_for(style),
+                font_size: size as u16,
+                font_scale: 1.0,
+                color,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Dessine un texte centré horizontalement dans la fenêtre
+    /// # Arguments
+    /// - `text`: le texte
+    /// - `y`: la position verticale de la ligne de base
+    /// - `style`: le style du texte
+    /// - `color`: la couleur du texte
+    pub fn draw_centered(&self, text: &str, y: f32, style: TextStyle, color: Color) {
+        let text_width = self.measure(text, style).width;
+        self.draw(text, (screen_width() - text_width) / 2.0, y, style, color);
+    }
+}
+
+/// Charge une police optionnelle, en affichant l'erreur si elle n'a pas pu être chargée.
+/// # Arguments
+/// - `name`: le nom de la police dans le dossier des ressources
+/// # Returns
+/// - `Option<Font>`: la police chargée, ou `None` en cas d'échec
+async fn load_optional_font(name: &str) -> Option<Font> {
+    let font = match load_asset(name).await {
+        Ok(bytes) => load_ttf_font_from_bytes(&bytes),
+        Err(err) => Err(err),
+    };
+    match font {
+        Ok(font) => Some(font),
+        Err(err) => {
+            log::warn!(
+                "Police manquante : {name}, remplacée par la police intégrée ({:?})",
+                err
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie que sans police chargée, tous les styles utilisent la police intégrée.
+    #[test]
+    fn test_missing_fonts_fall_back_to_builtin() {
+        let fonts = Fonts::default();
+        for style in [
+            TextStyle::Title,
+            TextStyle::Heading,
+            TextStyle::Body,
+            TextStyle::Popup,
+        ] {
+            assert!(
+                fonts.font_for(style).is_none(),
+                "Le style {:?} doit utiliser la police intégrée !",
+                style
+            );
+        }
+    }
+
+    /// Vérifie que l'échelle des tailles est ordonnée et suit l'échelle de l'interface.
+    #[test]
+    fn test_type_scale() {
+        assert_eq!(TextStyle::Title.size(1.0), 40.0);
+        assert_eq!(TextStyle::Heading.size(1.0), 30.0);
+        assert_eq!(TextStyle::Body.size(1.0), 22.0);
+        assert_eq!(TextStyle::Popup.size(1.0), 20.0);
+        assert_eq!(TextStyle::Title.size(1.25), 50.0);
+        assert!(TextStyle::Body.size(0.75) < TextStyle::Heading.size(0.75));
+    }
+
+    /// Vérifie que l'échelle de l'interface est bornée.
+    #[test]
+    fn test_ui_scale_is_clamped() {
+        assert_eq!(ui_scale(600.0), 1.0);
+        assert_eq!(ui_scale(100.0), 0.75);
+        assert_eq!(ui_scale(4000.0), 1.25);
+    }
+
+    /// Vérifie que le tableau de bord suit l'échelle de l'aire de jeu, sauf dans une toute petite fenêtre.
+    #[test]
+    fn test_hud_scale_follows_playfield() {
+        assert_eq!(hud_scale(REFERENCE_RESOLUTION), 1.0);
+        assert_eq!(hud_scale(vec2(3840.0, 2160.0)), 3.6);
+        assert_eq!(hud_scale(vec2(160.0, 120.0)), MIN_HUD_SCALE);
+        assert_eq!(Fonts::default().with_scale(2.0).scale(), 2.0);
+    }
+}
@@ -0,0 +1,74 @@
+//! Module pour le pas de temps fixe de la partie, indépendant de la fréquence d'affichage.
+//! Le temps de chaque image affichée s'accumule, et la partie avance d'autant de pas de
+//! `FIXED_DT` que le temps accumulé en contient : à 144 Hz certaines images n'en font aucun,
+//! à 30 Hz chaque image en fait deux. Les objets se déplacent d'un cran par pas, et tous les
+//! minuteurs avancent de `FIXED_DT` : une partie ne dépend que de sa graine et de ses commandes.
+//! Après un long blocage (chargement, fenêtre déplacée), le temps accumulé est limité à
+//! `MAX_FRAME_TIME` : la partie ralentit un instant au lieu de faire un bond.
+//! L'affichage montre l'état du dernier pas, sans interpolation entre deux pas.
+
+/// Durée (en secondes) d'un pas de la partie : les vitesses des objets sont prévues pour 60 pas par seconde
+pub const FIXED_DT: f32 = 1.0 / 60.0;
+/// Temps (en secondes) d'une image au-delà duquel le temps n'est plus rattrapé
+pub const MAX_FRAME_TIME: f32 = 0.1;
+
+/// Structure représentant le temps accumulé entre les pas de la partie
+/// # Champs
+/// - `accumulator`: le temps affiché pas encore joué, toujours inférieur à `FIXED_DT` entre deux images
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FixedTimestep {
+    accumulator: f32,
+}
+
+impl FixedTimestep {
+    /// Ajoute le temps d'une image affichée et renvoie le nombre de pas à jouer
+    /// # Arguments
+    /// - `frame_time`: le temps écoulé depuis la dernière image
+    /// # Returns
+    /// - `u32`: le nombre de pas de `FIXED_DT` à jouer pendant cette image
+    pub fn advance(&mut self, frame_time: f32) -> u32 {
+        self.accumulator += frame_time.clamp(0.0, MAX_FRAME_TIME);
+        let steps = (self.accumulator / FIXED_DT) as u32;
+        self.accumulator -= steps as f32 * FIXED_DT;
+        steps
+    }
+
+    /// Oublie le temps accumulé, quand la partie est en pause ou quitte l'écran de jeu
+    pub fn reset(&mut self) {
+        self.accumulator = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie qu'une seconde donne 60 pas quelle que soit la fréquence d'affichage,
+    /// le temps en trop étant gardé pour l'image suivante.
+    #[test]
+    fn test_steps_do_not_depend_on_refresh_rate() {
+        for hz in [30, 60, 75, 144, 240] {
+            let mut timestep = FixedTimestep::default();
+            let steps: u32 = (0..hz).map(|_| timestep.advance(1.0 / hz as f32)).sum();
+            assert!(
+                (59..=60).contains(&steps),
+                "{steps} pas en une seconde à {hz} Hz !"
+            );
+        }
+        let mut timestep = FixedTimestep::default();
+        assert_eq!(timestep.advance(FIXED_DT * 0.6), 0);
+        assert_eq!(timestep.advance(FIXED_DT * 0.6), 1);
+    }
+
+    /// Vérifie qu'un long blocage ne joue que quelques pas, et que la remise à zéro oublie le temps accumulé.
+    #[test]
+    fn test_stall_is_clamped_and_reset() {
+        let mut timestep = FixedTimestep::default();
+        assert_eq!(timestep.advance(5.0), (MAX_FRAME_TIME / FIXED_DT) as u32);
+        assert_eq!(timestep.advance(-1.0), 0);
+
+        timestep.advance(FIXED_DT * 0.9);
+        timestep.reset();
+        assert_eq!(timestep.advance(FIXED_DT * 0.5), 0);
+    }
+}
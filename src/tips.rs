@@ -0,0 +1,301 @@
+//! Module pour les astuces affichées quand le joueur répète une même erreur.
+//! Les erreurs sont repérées à partir des événements de la partie, et chaque astuce
+//! n'est plus jamais affichée une fois que le joueur l'a fermée : elle est alors
+//! enregistrée dans son profil.
+use asteroid::audio::SoundEvent;
+use asteroid::game_over::GameOverReason;
+use std::collections::{BTreeSet, VecDeque};
+
+/// Nombre de morts dans un trou noir qui déclenchent l'astuce sur les trous noirs
+const BLACK_HOLE_DEATHS: u32 = 2;
+/// Durée (en secondes) après le début d'une vague pendant laquelle perdre son bouclier est une erreur
+const EARLY_SHIELD_LOSS_TIME: f32 = 5.0;
+/// Nombre de boucliers perdus en début de vague qui déclenchent l'astuce sur le début de vague
+const EARLY_SHIELD_LOSSES: u32 = 3;
+/// Précision en dessous de laquelle l'astuce sur la visée est déclenchée
+const LOW_ACCURACY: f32 = 0.2;
+/// Nombre de vagues sur lesquelles la précision est mesurée
+const ACCURACY_WAVES: usize = 2;
+/// Nombre minimal de tirs sur ces vagues pour juger la précision
+const MIN_SHOTS: u32 = 10;
+
+/// Énumération représentant les erreurs répétées repérées pendant la session
+/// # Variantes
+/// - `BlackHoleDeaths`: le joueur meurt souvent dans un trou noir
+/// - `EarlyShieldLoss`: le joueur perd souvent son bouclier juste après le début d'une vague
+/// - `LowAccuracy`: le joueur rate presque tous ses tirs
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Mistake {
+    BlackHoleDeaths,
+    EarlyShieldLoss,
+    LowAccuracy,
+}
+
+/// Structure représentant une astuce
+/// # Champs
+/// - `id`: l'identifiant de l'astuce, enregistré dans le profil une fois fermée
+/// - `mistake`: l'erreur qui déclenche l'astuce
+/// - `key`: la clé de traduction du texte de l'astuce
+pub struct Tip {
+    pub id: &'static str,
+    pub mistake: Mistake,
+    pub key: &'static str,
+}
+
+/// Toutes les astuces du jeu, une par erreur repérée
+pub const TIPS: [Tip; 3] = [
+    Tip {
+        id: "black_hole",
+        mistake: Mistake::BlackHoleDeaths,
+        key: "tip.black_hole",
+    },
+    Tip {
+        id: "wave_start",
+        mistake: Mistake::EarlyShieldLoss,
+        key: "tip.wave_start",
+    },
+    Tip {
+        id: "accuracy",
+        mistake: Mistake::LowAccuracy,
+        key: "tip.accuracy",
+    },
+];
+
+/// Fonction qui renvoie l'astuce à afficher pour une erreur
+/// # Arguments
+/// - `mistake`: l'erreur repérée
+/// - `acknowledged`: les identifiants des astuces déjà fermées par le joueur
+/// # Returns
+/// - `Option<&Tip>`: l'astuce, ou `None` si le joueur l'a déjà fermée
+pub fn tip_for(mistake: Mistake, acknowledged: &BTreeSet<String>) -> Option<&'static Tip> {
+    TIPS.iter()
+        .find(|tip| tip.mistake == mistake && !acknowledged.contains(tip.id))
+}
+
+/// Énumération représentant les événements utiles pour repérer les erreurs
+/// # Variantes
+/// - `WaveStarted`: une vague commence
+/// - `ShotFired`: le vaisseau a tiré un missile
+/// - `Hit`: un missile a touché un ennemi
+/// - `ShieldLost`: le vaisseau a perdu son bouclier
+/// - `Died`: le vaisseau a été détruit, pour la raison donnée
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TipEvent {
+    WaveStarted,
+    ShotFired,
+    Hit,
+    ShieldLost,
+    Died(GameOverReason),
+}
+
+impl TipEvent {
+    /// Fonction qui traduit un événement sonore en événement utile pour les astuces.
+    /// Un asteroide aspiré par un trou noir produit le même son qu'un asteroide détruit,
+    /// il compte donc aussi comme un tir réussi.
+    /// # Arguments
+    /// - `event`: l'événement sonore
+    /// # Returns
+    /// - `Option<TipEvent>`: l'événement correspondant, ou `None` s'il n'est pas utile
+    pub fn from_sound(event: SoundEvent) -> Option<Self> {
+        match event {
+            SoundEvent::StartGame | SoundEvent::NewWave => Some(TipEvent::WaveStarted),
+            SoundEvent::MissileFired => Some(TipEvent::ShotFired),
+            SoundEvent::ShieldLost(_) => Some(TipEvent::ShieldLost),
+            SoundEvent::AsteroidDestroyed(_)
+            | SoundEvent::UfoDestroyed(_)
+            | SoundEvent::TurretDestroyed(_)
+            | SoundEvent::BossDestroyed(_) => Some(TipEvent::Hit),
+            _ => None,
+        }
+    }
+}
+
+/// Structure qui repère les erreurs répétées pendant la session
+/// # Champs
+/// - `black_hole_deaths`: le nombre de morts dans un trou noir
+/// - `early_shield_losses`: le nombre de boucliers perdus peu après le début d'une vague
+/// - `wave_clock`: le temps écoulé depuis le début de la vague
+/// - `wave_shots`: le nombre de tirs pendant la vague
+/// - `wave_hits`: le nombre de tirs réussis pendant la vague
+/// - `finished_waves`: les tirs et tirs réussis des dernières vagues terminées
+#[derive(Default)]
+pub struct MistakeTracker {
+    black_hole_deaths: u32,
+    early_shield_losses: u32,
+    wave_clock: f32,
+    wave_shots: u32,
+    wave_hits: u32,
+    finished_waves: VecDeque<(u32, u32)>,
+}
+
+impl MistakeTracker {
+    /// Crée un suivi sans aucune erreur
+    /// # Returns
+    /// - `Self`: le suivi des erreurs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fait avancer l'horloge de la vague
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.wave_clock += dt;
+    }
+
+    /// Prend en compte un événement, et renvoie l'erreur qu'il confirme.
+    /// Le compteur d'une erreur repérée repart de zéro.
+    /// # Arguments
+    /// - `event`: l'événement de la partie
+    /// # Returns
+    /// - `Option<Mistake>`: l'erreur repérée, ou `None`
+    pub fn record(&mut self, event: TipEvent) -> Option<Mistake> {
+        match event {
+            TipEvent::WaveStarted => {
+                if self.wave_shots > 0 {
+                    self.finished_waves
+                        .push_back((self.wave_shots, self.wave_hits));
+                    if self.finished_waves.len() > ACCURACY_WAVES {
+                        self.finished_waves.pop_front();
+                    }
+                }
+                self.wave_clock = 0.0;
+                self.wave_shots = 0;
+                self.wave_hits = 0;
+                self.check_accuracy()
+            }
+            TipEvent::ShotFired => {
+                self.wave_shots += 1;
+                None
+            }
+            TipEvent::Hit => {
+                self.wave_hits += 1;
+                None
+            }
+            TipEvent::ShieldLost => {
+                if self.wave_clock > EARLY_SHIELD_LOSS_TIME {
+                    return None;
+                }
+                self.early_shield_losses += 1;
+                (self.early_shield_losses >= EARLY_SHIELD_LOSSES).then(|| {
+                    self.early_shield_losses = 0;
+                    Mistake::EarlyShieldLoss
+                })
+            }
+            TipEvent::Died(GameOverReason::BlackHole) => {
+                self.black_hole_deaths += 1;
+                (self.black_hole_deaths >= BLACK_HOLE_DEATHS).then(|| {
+                    self.black_hole_deaths = 0;
+                    Mistake::BlackHoleDeaths
+                })
+            }
+            TipEvent::Died(_) => None,
+        }
+    }
+
+    /// Vérifie la précision sur les `ACCURACY_WAVES` dernières vagues terminées
+    /// # Returns
+    /// - `Option<Mistake>`: `LowAccuracy` si la précision est trop basse, sinon `None`
+    fn check_accuracy(&mut self) -> Option<Mistake> {
+        if self.finished_waves.len() < ACCURACY_WAVES {
+            return None;
+        }
+        let (shots, hits) = self
+            .finished_waves
+            .iter()
+            .fold((0, 0), |(shots, hits), wave| {
+                (shots + wave.0, hits + wave.1)
+            });
+        if shots < MIN_SHOTS || hits as f32 / shots as f32 >= LOW_ACCURACY {
+            return None;
+        }
+        self.finished_waves.clear();
+        Some(Mistake::LowAccuracy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Joue une suite d'événements et renvoie les erreurs repérées
+    fn replay(tracker: &mut MistakeTracker, events: &[(f32, TipEvent)]) -> Vec<Mistake> {
+        events
+            .iter()
+            .filter_map(|(dt, event)| {
+                tracker.update(*dt);
+                tracker.record(*event)
+            })
+            .collect()
+    }
+
+    /// Vérifie que deux morts dans un trou noir déclenchent l'astuce, mais pas les autres morts.
+    #[test]
+    fn test_black_hole_deaths_detector() {
+        let mut tracker = MistakeTracker::new();
+        let mistakes = replay(
+            &mut tracker,
+            &[
+                (1.0, TipEvent::Died(GameOverReason::BlackHole)),
+                (1.0, TipEvent::Died(GameOverReason::EnemyFire)),
+                (1.0, TipEvent::Died(GameOverReason::BlackHole)),
+            ],
+        );
+
+        assert_eq!(mistakes, vec![Mistake::BlackHoleDeaths]);
+    }
+
+    /// Vérifie que seuls les boucliers perdus dans les 5 premières secondes d'une vague comptent.
+    #[test]
+    fn test_early_shield_loss_detector() {
+        let mut tracker = MistakeTracker::new();
+        let early_loss = [(0.0, TipEvent::WaveStarted), (2.0, TipEvent::ShieldLost)];
+        let late_loss = [(0.0, TipEvent::WaveStarted), (8.0, TipEvent::ShieldLost)];
+
+        assert!(replay(&mut tracker, &early_loss).is_empty());
+        assert!(replay(&mut tracker, &late_loss).is_empty());
+        assert!(replay(&mut tracker, &early_loss).is_empty());
+        assert_eq!(
+            replay(&mut tracker, &early_loss),
+            vec![Mistake::EarlyShieldLoss]
+        );
+    }
+
+    /// Vérifie que la précision est jugée sur deux vagues terminées, avec assez de tirs.
+    #[test]
+    fn test_low_accuracy_detector() {
+        let mut tracker = MistakeTracker::new();
+        let wave = |shots: usize, hits: usize| {
+            let mut events = vec![(0.0, TipEvent::WaveStarted)];
+            events.extend(std::iter::repeat_n((0.1, TipEvent::ShotFired), shots));
+            events.extend(std::iter::repeat_n((0.1, TipEvent::Hit), hits));
+            events
+        };
+
+        // Une bonne vague puis une mauvaise : 6 sur 20, la précision est suffisante
+        assert!(replay(&mut tracker, &wave(10, 5)).is_empty());
+        assert!(replay(&mut tracker, &wave(10, 1)).is_empty());
+        // Deux mauvaises vagues : 2 sur 20
+        let mistakes = replay(&mut tracker, &[wave(10, 1), wave(0, 0)].concat());
+        assert_eq!(mistakes, vec![Mistake::LowAccuracy]);
+    }
+
+    /// Vérifie qu'une astuce fermée par le joueur n'est plus jamais proposée.
+    #[test]
+    fn test_acknowledged_tip_is_not_shown_again() {
+        let mut tracker = MistakeTracker::new();
+        let mut acknowledged = BTreeSet::new();
+        let deaths = [TipEvent::Died(GameOverReason::BlackHole); 2].map(|event| (1.0, event));
+
+        let mistake = replay(&mut tracker, &deaths)[0];
+        let tip = tip_for(mistake, &acknowledged).expect("L'astuce doit être proposée");
+        assert_eq!(tip.key, "tip.black_hole");
+        acknowledged.insert(tip.id.to_string());
+
+        let mistake = replay(&mut tracker, &deaths)[0];
+        assert!(
+            tip_for(mistake, &acknowledged).is_none(),
+            "Une astuce fermée ne revient pas !"
+        );
+    }
+}
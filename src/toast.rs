@@ -0,0 +1,71 @@
+//! Module pour les notifications affichées dans un coin de l'écran.
+//! Une notification reste visible jusqu'à ce que le joueur clique dessus.
+use asteroid::text::{Fonts, TextStyle};
+use macroquad::prelude::*;
+
+/// Structure représentant une notification
+/// # Champs
+/// - `title`: le titre de la notification
+/// - `lines`: les lignes de texte sous le titre
+pub struct Toast {
+    title: String,
+    lines: Vec<String>,
+}
+
+impl Toast {
+    /// Crée une nouvelle notification
+    /// # Arguments
+    /// - `title`: le titre de la notification
+    /// - `lines`: les lignes de texte sous le titre
+    /// # Returns
+    /// - `Self`: la notification
+    pub fn new(title: String, lines: Vec<String>) -> Self {
+        Self { title, lines }
+    }
+
+    /// Dessine la notification dans le coin en haut à droite de l'écran
+    /// # Arguments
+    /// - `fonts`: les polices du jeu
+    /// # Returns
+    /// - `bool`: `true` si le joueur a cliqué sur la notification pour la fermer
+    pub fn draw(&self, fonts: &Fonts) -> bool {
+        let padding = 12.0;
+        let line_height = fonts.measure("Mg", TextStyle::Body).height + 8.0;
+        let width = self
+            .lines
+            .iter()
+            .map(|line| fonts.measure(line, TextStyle::Body).width)
+            .chain(std::iter::once(
+                fonts.measure(&self.title, TextStyle::Heading).width,
+            ))
+            .fold(0.0, f32::max)
+            + padding * 2.0;
+        let height = line_height * (self.lines.len() as f32 + 1.5) + padding * 2.0;
+        let rect = Rect::new(screen_width() - width - 20.0, 20.0, width, height);
+
+        draw_rectangle(
+            rect.x,
+            rect.y,
+            rect.w,
+            rect.h,
+            Color::new(0.0, 0.0, 0.0, 0.8),
+        );
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, GOLD);
+        let mut y = rect.y + padding + line_height;
+        fonts.draw(&self.title, rect.x + padding, y, TextStyle::Heading, GOLD);
+        y += line_height * 0.5;
+        for line in &self.lines {
+            y += line_height;
+            fonts.draw(line, rect.x + padding, y, TextStyle::Body, WHITE);
+        }
+        fonts.draw(
+            "x",
+            rect.x + rect.w - padding - 6.0,
+            rect.y + padding + 8.0,
+            TextStyle::Popup,
+            LIGHTGRAY,
+        );
+
+        is_mouse_button_pressed(MouseButton::Left) && rect.contains(mouse_position().into())
+    }
+}
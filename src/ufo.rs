@@ -0,0 +1,241 @@
+//! Module pour gérer la soucoupe volante ennemie.
+//! La soucoupe traverse l'écran horizontalement, change parfois de direction verticale
+//! et tire régulièrement des missiles vers le vaisseau.
+use crate::missile::Missile;
+use ::rand::Rng;
+use macroquad::prelude::*;
+
+/// Vitesse horizontale de la soucoupe
+const SPEED: f32 = 1.5;
+/// Temps (en secondes) entre deux tirs
+pub const FIRE_INTERVAL: f32 = 2.0;
+/// Temps (en secondes) entre deux changements de direction verticale
+const TURN_INTERVAL: f32 = 1.5;
+/// Imprécision maximale (en radians) des tirs de la soucoupe
+pub const AIM_SPREAD: f32 = 0.2;
+/// Bonus de score pour la destruction d'une soucoupe
+pub const SCORE_BONUS: i32 = 50;
+/// Probabilité par défaut qu'une soucoupe apparaisse au début d'une vague
+pub const DEFAULT_SPAWN_CHANCE: f64 = 0.35;
+
+/// Structure représentant une soucoupe volante ennemie
+/// # Champs
+/// - `position`: la position de la soucoupe
+/// - `velocity`: la vitesse de la soucoupe
+/// - `fire_timer`: le temps restant avant le prochain tir
+/// - `turn_timer`: le temps restant avant le prochain changement de direction verticale
+/// - `active`: permet de savoir si la soucoupe est active ou non
+/// - `radius`: le rayon de la soucoupe
+/// - `texture`: la texture de la soucoupe
+pub struct Ufo {
+    position: Vec2,
+    velocity: Vec2,
+    fire_timer: f32,
+    turn_timer: f32,
+    pub active: bool,
+    radius: f32,
+    texture: Texture2D,
+}
+
+impl Ufo {
+    /// Crée une soucoupe qui entre par un bord choisi au hasard, à partir d'une texture déjà chargée
+    /// # Arguments
+    /// - `bounds`: la taille de l'écran
+    /// - `texture`: la texture de la soucoupe
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `Self`: une soucoupe prête à traverser l'écran
+    pub fn random(bounds: Vec2, texture: Texture2D, rng: &mut impl Rng) -> Self {
+        let from_left = rng.gen_bool(0.5);
+        let position = vec2(
+            if from_left { 0.0 } else { bounds.x },
+            rng.gen_range(0.0..bounds.y),
+        );
+        let direction = if from_left { 1.0 } else { -1.0 };
+        Self::with_texture(position, direction, texture)
+    }
+
+    /// Crée une soucoupe à partir d'une texture déjà chargée
+    /// # Arguments
+    /// - `position`: la position initiale de la soucoupe
+    /// - `direction`: `1.0` pour aller vers la droite, `-1.0` pour aller vers la gauche
+    /// - `texture`: la texture de la soucoupe
+    /// # Returns
+    /// - `Self`: une soucoupe qui se déplace horizontalement
+    pub fn with_texture(position: Vec2, direction: f32, texture: Texture2D) -> Self {
+        Self {
+            position,
+            velocity: vec2(direction * SPEED, 0.0),
+            fire_timer: FIRE_INTERVAL,
+            turn_timer: TURN_INTERVAL,
+            active: true,
+            radius: 25.0,
+            texture,
+        }
+    }
+
+    /// Fonction qui dessine la soucoupe
+    pub fn draw(&self) {
+        draw_texture_ex(
+            &self.texture,
+            self.position.x - self.radius,
+            self.position.y - self.radius,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(self.radius * 2.0, self.radius * 2.0)),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Met à jour les minuteries de la soucoupe : changement de direction et tir
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    /// - `target`: la position visée, celle du vaisseau
+    /// - `rng`: le générateur de la partie
+    /// # Returns
+    /// - `Option<Missile>`: le missile ennemi tiré pendant cette image, s'il y en a un
+    pub fn update(&mut self, dt: f32, target: Vec2, rng: &mut impl Rng) -> Option<Missile> {
+        self.turn_timer -= dt;
+        if self.turn_timer <= 0.0 {
+            self.turn_timer = TURN_INTERVAL;
+            self.velocity.y = [-SPEED, 0.0, SPEED][rng.gen_range(0..3)] * 0.6;
+        }
+
+        self.fire_timer -= dt;
+        if self.fire_timer <= 0.0 {
+            self.fire_timer = FIRE_INTERVAL;
+            let aim = (target - self.position).to_angle() + rng.gen_range(-AIM_SPREAD..AIM_SPREAD);
+            return Some(Missile::enemy(self.position, aim));
+        }
+        None
+    }
+}
+
+use crate::stellarobject::{debug_field, format_vec2, StellarObject}; // Utilise le trait StellarObject
+
+impl StellarObject for Ufo {
+    /// Retourne la position de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: un vecteur avec la position x et y de l'objet stellaire
+    fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la vitesse de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec2`: la vitesse de l'objet, en pixels par image
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Change la vitesse de l'objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `velocity`: la nouvelle vitesse, en pixels par image
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    /// Met a jour la position de l'objet.
+    /// La soucoupe passe de haut en bas de l'écran, mais disparait une fois sortie sur les côtés.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    /// - `bounds`: la taille de l'écran
+    fn move_obj(&mut self, bounds: Vec2) {
+        self.position += self.velocity;
+        self.position.y = (self.position.y + bounds.y) % bounds.y;
+        if self.position.x < -self.radius || self.position.x > bounds.x + self.radius {
+            self.active = false;
+        }
+    }
+
+    /// Retourne le rayon de l'objet.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `f32`: le rayon de l'objet stellaire
+    fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Gere la collision avec un autre objet.
+    /// # Arguments
+    /// - `&mut self`: une instance de l'objet stellaire
+    fn handle_collision(&mut self) {
+        self.active = false;
+    }
+
+    /// Permet de savoir si l'objet est toujours dans la partie.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `bool`: `true` si l'objet est actif
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Retourne l'état de la soucoupe pour l'inspecteur de développement.
+    /// # Arguments
+    /// - `&self`: une instance de l'objet stellaire
+    /// # Returns
+    /// - `Vec<(String, String)>`: la liste des champs, avec leur nom et leur valeur
+    fn debug_fields(&self) -> Vec<(String, String)> {
+        vec![
+            debug_field("type", "Ufo"),
+            debug_field("position", format_vec2(self.position)),
+            debug_field("velocity", format_vec2(self.velocity)),
+            debug_field("radius", format!("{:.1}", self.radius)),
+            debug_field("active", self.active),
+            debug_field("fire_timer", format!("{:.2}", self.fire_timer)),
+            debug_field("turn_timer", format!("{:.2}", self.turn_timer)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::dummy_texture;
+    use crate::missile::MissileSource;
+    use ::rand::thread_rng;
+
+    /// Vérifie que la soucoupe tire un missile ennemi vers sa cible après `FIRE_INTERVAL`.
+    #[test]
+    fn test_fires_toward_target() {
+        let mut ufo = Ufo::with_texture(Vec2::ZERO, 1.0, dummy_texture());
+        let target = vec2(100.0, 0.0);
+
+        assert!(ufo
+            .update(FIRE_INTERVAL / 2.0, target, &mut thread_rng())
+            .is_none());
+        let missile = ufo
+            .update(FIRE_INTERVAL / 2.0, target, &mut thread_rng())
+            .expect("La soucoupe doit tirer !");
+
+        assert_eq!(missile.source, MissileSource::Enemy);
+        let direction = missile.velocity.normalize();
+        assert!(
+            direction.to_angle().abs() <= AIM_SPREAD,
+            "Le tir doit viser le vaisseau !"
+        );
+        assert!(
+            ufo.update(0.1, target, &mut thread_rng()).is_none(),
+            "Un seul tir par période !"
+        );
+    }
+
+    /// Vérifie qu'une soucoupe touchée est désactivée.
+    #[test]
+    fn test_collision_destroys_ufo() {
+        let mut ufo = Ufo::with_texture(Vec2::ZERO, -1.0, dummy_texture());
+
+        ufo.handle_collision();
+
+        assert!(!ufo.active);
+    }
+}
@@ -0,0 +1,132 @@
+//! Module pour les améliorations choisies entre deux vagues.
+//! Après chaque vague, le joueur choisit une amélioration parmi `UPGRADE_CHOICES` tirées au hasard.
+//! Les améliorations s'accumulent pendant toute la partie et sont perdues à la fin de la partie.
+use ::rand::seq::SliceRandom;
+use ::rand::Rng;
+
+/// Nombre d'améliorations proposées entre deux vagues
+pub const UPGRADE_CHOICES: usize = 3;
+/// Vitesse des missiles gagnée par amélioration, 10 %
+pub const MISSILE_SPEED_BONUS: f32 = 0.10;
+/// Temps de recharge de l'arme perdu par amélioration, 15 %
+pub const FIRE_COOLDOWN_REDUCTION: f32 = 0.15;
+/// Vitesse de rotation du vaisseau gagnée par amélioration, 10 %
+pub const TURN_RATE_BONUS: f32 = 0.10;
+/// Nombre de charges du bouclier rendues par amélioration
+pub const SHIELD_CHARGE_BONUS: u32 = 1;
+
+/// Énumération représentant les types d'amélioration
+/// # Variantes
+/// - `MissileSpeed`: les missiles vont `MISSILE_SPEED_BONUS` plus vite
+/// - `FireRate`: l'arme se recharge en `FIRE_COOLDOWN_REDUCTION` de temps en moins
+/// - `ShieldCharge`: le bouclier regagne `SHIELD_CHARGE_BONUS` charge tout de suite
+/// - `TurnRate`: le vaisseau tourne `TURN_RATE_BONUS` plus vite
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum UpgradeKind {
+    MissileSpeed,
+    FireRate,
+    ShieldCharge,
+    TurnRate,
+}
+
+impl UpgradeKind {
+    /// Tous les types d'amélioration, parmi lesquels les choix sont tirés
+    pub const ALL: [UpgradeKind; 4] = [
+        UpgradeKind::MissileSpeed,
+        UpgradeKind::FireRate,
+        UpgradeKind::ShieldCharge,
+        UpgradeKind::TurnRate,
+    ];
+
+    /// Fonction qui renvoie la clé de traduction du nom de l'amélioration
+    /// # Returns
+    /// - `&'static str`: la clé du nom de l'amélioration
+    pub fn label_key(self) -> &'static str {
+        match self {
+            UpgradeKind::MissileSpeed => "upgrade.missile_speed",
+            UpgradeKind::FireRate => "upgrade.fire_rate",
+            UpgradeKind::ShieldCharge => "upgrade.shield_charge",
+            UpgradeKind::TurnRate => "upgrade.turn_rate",
+        }
+    }
+}
+
+/// Fonction qui tire les améliorations proposées, toutes différentes
+/// # Arguments
+/// - `rng`: le générateur de la partie, pour que les choix soient les mêmes dans un replay
+/// # Returns
+/// - `[UpgradeKind; UPGRADE_CHOICES]`: les améliorations proposées
+pub fn random_choices(rng: &mut impl Rng) -> [UpgradeKind; UPGRADE_CHOICES] {
+    let mut kinds = UpgradeKind::ALL;
+    kinds.shuffle(rng);
+    [kinds[0], kinds[1], kinds[2]]
+}
+
+/// Structure regroupant les améliorations accumulées pendant une partie,
+/// sous forme de multiplicateurs des réglages du vaisseau
+/// # Champs
+/// - `missile_speed`: le multiplicateur de la vitesse propre des missiles
+/// - `fire_cooldown`: le multiplicateur du temps de recharge de l'arme
+/// - `turn_rate`: le multiplicateur de la vitesse de rotation du vaisseau
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Upgrades {
+    pub missile_speed: f32,
+    pub fire_cooldown: f32,
+    pub turn_rate: f32,
+}
+
+impl Default for Upgrades {
+    /// Crée les améliorations d'une nouvelle partie, qui ne changent rien
+    fn default() -> Self {
+        Self {
+            missile_speed: 1.0,
+            fire_cooldown: 1.0,
+            turn_rate: 1.0,
+        }
+    }
+}
+
+impl Upgrades {
+    /// Ajoute une amélioration. Les améliorations du même type se multiplient.
+    /// Les charges du bouclier ne sont pas un réglage : elles sont rendues par la partie.
+    /// # Arguments
+    /// - `kind`: le type de l'amélioration
+    pub fn apply(&mut self, kind: UpgradeKind) {
+        match kind {
+            UpgradeKind::MissileSpeed => self.missile_speed *= 1.0 + MISSILE_SPEED_BONUS,
+            UpgradeKind::FireRate => self.fire_cooldown *= 1.0 - FIRE_COOLDOWN_REDUCTION,
+            UpgradeKind::TurnRate => self.turn_rate *= 1.0 + TURN_RATE_BONUS,
+            UpgradeKind::ShieldCharge => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    /// Vérifie que les améliorations proposées sont toujours différentes.
+    #[test]
+    fn test_choices_are_distinct() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..50 {
+            let choices = random_choices(&mut rng);
+            for (i, kind) in choices.iter().enumerate() {
+                assert!(!choices[i + 1..].contains(kind));
+            }
+        }
+    }
+
+    /// Vérifie que deux améliorations du même type se multiplient.
+    #[test]
+    fn test_upgrades_stack() {
+        let mut upgrades = Upgrades::default();
+        upgrades.apply(UpgradeKind::FireRate);
+        upgrades.apply(UpgradeKind::FireRate);
+        assert!((upgrades.fire_cooldown - 0.85 * 0.85).abs() < 1e-5);
+        assert_eq!(upgrades.missile_speed, 1.0);
+        assert_eq!(upgrades.turn_rate, 1.0);
+    }
+}
@@ -0,0 +1,143 @@
+//! Module pour les réglages du volume, sauvegardés entre les parties.
+//! Les réglages sont un petit fichier texte avec une entrée `clé=valeur` par ligne,
+//! comme le profil du joueur.
+use asteroid::audio_manager::AudioManager;
+use asteroid::platform;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier des réglages du volume
+const VOLUME_FILE: &str = "volume.txt";
+
+/// Structure représentant les réglages du volume
+/// # Champs
+/// - `master`: le volume général, entre 0 et 1
+/// - `sfx`: le volume des effets sonores, entre 0 et 1, multiplié au volume général
+/// - `music`: le volume de la musique, entre 0 et 1, multiplié au volume général
+/// - `muted`: permet de couper tous les sons (touche M, utilisable partout)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VolumeSettings {
+    pub master: f32,
+    pub sfx: f32,
+    pub music: f32,
+    pub muted: bool,
+}
+
+impl Default for VolumeSettings {
+    /// Tous les sons au volume maximal
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            sfx: 1.0,
+            music: 1.0,
+            muted: false,
+        }
+    }
+}
+
+impl VolumeSettings {
+    /// Charge les réglages depuis un fichier.
+    /// Un fichier absent ou des lignes illisibles gardent les réglages par défaut,
+    /// et les volumes sont ramenés entre 0 et 1.
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `Self`: les réglages chargés
+    pub fn load(path: &Path) -> Self {
+        let mut volume = Self::default();
+        let Ok(content) = platform::read_to_string(path) else {
+            return volume;
+        };
+        for line in content.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let level = match key {
+                "master" => &mut volume.master,
+                "sfx" => &mut volume.sfx,
+                "music" => &mut volume.music,
+                "muted" => {
+                    volume.muted = value == "true";
+                    continue;
+                }
+                _ => continue,
+            };
+            match value.parse::<f32>() {
+                Ok(value) => *level = value.clamp(0.0, 1.0),
+                Err(_) => log::warn!("Volume illisible dans {} : {line}", path.display()),
+            }
+        }
+        volume
+    }
+
+    /// Sauvegarde les réglages dans un fichier
+    /// # Arguments
+    /// - `path`: le chemin du fichier
+    /// # Returns
+    /// - `io::Result<()>`: une erreur si le fichier n'a pas pu être écrit
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        platform::write(
+            path,
+            &format!(
+                "master={}\nsfx={}\nmusic={}\nmuted={}\n",
+                self.master, self.sfx, self.music, self.muted
+            ),
+        )
+    }
+
+    /// Applique les réglages au gestionnaire audio
+    /// # Arguments
+    /// - `audio`: le gestionnaire audio
+    pub fn apply(&self, audio: &mut AudioManager) {
+        audio.master_volume = self.master;
+        audio.sfx_volume = self.sfx;
+        audio.music_volume = self.music;
+        audio.muted = self.muted;
+    }
+}
+
+/// Fonction qui renvoie le chemin du fichier des réglages du volume
+/// # Returns
+/// - `PathBuf`: le chemin du fichier des réglages du volume
+pub fn volume_path() -> PathBuf {
+    crate::profile::data_path(VOLUME_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Vérifie que des réglages sauvegardés puis rechargés sont identiques.
+    #[test]
+    fn test_volume_round_trip() {
+        let path = std::env::temp_dir().join("asteroid_test_volume_round_trip.txt");
+        let volume = VolumeSettings {
+            master: 0.25,
+            sfx: 0.5,
+            music: 0.75,
+            muted: true,
+        };
+
+        volume.save(&path).unwrap();
+        let loaded = VolumeSettings::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, volume, "Les réglages rechargés sont différents !");
+    }
+
+    /// Vérifie que des volumes hors limites sont ramenés entre 0 et 1,
+    /// et qu'une ligne illisible garde la valeur par défaut.
+    #[test]
+    fn test_volume_is_clamped() {
+        let path = std::env::temp_dir().join("asteroid_test_volume_clamped.txt");
+        fs::write(&path, "master=3\nsfx=abc\nmuted=true\n").unwrap();
+
+        let loaded = VolumeSettings::load(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.master, 1.0);
+        assert_eq!(loaded.sfx, 1.0);
+        assert!(loaded.muted);
+    }
+}
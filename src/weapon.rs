@@ -0,0 +1,307 @@
+//! Module pour gérer l'arme du vaisseau.
+//! L'arme a un temps de recharge entre deux tirs et limite le nombre de missiles en vol.
+//! Avec la surchauffe, chaque tir chauffe l'arme, qui refroidit avec le temps :
+//! une arme trop chaude se bloque pendant `OVERHEAT_LOCK_TIME`.
+use crate::missile::{Missile, MissileSource};
+use crate::pool::Pool;
+use macroquad::prelude::*;
+
+/// Temps de recharge (en secondes) entre deux tirs
+pub const FIRE_COOLDOWN: f32 = 0.25;
+/// Nombre maximal de missiles du joueur en vol en même temps, comme dans le jeu d'origine
+pub const MAX_ACTIVE_MISSILES: usize = 4;
+/// Multiplicateur du temps de recharge pendant le tir rapide
+pub const RAPID_FIRE_FACTOR: f32 = 0.5;
+/// Angle (en radians) entre le missile central et les missiles latéraux du tir triple, 15°
+pub const SPREAD_ANGLE: f32 = std::f32::consts::PI / 12.0;
+/// Durée (en secondes) du tir triple
+pub const TRIPLE_SHOT_TIME: f32 = 8.0;
+/// Chaleur à partir de laquelle l'arme surchauffe et se bloque
+pub const MAX_HEAT: f32 = 1.0;
+/// Chaleur ajoutée par chaque tir, ou chaque salve du tir triple
+pub const HEAT_PER_SHOT: f32 = 0.15;
+/// Chaleur perdue par seconde quand l'arme n'est pas bloquée
+pub const HEAT_DISSIPATION: f32 = 0.25;
+/// Durée (en secondes) du blocage de l'arme qui a surchauffé
+pub const OVERHEAT_LOCK_TIME: f32 = 2.0;
+
+/// Fonction qui renvoie les directions des trois missiles du tir triple
+/// # Arguments
+/// - `rotation`: la direction du vaisseau, en radians
+/// # Returns
+/// - `[f32; 3]`: les directions, le missile central en premier
+pub fn triple_shot_angles(rotation: f32) -> [f32; 3] {
+    [rotation, rotation - SPREAD_ANGLE, rotation + SPREAD_ANGLE]
+}
+
+/// Structure représentant l'état de l'arme du vaisseau
+/// # Champs
+/// - `cooldown`: le temps restant avant de pouvoir tirer à nouveau
+/// - `overheat`: permet de savoir si la surchauffe est active
+/// - `heat`: la chaleur de l'arme, entre 0 et `MAX_HEAT`
+/// - `lockout`: le temps restant du blocage après une surchauffe
+#[derive(Clone, Default, Debug)]
+pub struct WeaponState {
+    cooldown: f32,
+    pub overheat: bool,
+    heat: f32,
+    lockout: f32,
+}
+
+impl WeaponState {
+    /// Fait avancer le temps de recharge et le refroidissement de l'arme.
+    /// Pendant le blocage, la chaleur redescend à zéro en `OVERHEAT_LOCK_TIME`.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        self.cooldown = (self.cooldown - dt).max(0.0);
+        if self.lockout > 0.0 {
+            self.lockout = (self.lockout - dt).max(0.0);
+            self.heat = MAX_HEAT * self.lockout / OVERHEAT_LOCK_TIME;
+        } else {
+            self.heat = (self.heat - HEAT_DISSIPATION * dt).max(0.0);
+        }
+    }
+
+    /// Fonction qui renvoie la chaleur de l'arme
+    /// # Returns
+    /// - `f32`: la part de la chaleur maximale, entre 0 et 1
+    pub fn heat(&self) -> f32 {
+        self.heat / MAX_HEAT
+    }
+
+    /// Permet de savoir si l'arme est bloquée par une surchauffe
+    /// # Returns
+    /// - `bool`: `true` pendant le blocage
+    pub fn overheated(&self) -> bool {
+        self.lockout > 0.0
+    }
+
+    /// Appuie sur la détente d'une arme bloquée : elle ne tire pas, mais fait un clic à vide
+    /// au rythme des tirs, pour que le joueur sache pourquoi rien ne part
+    /// # Returns
+    /// - `bool`: `true` si un clic à vide doit être joué
+    pub fn dry_fire(&mut self) -> bool {
+        if !self.overheated() || self.cooldown > 0.0 {
+            return false;
+        }
+        self.cooldown = FIRE_COOLDOWN;
+        true
+    }
+
+    /// Tire un missile si l'arme est rechargée et que la limite de missiles n'est pas atteinte
+    /// # Arguments
+    /// - `position`: la position de départ du missile
+    /// - `rotation`: la direction du tir, en radians
+    /// - `missiles`: contient tous les missiles du jeu
+    /// # Returns
+    /// - `bool`: `true` si un missile a été tiré
+    pub fn try_fire(
+        &mut self,
+        position: Vec2,
+        rotation: f32,
+        missiles: &mut Pool<Missile>,
+    ) -> bool {
+        self.try_fire_volley(position, &[rotation], Vec2::ZERO, missiles, FIRE_COOLDOWN)
+    }
+
+    /// Tire une salve de missiles, un par direction, si l'arme est rechargée.
+    /// Chaque missile compte dans la limite de missiles en vol : s'il n'y a pas de place
+    /// pour toute la salve, seules les premières directions sont tirées.
+    /// Un missile qui ne trouve pas de place dans la réserve n'est pas tiré.
+    /// # Arguments
+    /// - `position`: la position de départ des missiles
+    /// - `rotations`: les directions des missiles, en radians
+    /// - `base_velocity`: la vitesse du tireur, ajoutée à celle des missiles
+    /// - `missiles`: contient tous les missiles du jeu
+    /// - `cooldown`: le temps de recharge après cette salve, en secondes
+    /// # Returns
+    /// - `bool`: `true` si au moins un missile a été tiré
+    pub fn try_fire_volley(
+        &mut self,
+        position: Vec2,
+        rotations: &[f32],
+        base_velocity: Vec2,
+        missiles: &mut Pool<Missile>,
+        cooldown: f32,
+    ) -> bool {
+        let in_flight = missiles
+            .iter()
+            .filter(|m| m.active && m.source == MissileSource::Player)
+            .count();
+        let room = MAX_ACTIVE_MISSILES.saturating_sub(in_flight);
+        if self.cooldown > 0.0 || self.overheated() || room == 0 {
+            return false;
+        }
+        let mut fired = false;
+        for &rotation in rotations.iter().take(room) {
+            if !missiles.push(Missile::with_base_velocity(
+                position,
+                rotation,
+                base_velocity,
+            )) {
+                break;
+            }
+            fired = true;
+        }
+        if fired {
+            self.cooldown = cooldown;
+            if self.overheat {
+                self.heat += HEAT_PER_SHOT;
+                if self.heat >= MAX_HEAT {
+                    self.heat = MAX_HEAT;
+                    self.lockout = OVERHEAT_LOCK_TIME;
+                }
+            }
+        }
+        fired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::MISSILE_POOL_SIZE;
+
+    /// Vérifie que deux tirs dans la même image ne créent qu'un seul missile.
+    #[test]
+    fn test_cooldown_blocks_second_shot_in_same_frame() {
+        let mut weapon = WeaponState::default();
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+
+        assert!(weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+        assert!(!weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+
+        assert_eq!(missiles.len(), 1);
+    }
+
+    /// Vérifie que l'arme peut tirer à nouveau une fois rechargée.
+    #[test]
+    fn test_cooldown_elapses() {
+        let mut weapon = WeaponState::default();
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+        weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles);
+
+        weapon.update(FIRE_COOLDOWN / 2.0);
+        assert!(!weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+        weapon.update(FIRE_COOLDOWN / 2.0);
+        assert!(weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+    }
+
+    /// Vérifie la limite de missiles en vol, sans compter les tirs ennemis.
+    #[test]
+    fn test_active_missile_cap() {
+        let mut weapon = WeaponState::default();
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+        missiles.push(Missile::enemy(Vec2::ZERO, 0.0));
+        for _ in 0..MAX_ACTIVE_MISSILES + 2 {
+            weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles);
+            weapon.update(FIRE_COOLDOWN);
+        }
+
+        assert_eq!(missiles.len(), MAX_ACTIVE_MISSILES + 1);
+    }
+
+    /// Vérifie qu'une salve du tir triple crée trois missiles écartés de `SPREAD_ANGLE`,
+    /// qui comptent tous dans la limite de missiles en vol.
+    #[test]
+    fn test_triple_shot_volley() {
+        let mut weapon = WeaponState::default();
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+
+        assert!(weapon.try_fire_volley(
+            Vec2::ZERO,
+            &triple_shot_angles(0.0),
+            Vec2::ZERO,
+            &mut missiles,
+            FIRE_COOLDOWN
+        ));
+
+        assert_eq!(missiles.len(), 3);
+        let center = missiles[0].velocity;
+        for side in &missiles[1..] {
+            let separation = center.angle_between(side.velocity).abs();
+            assert!((separation - SPREAD_ANGLE).abs() < 1e-4);
+        }
+        assert!(
+            (missiles[1]
+                .velocity
+                .angle_between(missiles[2].velocity)
+                .abs()
+                - 2.0 * SPREAD_ANGLE)
+                .abs()
+                < 1e-4
+        );
+
+        // Il ne reste de la place que pour un missile
+        weapon.update(FIRE_COOLDOWN);
+        weapon.try_fire_volley(
+            Vec2::ZERO,
+            &triple_shot_angles(0.0),
+            Vec2::ZERO,
+            &mut missiles,
+            FIRE_COOLDOWN,
+        );
+        assert_eq!(missiles.len(), MAX_ACTIVE_MISSILES);
+    }
+
+    /// Vérifie que les tirs ne chauffent l'arme qu'avec la surchauffe,
+    /// et que chaque tir ajoute `HEAT_PER_SHOT`.
+    #[test]
+    fn test_heat_accumulates_per_shot() {
+        let mut weapon = WeaponState::default();
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+        assert!(weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+        assert_eq!(weapon.heat(), 0.0);
+
+        weapon.overheat = true;
+        weapon.update(FIRE_COOLDOWN);
+        assert!(weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+        weapon.update(FIRE_COOLDOWN);
+        assert!(weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+        let expected = 2.0 * HEAT_PER_SHOT - HEAT_DISSIPATION * FIRE_COOLDOWN;
+        assert!((weapon.heat() - expected).abs() < 1e-5);
+    }
+
+    /// Vérifie que l'arme refroidit de `HEAT_DISSIPATION` par seconde, sans passer sous zéro.
+    #[test]
+    fn test_heat_dissipates() {
+        let mut weapon = WeaponState {
+            overheat: true,
+            heat: 0.5,
+            ..WeaponState::default()
+        };
+        weapon.update(1.0);
+        assert!((weapon.heat() - (0.5 - HEAT_DISSIPATION)).abs() < 1e-5);
+        weapon.update(10.0);
+        assert_eq!(weapon.heat(), 0.0);
+    }
+
+    /// Vérifie que l'arme se bloque quand la chaleur atteint `MAX_HEAT`, fait des clics à vide
+    /// pendant `OVERHEAT_LOCK_TIME`, puis tire de nouveau, refroidie.
+    #[test]
+    fn test_overheat_locks_then_unlocks() {
+        let mut weapon = WeaponState {
+            overheat: true,
+            heat: MAX_HEAT - HEAT_PER_SHOT / 2.0,
+            ..WeaponState::default()
+        };
+        let mut missiles = Pool::new(MISSILE_POOL_SIZE);
+        assert!(!weapon.dry_fire());
+        assert!(weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+        assert!(weapon.overheated());
+        assert_eq!(weapon.heat(), 1.0);
+
+        weapon.update(FIRE_COOLDOWN);
+        assert!(!weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+        assert!(weapon.dry_fire());
+        assert!(!weapon.dry_fire(), "Un seul clic par temps de recharge !");
+
+        weapon.update(OVERHEAT_LOCK_TIME / 2.0);
+        assert!(weapon.overheated());
+        weapon.update(OVERHEAT_LOCK_TIME / 2.0);
+        assert!(!weapon.overheated());
+        assert_eq!(weapon.heat(), 0.0);
+        assert!(weapon.try_fire(Vec2::ZERO, 0.0, &mut missiles));
+    }
+}
@@ -0,0 +1,3489 @@
+//! Module pour la logique de la partie : déplacement des objets, collisions, vagues et score.
+//! Ce module ne dessine rien et ne joue aucun son : il produit des événements sonores et des
+//! textes temporaires que le jeu affiche, ce qui permet de simuler des parties sans fenêtre.
+use crate::accounting::{report_leak, Accounting};
+use crate::activity::ActivityMeter;
+use crate::assets::{load_optional_texture, load_required_texture, Placeholder};
+use crate::asteroid::{
+    Asteroid, AsteroidKind, Size, ASTEROID_VARIANTS, INCUBATION_TIME, MAX_ASTEROIDS,
+    SAFE_SPAWN_DISTANCE,
+};
+use crate::audio::SoundEvent;
+use crate::black_hole::{self, BlackHole};
+use crate::boss::{self, BossAction, Mothership, BOSS_WAVE};
+use crate::boss_asteroid::{self, is_boss_asteroid_wave, BossAsteroid};
+use crate::comet::{Comet, CometScheduler};
+use crate::decals::Decals;
+use crate::difficulty::Difficulty;
+use crate::effects::{Effects, BLACK_HOLE_BURST, LARGE_KILL_SHAKE, SHIELD_LOST_SHAKE};
+use crate::endless::{self, EndlessSpawner};
+use crate::game_event::GameEvent;
+use crate::game_mode::GameMode;
+use crate::game_over::GameOverReason;
+use crate::gold_asteroid::{self, GoldAsteroid};
+use crate::missile::{self, Missile, MissileSource};
+use crate::platform::entropy_rng;
+use crate::pool::Pool;
+use crate::powerup::{self, PowerUp};
+use crate::remix::{remix, AsteroidSnapshot};
+use crate::run_stats::RunStats;
+use crate::score::ScoreSystem;
+use crate::spaceship::{Spaceship, HIT_INVINCIBILITY};
+use crate::spatial_grid::{largest_radius, SpatialGrid};
+use crate::stellarobject::{wrapped_distance, wrapped_offset, StellarObject};
+use crate::ufo::{self, Ufo};
+use crate::upgrade::{self, UpgradeKind, Upgrades, SHIELD_CHARGE_BONUS, UPGRADE_CHOICES};
+use ::rand::rngs::StdRng;
+use ::rand::{Rng, SeedableRng};
+use macroquad::prelude::*;
+use std::fmt::{self, Write};
+
+/// Durée (en secondes) du fondu des textes temporaires, aussi utilisée pour effacer
+/// rapidement les textes de la vague précédente
+pub const TEXT_FADE_TIME: f32 = 0.2;
+/// Nombre d'asteroides de la première vague, chaque vague suivante en ajoute un
+pub const FIRST_WAVE_ASTEROIDS: u32 = 5;
+/// Augmentation de la vitesse des asteroides à chaque vague
+pub const SPEED_GROWTH_PER_WAVE: f32 = 0.06;
+/// Multiplicateur maximal de la vitesse des asteroides
+pub const MAX_SPEED_FACTOR: f32 = 1.8;
+/// Augmentation du poids des grands asteroides à chaque vague
+pub const LARGE_WEIGHT_GROWTH_PER_WAVE: f32 = 0.15;
+/// Poids maximal des grands asteroides, les moyens et les petits gardant un poids de 1
+pub const MAX_LARGE_WEIGHT: f32 = 3.0;
+/// Augmentation de la probabilité qu'un asteroide soit blindé à chaque vague, à partir de la deuxième
+pub const ARMORED_CHANCE_GROWTH_PER_WAVE: f64 = 0.04;
+/// Probabilité maximale qu'un asteroide soit blindé
+pub const MAX_ARMORED_CHANCE: f64 = 0.4;
+/// Augmentation de la probabilité qu'un asteroide soit explosif à chaque vague, à partir de la deuxième
+pub const VOLATILE_CHANCE_GROWTH_PER_WAVE: f64 = 0.03;
+/// Probabilité maximale qu'un asteroide soit explosif
+pub const MAX_VOLATILE_CHANCE: f64 = 0.25;
+/// Nombre de bombes au début de la partie
+pub const BOMB_START_CHARGES: u32 = 1;
+/// Une bombe est gagnée chaque fois que le numéro de la vague est un multiple de ce nombre
+pub const BOMB_CHARGE_WAVES: u32 = 3;
+/// Fraction des points d'un asteroide gagnée quand il est détruit par une bombe
+pub const BOMB_SCORE_FACTOR: f32 = 0.5;
+/// Nombre de charges du bouclier rendues par défaut à la fin de chaque vague
+pub const WAVE_SHIELD_CHARGES: u32 = 1;
+/// Durée (en secondes) de l'invincibilité au début de chaque vague, en difficulté normale
+pub const WAVE_INVINCIBILITY: f32 = 1.0;
+/// Durée (en secondes) du compte à rebours entre deux vagues, pendant lequel
+/// les asteroides de la nouvelle vague sont visibles mais immobiles
+pub const WAVE_COUNTDOWN: f32 = 2.0;
+/// Nombre de particules de l'explosion du vaisseau détruit
+pub const SHIP_EXPLOSION_PARTICLES: usize = 60;
+/// Rayon (en pixels) de l'onde de choc de l'explosion du vaisseau détruit
+pub const SHIP_EXPLOSION_RADIUS: f32 = 150.0;
+/// Nombre de places de la réserve des missiles, créées au début de la partie
+pub const MISSILE_POOL_SIZE: usize = 128;
+/// Nombre de places de la réserve des textes temporaires, créées au début de la partie
+pub const TEXT_POOL_SIZE: usize = 64;
+/// Part de leur vitesse de rapprochement que gardent deux vaisseaux qui se cognent en coopération
+pub const SHIP_RESTITUTION: f32 = 0.5;
+
+/// Fonction qui renvoie le nombre d'asteroides d'une vague
+/// # Arguments
+/// - `wave`: le numéro de la vague
+/// # Returns
+/// - `u32`: `FIRST_WAVE_ASTEROIDS` pour la première vague, un de plus à chaque vague
+pub fn wave_asteroid_count(wave: u32) -> u32 {
+    FIRST_WAVE_ASTEROIDS + wave.saturating_sub(1)
+}
+
+/// Fonction qui renvoie le multiplicateur de la vitesse des asteroides d'une vague
+/// # Arguments
+/// - `wave`: le numéro de la vague
+/// # Returns
+/// - `f32`: `1` pour la première vague, puis `SPEED_GROWTH_PER_WAVE` de plus à chaque vague,
+///   jusqu'à `MAX_SPEED_FACTOR`
+pub fn wave_speed_factor(wave: u32) -> f32 {
+    (1.0 + SPEED_GROWTH_PER_WAVE * wave.saturating_sub(1) as f32).min(MAX_SPEED_FACTOR)
+}
+
+/// Fonction qui renvoie les poids du tirage de la taille des asteroides d'une vague
+/// # Arguments
+/// - `wave`: le numéro de la vague
+/// # Returns
+/// - `[f32; 3]`: les poids des grands, moyens et petits asteroides, égaux pour la première vague
+pub fn wave_size_weights(wave: u32) -> [f32; 3] {
+    let large =
+        (1.0 + LARGE_WEIGHT_GROWTH_PER_WAVE * wave.saturating_sub(1) as f32).min(MAX_LARGE_WEIGHT);
+    [large, 1.0, 1.0]
+}
+
+/// Fonction qui renvoie la probabilité qu'un asteroide d'une vague soit blindé
+/// # Arguments
+/// - `wave`: le numéro de la vague
+/// # Returns
+/// - `f64`: une probabilité nulle pour la première vague, qui grandit jusqu'à `MAX_ARMORED_CHANCE`
+pub fn wave_armored_chance(wave: u32) -> f64 {
+    (ARMORED_CHANCE_GROWTH_PER_WAVE * wave.saturating_sub(1) as f64).min(MAX_ARMORED_CHANCE)
+}
+
+/// Fonction qui renvoie la probabilité qu'un asteroide d'une vague soit explosif
+/// # Arguments
+/// - `wave`: le numéro de la vague
+/// # Returns
+/// - `f64`: une probabilité nulle pour la première vague, qui grandit jusqu'à `MAX_VOLATILE_CHANCE`
+pub fn wave_volatile_chance(wave: u32) -> f64 {
+    (VOLATILE_CHANCE_GROWTH_PER_WAVE * wave.saturating_sub(1) as f64).min(MAX_VOLATILE_CHANCE)
+}
+
+/// Fonction qui tire une graine au hasard, pour une partie qui n'a pas de graine imposée
+/// # Returns
+/// - `u64`: la graine
+pub fn random_seed() -> u64 {
+    entropy_rng().gen()
+}
+
+/// Structure permettant de gérer les textes temporaires affichés à l'écran
+/// # Champs
+/// - `text`: le texte lui même
+/// - `position`: position du texte
+/// - `color`: couleur du texte à l'écran
+/// - `lifetime`: durée de vie du texte à l'écran
+#[derive(Default)]
+pub struct TemporaryText {
+    pub text: String,
+    pub position: Vec2,
+    pub color: Color,
+    pub lifetime: f32, // Temps restant avant disparition
+}
+
+impl TemporaryText {
+    /// Fonction qui renvoie l'opacité du texte, qui s'efface pendant `TEXT_FADE_TIME`
+    /// # Returns
+    /// - `f32`: l'opacité entre 0 et 1
+    pub fn alpha(&self) -> f32 {
+        (self.lifetime / TEXT_FADE_TIME).clamp(0.0, 1.0)
+    }
+
+    /// Remplace le texte, en réutilisant la mémoire de l'ancien
+    /// # Arguments
+    /// - `text`: le nouveau texte
+    /// - `position`: la position du texte
+    /// - `color`: la couleur du texte
+    /// - `lifetime`: la durée d'affichage du texte
+    pub fn set(&mut self, text: fmt::Arguments, position: Vec2, color: Color, lifetime: f32) {
+        self.text.clear();
+        let _ = self.text.write_fmt(text);
+        self.position = position;
+        self.color = color;
+        self.lifetime = lifetime;
+    }
+}
+
+/// Structure regroupant les textures des objets créés pendant la partie
+/// # Champs
+/// - `asteroids`: les textures des asteroides, dont au moins une : chaque asteroide garde l'indice de la sienne
+/// - `black_hole`: la texture des trous noirs
+/// - `ufo`: la texture des soucoupes
+#[derive(Clone)]
+pub struct WorldTextures {
+    pub asteroids: Vec<Texture2D>,
+    pub black_hole: Texture2D,
+    pub ufo: Texture2D,
+}
+
+impl WorldTextures {
+    /// Charge les textures des objets de la partie.
+    /// Les textures `asteroid_1.png` à `asteroid_4.png` sont facultatives :
+    /// si aucune n'existe, tous les asteroides utilisent `asteroid.png`.
+    /// # Returns
+    /// - `Self`: les textures chargées, avec des dessins à la place des textures indispensables manquantes
+    pub async fn load() -> Self {
+        let mut asteroids = Vec::new();
+        for i in 1..=ASTEROID_VARIANTS {
+            if let Some(texture) = load_optional_texture(&format!("asteroid_{i}.png")).await {
+                asteroids.push(texture);
+            }
+        }
+        if asteroids.is_empty() {
+            asteroids.push(load_required_texture("asteroid.png", Placeholder::Asteroid).await);
+        }
+        Self {
+            asteroids,
+            black_hole: load_required_texture("black_hole.png", Placeholder::BlackHole).await,
+            ufo: load_required_texture("ufo.png", Placeholder::Ufo).await,
+        }
+    }
+}
+
+/// Structure représentant l'état d'une partie
+/// # Champs
+/// - `spaceship`: le vaisseau du joueur
+/// - `partner`: le vaisseau du second joueur, présent seulement en coopération
+/// - `asteroids`: tous les asteroides du jeu
+/// - `missiles`: tous les missiles, ceux du joueur et ceux des ennemis, dans une réserve
+///   de `MISSILE_POOL_SIZE` places
+/// - `black_holes`: tous les trous noirs
+/// - `ufos`: toutes les soucoupes
+/// - `power_ups`: tous les bonus à ramasser
+/// - `effects`: les effets visuels, comme les débris des asteroides rapides
+/// - `temporary_texts`: les textes temporaires, comme les points gagnés, dans une réserve
+///   de `TEXT_POOL_SIZE` places
+/// - `sound_events`: les événements sonores produits depuis la dernière lecture
+/// - `score`: le score actuel du joueur
+/// - `partner_score`: le score du second joueur, séparé de celui du premier
+/// - `wave`: le numéro de la vague en cours
+/// - `bounds`: la taille de l'écran
+/// - `ufo_spawn_chance`: la probabilité qu'une soucoupe apparaisse au début de chaque vague
+/// - `power_up_chance`: la probabilité qu'un asteroide détruit laisse un bonus
+/// - `black_hole_lifetime`: la durée de vie (en secondes) des nouveaux trous noirs
+/// - `missile_lifetime`: la durée de vie (en secondes) des missiles sans tête chercheuse
+/// - `max_missiles`: le nombre maximal de missiles en vol, au-delà duquel les plus anciens disparaissent
+/// - `black_hole_warning`: la durée (en secondes) de l'annonce des nouveaux trous noirs
+/// - `mode`: le mode de jeu
+/// - `difficulty`: la difficulté de la partie
+/// - `activity`: la jauge d'activité, qui n'existe qu'en mode sans fin
+/// - `remix`: le champ capturé à la fin de la partie précédente, repris à la place de la première vague
+/// - `boss`: le vaisseau mère, présent seulement pendant la vague `BOSS_WAVE` du mode classique
+/// - `boss_asteroid`: l'asteroide géant, présent seulement pendant ses vagues du mode classique
+/// - `gold_asteroid`: l'asteroide doré, une cible bonus qui apparaît rarement au début d'une vague
+/// - `comets`: les comètes, annoncées ou en train de traverser l'écran
+/// - `comet_scheduler`: décide quand la prochaine comète apparaît
+/// - `victory`: permet de savoir si le vaisseau mère a été détruit, les vagues s'arrêtent alors
+///   jusqu'au choix du joueur
+/// - `spawner`: décide quand un asteroide apparaît en mode sans fin
+/// - `accounting`: les compteurs des objets créés et supprimés, pour repérer les fuites
+/// - `decals`: les traces laissées par les asteroides détruits pendant la vague
+/// - `bombs`: le nombre de bombes du joueur
+/// - `bomb_splits`: permet de savoir si les asteroides touchés par une bombe se séparent
+///   comme sous un missile, ou sont détruits d'un coup
+/// - `wave_shield_charges`: le nombre de charges du bouclier rendues à la fin de chaque vague,
+///   sauf dans les difficultés qui ne rendent pas le bouclier
+/// - `overheat`: permet d'imposer la surchauffe de l'arme, même dans les difficultés qui ne l'ont pas
+/// - `offer_upgrades`: permet de savoir si une amélioration est proposée à la fin de chaque vague
+///   du mode classique ; la vague suivante attend alors le choix du joueur
+/// - `start_wave`: la vague par laquelle la partie commence, 1 sauf avec l'option `--wave`
+/// - `upgrade_choices`: les améliorations proposées, tant que le joueur n'a pas choisi
+/// - `combo`: le multiplicateur des points des asteroides détruits à la suite
+/// - `wave_countdown`: le temps restant du compte à rebours avant que la nouvelle vague ne s'anime
+/// - `seed`: la graine de la partie, qui permet de la rejouer à l'identique
+/// - `stats`: les statistiques de la partie, sans les missiles encore en vol
+/// - `rng`: le générateur des asteroides, des soucoupes et des bonus, tiré de `seed`
+/// - `textures`: les textures des objets créés pendant la partie
+pub struct World {
+    pub spaceship: Spaceship,
+    pub partner: Option<Spaceship>,
+    pub asteroids: Vec<Asteroid>,
+    pub missiles: Pool<Missile>,
+    pub black_holes: Vec<BlackHole>,
+    pub ufos: Vec<Ufo>,
+    pub power_ups: Vec<PowerUp>,
+    pub effects: Effects,
+    pub temporary_texts: Pool<TemporaryText>,
+    pub sound_events: Vec<SoundEvent>,
+    pub score: i32,
+    pub partner_score: i32,
+    pub wave: u32,
+    pub bounds: Vec2,
+    pub ufo_spawn_chance: f64,
+    pub power_up_chance: f64,
+    pub black_hole_lifetime: f32,
+    pub missile_lifetime: f32,
+    pub max_missiles: usize,
+    pub black_hole_warning: f32,
+    pub mode: GameMode,
+    pub difficulty: Difficulty,
+    pub activity: Option<ActivityMeter>,
+    pub remix: Vec<AsteroidSnapshot>,
+    pub boss: Option<Mothership>,
+    pub boss_asteroid: Option<BossAsteroid>,
+    pub gold_asteroid: Option<GoldAsteroid>,
+    pub comets: Vec<Comet>,
+    comet_scheduler: CometScheduler,
+    pub victory: bool,
+    spawner: EndlessSpawner,
+    pub accounting: Accounting,
+    pub decals: Decals,
+    pub bombs: u32,
+    pub bomb_splits: bool,
+    pub wave_shield_charges: u32,
+    pub overheat: bool,
+    pub offer_upgrades: bool,
+    pub start_wave: u32,
+    pub upgrade_choices: Option<[UpgradeKind; UPGRADE_CHOICES]>,
+    pub combo: ScoreSystem,
+    pub wave_countdown: f32,
+    pub seed: u64,
+    pub stats: RunStats,
+    rng: StdRng,
+    textures: WorldTextures,
+}
+
+impl World {
+    /// Crée une partie avec une graine tirée au hasard et lance la première vague
+    /// # Arguments
+    /// - `spaceship`: le vaisseau du joueur
+    /// - `textures`: les textures des objets créés pendant la partie
+    /// - `bounds`: la taille de l'écran
+    /// - `ufo_spawn_chance`: la probabilité qu'une soucoupe apparaisse au début de chaque vague
+    /// # Returns
+    /// - `Self`: la partie, avec les asteroides de la première vague
+    pub fn new(
+        spaceship: Spaceship,
+        textures: WorldTextures,
+        bounds: Vec2,
+        ufo_spawn_chance: f64,
+    ) -> Self {
+        let seed = random_seed();
+        let mut world = Self {
+            spaceship,
+            partner: None,
+            asteroids: Vec::new(),
+            missiles: Pool::new(MISSILE_POOL_SIZE),
+            black_holes: Vec::new(),
+            ufos: Vec::new(),
+            power_ups: Vec::new(),
+            effects: Effects::new(),
+            temporary_texts: Pool::new(TEXT_POOL_SIZE),
+            sound_events: Vec::new(),
+            score: 0,
+            partner_score: 0,
+            wave: 1,
+            bounds,
+            ufo_spawn_chance,
+            power_up_chance: powerup::DEFAULT_DROP_CHANCE,
+            black_hole_lifetime: black_hole::DEFAULT_LIFETIME,
+            missile_lifetime: missile::MISSILE_LIFETIME,
+            max_missiles: missile::MAX_MISSILES,
+            black_hole_warning: black_hole::WARNING_TIME,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::default(),
+            activity: None,
+            remix: Vec::new(),
+            boss: None,
+            boss_asteroid: None,
+            gold_asteroid: None,
+            comets: Vec::new(),
+            comet_scheduler: CometScheduler::default(),
+            victory: false,
+            spawner: EndlessSpawner::default(),
+            accounting: Accounting::default(),
+            decals: Decals::default(),
+            bombs: BOMB_START_CHARGES,
+            bomb_splits: true,
+            wave_shield_charges: WAVE_SHIELD_CHARGES,
+            overheat: false,
+            offer_upgrades: false,
+            start_wave: 1,
+            upgrade_choices: None,
+            combo: ScoreSystem::new(),
+            wave_countdown: 0.0,
+            seed,
+            stats: RunStats::default(),
+            rng: StdRng::seed_from_u64(seed),
+            textures,
+        };
+        world.apply_difficulty();
+        world.start_new_wave();
+        world
+    }
+
+    /// Recommence la partie depuis la première vague avec un nouveau vaisseau.
+    /// Le vaisseau du second joueur, s'il y en a un, revient à sa position de départ.
+    /// Les améliorations de la partie précédente sont perdues.
+    /// Le générateur repart de `seed` : la même graine redonne les mêmes asteroides.
+    /// Les événements sonores en attente sont gardés pour être joués.
+    /// # Arguments
+    /// - `spaceship`: le nouveau vaisseau du joueur
+    pub fn reset(&mut self, spaceship: Spaceship) {
+        self.spaceship = spaceship;
+        if let Some(partner) = &mut self.partner {
+            partner.respawn(self.bounds);
+            partner.invincible = false;
+            partner.upgrades = Upgrades::default();
+        }
+        self.rng = StdRng::seed_from_u64(self.seed);
+        self.apply_difficulty();
+        self.accounting.asteroids.clear(&mut self.asteroids);
+        self.accounting.missiles.clear_pool(&mut self.missiles);
+        self.accounting.black_holes.clear(&mut self.black_holes);
+        self.accounting.ufos.clear(&mut self.ufos);
+        self.accounting.power_ups.clear(&mut self.power_ups);
+        self.temporary_texts.clear();
+        self.decals.clear();
+        self.score = 0;
+        self.partner_score = 0;
+        self.wave = self.start_wave.max(1);
+        self.bombs = BOMB_START_CHARGES;
+        self.wave_countdown = 0.0;
+        self.upgrade_choices = None;
+        self.combo.reset();
+        self.boss = None;
+        self.boss_asteroid = None;
+        self.gold_asteroid = None;
+        self.comets.clear();
+        self.comet_scheduler = CometScheduler::default();
+        self.victory = false;
+        self.stats = RunStats::default();
+        self.set_mode(self.mode);
+        self.start_new_wave();
+    }
+
+    /// Fonction qui renvoie les statistiques de la partie jusqu'ici.
+    /// Les missiles encore en vol comptent comme tirés, sans avoir touché.
+    /// # Returns
+    /// - `RunStats`: les statistiques, avec le score et la vague en cours
+    pub fn run_stats(&self) -> RunStats {
+        let mut stats = self.stats;
+        for missile in self.missiles.iter() {
+            stats.record_missile(missile);
+        }
+        stats.highest_wave = self.wave;
+        stats.score = self.score;
+        stats
+    }
+
+    /// Fonction qui résume la partie terminée sur une ligne, à copier dans un rapport de bug :
+    /// la graine et les réglages permettent de rejouer la même partie avec `--seed`
+    /// # Arguments
+    /// - `reason`: la raison de la fin de la partie
+    /// # Returns
+    /// - `String`: le résumé, en entrées `clé=valeur`
+    pub fn report_line(&self, reason: GameOverReason) -> String {
+        format!(
+            "asteroid {} seed={} mode={} difficulty={} start_wave={} wave={} score={} time={:.1} reason={}",
+            env!("CARGO_PKG_VERSION"),
+            self.seed,
+            self.mode.id(),
+            self.difficulty.id(),
+            self.start_wave,
+            self.wave,
+            self.score,
+            self.stats.time_survived,
+            reason.key()
+        )
+    }
+
+    /// Fonction qui renvoie les vaisseaux de la partie, celui du premier joueur en premier
+    /// # Returns
+    /// - `impl Iterator<Item = &Spaceship>`: un ou deux vaisseaux
+    pub fn ships(&self) -> impl Iterator<Item = &Spaceship> {
+        std::iter::once(&self.spaceship).chain(self.partner.as_ref())
+    }
+
+    /// Fonction qui renvoie les vaisseaux de la partie, modifiables
+    /// # Returns
+    /// - `impl Iterator<Item = &mut Spaceship>`: un ou deux vaisseaux
+    pub fn ships_mut(&mut self) -> impl Iterator<Item = &mut Spaceship> {
+        std::iter::once(&mut self.spaceship).chain(self.partner.as_mut())
+    }
+
+    /// Fonction qui renvoie le vaisseau d'un joueur
+    /// # Arguments
+    /// - `player`: le numéro du joueur
+    /// # Returns
+    /// - `&mut Spaceship`: le vaisseau du second joueur s'il existe et qu'il est demandé,
+    ///   sinon celui du premier joueur
+    fn ship_mut(&mut self, player: usize) -> &mut Spaceship {
+        match &mut self.partner {
+            Some(partner) if player == partner.player => partner,
+            _ => &mut self.spaceship,
+        }
+    }
+
+    /// Fonction qui permet de savoir si tous les vaisseaux ont été détruits
+    /// # Returns
+    /// - `bool`: `true` si plus aucun vaisseau ne vole, la partie est alors perdue
+    pub fn all_ships_down(&self) -> bool {
+        self.ships().all(|ship| ship.dying)
+    }
+
+    /// Fonction qui permet de savoir si l'explosion du dernier vaisseau détruit est terminée
+    /// # Returns
+    /// - `bool`: `true` si tous les vaisseaux ont fini d'exploser
+    pub fn explosion_finished(&self) -> bool {
+        self.ships().all(Spaceship::explosion_finished)
+    }
+
+    /// Fonction qui permet de savoir si la vague est terminée, en mode classique
+    /// # Returns
+    /// - `bool`: `true` s'il ne reste ni asteroide, ni vaisseau mère, ni asteroide géant
+    pub fn is_cleared(&self) -> bool {
+        self.asteroids.is_empty() && self.boss.is_none() && self.boss_asteroid.is_none()
+    }
+
+    /// Fonction qui renvoie la position visée par les ennemis : le premier joueur,
+    /// ou le second quand le premier a été détruit
+    /// # Returns
+    /// - `Vec2`: la position du vaisseau visé
+    fn target(&self) -> Vec2 {
+        self.ships()
+            .find(|ship| !ship.dying)
+            .unwrap_or(&self.spaceship)
+            .get_pos()
+    }
+
+    /// Applique la difficulté de la partie aux vaisseaux, avec la surchauffe de l'arme
+    /// si la difficulté ou `overheat` l'imposent
+    fn apply_difficulty(&mut self) {
+        let invincibility_time = HIT_INVINCIBILITY * self.difficulty.invincibility_factor();
+        let overheat = self.overheat || self.difficulty.overheats();
+        for ship in self.ships_mut() {
+            ship.invincibility_time = invincibility_time;
+            ship.weapon.overheat = overheat;
+        }
+    }
+
+    /// Change le mode de jeu. La jauge d'activité est créée, pleine à moitié,
+    /// seulement pour les modes qui l'utilisent.
+    /// # Arguments
+    /// - `mode`: le nouveau mode de jeu
+    pub fn set_mode(&mut self, mode: GameMode) {
+        self.mode = mode;
+        self.activity = mode.has_activity_meter().then(ActivityMeter::new);
+        self.spawner = EndlessSpawner::default();
+    }
+
+    /// Lance une nouvelle vague d'astéroïdes, accompagnée parfois d'une soucoupe
+    /// et, plus rarement, d'un asteroide doré.
+    /// Les asteroides apparaissent à `SAFE_SPAWN_DISTANCE` du vaisseau au moins,
+    /// et sont d'abord annoncés pendant `INCUBATION_TIME`.
+    /// Pour la première vague, un champ capturé dans `remix` est repris en miroir, puis oublié.
+    /// En mode classique, la vague `BOSS_WAVE` est remplacée par le combat contre le vaisseau mère.
+    /// En mode sans fin, seuls les `START_ASTEROIDS` premiers asteroides arrivent par les bords :
+    /// les suivants viennent avec le temps.
+    pub fn start_new_wave(&mut self) {
+        log::info!("Vague {} ({})", self.wave, self.mode.id());
+        if self.mode == GameMode::Endless {
+            for _ in 0..endless::START_ASTEROIDS {
+                self.spawn_edge_asteroid();
+            }
+            return;
+        }
+        if self.mode == GameMode::Classic && self.wave == BOSS_WAVE {
+            self.boss = Some(Mothership::new(self.bounds));
+            return;
+        }
+        if self.mode == GameMode::Classic && is_boss_asteroid_wave(self.wave) {
+            let avoid = self.spaceship.get_pos();
+            self.boss_asteroid = Some(BossAsteroid::new(self.bounds, avoid, &mut self.rng));
+            return;
+        }
+        if self.wave == 1 && !self.remix.is_empty() {
+            for snapshot in remix(&self.remix, self.bounds) {
+                let mut asteroid =
+                    Asteroid::new_with_size(snapshot.size, snapshot.position, snapshot.velocity);
+                asteroid.incubate(INCUBATION_TIME);
+                self.asteroids.push(asteroid);
+            }
+            self.remix.clear();
+        } else {
+            let num_asteroids =
+                (wave_asteroid_count(self.wave) as i32 + self.difficulty.extra_asteroids()).max(1);
+            for _ in 0..num_asteroids {
+                self.spawn_asteroid();
+            }
+        }
+        if self.rng.gen_bool(self.ufo_spawn_chance.clamp(0.0, 1.0)) {
+            self.ufos.push(Ufo::random(
+                self.bounds,
+                self.textures.ufo.clone(),
+                &mut self.rng,
+            ));
+        }
+        if self.gold_asteroid.is_none() && self.rng.gen_bool(gold_asteroid::SPAWN_CHANCE) {
+            let avoid = self.spaceship.get_pos();
+            self.gold_asteroid = Some(GoldAsteroid::new(self.bounds, avoid, &mut self.rng));
+        }
+    }
+
+    /// Fait apparaître un asteroide loin du vaisseau, d'abord annoncé pendant `INCUBATION_TIME`
+    fn spawn_asteroid(&mut self) {
+        let mut asteroid = Asteroid::new_away_from(
+            self.bounds,
+            self.wave,
+            self.spaceship.get_pos(),
+            SAFE_SPAWN_DISTANCE,
+            &mut self.rng,
+        );
+        asteroid.scale_speed(self.difficulty.speed_factor());
+        asteroid.incubate(INCUBATION_TIME);
+        self.asteroids.push(asteroid);
+    }
+
+    /// Fait arriver un asteroide par un bord de l'écran, en mode sans fin,
+    /// d'abord annoncé pendant `INCUBATION_TIME`
+    fn spawn_edge_asteroid(&mut self) {
+        let mut asteroid = Asteroid::new_from_edge(self.bounds, self.wave, &mut self.rng);
+        asteroid.scale_speed(self.difficulty.speed_factor());
+        asteroid.incubate(INCUBATION_TIME);
+        self.asteroids.push(asteroid);
+    }
+
+    /// Fonction qui renvoie les textures des asteroides, pour les dessiner
+    /// # Returns
+    /// - `&[Texture2D]`: les textures, dont au moins une
+    pub fn asteroid_textures(&self) -> &[Texture2D] {
+        &self.textures.asteroids
+    }
+
+    /// Change la taille de l'aire de jeu, quand la fenêtre change de taille ou passe en plein écran.
+    /// Les vaisseaux sortis de la nouvelle aire de jeu y sont ramenés, au lieu de passer
+    /// à l'autre bout de l'écran. Les autres objets y reviennent en passant d'un bord à l'autre
+    /// à leur prochain déplacement.
+    /// # Arguments
+    /// - `bounds`: la nouvelle taille de l'aire de jeu
+    pub fn set_bounds(&mut self, bounds: Vec2) {
+        if bounds == self.bounds {
+            return;
+        }
+        self.bounds = bounds;
+        self.spaceship.clamp_into(bounds);
+        if let Some(partner) = &mut self.partner {
+            partner.clamp_into(bounds);
+        }
+    }
+
+    /// Fonction qui renvoie le multiplicateur de la vitesse des asteroides de la vague en cours
+    /// # Returns
+    /// - `f32`: le multiplicateur de la vague, multiplié par celui de la difficulté
+    pub fn asteroid_speed_factor(&self) -> f32 {
+        wave_speed_factor(self.wave) * self.difficulty.speed_factor()
+    }
+
+    /// Fonction qui renvoie le chiffre du compte à rebours entre deux vagues
+    /// # Returns
+    /// - `Option<u32>`: 3, 2 puis 1 pendant `WAVE_COUNTDOWN`, ou `None` en dehors du compte à rebours
+    pub fn countdown_step(&self) -> Option<u32> {
+        (self.wave_countdown > 0.0).then(|| {
+            (self.wave_countdown / WAVE_COUNTDOWN * 3.0)
+                .ceil()
+                .clamp(1.0, 3.0) as u32
+        })
+    }
+
+    /// Met à jour la partie pour une image : fin de vague, déplacements, textes temporaires
+    /// et invincibilité du vaisseau.
+    /// En mode sans fin, il n'y a pas de vagues : les asteroides arrivent par les bords de l'écran
+    /// à la cadence de `spawner`, qui accélère avec le temps.
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    pub fn update(&mut self, dt: f32) {
+        match self.mode {
+            GameMode::Classic => {
+                let waiting =
+                    self.victory || self.all_ships_down() || self.upgrade_choices.is_some();
+                if self.is_cleared() && !waiting {
+                    if self.offer_upgrades {
+                        self.upgrade_choices = Some(upgrade::random_choices(&mut self.rng));
+                    } else {
+                        self.transition_wave();
+                    }
+                }
+            }
+            GameMode::Endless => {
+                if self.spawner.update(dt, self.asteroids.len()) {
+                    self.spawn_edge_asteroid();
+                }
+            }
+        }
+
+        self.update_model(dt);
+        self.wave_countdown = (self.wave_countdown - dt).max(0.0);
+        self.combo.update(dt);
+        if !self.all_ships_down() {
+            self.stats.time_survived += dt;
+        }
+        if let Some(activity) = &mut self.activity {
+            activity.record_travel(self.spaceship.velocity.length());
+            activity.update(dt);
+        }
+
+        self.update_temporary_texts(dt);
+
+        let mut restored = Vec::new();
+        for ship in self.ships_mut() {
+            ship.update_timers(dt);
+            if ship.update_shield_regen(dt) {
+                restored.push(SoundEvent::ShieldRestored(ship.get_pos()));
+            }
+        }
+        self.sound_events.extend(restored);
+
+        self.check_leaks(dt);
+    }
+
+    /// Fait vieillir les textes temporaires et rend la place de ceux qui ont disparu
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    fn update_temporary_texts(&mut self, dt: f32) {
+        for text in self.temporary_texts.iter_mut() {
+            text.lifetime -= dt;
+        }
+        self.temporary_texts.release(|text| text.lifetime > 0.0);
+    }
+
+    /// Met à jour les compteurs d'objets et signale les listes qui gardent trop d'objets inactifs
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    fn check_leaks(&mut self, dt: f32) {
+        let accounting = &mut self.accounting;
+        let leaks = [
+            accounting.asteroids.observe(
+                self.asteroids.len(),
+                self.asteroids.iter().filter(|a| a.active).count(),
+                dt,
+            ),
+            accounting.missiles.observe(
+                self.missiles.len(),
+                self.missiles.iter().filter(|m| m.active).count(),
+                dt,
+            ),
+            accounting.black_holes.observe(
+                self.black_holes.len(),
+                self.black_holes.iter().filter(|b| b.active).count(),
+                dt,
+            ),
+            accounting.ufos.observe(
+                self.ufos.len(),
+                self.ufos.iter().filter(|u| u.active).count(),
+                dt,
+            ),
+            accounting.power_ups.observe(
+                self.power_ups.len(),
+                self.power_ups.iter().filter(|p| p.active).count(),
+                dt,
+            ),
+        ];
+        for ((name, counter), leak) in self.accounting.entries().into_iter().zip(leaks) {
+            if leak {
+                report_leak(name, counter);
+            }
+        }
+    }
+
+    /// Applique l'amélioration choisie par le joueur parmi celles proposées à tous les vaisseaux,
+    /// puis passe à la vague suivante
+    /// # Arguments
+    /// - `index`: l'indice de l'amélioration choisie dans `upgrade_choices`
+    /// # Returns
+    /// - `bool`: `true` si une amélioration était proposée à cet indice
+    pub fn choose_upgrade(&mut self, index: usize) -> bool {
+        let Some(kind) = self
+            .upgrade_choices
+            .and_then(|choices| choices.get(index).copied())
+        else {
+            return false;
+        };
+        self.upgrade_choices = None;
+        for ship in self.ships_mut() {
+            ship.upgrades.apply(kind);
+            if kind == UpgradeKind::ShieldCharge {
+                ship.add_shield_charges(SHIELD_CHARGE_BONUS);
+            }
+        }
+        self.transition_wave();
+        true
+    }
+
+    /// Passe à la vague suivante, en appliquant la politique de transition :
+    /// - les missiles en vol terminent leur course, mais ne peuvent pas toucher
+    ///   les asteroides de la nouvelle vague tant qu'ils sont annoncés ;
+    /// - les textes de la vague précédente s'effacent en `TEXT_FADE_TIME` ;
+    /// - le bouclier des vaisseaux regagne `wave_shield_charges` charges, sauf en difficulté difficile,
+    ///   avec une courte invincibilité ;
+    /// - en coopération, le vaisseau détruit revient à sa position de départ ;
+    /// - les asteroides de la nouvelle vague attendent la fin de `WAVE_COUNTDOWN` pour bouger
+    ///   et pour pouvoir être touchés ;
+    /// - le combo retombe à x1 : le premier asteroide de la nouvelle vague commence un nouveau combo.
+    fn transition_wave(&mut self) {
+        for text in self.temporary_texts.iter_mut() {
+            text.lifetime = text.lifetime.min(TEXT_FADE_TIME);
+        }
+        self.show_text(format_args!("+10"), self.spaceship.get_pos(), GOLD, 1.0);
+
+        self.score += 10;
+        if self.partner.is_some() {
+            self.partner_score += 10;
+        }
+        self.wave += 1;
+        if self.wave.is_multiple_of(BOMB_CHARGE_WAVES) {
+            self.bombs += 1;
+        }
+        self.decals.clear();
+        self.combo.reset();
+        let (bounds, refreshes_shield) = (self.bounds, self.difficulty.refreshes_shield());
+        let shield_charges = self.wave_shield_charges;
+        let invincibility = WAVE_INVINCIBILITY * self.difficulty.invincibility_factor();
+        for ship in self.ships_mut() {
+            if ship.dying {
+                ship.respawn(bounds);
+            }
+            if refreshes_shield {
+                ship.add_shield_charges(shield_charges);
+            }
+            ship.invincible = true;
+            ship.hit = false;
+            ship.invincibility_timer = invincibility;
+        }
+        self.sound_events.push(SoundEvent::NewWave);
+        self.wave_countdown = WAVE_COUNTDOWN;
+        self.start_new_wave();
+    }
+
+    /// Met à jour le mouvement des différents objets
+    /// # Arguments
+    /// - `dt`: le temps écoulé depuis la dernière image
+    fn update_model(&mut self, dt: f32) {
+        // Pendant le compte à rebours, les asteroides restent immobiles mais finissent d'être annoncés
+        let frozen = self.wave_countdown > 0.0;
+        let speed_factor = self.asteroid_speed_factor();
+        for asteroid in self.asteroids.iter_mut() {
+            if !frozen {
+                asteroid.move_obj(self.bounds); // Utilisation trait
+            }
+            asteroid.update(dt, speed_factor, &mut self.effects);
+        }
+        self.effects.update(dt);
+        self.accounting
+            .asteroids
+            .retain(&mut self.asteroids, |a| a.is_active());
+
+        merge_black_holes(&mut self.black_holes, self.bounds);
+        self.accounting
+            .black_holes
+            .retain(&mut self.black_holes, |b| b.is_active());
+        for black_hole in self.black_holes.iter_mut() {
+            let room = MAX_ASTEROIDS.saturating_sub(self.asteroids.len());
+            let announced = black_hole.announced();
+            self.asteroids
+                .extend(black_hole.update(dt, room, &mut self.rng));
+            if announced && !black_hole.announced() {
+                self.sound_events
+                    .push(SoundEvent::BlackHoleFormed(black_hole.get_pos()));
+            }
+        }
+
+        let bounds = self.bounds;
+        for ship in self.ships_mut() {
+            ship.move_obj(bounds); // Utilisation trait
+            ship.update_thrust();
+        }
+        if let Some(partner) = &mut self.partner {
+            bounce_ships(&mut self.spaceship, partner, self.bounds);
+        }
+
+        let target = self.target();
+        for ufo in self.ufos.iter_mut() {
+            ufo.move_obj(self.bounds); // Utilisation trait
+            if let Some(missile) = ufo.update(dt, target, &mut self.rng) {
+                self.missiles.push(missile);
+            }
+        }
+        self.accounting
+            .ufos
+            .retain(&mut self.ufos, |u| u.is_active());
+
+        for power_up in self.power_ups.iter_mut() {
+            power_up.move_obj(self.bounds); // Utilisation trait
+            power_up.update(dt);
+        }
+        self.accounting
+            .power_ups
+            .retain(&mut self.power_ups, |p| p.is_active());
+
+        let actions = match &mut self.boss {
+            Some(boss) => {
+                boss.move_obj(self.bounds); // Utilisation trait
+                boss.update(dt, target, self.bounds, &mut self.rng)
+            }
+            None => Vec::new(),
+        };
+        for action in actions {
+            self.apply_boss_action(action);
+        }
+        // L'asteroide géant attend la fin du compte à rebours, comme les autres asteroides
+        if let (false, Some(boss_asteroid)) = (frozen, &mut self.boss_asteroid) {
+            boss_asteroid.update(dt, target, self.bounds);
+            boss_asteroid.move_obj(self.bounds); // Utilisation trait
+        }
+        if let (false, Some(gold_asteroid)) = (frozen, &mut self.gold_asteroid) {
+            gold_asteroid.update(dt, target, self.bounds);
+            gold_asteroid.move_obj(self.bounds); // Utilisation trait
+        }
+        // L'asteroide doré qui n'a pas été détruit à temps disparaît sans rien rapporter
+        if self.gold_asteroid.as_ref().is_some_and(|g| !g.is_active()) {
+            self.gold_asteroid = None;
+        }
+
+        if !frozen && self.comet_scheduler.update(dt, self.wave, &mut self.rng) {
+            self.comets.push(Comet::random(self.bounds, &mut self.rng));
+        }
+        for comet in self.comets.iter_mut() {
+            comet.update(dt, &mut self.effects);
+            comet.move_obj(self.bounds); // Utilisation trait
+        }
+        self.comets.retain(|c| c.is_active());
+
+        for missile in self.missiles.iter_mut() {
+            missile.update(dt, self.missile_lifetime, &self.asteroids, self.bounds);
+            missile.move_obj(self.bounds); // Utilisation trait
+        }
+        // La réserve garde les missiles dans l'ordre des tirs : les plus anciens sont au début
+        let active = self.missiles.iter().filter(|m| m.active).count();
+        for missile in self
+            .missiles
+            .iter_mut()
+            .filter(|m| m.active)
+            .take(active.saturating_sub(self.max_missiles))
+        {
+            missile.active = false;
+        }
+        for missile in self.missiles.iter().filter(|m| !m.is_active()) {
+            self.stats.record_missile(missile);
+        }
+        self.accounting
+            .missiles
+            .release(&mut self.missiles, |m| m.is_active());
+    }
+
+    /// Applique ce que le vaisseau mère demande pendant une image
+    /// # Arguments
+    /// - `action`: l'action du vaisseau mère
+    fn apply_boss_action(&mut self, action: BossAction) {
+        match action {
+            BossAction::SpawnAsteroid { position, velocity } => {
+                if self.asteroids.len() < MAX_ASTEROIDS {
+                    self.asteroids
+                        .push(Asteroid::new_with_size(Size::Small, position, velocity));
+                }
+            }
+            BossAction::Fire(missile) => {
+                self.missiles.push(missile);
+            }
+            BossAction::SpawnBlackHole(pos) => self.spawn_black_hole(pos, Size::Large.scale()),
+            BossAction::PhaseChanged(_) => self.sound_events.push(SoundEvent::BossPhase),
+        }
+    }
+
+    /// Fait apparaitre un trou noir, annoncé puis formé, avec la durée de vie de la partie
+    /// # Arguments
+    /// - `pos`: la position du trou noir
+    /// - `size`: la taille du trou noir
+    fn spawn_black_hole(&mut self, pos: Vec2, size: f32) {
+        let mut black_hole = BlackHole::with_texture(pos, size, self.textures.black_hole.clone());
+        black_hole.lifetime = self.black_hole_lifetime;
+        black_hole.announce(self.black_hole_warning);
+        self.black_holes.push(black_hole);
+        self.stats.black_holes_created += 1;
+        self.sound_events.push(SoundEvent::BlackHoleWarning(pos));
+    }
+
+    /// Gère toutes les collisions de l'image, puis applique leurs conséquences.
+    /// Pendant le compte à rebours entre deux vagues, rien ne peut toucher les asteroides.
+    /// En coopération, la partie continue tant qu'un des deux vaisseaux vole encore.
+    /// # Returns
+    /// - `Option<GameOverReason>`: la raison de la destruction du dernier vaisseau,
+    ///   ou `None` si un vaisseau est toujours en vie
+    pub fn handle_collisions(&mut self) -> Option<GameOverReason> {
+        let mut frozen = Vec::new();
+        let countdown = self.wave_countdown > 0.0;
+        let asteroids = if countdown {
+            &mut frozen
+        } else {
+            &mut self.asteroids
+        };
+        bounce_asteroids(asteroids, self.bounds);
+        let mut events = Vec::new();
+        if let Some(partner) = &mut self.partner {
+            events.extend(check_ship_collisions(
+                partner,
+                asteroids,
+                &mut self.black_holes,
+                self.bounds,
+            ));
+        }
+        events.extend(check_collision(
+            &mut self.spaceship,
+            asteroids,
+            &mut self.missiles,
+            &mut self.black_holes,
+            self.bounds,
+            &mut self.rng,
+        ));
+        let destroyed = |events: &[GameEvent], ship: &Spaceship| {
+            events.iter().any(|event| {
+                matches!(event, GameEvent::ShipDestroyed { player, .. } if *player == ship.player)
+            })
+        };
+        events.extend(check_comet_collisions(
+            &mut self.comets,
+            asteroids,
+            &mut self.missiles,
+            self.bounds,
+        ));
+        let blasts: Vec<GameEvent> = events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::AsteroidExploded { .. }))
+            .copied()
+            .collect();
+        let ships = std::iter::once(&mut self.spaceship).chain(self.partner.as_mut());
+        for ship in ships {
+            if !destroyed(&events, ship) {
+                events.extend(check_blast_collisions(ship, &blasts, self.bounds));
+            }
+            if !destroyed(&events, ship) {
+                events.extend(check_comet_ship_collisions(ship, &self.comets, self.bounds));
+            }
+            if !destroyed(&events, ship) {
+                events.extend(check_ufo_collisions(
+                    ship,
+                    &mut self.ufos,
+                    &mut self.missiles,
+                    self.bounds,
+                ));
+            }
+            if !destroyed(&events, ship) {
+                events.extend(check_power_up_collisions(
+                    ship,
+                    &mut self.power_ups,
+                    self.bounds,
+                ));
+            }
+            if let (false, Some(boss)) = (destroyed(&events, ship), &mut self.boss) {
+                events.extend(check_boss_collisions(
+                    ship,
+                    boss,
+                    &mut self.missiles,
+                    self.bounds,
+                ));
+            }
+            if let (false, false, Some(boss_asteroid)) =
+                (countdown, destroyed(&events, ship), &mut self.boss_asteroid)
+            {
+                events.extend(check_boss_asteroid_collisions(
+                    ship,
+                    boss_asteroid,
+                    &mut self.missiles,
+                    self.bounds,
+                ));
+            }
+            if let (false, Some(gold_asteroid)) =
+                (destroyed(&events, ship), &mut self.gold_asteroid)
+            {
+                events.extend(check_gold_asteroid_collisions(
+                    ship,
+                    gold_asteroid,
+                    &mut self.missiles,
+                    self.bounds,
+                ));
+            }
+        }
+
+        for event in &events {
+            self.apply_event(*event);
+        }
+        let reason = events.iter().find_map(|event| match event {
+            GameEvent::ShipDestroyed { reason, .. } => Some(*reason),
+            _ => None,
+        });
+        reason.filter(|_| self.all_ships_down())
+    }
+
+    /// Applique un événement de la partie : score, textes temporaires, sons et effets
+    /// # Arguments
+    /// - `event`: l'événement produit par les collisions
+    pub fn apply_event(&mut self, event: GameEvent) {
+        log::trace!("Collision : {event:?}");
+        match event {
+            GameEvent::AsteroidDestroyed {
+                pos,
+                size,
+                kind,
+                player,
+            } => {
+                if let Some(activity) = &mut self.activity {
+                    activity.record_kill();
+                }
+                self.stats.record_asteroid(size);
+                self.sound_events.push(SoundEvent::AsteroidDestroyed(pos));
+                if size == Size::Large {
+                    self.effects.flash();
+                    self.effects.shake(LARGE_KILL_SHAKE);
+                }
+                self.decals.push(pos, size.scale() / 2.0);
+                if self.rng.gen_bool(self.power_up_chance.clamp(0.0, 1.0)) {
+                    self.power_ups
+                        .push(PowerUp::random(pos, self.wave, &mut self.rng));
+                }
+                self.effects.explosion(
+                    pos,
+                    size.explosion_particles(),
+                    size.scale() / 25.0,
+                    size.scale() / 25.0,
+                    size.debris_color(),
+                );
+                let multiplier = self.combo.record_kill();
+                let color = ScoreSystem::color(multiplier);
+                let points = kind.score() * multiplier as i32;
+                self.push_score_text(points, player, pos, color, 0.4);
+            }
+            GameEvent::AsteroidDamaged { pos } => {
+                self.sound_events.push(SoundEvent::ArmorHit(pos));
+            }
+            GameEvent::AsteroidExploded { pos, size } => {
+                self.effects.shockwave(pos, size.blast_radius());
+                self.effects.shake(LARGE_KILL_SHAKE);
+            }
+            GameEvent::AsteroidAbsorbed { pos, hole } => {
+                self.sound_events.push(SoundEvent::AsteroidDestroyed(pos));
+                // Le texte est une clé, traduite à l'affichage
+                self.show_text(format_args!("popup.absorbed"), hole, GRAY, 0.4);
+            }
+            GameEvent::ShieldLost { pos, player } => {
+                self.combo.reset();
+                self.effects.shake(SHIELD_LOST_SHAKE);
+                self.sound_events.push(SoundEvent::ShieldLost(pos));
+                self.push_score_text(-5, player, pos, RED, 1.0);
+            }
+            GameEvent::ShipKnockedBack { impulse, player } => {
+                self.ship_mut(player).apply_impulse(impulse)
+            }
+            GameEvent::BlackHoleFormed { pos, size } => self.spawn_black_hole(pos, size.scale()),
+            GameEvent::BlackHoleHit { pos, player } => {
+                self.stats.black_holes_destroyed += 1;
+                self.effects.explosion(
+                    pos,
+                    BLACK_HOLE_BURST,
+                    4.0,
+                    3.0,
+                    Color::new(0.6, 0.3, 0.9, 0.9),
+                );
+                self.push_score_text(black_hole::COLLAPSE_SCORE, player, pos, GREEN, 0.4);
+            }
+            GameEvent::BlackHoleDamaged { pos, player } => {
+                self.push_score_text(black_hole::HIT_SCORE, player, pos, GREEN, 0.4);
+            }
+            GameEvent::UfoDestroyed { pos, player } => {
+                self.sound_events.push(SoundEvent::UfoDestroyed(pos));
+                self.push_score_text(ufo::SCORE_BONUS, player, pos, GOLD, 1.0);
+            }
+            GameEvent::TurretDestroyed { pos, player } => {
+                self.sound_events.push(SoundEvent::TurretDestroyed(pos));
+                self.push_score_text(boss::TURRET_BONUS, player, pos, GOLD, 1.0);
+            }
+            GameEvent::BossDestroyed { pos, player } => {
+                self.sound_events.push(SoundEvent::BossDestroyed(pos));
+                self.effects.flash();
+                self.push_score_text(boss::SCORE_BONUS, player, pos, GOLD, 2.0);
+                self.boss = None;
+                self.boss_asteroid = None;
+                self.victory = true;
+            }
+            GameEvent::BossAsteroidDestroyed { pos, player } => {
+                let Some(boss_asteroid) = self.boss_asteroid.take() else {
+                    return;
+                };
+                self.asteroids.extend(boss_asteroid.split(&mut self.rng));
+                self.sound_events.push(SoundEvent::AsteroidDestroyed(pos));
+                self.effects.flash();
+                self.effects.shake(LARGE_KILL_SHAKE);
+                self.decals.push(pos, Size::Large.scale());
+                self.effects.explosion(
+                    pos,
+                    Size::Large.explosion_particles() * 2,
+                    Size::Large.scale() / 20.0,
+                    Size::Large.scale() / 20.0,
+                    Size::Large.debris_color(),
+                );
+                self.push_score_text(boss_asteroid::SCORE_BONUS, player, pos, GOLD, 2.0);
+            }
+            GameEvent::AsteroidCrushed { pos, size } => {
+                self.sound_events.push(SoundEvent::AsteroidDestroyed(pos));
+                self.decals.push(pos, size.scale());
+                self.effects.explosion(
+                    pos,
+                    size.explosion_particles(),
+                    size.scale() / 20.0,
+                    size.scale() / 20.0,
+                    size.debris_color(),
+                );
+            }
+            GameEvent::GoldAsteroidDestroyed { pos, player } => {
+                self.gold_asteroid = None;
+                self.sound_events.push(SoundEvent::AsteroidDestroyed(pos));
+                self.effects.explosion(
+                    pos,
+                    Size::Small.explosion_particles(),
+                    Size::Small.scale() / 20.0,
+                    Size::Small.scale() / 20.0,
+                    GOLD,
+                );
+                self.push_score_text(gold_asteroid::SCORE_BONUS, player, pos, GOLD, 1.5);
+            }
+            GameEvent::PowerUpCollected { pos, kind, player } => {
+                let ship = self.ship_mut(player);
+                match kind.duration() {
+                    Some(duration) => ship.active_effects.activate(kind, duration),
+                    None => ship.restore_shield(),
+                }
+                self.sound_events.push(SoundEvent::PowerUpCollected(pos));
+                self.show_text(format_args!("{}", kind.label()), pos, kind.color(), 1.0);
+            }
+            GameEvent::ShipDestroyed { player, .. } => {
+                // Le son de fin de partie accompagne l'explosion du dernier vaisseau,
+                // pas l'écran de fin
+                self.combo.reset();
+                let ship = self.ship_mut(player);
+                let pos = ship.get_pos();
+                ship.explode();
+                self.sound_events.push(SoundEvent::ShipDestroyed);
+                if self.all_ships_down() {
+                    self.sound_events.push(SoundEvent::GameOver);
+                }
+                self.effects
+                    .explosion(pos, SHIP_EXPLOSION_PARTICLES, 5.0, 5.0, ORANGE);
+                self.effects
+                    .explosion(pos, SHIP_EXPLOSION_PARTICLES / 2, 2.5, 3.0, YELLOW);
+                self.effects.shockwave(pos, SHIP_EXPLOSION_RADIUS);
+                self.effects.shake(LARGE_KILL_SHAKE);
+                self.effects.flash();
+            }
+        }
+    }
+
+    /// Fait exploser une bombe, si le joueur en a une : tous les asteroides présents sont touchés
+    /// comme par un missile, et chaque trou noir compte un coup de plus.
+    /// Les asteroides encore annoncés ne sont pas touchés.
+    /// # Returns
+    /// - `bool`: `true` si une bombe a explosé
+    pub fn detonate_bomb(&mut self) -> bool {
+        if self.bombs == 0 {
+            return false;
+        }
+        self.bombs -= 1;
+        let center = self.spaceship.get_pos();
+
+        let mut fragments = Vec::new();
+        let mut destroyed = 0;
+        for asteroid in self.asteroids.iter_mut() {
+            // Les asteroides de la vague qui attend son compte à rebours sont épargnés
+            if !asteroid.active || asteroid.incubating() || self.wave_countdown > 0.0 {
+                continue;
+            }
+            asteroid.destroy();
+            destroyed += 1;
+            let (pos, size) = (asteroid.get_pos(), asteroid.get_size());
+            self.stats.record_asteroid(size);
+            if self.bomb_splits {
+                let impact = wrapped_offset(center, pos, self.bounds);
+                if let Some((child1, child2)) = asteroid.split(impact, &mut self.rng) {
+                    fragments.push(child1);
+                    fragments.push(child2);
+                }
+            }
+            self.decals.push(pos, size.scale() / 2.0);
+            self.effects.explosion(
+                pos,
+                size.explosion_particles(),
+                size.scale() / 25.0,
+                size.scale() / 25.0,
+                size.debris_color(),
+            );
+        }
+        self.asteroids.extend(fragments);
+
+        let mut collapsed = Vec::new();
+        for black_hole in self.black_holes.iter_mut().filter(|b| b.solid()) {
+            black_hole.handle_collision();
+            if black_hole.collapsing() {
+                collapsed.push(black_hole.get_pos());
+            }
+        }
+        for pos in collapsed {
+            self.apply_event(GameEvent::BlackHoleHit {
+                pos,
+                player: self.spaceship.player,
+            });
+        }
+
+        self.effects.shockwave(center, self.bounds.length());
+        self.effects.shake(LARGE_KILL_SHAKE);
+        self.sound_events.push(SoundEvent::BombDetonated(center));
+        let points = (destroyed as f32 * BOMB_SCORE_FACTOR) as i32;
+        if points > 0 {
+            self.push_score_text(points, self.spaceship.player, center, GREEN, 1.0);
+        }
+        true
+    }
+
+    /// Ajoute des points au score d'un joueur et affiche le gain ou la perte près de la position donnée.
+    /// En mode sans fin, les points gagnés suivent le multiplicateur de la jauge d'activité.
+    /// # Arguments
+    /// - `points`: les points gagnés, ou perdus s'ils sont négatifs
+    /// - `player`: le numéro du joueur qui gagne ou perd les points
+    /// - `position`: la position de l'objet qui rapporte les points
+    /// - `color`: la couleur du texte
+    /// - `lifetime`: la durée d'affichage du texte
+    fn push_score_text(
+        &mut self,
+        points: i32,
+        player: usize,
+        position: Vec2,
+        color: Color,
+        lifetime: f32,
+    ) {
+        let points = self
+            .activity
+            .map_or(points, |activity| activity.award(points));
+        match &self.partner {
+            Some(partner) if player == partner.player => self.partner_score += points,
+            _ => self.score += points,
+        }
+        self.show_text(format_args!("{points:+}"), position, color, lifetime);
+    }
+
+    /// Affiche un texte temporaire près de la position donnée, dans une place de la réserve.
+    /// Si la réserve est pleine, le texte n'est pas affiché.
+    /// # Arguments
+    /// - `text`: le texte
+    /// - `position`: la position de l'objet concerné par le texte
+    /// - `color`: la couleur du texte
+    /// - `lifetime`: la durée d'affichage du texte
+    fn show_text(&mut self, text: fmt::Arguments, position: Vec2, color: Color, lifetime: f32) {
+        if let Some(slot) = self.temporary_texts.acquire(TemporaryText::default) {
+            slot.set(text, position + Vec2::new(20.0, 20.0), color, lifetime);
+        }
+    }
+}
+
+/// Structure décrivant le contact entre deux objets qui se touchent
+/// # Champs
+/// - `normal`: la direction du premier objet vers le second, de longueur 1
+/// - `overlap`: la profondeur à laquelle les deux objets se chevauchent, en pixels
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CollisionInfo {
+    pub normal: Vec2,
+    pub overlap: f32,
+}
+
+impl CollisionInfo {
+    /// Calcule le contact entre deux objets, sans toucher à leur état.
+    /// Les objets passent d'un bord de l'écran à l'autre, la distance est donc mesurée sur un tore.
+    /// # Arguments
+    /// - `obj1`: contient un objet stellaire
+    /// - `obj2`: contient un autre objet stellaire
+    /// - `bounds`: la taille de l'écran
+    /// # Returns
+    /// - `Option<Self>`: le contact, ou `None` si les objets ne se touchent pas.
+    ///   Deux objets au même endroit ont une normale vers la droite.
+    pub fn between(
+        obj1: &dyn StellarObject,
+        obj2: &dyn StellarObject,
+        bounds: Vec2,
+    ) -> Option<Self> {
+        let offset = wrapped_offset(obj1.get_pos(), obj2.get_pos(), bounds);
+        let overlap = obj1.radius() + obj2.radius() - offset.length();
+        (overlap > 0.0).then(|| Self {
+            normal: offset.try_normalize().unwrap_or(Vec2::X),
+            overlap,
+        })
+    }
+}
+
+///Fonction qui gère la collision entre deux objets et renvoie leur contact.
+/// # Arguments
+/// - `obj1`: contient un objet stellaire
+/// - `obj2`: contient un autre objet stellaire
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Option<CollisionInfo>`: le contact calculé avant `handle_collision`, ou `None` sans collision
+pub fn collide(
+    obj1: &mut dyn StellarObject,
+    obj2: &mut dyn StellarObject,
+    bounds: Vec2,
+) -> Option<CollisionInfo> {
+    let info = CollisionInfo::between(obj1, obj2, bounds)?;
+    obj1.handle_collision();
+    obj2.handle_collision();
+    Some(info)
+}
+
+///Fonction qui gère la collision entre deux objets.
+/// Les objets passent d'un bord de l'écran à l'autre, la distance est donc mesurée sur un tore.
+/// # Arguments
+/// - `obj1`: contient un objet stellaire
+/// - `obj2`: contient un autre objet stellaire
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `bool`: Retourne `true` si il y a une collision sinon `false`.
+pub fn check_collision_between(
+    obj1: &mut dyn StellarObject,
+    obj2: &mut dyn StellarObject,
+    bounds: Vec2,
+) -> bool {
+    collide(obj1, obj2, bounds).is_some()
+}
+
+/// Fonction qui fait rebondir les asteroides qui se touchent les uns sur les autres.
+/// Les rebonds ne détruisent rien et ne produisent aucun événement.
+/// # Arguments
+/// - `asteroids`: contient tous nos asteroides
+/// - `bounds`: la taille de l'écran
+fn bounce_asteroids(asteroids: &mut [Asteroid], bounds: Vec2) {
+    for i in 0..asteroids.len() {
+        let (head, tail) = asteroids.split_at_mut(i + 1);
+        for other in tail.iter_mut() {
+            head[i].bounce(other, bounds);
+        }
+    }
+}
+
+/// Fonction qui fusionne les trous noirs qui se touchent.
+/// Un trou noir agrandi par une fusion peut en toucher un autre : les fusions sont répétées
+/// jusqu'à ce que plus aucun trou noir actif n'en touche un autre.
+/// # Arguments
+/// - `black_holes`: contient tous les trous noirs
+/// - `bounds`: la taille de l'écran
+fn merge_black_holes(black_holes: &mut [BlackHole], bounds: Vec2) {
+    let mut merged = true;
+    while merged {
+        merged = false;
+        for i in 0..black_holes.len() {
+            let (head, tail) = black_holes.split_at_mut(i + 1);
+            for other in tail.iter_mut() {
+                merged |= head[i].merge(other, bounds);
+            }
+        }
+    }
+}
+
+/// Fonction qui fait rebondir deux vaisseaux qui se touchent, en coopération.
+/// Le choc n'abîme aucun des deux : seule la vitesse de rapprochement est échangée,
+/// amortie par `SHIP_RESTITUTION`. Un vaisseau qui explose ne rebondit pas.
+/// # Arguments
+/// - `first`: le vaisseau du premier joueur
+/// - `second`: le vaisseau du second joueur
+/// - `bounds`: la taille de l'écran
+fn bounce_ships(first: &mut Spaceship, second: &mut Spaceship, bounds: Vec2) {
+    if first.dying || second.dying {
+        return;
+    }
+    let Some(contact) = CollisionInfo::between(first, second, bounds) else {
+        return;
+    };
+    // La normale va du premier vaisseau vers le second
+    let closing = (first.velocity - second.velocity).dot(contact.normal);
+    if closing <= 0.0 {
+        return;
+    }
+    let impulse = contact.normal * closing * (1.0 + SHIP_RESTITUTION) / 2.0;
+    first.apply_impulse(-impulse);
+    second.apply_impulse(impulse);
+}
+
+/// Fonction qui gère les collisions d'un vaisseau avec les asteroides et les trous noirs.
+/// Un asteroide qui touche le bouclier forme un trou noir ; un trou noir détruit le vaisseau.
+/// # Arguments
+/// - `spaceship`: contient le vaisseau avec ses propriétés
+/// - `asteroids`: contient tous nos asteroid
+/// - `black_holes`: contient tous les trous noirs
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements de l'image, dans l'ordre où ils se sont produits
+fn check_ship_collisions(
+    spaceship: &mut Spaceship,
+    asteroids: &mut [Asteroid],
+    black_holes: &mut [BlackHole],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    let player = spaceship.player;
+
+    if spaceship.can_be_hit() {
+        // Collision entre Asteroids et  SpaceShip
+        for asteroid in asteroids.iter_mut() {
+            if let Some(contact) = collide(asteroid, spaceship, bounds) {
+                // Le bouclier transforme l'asteroide en trou noir, même s'il est blindé
+                asteroid.destroy();
+                if !spaceship.active {
+                    events.push(GameEvent::ShipDestroyed {
+                        reason: GameOverReason::AsteroidImpact {
+                            size: asteroid.get_size(),
+                        },
+                        player,
+                    });
+                    return events;
+                }
+                events.push(GameEvent::ShieldLost {
+                    pos: spaceship.get_pos(),
+                    player,
+                });
+                // Le bouclier repousse le vaisseau loin de l'asteroide, plus fort pour un gros asteroide
+                events.push(GameEvent::ShipKnockedBack {
+                    impulse: contact.normal * spaceship.knockback * asteroid.get_size().scale(),
+                    player,
+                });
+                events.push(GameEvent::BlackHoleFormed {
+                    pos: asteroid.get_pos(),
+                    size: asteroid.get_size(),
+                });
+
+                // Le vaisseau est maintenant invincible, inutile de tester les autres asteroides
+                break;
+            }
+        }
+    }
+    if spaceship.can_be_hit() {
+        // Collision entre Trou Noir et Spaceship
+        for black_hole in black_holes.iter_mut().filter(|b| b.solid()) {
+            if check_collision_between(black_hole, spaceship, bounds) {
+                events.push(GameEvent::ShipDestroyed {
+                    reason: GameOverReason::BlackHole,
+                    player,
+                });
+                return events;
+            }
+        }
+    }
+
+    events
+}
+
+/// Fonction qui gère toutes les collissions qui peuvent se produire dans le jeu.
+/// L'invincibilité et l'explosion du vaisseau ne protègent que le vaisseau : les collisions entre
+/// asteroides, missiles et trous noirs sont toujours traitées.
+/// Les collisions du vaisseau passent par `check_ship_collisions` ; sa destruction arrête les tests.
+/// Les trous noirs formés par les collisions sont seulement annoncés par un événement.
+/// Les missiles et les trous noirs sont rangés dans une grille : seules les paires proches
+/// sont testées par `check_collision_between`, dans le même ordre qu'en testant toutes les paires.
+/// # Arguments
+/// - `spaceship`: contient notre asteroid avec ses propriétés
+/// - `asteroids`: contient tous nos asteroid
+/// - `missiles`: contient tous nos missiles
+/// - `black_holes`: contient tous les trous noirs
+/// - `bounds`: la taille de l'écran
+/// - `rng`: le générateur de la partie, pour la séparation des asteroides
+/// # Returns
+/// - `Vec<GameEvent>`: les événements de l'image, dans l'ordre où ils se sont produits
+fn check_collision(
+    spaceship: &mut Spaceship,
+    asteroids: &mut Vec<Asteroid>,
+    missiles: &mut [Missile],
+    black_holes: &mut [BlackHole],
+    bounds: Vec2,
+    rng: &mut impl Rng,
+) -> Vec<GameEvent> {
+    let mut events = check_ship_collisions(spaceship, asteroids, black_holes, bounds);
+    if events
+        .iter()
+        .any(|event| matches!(event, GameEvent::ShipDestroyed { .. }))
+    {
+        return events;
+    }
+    let mut asteroids_to_split = Vec::new();
+    let mut blasts = Vec::new();
+    // Les positions ne changent pas pendant les tests, et les rayons ne peuvent que diminuer :
+    // les grilles restent valables jusqu'à la fin de la fonction
+    let asteroid_radius = largest_radius(asteroids);
+    let hole_radius = largest_radius(black_holes);
+    let hole_grid = SpatialGrid::build(
+        black_holes.iter().map(|b| b.get_pos()),
+        bounds,
+        asteroid_radius + hole_radius,
+    );
+    let missile_grid = SpatialGrid::build(
+        missiles.iter().map(|m| m.get_pos()),
+        bounds,
+        asteroid_radius.max(hole_radius) + largest_radius(missiles),
+    );
+    // Collision entre Asteroids et Trou Noir : un asteroide aspiré disparait en entier,
+    // sans se séparer ni rapporter de points, et n'est aspiré que par un seul trou noir.
+    // Sa matière n'est pas perdue pour autant : le trou noir peut la rejeter en petit asteroide.
+    for asteroid in asteroids.iter_mut().filter(|a| a.active) {
+        for k in hole_grid.candidates(asteroid.get_pos()) {
+            let black_hole = &mut black_holes[k];
+            if !black_hole.solid() {
+                continue;
+            }
+            if check_collision_between(asteroid, black_hole, bounds) {
+                // Le blindage ne protège pas d'un trou noir
+                asteroid.destroy();
+                black_hole.capture_asteroid(rng);
+                events.push(GameEvent::AsteroidAbsorbed {
+                    pos: asteroid.get_pos(),
+                    hole: black_hole.get_pos(),
+                });
+                break;
+            }
+        }
+    }
+    // Collision entre Trou Noir et missiles : chaque tir du joueur est récompensé,
+    // le tir qui achève le trou noir l'est davantage
+    for black_hole in black_holes.iter_mut() {
+        for j in missile_grid
+            .candidates(black_hole.get_pos())
+            .into_iter()
+            .rev()
+        {
+            let missile = &mut missiles[j];
+            if !black_hole.solid() || !missile.active {
+                continue;
+            }
+            if check_collision_between(missile, black_hole, bounds)
+                && missile.source == MissileSource::Player
+            {
+                let (pos, player) = (black_hole.get_pos(), missile.player);
+                events.push(if black_hole.solid() {
+                    GameEvent::BlackHoleDamaged { pos, player }
+                } else {
+                    GameEvent::BlackHoleHit { pos, player }
+                });
+            }
+        }
+    }
+    // Collision entre Asteroids et missiles, les asteroides annoncés ne peuvent pas être touchés
+    for asteroid in asteroids.iter_mut().filter(|a| a.active && !a.incubating()) {
+        for j in missile_grid
+            .candidates(asteroid.get_pos())
+            .into_iter()
+            .rev()
+        {
+            let missile = &mut missiles[j];
+            // Les tirs ennemis traversent les asteroides
+            if missile.source != MissileSource::Player {
+                continue;
+            }
+            if check_collision_between(asteroid, missile, bounds) {
+                // Un asteroide blindé encore actif a seulement été abîmé
+                if asteroid.active {
+                    events.push(GameEvent::AsteroidDamaged {
+                        pos: asteroid.get_pos(),
+                    });
+                    break;
+                }
+                events.push(GameEvent::AsteroidDestroyed {
+                    pos: asteroid.get_pos(),
+                    size: asteroid.get_size(),
+                    kind: asteroid.kind(),
+                    player: missile.player,
+                });
+                if asteroid.kind() == AsteroidKind::Volatile {
+                    blasts.push((asteroid.get_pos(), asteroid.get_size(), missile.player));
+                }
+
+                if let Some((child1, child2)) = asteroid.split(missile.velocity, rng) {
+                    asteroids_to_split.push(child1);
+                    asteroids_to_split.push(child2);
+                }
+                break;
+            }
+        }
+    }
+
+    // Les explosions en chaîne sont traitées avant l'ajout des morceaux,
+    // qui ne peuvent donc pas être touchés par le souffle qui les a créés
+    let (blast_events, fragments) = detonate_volatiles(blasts, asteroids, black_holes, bounds, rng);
+    events.extend(blast_events);
+    asteroids_to_split.extend(fragments);
+
+    // Ajouter les nouveaux astéroïdes qui sortent d'un split avec missile à la liste asteroids
+    asteroids.extend(asteroids_to_split);
+
+    events
+}
+
+/// Fonction qui fait exploser les asteroides explosifs détruits pendant l'image.
+/// Le souffle touche une fois chaque asteroide et chaque trou noir à sa portée ;
+/// un asteroide explosif détruit par le souffle explose à son tour. Les explosions sont
+/// traitées une par une dans une file : un asteroide déjà détruit n'est plus touché,
+/// ce qui garantit que la chaîne se termine.
+/// # Arguments
+/// - `blasts`: les explosions de départ, avec leur position, la taille de l'asteroide
+///   et le numéro du joueur qui l'a détruit
+/// - `asteroids`: contient tous nos asteroid
+/// - `black_holes`: contient tous les trous noirs
+/// - `bounds`: la taille de l'écran
+/// - `rng`: le générateur de la partie, pour la séparation des asteroides
+/// # Returns
+/// - `(Vec<GameEvent>, Vec<Asteroid>)`: les événements des explosions,
+///   et les morceaux des asteroides séparés par le souffle
+fn detonate_volatiles(
+    mut blasts: Vec<(Vec2, Size, usize)>,
+    asteroids: &mut [Asteroid],
+    black_holes: &mut [BlackHole],
+    bounds: Vec2,
+    rng: &mut impl Rng,
+) -> (Vec<GameEvent>, Vec<Asteroid>) {
+    let mut events = Vec::new();
+    let mut fragments = Vec::new();
+    while let Some((center, size, player)) = blasts.pop() {
+        events.push(GameEvent::AsteroidExploded { pos: center, size });
+        let radius = size.blast_radius();
+        for asteroid in asteroids.iter_mut().filter(|a| a.active && !a.incubating()) {
+            let pos = asteroid.get_pos();
+            if wrapped_distance(center, pos, bounds) >= radius + asteroid.radius() {
+                continue;
+            }
+            asteroid.handle_collision();
+            if asteroid.active {
+                events.push(GameEvent::AsteroidDamaged { pos });
+                continue;
+            }
+            events.push(GameEvent::AsteroidDestroyed {
+                pos,
+                size: asteroid.get_size(),
+                kind: asteroid.kind(),
+                player,
+            });
+            if let Some((child1, child2)) = asteroid.split(wrapped_offset(center, pos, bounds), rng)
+            {
+                fragments.push(child1);
+                fragments.push(child2);
+            }
+            if asteroid.kind() == AsteroidKind::Volatile {
+                blasts.push((pos, asteroid.get_size(), player));
+            }
+        }
+        for black_hole in black_holes.iter_mut().filter(|b| b.solid()) {
+            let pos = black_hole.get_pos();
+            if wrapped_distance(center, pos, bounds) >= radius + black_hole.radius() {
+                continue;
+            }
+            black_hole.handle_collision();
+            events.push(if black_hole.solid() {
+                GameEvent::BlackHoleDamaged { pos, player }
+            } else {
+                GameEvent::BlackHoleHit { pos, player }
+            });
+        }
+    }
+    (events, fragments)
+}
+
+/// Fonction qui gère le souffle des asteroides explosifs sur un vaisseau :
+/// le vaisseau pris dans un souffle perd son bouclier, ou est détruit s'il n'en a plus.
+/// # Arguments
+/// - `spaceship`: contient notre vaisseau avec ses propriétés
+/// - `events`: les événements de l'image, dont les explosions
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements du vaisseau
+fn check_blast_collisions(
+    spaceship: &mut Spaceship,
+    events: &[GameEvent],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut ship_events = Vec::new();
+    for event in events {
+        let GameEvent::AsteroidExploded { pos, size } = *event else {
+            continue;
+        };
+        if !spaceship.can_be_hit()
+            || wrapped_distance(pos, spaceship.get_pos(), bounds)
+                >= size.blast_radius() + spaceship.radius()
+        {
+            continue;
+        }
+        spaceship.handle_collision();
+        if !spaceship.active {
+            ship_events.push(GameEvent::ShipDestroyed {
+                reason: GameOverReason::AsteroidImpact { size },
+                player: spaceship.player,
+            });
+            break;
+        }
+        ship_events.push(GameEvent::ShieldLost {
+            pos: spaceship.get_pos(),
+            player: spaceship.player,
+        });
+    }
+    ship_events
+}
+
+/// Fonction qui gère les collisions liées aux soucoupes :
+/// les missiles du joueur détruisent les soucoupes, les tirs ennemis touchent le vaisseau.
+/// # Arguments
+/// - `spaceship`: contient notre vaisseau avec ses propriétés
+/// - `ufos`: contient toutes les soucoupes
+/// - `missiles`: contient tous nos missiles, ceux du joueur et ceux des ennemis
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements de l'image, dans l'ordre où ils se sont produits
+fn check_ufo_collisions(
+    spaceship: &mut Spaceship,
+    ufos: &mut [Ufo],
+    missiles: &mut [Missile],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+
+    // Collision entre Soucoupes et missiles du joueur
+    for ufo in ufos.iter_mut().filter(|u| u.active) {
+        for missile in missiles
+            .iter_mut()
+            .filter(|m| m.active && m.source == MissileSource::Player)
+        {
+            if check_collision_between(ufo, missile, bounds) {
+                events.push(GameEvent::UfoDestroyed {
+                    pos: ufo.get_pos(),
+                    player: missile.player,
+                });
+                break;
+            }
+        }
+    }
+
+    if spaceship.can_be_hit() {
+        // Collision entre tirs ennemis et Spaceship
+        for missile in missiles
+            .iter_mut()
+            .filter(|m| m.active && m.source == MissileSource::Enemy)
+        {
+            if check_collision_between(missile, spaceship, bounds) {
+                if !spaceship.active {
+                    events.push(GameEvent::ShipDestroyed {
+                        reason: GameOverReason::EnemyFire,
+                        player: spaceship.player,
+                    });
+                    return events;
+                }
+                events.push(GameEvent::ShieldLost {
+                    pos: spaceship.get_pos(),
+                    player: spaceship.player,
+                });
+                break;
+            }
+        }
+    }
+
+    events
+}
+
+/// Fonction qui gère le ramassage des bonus : le vaisseau ramasse les bonus qu'il touche,
+/// même quand il est invincible, et n'est pas abîmé par eux. Un vaisseau qui explose ne ramasse rien.
+/// # Arguments
+/// - `spaceship`: contient notre vaisseau avec ses propriétés
+/// - `power_ups`: contient tous les bonus
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les bonus ramassés pendant l'image
+fn check_power_up_collisions(
+    spaceship: &Spaceship,
+    power_ups: &mut [PowerUp],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    if spaceship.dying {
+        return events;
+    }
+
+    for power_up in power_ups.iter_mut().filter(|p| p.active) {
+        // Seul le bonus subit la collision, le vaisseau ne perd pas son bouclier
+        if wrapped_distance(spaceship.get_pos(), power_up.get_pos(), bounds)
+            < spaceship.radius() + power_up.radius()
+        {
+            power_up.handle_collision();
+            events.push(GameEvent::PowerUpCollected {
+                pos: power_up.get_pos(),
+                kind: power_up.kind,
+                player: spaceship.player,
+            });
+        }
+    }
+
+    events
+}
+
+/// Fonction qui gère les collisions liées au vaisseau mère :
+/// les missiles du joueur abîment les tourelles, puis le noyau quand il n'est plus protégé,
+/// et le vaisseau qui percute le vaisseau mère perd son bouclier.
+/// # Arguments
+/// - `spaceship`: contient notre vaisseau avec ses propriétés
+/// - `boss`: le vaisseau mère
+/// - `missiles`: contient tous nos missiles, ceux du joueur et ceux des ennemis
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements de l'image, dans l'ordre où ils se sont produits
+fn check_boss_collisions(
+    spaceship: &mut Spaceship,
+    boss: &mut Mothership,
+    missiles: &mut [Missile],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+
+    if spaceship.can_be_hit()
+        && wrapped_distance(spaceship.get_pos(), boss.get_pos(), bounds)
+            < spaceship.radius() + boss.radius()
+    {
+        // Seul le vaisseau subit la collision, le vaisseau mère n'est pas abîmé
+        spaceship.handle_collision();
+        if !spaceship.active {
+            events.push(GameEvent::ShipDestroyed {
+                reason: GameOverReason::Mothership,
+                player: spaceship.player,
+            });
+            return events;
+        }
+        events.push(GameEvent::ShieldLost {
+            pos: spaceship.get_pos(),
+            player: spaceship.player,
+        });
+    }
+
+    // Collision entre Tourelles et missiles du joueur
+    for turret in boss.turrets.iter_mut().filter(|t| t.active) {
+        for missile in missiles
+            .iter_mut()
+            .filter(|m| m.active && m.source == MissileSource::Player)
+        {
+            if check_collision_between(turret, missile, bounds) {
+                if !turret.active {
+                    events.push(GameEvent::TurretDestroyed {
+                        pos: turret.get_pos(),
+                        player: missile.player,
+                    });
+                }
+                break;
+            }
+        }
+    }
+
+    // Collision entre Vaisseau mère et missiles du joueur, le noyau protégé arrête les missiles
+    for missile in missiles
+        .iter_mut()
+        .filter(|m| m.active && m.source == MissileSource::Player)
+    {
+        if !boss.active {
+            break;
+        }
+        if check_collision_between(boss, missile, bounds) && !boss.active {
+            events.push(GameEvent::BossDestroyed {
+                pos: boss.get_pos(),
+                player: missile.player,
+            });
+        }
+    }
+
+    events
+}
+
+/// Fonction qui gère les collisions liées à l'asteroide géant :
+/// chaque missile du joueur lui retire un point de vie, et le vaisseau qui le percute
+/// perd son bouclier sans l'abîmer.
+/// # Arguments
+/// - `spaceship`: contient notre vaisseau avec ses propriétés
+/// - `boss_asteroid`: l'asteroide géant
+/// - `missiles`: contient tous nos missiles, ceux du joueur et ceux des ennemis
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements de l'image, dans l'ordre où ils se sont produits
+fn check_boss_asteroid_collisions(
+    spaceship: &mut Spaceship,
+    boss_asteroid: &mut BossAsteroid,
+    missiles: &mut [Missile],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+
+    if spaceship.can_be_hit()
+        && wrapped_distance(spaceship.get_pos(), boss_asteroid.get_pos(), bounds)
+            < spaceship.radius() + boss_asteroid.radius()
+    {
+        spaceship.handle_collision();
+        if !spaceship.active {
+            events.push(GameEvent::ShipDestroyed {
+                reason: GameOverReason::AsteroidImpact { size: Size::Large },
+                player: spaceship.player,
+            });
+            return events;
+        }
+        events.push(GameEvent::ShieldLost {
+            pos: spaceship.get_pos(),
+            player: spaceship.player,
+        });
+    }
+
+    // Collision entre Asteroide géant et missiles du joueur
+    for missile in missiles
+        .iter_mut()
+        .filter(|m| m.active && m.source == MissileSource::Player)
+    {
+        if !boss_asteroid.active {
+            break;
+        }
+        if check_collision_between(boss_asteroid, missile, bounds) && !boss_asteroid.active {
+            events.push(GameEvent::BossAsteroidDestroyed {
+                pos: boss_asteroid.get_pos(),
+                player: missile.player,
+            });
+        }
+    }
+
+    events
+}
+
+/// Fonction qui gère les collisions des comètes avec les asteroides et les missiles :
+/// une comète pulvérise d'un coup les asteroides qu'elle touche, même blindés, sans les séparer,
+/// et les missiles s'y brisent sans l'abîmer. Une comète encore annoncée ne touche rien.
+/// # Arguments
+/// - `comets`: contient toutes les comètes
+/// - `asteroids`: contient tous nos asteroid
+/// - `missiles`: contient tous nos missiles, ceux du joueur et ceux des ennemis
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements de l'image, dans l'ordre où ils se sont produits
+fn check_comet_collisions(
+    comets: &mut [Comet],
+    asteroids: &mut [Asteroid],
+    missiles: &mut [Missile],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    for comet in comets.iter_mut().filter(|c| c.active && !c.warning()) {
+        for asteroid in asteroids.iter_mut().filter(|a| a.active && !a.incubating()) {
+            if CollisionInfo::between(comet, asteroid, bounds).is_some() {
+                asteroid.destroy();
+                events.push(GameEvent::AsteroidCrushed {
+                    pos: asteroid.get_pos(),
+                    size: asteroid.get_size(),
+                });
+            }
+        }
+        for missile in missiles.iter_mut().filter(|m| m.active) {
+            // La comète ne réagit pas au choc, seul le missile se brise
+            check_collision_between(comet, missile, bounds);
+        }
+    }
+    events
+}
+
+/// Fonction qui gère les collisions d'un vaisseau avec les comètes :
+/// la comète retire le bouclier, ou détruit le vaisseau qui n'en a plus.
+/// # Arguments
+/// - `spaceship`: contient notre vaisseau avec ses propriétés
+/// - `comets`: contient toutes les comètes
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements du vaisseau
+fn check_comet_ship_collisions(
+    spaceship: &mut Spaceship,
+    comets: &[Comet],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    for comet in comets.iter().filter(|c| c.active && !c.warning()) {
+        if !spaceship.can_be_hit() || CollisionInfo::between(comet, spaceship, bounds).is_none() {
+            continue;
+        }
+        spaceship.handle_collision();
+        if !spaceship.active {
+            events.push(GameEvent::ShipDestroyed {
+                reason: GameOverReason::Comet,
+                player: spaceship.player,
+            });
+            break;
+        }
+        events.push(GameEvent::ShieldLost {
+            pos: spaceship.get_pos(),
+            player: spaceship.player,
+        });
+    }
+    events
+}
+
+/// Fonction qui gère les collisions liées à l'asteroide doré :
+/// un missile du joueur le détruit d'un coup, et le vaisseau qui le touche le repousse
+/// sans perdre son bouclier ni former de trou noir.
+/// # Arguments
+/// - `spaceship`: contient notre vaisseau avec ses propriétés
+/// - `gold_asteroid`: l'asteroide doré
+/// - `missiles`: contient tous nos missiles, ceux du joueur et ceux des ennemis
+/// - `bounds`: la taille de l'écran
+/// # Returns
+/// - `Vec<GameEvent>`: les événements de l'image, dans l'ordre où ils se sont produits
+fn check_gold_asteroid_collisions(
+    spaceship: &mut Spaceship,
+    gold_asteroid: &mut GoldAsteroid,
+    missiles: &mut [Missile],
+    bounds: Vec2,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    if !gold_asteroid.active {
+        return events;
+    }
+
+    if !spaceship.dying {
+        if let Some(contact) = CollisionInfo::between(gold_asteroid, spaceship, bounds) {
+            gold_asteroid.bounce(contact.normal);
+        }
+    }
+
+    // Collision entre Asteroide doré et missiles du joueur
+    for missile in missiles
+        .iter_mut()
+        .filter(|m| m.active && m.source == MissileSource::Player)
+    {
+        if check_collision_between(gold_asteroid, missile, bounds) {
+            events.push(GameEvent::GoldAsteroidDestroyed {
+                pos: gold_asteroid.get_pos(),
+                player: missile.player,
+            });
+            break;
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::dummy_texture;
+    use crate::asteroid::AsteroidKind;
+    use crate::comet;
+    use crate::powerup::PowerUpKind;
+    use crate::replay::ReplayFrame;
+    use crate::spaceship::{
+        spawn_position, SpaceshipTextures, KNOCKBACK_FACTOR, MAX_SHIELD_CHARGES, MAX_SPEED,
+    };
+    use crate::weapon::{FIRE_COOLDOWN, MAX_ACTIVE_MISSILES};
+    use ::rand::thread_rng;
+    use std::cmp::Ordering;
+
+    /// Taille de l'écran utilisée par les tests
+    const BOUNDS: Vec2 = vec2(800.0, 600.0);
+
+    /// Crée un vaisseau immobile à la position donnée, sans fenêtre.
+    fn test_spaceship(position: Vec2) -> Spaceship {
+        Spaceship::with_textures(
+            position,
+            SpaceshipTextures {
+                ship: dummy_texture(),
+                shield_on: dummy_texture(),
+                shield_off: dummy_texture(),
+                shield_dead: dummy_texture(),
+            },
+        )
+    }
+
+    /// Crée une partie sans soucoupe, avec un vaisseau immobile à la position donnée, sans fenêtre.
+    fn test_world(ship_pos: Vec2) -> World {
+        let textures = WorldTextures {
+            asteroids: vec![dummy_texture()],
+            black_hole: dummy_texture(),
+            ufo: dummy_texture(),
+        };
+        World::new(test_spaceship(ship_pos), textures, BOUNDS, 0.0)
+    }
+
+    /// Structure représentant un objet avec une position, un rayon, et un état actif/inactif.
+    struct Object {
+        position: Vec2,
+        radius: f32,
+        pub active: bool,
+    }
+
+    impl Object {
+        /// Crée une nouvelle instance d'`Object`.
+        ///
+        /// # Arguments
+        /// - `position`: Position initiale de l'objet.
+        /// - `radius`: Rayon de l'objet.
+        fn new(position: Vec2, radius: f32) -> Self {
+            Self {
+                position,
+                active: true,
+                radius,
+            }
+        }
+    }
+
+    impl StellarObject for Object {
+        fn get_pos(&self) -> Vec2 {
+            self.position
+        }
+
+        fn move_obj(&mut self, _bounds: Vec2) {
+            self.position.x += 10.0;
+            self.position.y += 10.0;
+        }
+
+        fn radius(&self) -> f32 {
+            self.radius
+        }
+
+        fn handle_collision(&mut self) {
+            self.active = false;
+        }
+
+        fn is_active(&self) -> bool {
+            self.active
+        }
+
+        fn debug_fields(&self) -> Vec<(String, String)> {
+            Vec::new()
+        }
+    }
+
+    /// Vérifie que la fonction `check_collision_between` détecte correctement une collision entre deux objets.
+    ///
+    /// # Contexte
+    /// - `obj1` est initialisé à `(50.0, 100.0)` avec un rayon de `20.0`.
+    /// - `obj2` est initialisé à `(20.0, 70.0)` avec un rayon de `30.0`.
+    ///
+    /// # Comportement attendu
+    /// La collision doit être détectée si la distance entre les deux objets est inférieure ou égale à la somme de leurs rayons.
+    ///
+    /// # Panique
+    /// Le test échoue avec le message `"Il n'y a pas de collision !"` si aucune collision n'est détectée.
+    #[test]
+    fn test_check_collision() {
+        let mut obj1 = Object::new(vec2(50.0, 100.0), 20.0);
+        let mut obj2 = Object::new(vec2(20.0, 70.0), 30.0);
+
+        let expected_collision = check_collision_between(&mut obj1, &mut obj2, BOUNDS);
+
+        assert!(expected_collision, "Il n'y a pas de collision !");
+    }
+
+    /// Vérifie que deux objets placés de part et d'autre d'un bord de l'écran entrent en collision,
+    /// alors que leur distance euclidienne est bien plus grande que la somme de leurs rayons.
+    #[test]
+    fn test_check_collision_across_screen_edges() {
+        let mut left = Object::new(vec2(5.0, 300.0), 20.0);
+        let mut right = Object::new(vec2(790.0, 300.0), 20.0);
+        assert!(left.get_pos().distance(right.get_pos()) > 40.0);
+        assert!(
+            check_collision_between(&mut left, &mut right, BOUNDS),
+            "Pas de collision à travers le bord gauche/droit !"
+        );
+
+        let mut top = Object::new(vec2(400.0, 3.0), 10.0);
+        let mut bottom = Object::new(vec2(400.0, 590.0), 10.0);
+        assert!(
+            check_collision_between(&mut top, &mut bottom, BOUNDS),
+            "Pas de collision à travers le bord haut/bas !"
+        );
+
+        let mut far = Object::new(vec2(400.0, 300.0), 10.0);
+        assert!(!check_collision_between(&mut left, &mut far, BOUNDS));
+    }
+
+    /// Vérifie que la méthode `get_pos` retourne correctement la position initiale de l'objet.
+    ///
+    /// # Contexte
+    /// - L'objet est initialisé avec la position `(50.0, 100.0)`.
+    ///
+    /// # Comportement attendu
+    /// La méthode `get_pos` doit retourner exactement la position initiale.
+    ///
+    /// # Panique
+    /// Le test échoue avec le message `"La position retournée par get_pos() est incorrecte !"`
+    /// si la position retournée diffère de la position attendue.
+    #[test]
+    fn test_get_position() {
+        let obj = Object::new(vec2(50.0, 100.0), 30.0);
+
+        let expected_position = vec2(50.0, 100.0);
+
+        assert_eq!(
+            obj.get_pos(),
+            expected_position,
+            "La position retournée par get_pos() est incorrecte !"
+        );
+    }
+
+    /// Vérifie que la méthode `move_obj` déplace correctement l'objet.
+    ///
+    /// # Contexte
+    /// - L'objet est initialisé avec la position `(50.0, 100.0)`.
+    /// - La méthode `move_obj` doit ajouter `10.0` aux coordonnées **x** et **y**.
+    ///
+    /// # Comportement attendu
+    /// Après l'appel à `move_obj`, la nouvelle position doit être `(60.0, 110.0)`.
+    ///
+    /// # Panique
+    /// Le test échoue avec le message `"L'objet n'a pas été déplacé correctement !"`
+    /// si la nouvelle position ne correspond pas à la position attendue.
+    #[test]
+    fn test_move_object() {
+        let mut obj = Object::new(vec2(50.0, 100.0), 30.0);
+
+        let initial_position = obj.get_pos();
+
+        obj.move_obj(BOUNDS);
+
+        let new_position = obj.get_pos();
+
+        assert_eq!(
+            new_position,
+            vec2(initial_position.x + 10.0, initial_position.y + 10.0),
+            "L'objet n'a pas été déplacé correctement !"
+        );
+    }
+
+    /// Vérifie que les missiles détruisent les asteroides pendant l'invincibilité du vaisseau.
+    ///
+    /// # Contexte
+    /// - Le vaisseau est invincible et loin de l'asteroide.
+    /// - Un missile est placé sur un grand asteroide immobile.
+    ///
+    /// # Comportement attendu
+    /// L'asteroide est détruit et séparé en deux, et sa destruction est annoncée.
+    #[test]
+    fn test_missile_hits_asteroid_while_invincible() {
+        let mut spaceship = test_spaceship(vec2(100.0, 100.0));
+        spaceship.invincible = true;
+        spaceship.invincibility_timer = 2.0;
+        let mut asteroids = vec![Asteroid::new_with_size(
+            Size::Large,
+            vec2(400.0, 400.0),
+            Vec2::ZERO,
+        )];
+        let mut missiles = vec![Missile::new(vec2(400.0, 400.0), 0.0)];
+
+        let events = check_collision(
+            &mut spaceship,
+            &mut asteroids,
+            &mut missiles,
+            &mut [],
+            BOUNDS,
+            &mut thread_rng(),
+        );
+
+        assert_eq!(
+            events,
+            vec![GameEvent::AsteroidDestroyed {
+                pos: vec2(400.0, 400.0),
+                size: Size::Large,
+                kind: AsteroidKind::Normal,
+                player: 0
+            }],
+            "L'asteroide détruit doit rapporter un point !"
+        );
+        assert!(!asteroids[0].active, "L'asteroide n'a pas été détruit !");
+        assert!(!missiles[0].active, "Le missile n'a pas été détruit !");
+        let children: Vec<&Asteroid> = asteroids.iter().filter(|a| a.active).collect();
+        assert_eq!(children.len(), 2, "L'asteroide ne s'est pas séparé !");
+        assert!(children.iter().all(|a| a.get_size() == Size::Medium));
+    }
+
+    /// Vérifie que la raison de fin de partie correspond à un asteroide percuté sans bouclier.
+    #[test]
+    fn test_game_over_reason_asteroid_impact() {
+        let mut spaceship = test_spaceship(vec2(100.0, 100.0));
+        spaceship.shield_charges = 0;
+        let mut asteroids = vec![Asteroid::new_with_size(
+            Size::Medium,
+            vec2(110.0, 100.0),
+            Vec2::ZERO,
+        )];
+
+        let events = check_collision(
+            &mut spaceship,
+            &mut asteroids,
+            &mut [],
+            &mut [],
+            BOUNDS,
+            &mut thread_rng(),
+        );
+
+        assert_eq!(
+            events,
+            vec![GameEvent::ShipDestroyed {
+                reason: GameOverReason::AsteroidImpact { size: Size::Medium },
+                player: 0
+            }]
+        );
+    }
+
+    /// Vérifie que la raison de fin de partie correspond à un trou noir.
+    #[test]
+    fn test_game_over_reason_black_hole() {
+        let mut spaceship = test_spaceship(vec2(100.0, 100.0));
+        let mut black_holes = vec![BlackHole::with_texture(
+            vec2(120.0, 100.0),
+            70.0,
+            dummy_texture(),
+        )];
+
+        let events = check_collision(
+            &mut spaceship,
+            &mut Vec::new(),
+            &mut [],
+            &mut black_holes,
+            BOUNDS,
+            &mut thread_rng(),
+        );
+
+        assert_eq!(
+            events,
+            vec![GameEvent::ShipDestroyed {
+                reason: GameOverReason::BlackHole,
+                player: 0
+            }]
+        );
+    }
+
+    /// Vérifie qu'un tir ennemi détruit le vaisseau sans bouclier.
+    #[test]
+    fn test_game_over_reason_enemy_fire() {
+        let mut spaceship = test_spaceship(vec2(100.0, 100.0));
+        spaceship.shield_charges = 0;
+        let mut missiles = vec![Missile::enemy(vec2(110.0, 100.0), 0.0)];
+
+        let events = check_ufo_collisions(&mut spaceship, &mut [], &mut missiles, BOUNDS);
+
+        assert_eq!(
+            events,
+            vec![GameEvent::ShipDestroyed {
+                reason: GameOverReason::EnemyFire,
+                player: 0
+            }]
+        );
+    }
+
+    /// Vérifie qu'un missile du joueur détruit la soucoupe, mais pas un tir ennemi.
+    #[test]
+    fn test_player_missile_destroys_ufo() {
+        let mut spaceship = test_spaceship(vec2(400.0, 400.0));
+        let mut ufos = vec![Ufo::with_texture(vec2(100.0, 100.0), 1.0, dummy_texture())];
+        let mut missiles = vec![
+            Missile::enemy(vec2(100.0, 100.0), 0.0),
+            Missile::new(vec2(105.0, 100.0), 0.0),
+        ];
+
+        let events = check_ufo_collisions(&mut spaceship, &mut ufos, &mut missiles, BOUNDS);
+
+        assert!(!ufos[0].active, "La soucoupe doit être détruite !");
+        assert!(
+            missiles[0].active,
+            "Un tir ennemi ne touche pas sa soucoupe !"
+        );
+        assert_eq!(
+            events,
+            vec![GameEvent::UfoDestroyed {
+                pos: vec2(100.0, 100.0),
+                player: 0
+            }]
+        );
+    }
+
+    /// Vérifie qu'un missile traverse un asteroide annoncé sans le toucher.
+    #[test]
+    fn test_missile_meets_incubating_asteroid() {
+        let mut spaceship = test_spaceship(vec2(100.0, 100.0));
+        let mut asteroid = Asteroid::new_with_size(Size::Small, vec2(400.0, 400.0), Vec2::ZERO);
+        asteroid.incubate(INCUBATION_TIME);
+        let mut asteroids = vec![asteroid];
+        let mut missiles = vec![Missile::new(vec2(400.0, 400.0), 0.0)];
+
+        let events = check_collision(
+            &mut spaceship,
+            &mut asteroids,
+            &mut missiles,
+            &mut [],
+            BOUNDS,
+            &mut thread_rng(),
+        );
+
+        assert_eq!(events, Vec::new());
+        assert!(asteroids[0].active, "L'asteroide annoncé a été touché !");
+        assert!(missiles[0].active, "Le missile doit continuer sa course !");
+    }
+
+    /// Crée le scénario scripté : le vaisseau avec bouclier percute un asteroide moyen,
+    /// un trou noir aspire un petit asteroide et un missile touche un grand asteroide.
+    fn scripted_collisions() -> (Spaceship, Vec<Asteroid>, Vec<Missile>, Vec<BlackHole>) {
+        let asteroid = |size, x, y| Asteroid::new_with_size(size, vec2(x, y), Vec2::ZERO);
+        (
+            test_spaceship(vec2(100.0, 100.0)),
+            vec![
+                asteroid(Size::Medium, 110.0, 100.0),
+                asteroid(Size::Small, 405.0, 100.0),
+                asteroid(Size::Large, 600.0, 400.0),
+            ],
+            vec![Missile::new(vec2(600.0, 400.0), 0.0)],
+            vec![BlackHole::with_texture(
+                vec2(400.0, 100.0),
+                70.0,
+                dummy_texture(),
+            )],
+        )
+    }
+
+    /// Vérifie la suite exacte des événements produits par le scénario scripté.
+    #[test]
+    fn test_scripted_collision_events() {
+        let (mut spaceship, mut asteroids, mut missiles, mut black_holes) = scripted_collisions();
+
+        let events = check_collision(
+            &mut spaceship,
+            &mut asteroids,
+            &mut missiles,
+            &mut black_holes,
+            BOUNDS,
+            &mut thread_rng(),
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                GameEvent::ShieldLost {
+                    pos: vec2(100.0, 100.0),
+                    player: 0
+                },
+                GameEvent::ShipKnockedBack {
+                    impulse: vec2(-KNOCKBACK_FACTOR * Size::Medium.scale(), 0.0),
+                    player: 0
+                },
+                GameEvent::BlackHoleFormed {
+                    pos: vec2(110.0, 100.0),
+                    size: Size::Medium
+                },
+                GameEvent::AsteroidAbsorbed {
+                    pos: vec2(405.0, 100.0),
+                    hole: vec2(400.0, 100.0)
+                },
+                GameEvent::AsteroidDestroyed {
+                    pos: vec2(600.0, 400.0),
+                    size: Size::Large,
+                    kind: AsteroidKind::Normal,
+                    player: 0
+                },
+            ]
+        );
+        assert_eq!(
+            black_holes.len(),
+            1,
+            "Le trou noir est créé par la partie !"
+        );
+    }
+
+    /// Vérifie que la partie traduit les événements du scénario scripté
+    /// en score, textes temporaires, sons et trous noirs.
+    #[test]
+    fn test_events_are_applied_by_world() {
+        let (spaceship, asteroids, missiles, black_holes) = scripted_collisions();
+        let mut world = test_world(spaceship.get_pos());
+        world.spaceship = spaceship;
+        world.asteroids = asteroids;
+        world.missiles.extend(missiles);
+        world.black_holes = black_holes;
+
+        assert_eq!(world.handle_collisions(), None);
+
+        assert_eq!(world.score, -5 + 1);
+        let texts: Vec<&str> = world
+            .temporary_texts
+            .iter()
+            .map(|t| t.text.as_str())
+            .collect();
+        assert_eq!(texts, ["-5", "popup.absorbed", "+1"]);
+        assert_eq!(
+            world.sound_events,
+            vec![
+                SoundEvent::ShieldLost(vec2(100.0, 100.0)),
+                SoundEvent::BlackHoleWarning(vec2(110.0, 100.0)),
+                SoundEvent::AsteroidDestroyed(vec2(405.0, 100.0)),
+                SoundEvent::AsteroidDestroyed(vec2(600.0, 400.0)),
+            ]
+        );
+        assert_eq!(world.black_holes.len(), 2);
+    }
+
+    /// Vérifie qu'un choc sur le bouclier repousse le vaisseau loin de l'asteroide,
+    /// plus fort pour un gros asteroide, sans dépasser la vitesse maximale.
+    #[test]
+    fn test_shield_hit_knocks_ship_away() {
+        let ship = vec2(300.0, 300.0);
+        let mut pushes = Vec::new();
+        for (size, offset) in [
+            (Size::Small, vec2(10.0, 15.0)),
+            (Size::Large, vec2(10.0, 15.0)),
+        ] {
+            let mut world = test_world(ship);
+            world.asteroids = vec![Asteroid::new_with_size(size, ship + offset, Vec2::ZERO)];
+            world.wave_countdown = 0.0;
+
+            assert_eq!(world.handle_collisions(), None);
+
+            let velocity = world.spaceship.velocity;
+            assert!(velocity.dot(-offset) > 0.0, "Le vaisseau doit s'éloigner !");
+            assert!(velocity.angle_between(-offset).abs() < 1e-4);
+            assert!(velocity.length() <= world.spaceship.max_speed + 1e-4);
+            pushes.push(velocity.length());
+        }
+        assert!(pushes[0] < pushes[1]);
+
+        let mut world = test_world(ship);
+        world.spaceship.velocity = vec2(-MAX_SPEED, 0.0);
+        world.apply_event(GameEvent::ShipKnockedBack {
+            impulse: vec2(-KNOCKBACK_FACTOR * Size::Large.scale(), 0.0),
+            player: 0,
+        });
+        assert!((world.spaceship.velocity.length() - MAX_SPEED).abs() < 1e-4);
+    }
+
+    /// Vérifie que la jauge d'activité n'existe qu'en mode sans fin,
+    /// et qu'elle change les points gagnés au moment où ils sont donnés.
+    #[test]
+    fn test_activity_meter_only_in_endless() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        let hit = GameEvent::BlackHoleHit {
+            pos: Vec2::ZERO,
+            player: 0,
+        };
+
+        assert_eq!(world.activity, None);
+        world.apply_event(hit);
+        assert_eq!(world.score, 10);
+
+        world.set_mode(GameMode::Endless);
+        world.score = 0;
+        world.activity = Some(ActivityMeter { level: 0.0 });
+        world.apply_event(hit);
+        assert_eq!(world.score, 5, "Les points sont divisés par deux !");
+        world.activity = Some(ActivityMeter { level: 0.9 });
+        world.apply_event(hit);
+        assert_eq!(world.score, 5 + 13);
+        world.apply_event(GameEvent::ShieldLost {
+            pos: Vec2::ZERO,
+            player: 0,
+        });
+        assert_eq!(world.score, 5 + 13 - 5);
+
+        world.reset(test_spaceship(vec2(100.0, 100.0)));
+        assert_eq!(world.mode, GameMode::Endless);
+        assert_eq!(world.activity, Some(ActivityMeter::new()));
+        world.set_mode(GameMode::Classic);
+        assert_eq!(world.activity, None);
+    }
+
+    /// Vérifie que la vague `BOSS_WAVE` fait apparaître le vaisseau mère à la place des asteroides,
+    /// que la vague ne se termine pas tant qu'il est en vie, et que sa destruction donne la victoire.
+    #[test]
+    fn test_boss_wave_and_victory() {
+        let mut world = test_world(vec2(100.0, 500.0));
+        world.wave = BOSS_WAVE - 1;
+        world.asteroids.clear();
+
+        world.update(1.0 / 60.0);
+        assert_eq!(world.wave, BOSS_WAVE);
+        assert!(world.boss.is_some(), "Le vaisseau mère doit apparaître !");
+        assert!(world.asteroids.is_empty());
+        assert!(!world.is_cleared());
+        world.update(1.0 / 60.0);
+        assert_eq!(
+            world.wave, BOSS_WAVE,
+            "La vague continue pendant le combat !"
+        );
+
+        let boss_pos = world.boss.as_ref().unwrap().get_pos();
+        world.boss.as_mut().unwrap().hp = 1;
+        world.missiles.push(Missile::new(boss_pos, 0.0));
+        assert_eq!(world.handle_collisions(), None);
+
+        assert!(world.victory);
+        assert!(world.boss.is_none());
+        assert!(world.is_cleared());
+        assert_eq!(world.score, 10 + boss::SCORE_BONUS);
+        assert!(world
+            .sound_events
+            .contains(&SoundEvent::BossDestroyed(boss_pos)));
+    }
+
+    /// Vérifie que la cinquième vague fait apparaître l'asteroide géant à la place des asteroides,
+    /// qu'il perd un point de vie par missile, et qu'il se sépare en quatre grands asteroides
+    /// qui doivent être détruits pour finir la vague.
+    #[test]
+    fn test_boss_asteroid_spawn_and_kill() {
+        let mut world = test_world(vec2(400.0, 300.0));
+        world.wave = boss_asteroid::BOSS_ASTEROID_INTERVAL - 1;
+        world.asteroids.clear();
+
+        world.update(0.0);
+        assert_eq!(world.wave, boss_asteroid::BOSS_ASTEROID_INTERVAL);
+        assert!(world.asteroids.is_empty());
+        world.wave_countdown = 0.0;
+        let boss_pos = world.boss_asteroid.as_ref().unwrap().get_pos();
+
+        for hp in (1..boss_asteroid::HP).rev() {
+            world.missiles.push(Missile::new(boss_pos, 0.0));
+            assert_eq!(world.handle_collisions(), None);
+            assert_eq!(world.boss_asteroid.as_ref().unwrap().hp, hp);
+            world.update(0.0);
+            assert_eq!(world.wave, 5, "La vague continue tant qu'il est en vie !");
+        }
+        world.missiles.push(Missile::new(boss_pos, 0.0));
+        world.handle_collisions();
+
+        assert!(world.boss_asteroid.is_none());
+        assert_eq!(world.asteroids.len(), 4);
+        assert!(world.asteroids.iter().all(|a| a.get_size() == Size::Large));
+        assert_eq!(world.score, 10 + boss_asteroid::SCORE_BONUS);
+    }
+
+    /// Vérifie que les tourelles arrêtent les missiles et rapportent un bonus quand elles tombent.
+    #[test]
+    fn test_turrets_protect_core() {
+        let mut spaceship = test_spaceship(vec2(100.0, 500.0));
+        let mut mothership = Mothership::new(BOUNDS);
+        for _ in 0..boss::CORE_HP / 3 {
+            mothership.handle_collision();
+        }
+        mothership.update(1.0 / 60.0, spaceship.get_pos(), BOUNDS, &mut thread_rng());
+        let hp = mothership.hp;
+        let turret_pos = mothership.turrets[0].get_pos();
+        let mut missiles: Vec<Missile> = (0..boss::TURRET_HP)
+            .map(|_| Missile::new(mothership.get_pos(), 0.0))
+            .collect();
+
+        let events = check_boss_collisions(&mut spaceship, &mut mothership, &mut missiles, BOUNDS);
+        assert_eq!(events, Vec::new());
+        assert_eq!(mothership.hp, hp, "Le noyau est protégé !");
+        assert!(missiles.iter().all(|m| !m.active));
+
+        let mut events = Vec::new();
+        for _ in 0..boss::TURRET_HP {
+            let mut missiles = vec![Missile::new(turret_pos, 0.0)];
+            events.extend(check_boss_collisions(
+                &mut spaceship,
+                &mut mothership,
+                &mut missiles,
+                BOUNDS,
+            ));
+        }
+        assert_eq!(
+            events,
+            vec![GameEvent::TurretDestroyed {
+                pos: turret_pos,
+                player: 0
+            }]
+        );
+        assert!(!mothership.core_exposed(), "Il reste une tourelle !");
+    }
+
+    /// Vérifie la politique de transition entre deux vagues : les missiles en vol sont gardés,
+    /// les anciens textes s'effacent vite et les nouveaux asteroides sont annoncés.
+    #[test]
+    fn test_wave_transition_policy() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        world.asteroids.clear();
+        world.missiles.push(Missile::new(vec2(200.0, 100.0), 0.0));
+        world.temporary_texts.push(TemporaryText {
+            text: "+1".to_string(),
+            position: Vec2::ZERO,
+            color: GREEN,
+            lifetime: 1.0,
+        });
+
+        world.update(1.0 / 60.0);
+
+        assert_eq!(world.wave, 2);
+        assert_eq!(
+            world.missiles.len(),
+            1,
+            "Le missile en vol doit être gardé !"
+        );
+        assert!(world.asteroids.iter().all(|a| a.incubating()));
+        let old = &world.temporary_texts[0];
+        assert!(old.text == "+1" && old.lifetime <= TEXT_FADE_TIME);
+        let bonus = &world.temporary_texts[1];
+        assert!(bonus.text == "+10" && bonus.lifetime > TEXT_FADE_TIME);
+    }
+
+    /// Vérifie que la vague suivante attend le choix d'une amélioration,
+    /// et que l'amélioration du bouclier rend une charge avant la nouvelle vague.
+    #[test]
+    fn test_wave_waits_for_upgrade_choice() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        world.offer_upgrades = true;
+        world.difficulty = Difficulty::Hard;
+        world.spaceship.shield_charges = 1;
+        world.asteroids.clear();
+
+        world.update(1.0 / 60.0);
+        world.update(1.0 / 60.0);
+        assert_eq!(world.wave, 1, "La vague suivante doit attendre le choix !");
+        assert!(world.upgrade_choices.is_some());
+        assert!(!world.choose_upgrade(UPGRADE_CHOICES));
+
+        world.upgrade_choices = Some([
+            UpgradeKind::ShieldCharge,
+            UpgradeKind::FireRate,
+            UpgradeKind::TurnRate,
+        ]);
+        assert!(world.choose_upgrade(0));
+        assert_eq!(world.wave, 2);
+        assert!(world.upgrade_choices.is_none());
+        assert_eq!(world.spaceship.shield_charges, 1 + SHIELD_CHARGE_BONUS);
+        assert_eq!(world.spaceship.upgrades, Upgrades::default());
+    }
+
+    /// Vérifie que la flamme du réacteur s'allume pendant la mise à jour qui suit une poussée,
+    /// dans le sens de la poussée, puis s'éteint quand le joueur arrête de pousser.
+    #[test]
+    fn test_thrust_lights_flame_for_one_update() {
+        let mut world = test_world(vec2(100.0, 100.0));
+
+        world.spaceship.apply_thrust(-0.01);
+        assert_eq!(world.spaceship.thrusting, -1);
+        world.update(1.0 / 60.0);
+        assert_eq!(world.spaceship.exhaust, -1);
+        assert_eq!(world.spaceship.thrusting, 0);
+
+        world.update(1.0 / 60.0);
+        assert_eq!(world.spaceship.exhaust, 0, "La flamme doit s'éteindre !");
+    }
+
+    /// Vérifie que le vaisseau sans bouclier ramasse un bonus en passant dessus,
+    /// sans être abîmé, et que le bonus lui rend son bouclier.
+    #[test]
+    fn test_shield_restore_power_up() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        world.asteroids.clear();
+        world.spaceship.shield_charges = 0;
+        world
+            .power_ups
+            .push(PowerUp::new(vec2(110.0, 100.0), PowerUpKind::ShieldRestore));
+
+        assert_eq!(world.handle_collisions(), None);
+
+        assert!(
+            world.spaceship.has_shield(),
+            "Le bouclier doit être rendu !"
+        );
+        assert!(world.spaceship.active);
+        assert!(!world.power_ups[0].active);
+        assert_eq!(
+            world.sound_events,
+            vec![SoundEvent::PowerUpCollected(vec2(110.0, 100.0))]
+        );
+        assert_eq!(world.temporary_texts[0].text, "popup.shield_restore");
+
+        world.update(1.0 / 60.0);
+        assert!(world.power_ups.is_empty());
+    }
+
+    /// Vérifie qu'une bombe touche tous les asteroides présents sauf ceux encore annoncés,
+    /// que les grands se séparent, qu'un trou noir compte un coup, et qu'il faut une charge.
+    #[test]
+    fn test_bomb_clears_the_screen() {
+        let mut world = test_world(vec2(400.0, 300.0));
+        world.asteroids.clear();
+        world.power_up_chance = 0.0;
+        for (size, x) in [
+            (Size::Large, 100.0),
+            (Size::Small, 700.0),
+            (Size::Small, 500.0),
+        ] {
+            world
+                .asteroids
+                .push(Asteroid::new_with_size(size, vec2(x, 100.0), Vec2::ZERO));
+        }
+        world.asteroids[2].incubate(INCUBATION_TIME);
+        world.black_holes.push(BlackHole::with_texture(
+            vec2(200.0, 500.0),
+            70.0,
+            dummy_texture(),
+        ));
+
+        assert!(world.detonate_bomb());
+
+        assert_eq!(world.bombs, 0);
+        assert!(!world.asteroids[0].active && !world.asteroids[1].active);
+        assert!(
+            world.asteroids[2].active,
+            "Un asteroide annoncé n'est pas touché !"
+        );
+        let fragments: Vec<Size> = world.asteroids[3..].iter().map(|a| a.get_size()).collect();
+        assert_eq!(fragments, vec![Size::Medium, Size::Medium]);
+        assert_eq!(world.score, 1);
+        assert_eq!(
+            world.sound_events,
+            vec![SoundEvent::BombDetonated(vec2(400.0, 300.0))]
+        );
+        assert_eq!(world.black_holes[0].counter, 1, "La bombe compte un coup !");
+
+        assert!(!world.detonate_bomb(), "Il n'y a plus de bombe !");
+    }
+
+    /// Vérifie que trois trous noirs empilés fusionnent en un seul, qui a leur aire réunie.
+    #[test]
+    fn test_stacked_black_holes_merge() {
+        let mut black_holes: Vec<BlackHole> = [
+            (vec2(400.0, 300.0), 100.0),
+            (vec2(410.0, 300.0), 70.0),
+            (vec2(405.0, 305.0), 40.0),
+        ]
+        .into_iter()
+        .map(|(pos, size)| BlackHole::with_texture(pos, size, dummy_texture()))
+        .collect();
+        black_holes[0].counter = 3;
+
+        merge_black_holes(&mut black_holes, BOUNDS);
+
+        let active: Vec<&BlackHole> = black_holes.iter().filter(|b| b.active).collect();
+        assert_eq!(active.len(), 1, "Il ne doit rester qu'un trou noir !");
+        let expected_size = (100.0f32.powi(2) + 70.0f32.powi(2) + 40.0f32.powi(2)).sqrt();
+        assert!((active[0].radius() * 2.0 - expected_size).abs() < 1e-3);
+        assert_eq!(active[0].counter, 0);
+        let pos = active[0].get_pos();
+        assert!(pos.x > 400.0 && pos.x < 410.0 && pos.y > 300.0 && pos.y < 305.0);
+    }
+
+    /// Vérifie que cent missiles ajoutés à la suite n'en laissent jamais plus que `max_missiles`
+    /// en vol, en gardant les plus récents. L'arme limite le joueur à `MAX_ACTIVE_MISSILES`
+    /// missiles en vol : seuls les derniers passent par le tir du joueur, les autres sont
+    /// des missiles de soucoupe ajoutés directement à la partie.
+    #[test]
+    fn test_missiles_are_capped() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        // Un seul asteroide, loin et encore annoncé : la vague ne se termine pas
+        // et aucun asteroide ne croise les missiles
+        let mut asteroid = Asteroid::new_with_size(Size::Small, vec2(700.0, 550.0), Vec2::ZERO);
+        asteroid.incubate(60.0);
+        world.asteroids = vec![asteroid];
+        let fire = ReplayFrame {
+            dt: FIRE_COOLDOWN,
+            fire: true,
+            ..ReplayFrame::default()
+        };
+
+        for i in 0..100 {
+            if i < 100 - MAX_ACTIVE_MISSILES {
+                world
+                    .missiles
+                    .push(Missile::enemy(vec2(400.0, 300.0), i as f32 * 0.01));
+            } else {
+                fire.apply(&mut world);
+            }
+            world.update(1.0 / 60.0);
+            assert!(world.missiles.iter().filter(|m| m.active).count() <= missile::MAX_MISSILES);
+        }
+        assert_eq!(world.missiles.len(), missile::MAX_MISSILES);
+        let newest = world.missiles[missile::MAX_MISSILES - MAX_ACTIVE_MISSILES - 1].orientation();
+        assert!((newest - 0.95).abs() < 1e-5);
+        assert!(
+            world.missiles[missile::MAX_MISSILES - MAX_ACTIVE_MISSILES..]
+                .iter()
+                .all(|m| m.source == MissileSource::Player),
+            "Les derniers missiles tirés doivent rester !"
+        );
+    }
+
+    /// Vérifie qu'un trou noir annoncé sous le vaisseau ne peut pas le détruire
+    /// pendant l'annonce, dont la durée se règle, et qu'il le détruit une fois formé.
+    #[test]
+    fn test_black_hole_warning_protects_ship() {
+        let pos = vec2(400.0, 300.0);
+        let mut world = test_world(pos);
+        world.asteroids.clear();
+        world.offer_upgrades = true;
+        world.black_hole_warning = 0.5;
+        world.apply_event(GameEvent::BlackHoleFormed {
+            pos,
+            size: Size::Large,
+        });
+        assert_eq!(world.sound_events, [SoundEvent::BlackHoleWarning(pos)]);
+
+        for _ in 0..29 {
+            assert_eq!(world.handle_collisions(), None);
+            world.update(1.0 / 60.0);
+        }
+        assert!(world.black_holes[0].announced());
+        world.update(1.0 / 60.0);
+        assert!(world
+            .sound_events
+            .contains(&SoundEvent::BlackHoleFormed(pos)));
+        assert_eq!(world.spaceship.get_pos(), pos);
+        assert_eq!(world.handle_collisions(), Some(GameOverReason::BlackHole));
+    }
+
+    /// Vérifie que cinq tirs sur un trou noir rapportent les points des quatre premiers tirs
+    /// et le bonus du tir qui l'achève, une seule fois, et qu'il disparait après son effondrement.
+    #[test]
+    fn test_five_missiles_destroy_a_black_hole() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        world.asteroids.clear();
+        world.black_holes = vec![BlackHole::with_texture(
+            vec2(500.0, 300.0),
+            70.0,
+            dummy_texture(),
+        )];
+
+        for hit in 1..=black_hole::MAX_HITS + 2 {
+            let score = world.score;
+            world.missiles.push(Missile::new(vec2(500.0, 300.0), 0.0));
+            assert_eq!(world.handle_collisions(), None);
+            let expected = match hit.cmp(&black_hole::MAX_HITS) {
+                Ordering::Less => black_hole::HIT_SCORE,
+                Ordering::Equal => black_hole::COLLAPSE_SCORE,
+                Ordering::Greater => 0,
+            };
+            assert_eq!(world.score - score, expected, "Points du tir {hit}");
+        }
+        assert_eq!(
+            world.score,
+            (black_hole::MAX_HITS as i32 - 1) * black_hole::HIT_SCORE + black_hole::COLLAPSE_SCORE
+        );
+
+        assert!(world.black_holes[0].collapsing());
+        for _ in 0..(black_hole::COLLAPSE_TIME * 60.0) as u32 + 2 {
+            world.update(1.0 / 60.0);
+        }
+        assert!(world.black_holes.is_empty());
+    }
+
+    /// Vérifie la précision de la partie : le missile qui sépare un grand asteroide compte
+    /// un seul coup, celui qui abîme un trou noir compte un coup, celui qui sort de l'écran
+    /// un tir manqué, et celui encore en vol un tir sans coup.
+    #[test]
+    fn test_run_stats_count_each_missile_once() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        world.asteroids = vec![Asteroid::new_with_size(
+            Size::Large,
+            vec2(300.0, 300.0),
+            Vec2::ZERO,
+        )];
+        world.black_holes = vec![BlackHole::with_texture(
+            vec2(600.0, 450.0),
+            70.0,
+            dummy_texture(),
+        )];
+        world.missiles.push(Missile::new(vec2(300.0, 300.0), 0.0));
+        world.missiles.push(Missile::new(vec2(600.0, 450.0), 0.0));
+        world.missiles.push(Missile::new(vec2(799.0, 50.0), 0.0));
+        world.missiles.push(Missile::enemy(vec2(799.0, 550.0), 0.0));
+
+        world.handle_collisions();
+        world.update(0.1);
+        world.missiles.push(Missile::new(vec2(100.0, 500.0), 0.0));
+
+        let stats = world.run_stats();
+        assert_eq!(
+            world.asteroids.len(),
+            2,
+            "L'asteroide ne s'est pas séparé !"
+        );
+        assert_eq!(stats.asteroids_destroyed, [1, 0, 0]);
+        assert_eq!((stats.missiles_fired, stats.missile_hits), (4, 2));
+        assert_eq!(stats.accuracy(), Some(50.0));
+        assert_eq!(stats.highest_wave, 1);
+    }
+
+    /// Vérifie que trois asteroides qui tombent dans un trou noir, chacun à son tour,
+    /// produisent exactement un événement chacun.
+    #[test]
+    fn test_each_swallowed_asteroid_is_announced_once() {
+        let mut spaceship = test_spaceship(vec2(100.0, 500.0));
+        let mut black_holes = vec![BlackHole::with_texture(
+            vec2(400.0, 300.0),
+            70.0,
+            dummy_texture(),
+        )];
+        let mut asteroids: Vec<Asteroid> = [
+            (vec2(300.0, 300.0), vec2(2.0, 0.0)),
+            (vec2(560.0, 300.0), vec2(-2.0, 0.0)),
+            (vec2(400.0, 100.0), vec2(0.0, 2.0)),
+        ]
+        .into_iter()
+        .map(|(pos, speed)| Asteroid::new_with_size(Size::Small, pos, speed))
+        .collect();
+
+        let mut absorbed = Vec::new();
+        for _ in 0..120 {
+            for asteroid in asteroids.iter_mut() {
+                asteroid.move_obj(BOUNDS);
+            }
+            let events = check_collision(
+                &mut spaceship,
+                &mut asteroids,
+                &mut [],
+                &mut black_holes,
+                BOUNDS,
+                &mut thread_rng(),
+            );
+            absorbed.extend(events.into_iter().filter_map(|event| match event {
+                GameEvent::AsteroidAbsorbed { hole, .. } => Some(hole),
+                _ => None,
+            }));
+            asteroids.retain(|a| a.is_active());
+        }
+
+        assert_eq!(absorbed, vec![vec2(400.0, 300.0); 3]);
+        assert!(asteroids.is_empty());
+        assert_eq!(black_holes[0].counter, 3);
+    }
+
+    /// Vérifie qu'un asteroide blindé encaisse `ARMORED_HP` missiles : les premiers l'abîment
+    /// sans le séparer, le dernier le sépare et rapporte 3 points.
+    #[test]
+    fn test_armored_asteroid_takes_three_hits() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        let pos = vec2(400.0, 400.0);
+        world.asteroids =
+            vec![Asteroid::new_with_size(Size::Large, pos, Vec2::ZERO)
+                .with_kind(AsteroidKind::Armored)];
+
+        for hp in (1..crate::asteroid::ARMORED_HP).rev() {
+            world.missiles.push(Missile::new(pos, 0.0));
+            assert_eq!(world.handle_collisions(), None);
+            assert_eq!(world.asteroids.len(), 1);
+            assert!(world.asteroids[0].active);
+            assert_eq!(world.asteroids[0].hp(), hp);
+            assert!(world.sound_events.contains(&SoundEvent::ArmorHit(pos)));
+            assert!(!world
+                .sound_events
+                .contains(&SoundEvent::AsteroidDestroyed(pos)));
+        }
+        assert_eq!(world.score, 0);
+
+        world.missiles.push(Missile::new(pos, 0.0));
+        world.handle_collisions();
+        assert!(!world.asteroids[0].active);
+        assert_eq!(world.asteroids.len(), 3, "Le dernier missile le sépare !");
+        assert_eq!(world.score, 3);
+    }
+
+    /// Vérifie qu'un trou noir avale un asteroide blindé d'un coup, sans abîmer son blindage d'abord.
+    #[test]
+    fn test_black_hole_swallows_armored_asteroid() {
+        let mut spaceship = test_spaceship(vec2(100.0, 500.0));
+        let mut black_holes = vec![BlackHole::with_texture(
+            vec2(400.0, 300.0),
+            70.0,
+            dummy_texture(),
+        )];
+        let mut asteroids =
+            vec![
+                Asteroid::new_with_size(Size::Medium, vec2(420.0, 300.0), Vec2::ZERO)
+                    .with_kind(AsteroidKind::Armored),
+            ];
+
+        let events = check_collision(
+            &mut spaceship,
+            &mut asteroids,
+            &mut [],
+            &mut black_holes,
+            BOUNDS,
+            &mut thread_rng(),
+        );
+
+        assert_eq!(
+            events,
+            vec![GameEvent::AsteroidAbsorbed {
+                pos: vec2(420.0, 300.0),
+                hole: vec2(400.0, 300.0)
+            }]
+        );
+        assert!(!asteroids[0].active);
+    }
+
+    /// Vérifie qu'un missile sur le premier de trois asteroides explosifs alignés les détruit
+    /// tous les trois en chaîne, chacun n'étant qu'à portée du souffle de son voisin,
+    /// et que chaque asteroide n'est détruit qu'une seule fois.
+    #[test]
+    fn test_volatile_asteroids_chain() {
+        let mut spaceship = test_spaceship(vec2(100.0, 500.0));
+        let positions = [vec2(300.0, 300.0), vec2(350.0, 300.0), vec2(400.0, 300.0)];
+        let mut asteroids: Vec<Asteroid> = positions
+            .iter()
+            .map(|&pos| {
+                Asteroid::new_with_size(Size::Small, pos, Vec2::ZERO)
+                    .with_kind(AsteroidKind::Volatile)
+            })
+            .collect();
+        let mut missiles = vec![Missile::new(positions[0], 0.0)];
+
+        let events = check_collision(
+            &mut spaceship,
+            &mut asteroids,
+            &mut missiles,
+            &mut [],
+            BOUNDS,
+            &mut thread_rng(),
+        );
+
+        assert!(asteroids.iter().all(|a| !a.active));
+        let destroyed: Vec<Vec2> = events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::AsteroidDestroyed { pos, .. } => Some(*pos),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(destroyed, positions);
+        let blasts = events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::AsteroidExploded { .. }))
+            .count();
+        assert_eq!(blasts, 3);
+        assert!(spaceship.active);
+    }
+
+    /// Vérifie que le bouclier repousse l'asteroide doré sans se perdre,
+    /// et qu'un missile le détruit d'un coup pour `gold_asteroid::SCORE_BONUS` points.
+    #[test]
+    fn test_gold_asteroid_bounces_then_pays() {
+        let mut world = test_world(vec2(100.0, 100.0));
+        world.wave_countdown = 0.0;
+        world.asteroids.clear();
+        let mut gold = GoldAsteroid::with_position(vec2(110.0, 100.0), 0);
+        gold.set_velocity(vec2(-2.0, 0.0));
+        world.gold_asteroid = Some(gold);
+
+        assert_eq!(world.handle_collisions(), None);
+        let gold = world.gold_asteroid.as_ref().unwrap();
+        assert!(
+            gold.velocity().x > 0.0,
+            "Le bouclier doit repousser l'asteroide doré !"
+        );
+        assert_eq!(world.spaceship.shield_charges, MAX_SHIELD_CHARGES);
+        assert!(world.black_holes.is_empty());
+
+        let pos = vec2(500.0, 400.0);
+        world.gold_asteroid = Some(GoldAsteroid::with_position(pos, 0));
+        world.missiles.push(Missile::new(pos, 0.0));
+        world.handle_collisions();
+        assert!(world.gold_asteroid.is_none());
+        assert!(world.asteroids.is_empty(), "Il ne se sépare jamais !");
+        assert_eq!(world.score, gold_asteroid::SCORE_BONUS);
+    }
+
+    /// Vérifie qu'une comète pulvérise un asteroide blindé sans points ni morceaux,
+    /// brise les missiles sans s'abîmer, et détruit le vaisseau sans bouclier.
+    #[test]
+    fn test_comet_crushes_asteroids_and_ship() {
+        let pos = vec2(400.0, 300.0);
+        let mut comet = Comet::new(pos + vec2(24.0, 0.0), Vec2::X);
+        assert_eq!(comet.get_pos(), pos);
+        let mut spaceship = test_spaceship(pos);
+        spaceship.shield_charges = 0;
+        let mut asteroids =
+            vec![Asteroid::new_with_size(Size::Large, pos, Vec2::ZERO)
+                .with_kind(AsteroidKind::Armored)];
+        let mut missiles = vec![Missile::new(pos, 0.0)];
+
+        let events = check_comet_collisions(
+            std::slice::from_mut(&mut comet),
+            &mut asteroids,
+            &mut missiles,
+            BOUNDS,
+        );
+        assert!(events.is_empty(), "Une comète annoncée ne touche rien !");
+        assert!(
+            check_comet_ship_collisions(&mut spaceship, std::slice::from_ref(&comet), BOUNDS)
+                .is_empty()
+        );
+
+        comet.update(comet::WARNING_TIME, &mut Effects::new());
+        let mut comets = vec![comet];
+        let events = check_comet_collisions(&mut comets, &mut asteroids, &mut missiles, BOUNDS);
+        assert_eq!(
+            events,
+            vec![GameEvent::AsteroidCrushed {
+                pos,
+                size: Size::Large
+            }]
+        );
+        assert!(!asteroids[0].active);
+        assert!(!missiles[0].active);
+        assert!(comets[0].active);
+
+        let events = check_comet_ship_collisions(&mut spaceship, &comets, BOUNDS);
+        assert_eq!(
+            events,
+            vec![GameEvent::ShipDestroyed {
+                reason: GameOverReason::Comet,
+                player: 0
+            }]
+        );
+    }
+
+    /// Vérifie que la nouvelle vague attend la fin du compte à rebours pour bouger
+    /// et pour pouvoir être touchée, et que la fin de vague rapporte toujours son bonus.
+    #[test]
+    fn test_new_wave_waits_for_countdown() {
+        let mut world = test_world(vec2(400.0, 300.0));
+        world.asteroids.clear();
+        world.spaceship.shield_charges = 0;
+
+        world.update(0.0);
+        assert_eq!(world.wave, 2);
+        assert_eq!(world.score, 10);
+        assert!(world.spaceship.has_shield());
+        assert_eq!(world.countdown_step(), Some(3));
+        let positions: Vec<Vec2> = world.asteroids.iter().map(|a| a.get_pos()).collect();
+
+        // Un missile posé sur un asteroide ne le touche pas pendant le compte à rebours
+        let target = positions[0];
+        for _ in 0..10 {
+            world.missiles.push(Missile::new(target, 0.0));
+            world.update(0.15);
+            assert_eq!(world.handle_collisions(), None);
+        }
+        assert_eq!(world.countdown_step(), Some(1));
+        assert_eq!(world.score, 10);
+        let frozen: Vec<Vec2> = world.asteroids.iter().map(|a| a.get_pos()).collect();
+        assert_eq!(
+            frozen, positions,
+            "Les asteroides doivent rester immobiles !"
+        );
+
+        for _ in 0..5 {
+            world.update(0.15);
+        }
+        assert_eq!(world.countdown_step(), None);
+        assert!(world.asteroids.iter().all(|a| !a.incubating()));
+        assert_ne!(world.asteroids[0].get_pos(), positions[0]);
+    }
+
+    /// Vérifie que le combo de la vague terminée ne continue pas dans la vague suivante.
+    #[test]
+    fn test_combo_resets_between_waves() {
+        let mut world = test_world(vec2(400.0, 300.0));
+        world.combo.record_kill();
+        assert_eq!(world.combo.record_kill(), 2);
+
+        world.asteroids.clear();
+        world.update(0.0);
+        assert_eq!(world.wave, 2);
+        assert_eq!(world.combo.multiplier, 1);
+        assert_eq!(world.combo.record_kill(), 1);
+    }
+
+    /// Vérifie que la difficulté change le nombre d'asteroides, l'invincibilité
+    /// et le bouclier rendu à la fin des vagues.
+    #[test]
+    fn test_difficulty_presets() {
+        let mut easy = test_world(vec2(400.0, 300.0));
+        easy.difficulty = Difficulty::Easy;
+        easy.reset(test_spaceship(vec2(400.0, 300.0)));
+        assert_eq!(easy.asteroids.len(), 3);
+        assert_eq!(easy.spaceship.invincibility_time, 3.0);
+
+        let mut hard = test_world(vec2(400.0, 300.0));
+        hard.difficulty = Difficulty::Hard;
+        hard.reset(test_spaceship(vec2(400.0, 300.0)));
+        assert_eq!(hard.asteroids.len(), 7);
+        assert!(hard.spaceship.invincibility_time < HIT_INVINCIBILITY);
+
+        hard.asteroids.clear();
+        hard.spaceship.shield_charges = 0;
+        hard.update(0.0);
+        assert_eq!(hard.wave, 2);
+        assert_eq!(hard.score, 10, "Le bonus de fin de vague est gardé !");
+        assert!(
+            !hard.spaceship.has_shield(),
+            "Le bouclier n'est pas rendu en difficile !"
+        );
+    }
+
+    /// Vérifie qu'un missile qui passe dans la marge transparente de la texture d'un asteroide,
+    /// à l'intérieur de l'ancien rayon mais en dehors du rayon de collision, ne le touche pas.
+    #[test]
+    fn test_missile_in_texture_margin_misses() {
+        let mut asteroid = Asteroid::new_with_size(Size::Large, vec2(400.0, 300.0), Vec2::ZERO);
+        let missile_radius = Missile::new(Vec2::ZERO, 0.0).radius();
+        let old_distance = asteroid.visual_radius() + missile_radius;
+        let mut missile = Missile::new(vec2(400.0 + old_distance - 2.0, 300.0), 0.0);
+
+        assert!(!check_collision_between(
+            &mut asteroid,
+            &mut missile,
+            BOUNDS
+        ));
+        assert!(asteroid.active && missile.active);
+
+        let mut missile = Missile::new(vec2(400.0 + asteroid.radius(), 300.0), 0.0);
+        assert!(check_collision_between(&mut asteroid, &mut missile, BOUNDS));
+    }
+
+    /// Vérifie qu'en coopération la partie continue quand un seul vaisseau est détruit,
+    /// que le joueur revient à la vague suivante, et qu'elle finit quand les deux sont détruits.
+    #[test]
+    fn test_coop_ends_when_both_ships_are_destroyed() {
+        let mut world = test_world(vec2(400.0, 300.0));
+        let mut partner = test_spaceship(spawn_position(1, BOUNDS));
+        partner.player = 1;
+        world.partner = Some(partner);
+        world.asteroids.clear();
+        let hole = |pos| BlackHole::with_texture(pos, 30.0, dummy_texture());
+
+        world.black_holes = vec![hole(vec2(400.0, 300.0))];
+        assert_eq!(world.handle_collisions(), None);
+        assert!(world.spaceship.dying);
+        assert!(!world.all_ships_down());
+
+        world.black_holes.clear();
+        world.update(0.0);
+        assert_eq!(world.wave, 2);
+        assert!(
+            !world.spaceship.dying,
+            "Le joueur revient à la vague suivante !"
+        );
+        assert_eq!((world.score, world.partner_score), (10, 10));
+
+        world.spaceship.invincible = false;
+        let partner = world.partner.as_mut().unwrap();
+        partner.invincible = false;
+        world.black_holes = vec![hole(vec2(400.0, 300.0)), hole(vec2(400.0, 380.0))];
+        assert_eq!(world.handle_collisions(), Some(GameOverReason::BlackHole));
+        assert!(world.all_ships_down());
+    }
+
+    /// Vérifie qu'un vaisseau sorti de l'aire de jeu quand elle rétrécit y est ramené,
+    /// et qu'un vaisseau resté dedans ne bouge pas.
+    #[test]
+    fn test_resize_keeps_ship_on_screen() {
+        let mut world = test_world(vec2(780.0, 300.0));
+
+        world.set_bounds(vec2(1000.0, 600.0));
+        assert_eq!(world.spaceship.get_pos(), vec2(780.0, 300.0));
+
+        world.set_bounds(vec2(600.0, 600.0));
+        let position = world.spaceship.get_pos();
+        assert!(position.x < 600.0 - world.spaceship.radius());
+        assert_eq!(position.y, 300.0);
+    }
+
+    /// Vérifie que le résumé de fin de partie donne de quoi rejouer la partie.
+    #[test]
+    fn test_report_line() {
+        let mut world = test_world(vec2(400.0, 300.0));
+        world.seed = 42;
+        world.wave = 7;
+        world.score = 1234;
+
+        let line = world.report_line(GameOverReason::AsteroidImpact { size: Size::Large });
+        assert!(!line.contains('\n'));
+        for entry in [
+            "seed=42",
+            "mode=classic",
+            "difficulty=normal",
+            "wave=7",
+            "score=1234",
+            "reason=reason.asteroid_large",
+        ] {
+            assert!(line.contains(entry), "« {entry} » manque dans « {line} »");
+        }
+    }
+
+    /// Vérifie que deux vaisseaux qui se rentrent dedans rebondissent sans être abîmés.
+    #[test]
+    fn test_ships_bounce_off_each_other() {
+        let mut first = test_spaceship(vec2(100.0, 100.0));
+        let mut second = test_spaceship(vec2(110.0, 100.0));
+        first.velocity = vec2(2.0, 0.0);
+        second.velocity = vec2(-2.0, 0.0);
+
+        bounce_ships(&mut first, &mut second, BOUNDS);
+
+        assert_eq!(first.velocity, vec2(-1.0, 0.0));
+        assert_eq!(second.velocity, vec2(1.0, 0.0));
+        assert!(first.has_shield() && second.has_shield());
+
+        // Les vaisseaux s'éloignent déjà : pas de second rebond
+        bounce_ships(&mut first, &mut second, BOUNDS);
+        assert_eq!(first.velocity, vec2(-1.0, 0.0));
+    }
+}
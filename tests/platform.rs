@@ -0,0 +1,35 @@
+//! Tests d'intégration : sans la fonctionnalité `wasm`, la plateforme garde le comportement du jeu de bureau.
+#![cfg(not(feature = "wasm"))]
+use asteroid::platform::{self, CAN_QUIT};
+use asteroid::world::random_seed;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Vérifie que les données sont écrites dans de vrais fichiers, lisibles par le reste du système.
+#[test]
+fn test_data_is_stored_in_files() {
+    let path = std::env::temp_dir().join("asteroid_test_platform_files.txt");
+
+    platform::write(&path, "score=42\n").unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "score=42\n");
+    fs::write(&path, "score=7\n").unwrap();
+    assert_eq!(platform::read_to_string(&path).unwrap(), "score=7\n");
+
+    fs::remove_file(&path).unwrap();
+    assert!(platform::read_to_string(&path).is_err());
+}
+
+/// Vérifie que l'horloge est celle du système, que le hasard change d'un appel à l'autre
+/// et que le jeu peut se fermer.
+#[test]
+fn test_desktop_clock_entropy_and_quit() {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    assert!(platform::unix_seconds().abs_diff(now) <= 1);
+
+    let seeds: Vec<u64> = (0..4).map(|_| random_seed()).collect();
+    assert!(seeds.windows(2).any(|pair| pair[0] != pair[1]));
+    const { assert!(CAN_QUIT) };
+}
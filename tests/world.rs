@@ -0,0 +1,320 @@
+//! Tests d'intégration : des parties simulées sans fenêtre avec `World`.
+use asteroid::assets::dummy_texture;
+use asteroid::asteroid::{Asteroid, Size};
+use asteroid::audio::SoundEvent;
+use asteroid::game_event::GameEvent;
+use asteroid::game_mode::GameMode;
+use asteroid::game_over::GameOverReason;
+use asteroid::remix::AsteroidSnapshot;
+use asteroid::spaceship::{Spaceship, SpaceshipTextures, DEATH_SEQUENCE_TIME, MAX_SHIELD_CHARGES};
+use asteroid::stellarobject::StellarObject;
+use asteroid::world::{World, WorldTextures};
+use macroquad::prelude::*;
+
+/// Taille de l'écran simulé
+const BOUNDS: Vec2 = vec2(800.0, 600.0);
+/// Durée d'une image simulée, à 60 images par seconde
+const DT: f32 = 1.0 / 60.0;
+
+/// Crée un vaisseau immobile à la position donnée
+fn test_spaceship(position: Vec2) -> Spaceship {
+    Spaceship::with_textures(
+        position,
+        SpaceshipTextures {
+            ship: dummy_texture(),
+            shield_on: dummy_texture(),
+            shield_off: dummy_texture(),
+            shield_dead: dummy_texture(),
+        },
+    )
+}
+
+/// Crée une partie sans soucoupe, avec un vaisseau immobile à la position donnée
+fn test_world(ship_position: Vec2) -> World {
+    let spaceship = test_spaceship(ship_position);
+    let textures = WorldTextures {
+        asteroids: vec![dummy_texture()],
+        black_hole: dummy_texture(),
+        ufo: dummy_texture(),
+    };
+    World::new(spaceship, textures, BOUNDS, 0.0)
+}
+
+/// Joue une vague scriptée : trois petits asteroides immobiles alignés devant le vaisseau,
+/// qui tire dès que son arme le permet. Les asteroides sont détruits à la suite : le combo
+/// leur fait rapporter 1, 2 puis 3 points, et la fin de la vague rapporte 10 points.
+#[test]
+fn test_scripted_wave_score() {
+    let mut world = test_world(vec2(100.0, 300.0));
+    world.asteroids = [300.0, 450.0, 600.0]
+        .into_iter()
+        .map(|x| Asteroid::new_with_size(Size::Small, vec2(x, 300.0), Vec2::ZERO))
+        .collect();
+
+    let mut frames = 0;
+    while world.wave == 1 {
+        assert!(frames < 600, "La vague aurait dû être terminée !");
+        world.spaceship.weapon.update(DT);
+        world.spaceship.fire(&mut world.missiles);
+        assert_eq!(world.handle_collisions(), None);
+        world.update(DT);
+        frames += 1;
+    }
+
+    assert_eq!(world.score, 1 + 2 + 3 + 10);
+    assert_eq!(world.wave, 2);
+    assert_eq!(world.asteroids.len(), 6, "La vague 2 compte 6 asteroides !");
+    assert!(world.spaceship.invincible && world.spaceship.has_shield());
+}
+
+/// Fait tourner une vague aléatoire pendant des centaines d'images sans fenêtre.
+/// Le vaisseau invincible ne peut pas être détruit, et les objets restent dans l'écran.
+#[test]
+fn test_simulation_runs_headless() {
+    let mut world = test_world(vec2(400.0, 300.0));
+
+    for _ in 0..600 {
+        world.spaceship.invincible = true;
+        world.spaceship.invincibility_timer = 1.0;
+        world.spaceship.rotate(0.05);
+        world.spaceship.weapon.update(DT);
+        world.spaceship.fire(&mut world.missiles);
+        assert_eq!(world.handle_collisions(), None);
+        world.update(DT);
+    }
+
+    assert!(world.score >= 0);
+    for asteroid in &world.asteroids {
+        let position = asteroid.get_pos();
+        assert!((0.0..=BOUNDS.x).contains(&position.x) && (0.0..=BOUNDS.y).contains(&position.y));
+    }
+}
+
+/// Vérifie que le champ capturé remplace la première vague, en miroir, une seule fois.
+#[test]
+fn test_remix_replaces_first_wave_once() {
+    let mut world = test_world(vec2(400.0, 300.0));
+    world.remix = vec![AsteroidSnapshot {
+        size: Size::Medium,
+        position: vec2(100.0, 100.0),
+        velocity: vec2(5.0, 0.0),
+    }];
+
+    world.reset(test_spaceship(vec2(400.0, 300.0)));
+
+    assert_eq!(world.asteroids.len(), 1);
+    assert_eq!(world.asteroids[0].get_size(), Size::Medium);
+    assert_eq!(world.asteroids[0].get_pos(), vec2(700.0, 500.0));
+    assert!(world.asteroids[0].incubating());
+    assert!(
+        world.remix.is_empty(),
+        "Le champ capturé est oublié après usage !"
+    );
+
+    world.reset(test_spaceship(vec2(400.0, 300.0)));
+    assert_eq!(world.asteroids.len(), 5);
+}
+
+/// Joue 10 000 images du mode sans fin sans fenêtre, avec un vaisseau invincible qui tire sans arrêt
+/// et un trou noir qui apparaît toutes les 5 secondes.
+/// Les listes d'objets ne doivent pas grossir sans fin : leur capacité reste bornée,
+/// et les compteurs d'objets correspondent aux listes.
+#[test]
+fn test_survival_soak_capacities_stabilize() {
+    let mut world = test_world(vec2(400.0, 300.0));
+    world.set_mode(GameMode::Endless);
+    world.reset(test_spaceship(vec2(400.0, 300.0)));
+
+    let mut capacities = Vec::new();
+    for step in 0..10_000 {
+        world.spaceship.invincible = true;
+        world.spaceship.invincibility_timer = 1.0;
+        world.spaceship.rotate(0.03);
+        world.spaceship.weapon.update(DT);
+        world.spaceship.fire(&mut world.missiles);
+        assert_eq!(world.handle_collisions(), None);
+        world.update(DT);
+        world.sound_events.clear();
+        if step % 300 == 0 {
+            // Un trou noir apparaît régulièrement à l'opposé du vaisseau
+            world.apply_event(GameEvent::BlackHoleFormed {
+                pos: vec2(100.0, 500.0),
+                size: Size::Medium,
+            });
+        }
+        if step == 5_000 {
+            capacities = vec![
+                world.asteroids.capacity(),
+                world.missiles.capacity(),
+                world.black_holes.capacity(),
+                world.temporary_texts.capacity(),
+            ];
+        }
+    }
+
+    let end = [
+        world.asteroids.capacity(),
+        world.missiles.capacity(),
+        world.black_holes.capacity(),
+        world.temporary_texts.capacity(),
+    ];
+    for (mid, end) in capacities.iter().zip(end) {
+        assert!(end <= 128, "Une liste a grossi sans fin : {end} places !");
+        assert!(
+            end <= (mid * 2).max(8),
+            "Une liste grossit encore en fin de partie !"
+        );
+    }
+    assert_eq!(world.accounting.asteroids.len, world.asteroids.len());
+    assert_eq!(
+        world.accounting.missiles.spawned - world.accounting.missiles.despawned,
+        world.missiles.len() as u64
+    );
+}
+
+/// Place un asteroide immobile de la taille donnée à la position donnée
+fn still_asteroid(size: Size, position: Vec2) -> Asteroid {
+    Asteroid::new_with_size(size, position, Vec2::ZERO)
+}
+
+/// Un asteroide qui percute le vaisseau lui coûte une charge du bouclier et 5 points,
+/// et un trou noir se forme à l'endroit de l'impact.
+#[test]
+fn test_first_impact_costs_the_shield() {
+    let mut world = test_world(vec2(400.0, 300.0));
+    world.asteroids = vec![still_asteroid(Size::Large, vec2(400.0, 300.0))];
+
+    assert_eq!(world.handle_collisions(), None);
+
+    assert_eq!(world.spaceship.shield_charges, MAX_SHIELD_CHARGES - 1);
+    assert!(world.spaceship.invincible);
+    assert_eq!(world.score, -5);
+    assert_eq!(world.black_holes.len(), 1);
+    assert!(world
+        .sound_events
+        .contains(&SoundEvent::ShieldLost(vec2(400.0, 300.0))));
+}
+
+/// Quand le bouclier n'a plus qu'une charge, une fois l'invincibilité écoulée,
+/// un second impact sans bouclier termine la partie.
+#[test]
+fn test_second_impact_ends_the_run() {
+    let ship_position = vec2(400.0, 300.0);
+    let mut world = test_world(ship_position);
+    world.spaceship.shield_charges = 1;
+    // Un asteroide éloigné garde la vague en cours pendant l'invincibilité
+    world.asteroids = vec![
+        still_asteroid(Size::Small, vec2(50.0, 50.0)),
+        still_asteroid(Size::Large, ship_position),
+    ];
+    assert_eq!(world.handle_collisions(), None);
+    world.black_holes.clear();
+    world.asteroids.truncate(1);
+
+    let mut frames = 0;
+    while world.spaceship.invincible {
+        assert!(frames < 600, "L'invincibilité aurait dû se terminer !");
+        world.update(DT);
+        frames += 1;
+    }
+    // Le choc sur le bouclier a repoussé le vaisseau
+    let ship_position = world.spaceship.get_pos();
+    world
+        .asteroids
+        .push(still_asteroid(Size::Medium, ship_position));
+
+    assert_eq!(
+        world.handle_collisions(),
+        Some(GameOverReason::AsteroidImpact { size: Size::Medium })
+    );
+}
+
+/// Le vaisseau détruit explose pendant `DEATH_SEQUENCE_TIME` : le son de fin de partie est joué
+/// tout de suite, les asteroides continuent de dériver et rien ne peut plus toucher le vaisseau.
+#[test]
+fn test_destroyed_ship_explodes_before_game_over() {
+    let ship_position = vec2(400.0, 300.0);
+    let mut world = test_world(ship_position);
+    world.spaceship.shield_charges = 0;
+    let drifting = Asteroid::new_with_size(Size::Small, vec2(50.0, 50.0), vec2(1.0, 0.0));
+    world.asteroids = vec![drifting, still_asteroid(Size::Medium, ship_position)];
+
+    assert!(world.handle_collisions().is_some());
+    assert!(world.spaceship.dying);
+    assert!(world.sound_events.contains(&SoundEvent::GameOver));
+    world.sound_events.clear();
+
+    let mut frames = 0;
+    while !world.spaceship.explosion_finished() {
+        assert_eq!(world.handle_collisions(), None);
+        world.update(DT);
+        frames += 1;
+    }
+    let expected = (DEATH_SEQUENCE_TIME / DT).round() as i32;
+    assert!((frames - expected).abs() <= 1);
+    assert!(world.asteroids[0].get_pos().x > 50.0 + expected as f32 * 0.5);
+    assert!(!world.sound_events.contains(&SoundEvent::GameOver));
+}
+
+/// Recommencer après une partie perdue redonne la première vague, un vaisseau neuf au centre
+/// et un score nul, sans trace de la partie précédente.
+#[test]
+fn test_reset_after_game_over_starts_a_fresh_run() {
+    let mut world = test_world(vec2(100.0, 100.0));
+    world.score = 120;
+    world.wave = 4;
+    world.decals.push(vec2(200.0, 200.0), 20.0);
+    world.spaceship.shield_charges = 0;
+
+    world.reset(Spaceship::new(
+        BOUNDS,
+        SpaceshipTextures {
+            ship: dummy_texture(),
+            shield_on: dummy_texture(),
+            shield_off: dummy_texture(),
+            shield_dead: dummy_texture(),
+        },
+    ));
+
+    assert_eq!((world.score, world.wave), (0, 1));
+    assert_eq!(world.spaceship.get_pos(), BOUNDS / 2.0);
+    assert!(world.spaceship.has_shield());
+    assert!(world.decals.items.is_empty());
+    assert_eq!(world.asteroids.len(), 5);
+    assert!(world.asteroids.iter().all(|a| a.incubating()));
+}
+
+/// Joue les premières secondes d'une partie avec une graine donnée et des commandes scriptées :
+/// le vaisseau, invincible, tourne et tire sans arrêt.
+/// # Returns
+/// - `Vec<(Size, Vec2)>`: la taille et la position des asteroides à la fin du script
+fn scripted_run(seed: u64) -> Vec<(Size, Vec2)> {
+    let mut world = test_world(vec2(400.0, 300.0));
+    world.seed = seed;
+    world.reset(test_spaceship(vec2(400.0, 300.0)));
+    for _ in 0..300 {
+        world.spaceship.invincible = true;
+        world.spaceship.invincibility_timer = 1.0;
+        world.spaceship.rotate(0.03);
+        world.spaceship.weapon.update(DT);
+        world.spaceship.fire(&mut world.missiles);
+        world.handle_collisions();
+        world.update(DT);
+    }
+    world
+        .asteroids
+        .iter()
+        .map(|a| (a.get_size(), a.get_pos()))
+        .collect()
+}
+
+/// Vérifie que deux parties avec la même graine et les mêmes commandes ont exactement
+/// les mêmes asteroides, séparations comprises, et qu'une autre graine donne une autre vague.
+#[test]
+fn test_same_seed_replays_first_wave() {
+    let first = scripted_run(42);
+
+    assert!(!first.is_empty());
+    assert_eq!(first, scripted_run(42));
+    assert_ne!(first, scripted_run(43));
+}